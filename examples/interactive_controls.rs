@@ -68,13 +68,13 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         // Dropdowns Section
         .add(Comp::new(Kind::Label).text("🎯 DROPDOWNS (Selection Menus):").size(400.0, 25.0))
         .add(Comp::new(Kind::Label).text("Theme: Light • Dark • Auto").size(400.0, 20.0))
-        .add(Comp::new(Kind::Dropdown).text("▼ Select theme").size(300.0, 30.0))
+        .add(Comp::new(Kind::Dropdown).text("▼ Select theme").options(&["Light", "Dark", "Auto"]).size(300.0, 30.0))
         
         .add(Comp::new(Kind::Label).text("Language: English • Spanish • French • German").size(400.0, 20.0))
-        .add(Comp::new(Kind::Dropdown).text("▼ Choose language").size(300.0, 30.0))
+        .add(Comp::new(Kind::Dropdown).text("▼ Choose language").options(&["English", "Spanish", "French", "German"]).size(300.0, 30.0))
         
         .add(Comp::new(Kind::Label).text("Font Size: Small • Medium • Large • Extra Large").size(400.0, 20.0))
-        .add(Comp::new(Kind::Dropdown).text("▼ Font size").size(300.0, 30.0))
+        .add(Comp::new(Kind::Dropdown).text("▼ Font size").options(&["Small", "Medium", "Large", "Extra Large"]).size(300.0, 30.0))
         
         // TextArea Section
         .add(Comp::new(Kind::Label).text("📝 TEXTAREA (Multi-line Text Input):").size(400.0, 25.0))
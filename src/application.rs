@@ -4,11 +4,94 @@ use crate::core::error::{CocoanutError, Result};
 use objc::runtime::Object;
 use objc::{msg_send, sel, sel_impl};
 use std::ffi::CString;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+#[cfg(not(feature = "test-mock"))]
+use objc::declare::ClassDecl;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Class, Sel, BOOL, NO, YES};
+#[cfg(not(feature = "test-mock"))]
+use objc::class;
+#[cfg(not(feature = "test-mock"))]
+use std::ffi::c_void;
+#[cfg(not(feature = "test-mock"))]
+use std::sync::Once;
+
+type LifecycleHandler = Arc<dyn Fn() + Send + Sync>;
+type OpenFilesHandler = Arc<dyn Fn(Vec<PathBuf>) + Send + Sync>;
+
+/// Lifecycle handlers shared between an `Application` and its delegate
+#[derive(Default)]
+struct LifecycleHandlers {
+    on_launch: Mutex<Option<LifecycleHandler>>,
+    on_will_terminate: Mutex<Option<LifecycleHandler>>,
+    on_reopen: Mutex<Option<LifecycleHandler>>,
+    on_open_files: Mutex<Option<OpenFilesHandler>>,
+    terminate_after_last_window_closed: AtomicBool,
+}
+
+impl LifecycleHandlers {
+    /// Run the handler in `slot`, if any, without holding its lock
+    #[cfg(any(test, not(feature = "test-mock")))]
+    fn notify(slot: &Mutex<Option<LifecycleHandler>>) {
+        let handler = slot.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone();
+        if let Some(handler) = handler {
+            handler();
+        }
+    }
+
+    /// Pass files the system asked the app to open to the handler
+    #[cfg(any(test, not(feature = "test-mock")))]
+    fn open_files(&self, paths: Vec<PathBuf>) {
+        let handler = self
+            .on_open_files
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone();
+        if let Some(handler) = handler {
+            handler(paths);
+        }
+    }
+}
+
+/// How the application appears in the Dock and app switcher
+///
+/// Maps to `NSApplicationActivationPolicy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ActivationPolicy {
+    /// An ordinary app with a Dock icon and menu bar
+    #[default]
+    Regular,
+    /// No Dock icon or menu bar, but the app may show windows; the policy
+    /// for menu-bar-only apps
+    Accessory,
+    /// No Dock icon and no windows; the policy for background agents
+    Prohibited,
+}
+
+impl ActivationPolicy {
+    /// The `NSApplicationActivationPolicy` value
+    pub fn to_ns_policy(self) -> i64 {
+        match self {
+            ActivationPolicy::Regular => 0,
+            ActivationPolicy::Accessory => 1,
+            ActivationPolicy::Prohibited => 2,
+        }
+    }
+}
 
 /// Main application class for managing the macOS application lifecycle
+///
+/// The application installs its own `NSApplicationDelegate`, which runs the
+/// handlers set with [`Application::on_launch`] and friends.
 pub struct Application {
     app: *mut Object,
     name: String,
+    delegate: *mut Object,
+    handlers: Arc<LifecycleHandlers>,
+    menus: Mutex<Vec<crate::menu::Menu>>,
 }
 
 impl Application {
@@ -33,6 +116,9 @@ impl Application {
     /// }
     /// ```
     pub fn new(name: &str) -> Result<Self> {
+        #[cfg(not(feature = "test-mock"))]
+        crate::core::utils::debug_assert_main_thread("Application");
+
         unsafe {
             let app_class = objc::class!(NSApplication);
             let app: *mut Object = msg_send![app_class, sharedApplication];
@@ -49,10 +135,23 @@ impl Application {
             let ns_string_class = objc::class!(NSString);
             let name_nsstring: *mut Object = msg_send![ns_string_class, stringWithUTF8String:name_cstr.as_ptr()];
             let _: () = msg_send![app, setApplicationName: name_nsstring];
+
+            let handlers = Arc::new(LifecycleHandlers::default());
+            #[cfg(not(feature = "test-mock"))]
+            let delegate = {
+                let delegate = new_application_delegate(&handlers);
+                let _: () = msg_send![app, setDelegate: delegate];
+                delegate
+            };
+            #[cfg(feature = "test-mock")]
+            let delegate = std::ptr::null_mut();
             
             Ok(Application {
                 app,
                 name: name.to_string(),
+                delegate,
+                handlers,
+                menus: Mutex::new(Vec::new()),
             })
         }
     }
@@ -62,6 +161,55 @@ impl Application {
         &self.name
     }
     
+    /// Run `handler` once the application has finished launching
+    pub fn on_launch<F>(&mut self, handler: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        *self.handlers.on_launch.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Arc::new(handler));
+    }
+
+    /// Run `handler` just before the application terminates
+    pub fn on_will_terminate<F>(&mut self, handler: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        *self.handlers.on_will_terminate.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Arc::new(handler));
+    }
+
+    /// Run `handler` when the user reopens the running application, for
+    /// example by clicking its Dock icon
+    pub fn on_reopen<F>(&mut self, handler: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        *self.handlers.on_reopen.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Arc::new(handler));
+    }
+
+    /// Run `handler` with the paths of files the system asks the app to open
+    ///
+    /// This is how documents double-clicked in Finder or dropped on the Dock
+    /// icon reach the app. The app's `Info.plist` must declare the document
+    /// types it opens.
+    pub fn on_open_files<F>(&mut self, handler: F)
+    where
+        F: Fn(Vec<PathBuf>) + Send + Sync + 'static,
+    {
+        *self.handlers.on_open_files.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Arc::new(handler));
+    }
+
+    /// Set whether the application quits once its last window is closed
+    ///
+    /// Off by default, as in AppKit.
+    pub fn set_terminates_after_last_window_closed(&mut self, terminates: bool) {
+        self.handlers.terminate_after_last_window_closed.store(terminates, Ordering::SeqCst);
+    }
+
+    /// Check if the application quits once its last window is closed
+    pub fn terminates_after_last_window_closed(&self) -> bool {
+        self.handlers.terminate_after_last_window_closed.load(Ordering::SeqCst)
+    }
+    
     /// Run the application with the main window
     /// 
     /// # Arguments
@@ -83,12 +231,94 @@ impl Application {
         }
     }
     
+    /// Add `menu` to the end of the menu bar, such as the one from
+    /// [`edit_menu`](crate::menu::edit_menu)
+    ///
+    /// The application keeps the menu, and the handlers of its items, alive.
+    /// The first call creates the menu bar, starting with an application
+    /// menu that holds Quit.
+    pub fn add_menu(&self, menu: crate::menu::Menu) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let mut main_menu: *mut Object = msg_send![self.app, mainMenu];
+            if main_menu.is_null() {
+                // AppKit shows the first item under the application name,
+                // so give it the application menu before any other
+                let process: *mut Object = msg_send![class!(NSProcessInfo), processInfo];
+                let name: *mut Object = msg_send![process, processName];
+                let name = crate::core::utils::ns_string_to_string(name)?;
+                let quit = crate::core::utils::string_to_ns_string(&format!("Quit {}", name))?;
+                let q = crate::core::utils::string_to_ns_string("q")?;
+                main_menu = msg_send![class!(NSMenu), new];
+                let app_item: *mut Object = msg_send![class!(NSMenuItem), new];
+                let app_menu: *mut Object = msg_send![class!(NSMenu), new];
+                let _: *mut Object = msg_send![app_menu, addItemWithTitle: quit action: sel!(terminate:) keyEquivalent: q];
+                let _: () = msg_send![app_item, setSubmenu: app_menu];
+                let _: () = msg_send![main_menu, addItem: app_item];
+                let _: () = msg_send![app_menu, release];
+                let _: () = msg_send![app_item, release];
+                let _: () = msg_send![self.app, setMainMenu: main_menu];
+                let _: () = msg_send![main_menu, release];
+            }
+            let title = crate::core::utils::string_to_ns_string(menu.title())?;
+            let empty = crate::core::utils::string_to_ns_string("")?;
+            let nil: *mut Object = std::ptr::null_mut();
+            let item: *mut Object = msg_send![main_menu, addItemWithTitle: title action: nil keyEquivalent: empty];
+            let _: () = msg_send![item, setSubmenu: menu.ns_menu()];
+        }
+        self.menus.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).push(menu);
+        Ok(())
+    }
+
     /// Terminate the application
+    ///
+    /// Sends `terminate:` to `NSApp`, which runs the `on_will_terminate`
+    /// handler before the process exits.
     pub fn terminate(&self) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
         unsafe {
             let _: () = msg_send![self.app, terminate: self.app];
-            Ok(())
         }
+        Ok(())
+    }
+
+    /// Set how the application appears in the Dock and app switcher
+    ///
+    /// Use [`ActivationPolicy::Accessory`] for menu-bar-only apps so no Dock
+    /// icon appears.
+    pub fn set_activation_policy(&self, policy: ActivationPolicy) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let accepted: bool = msg_send![self.app, setActivationPolicy: policy.to_ns_policy()];
+            if !accepted {
+                return Err(CocoanutError::SystemError(
+                    format!("Failed to set activation policy {:?}", policy)
+                ));
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = policy;
+        Ok(())
+    }
+
+    /// Hide all of the application's windows
+    pub fn hide(&self) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let nil: *mut Object = std::ptr::null_mut();
+            let _: () = msg_send![self.app, hide: nil];
+        }
+        Ok(())
+    }
+
+    /// Show the application's windows again after [`Application::hide`]
+    pub fn unhide(&self) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let nil: *mut Object = std::ptr::null_mut();
+            let _: () = msg_send![self.app, unhide: nil];
+        }
+        Ok(())
     }
     
     /// Check if the application is running
@@ -102,9 +332,226 @@ impl Application {
 
 impl Drop for Application {
     fn drop(&mut self) {
-        // Application cleanup is handled by the system
+        if !self.delegate.is_null() {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                let current: *mut Object = msg_send![self.app, delegate];
+                if current == self.delegate {
+                    let nil: *mut Object = std::ptr::null_mut();
+                    let _: () = msg_send![self.app, setDelegate: nil];
+                }
+                let slot = *(*self.delegate).get_ivar::<*mut c_void>("rustHandlers");
+                (*self.delegate).set_ivar::<*mut c_void>("rustHandlers", std::ptr::null_mut());
+                if !slot.is_null() {
+                    drop(Box::from_raw(slot as *mut Arc<LifecycleHandlers>));
+                }
+                let _: () = msg_send![self.delegate, release];
+            }
+        }
     }
 }
 
+/// Create an application delegate running `handlers`
+#[cfg(not(feature = "test-mock"))]
+unsafe fn new_application_delegate(handlers: &Arc<LifecycleHandlers>) -> *mut Object {
+    unsafe {
+        let slot = Box::into_raw(Box::new(Arc::clone(handlers))) as *mut c_void;
+        let delegate: *mut Object = msg_send![application_delegate_class(), new];
+        (*delegate).set_ivar::<*mut c_void>("rustHandlers", slot);
+        delegate
+    }
+}
+
+/// The application delegate used by apps without an [`Application`], such
+/// as `SimpleApp`; it quits once the last window is closed
+///
+/// `NSApplication` does not retain its delegate, so the instance lives for
+/// the rest of the process.
+#[cfg(not(feature = "test-mock"))]
+pub(crate) fn quit_after_last_window_delegate() -> *mut Object {
+    use std::sync::OnceLock;
+
+    struct Delegate(*mut Object);
+    // Only ever handed to NSApplication; never dereferenced from Rust
+    unsafe impl Send for Delegate {}
+    unsafe impl Sync for Delegate {}
+
+    static DELEGATE: OnceLock<Delegate> = OnceLock::new();
+    DELEGATE
+        .get_or_init(|| {
+            let handlers = Arc::new(LifecycleHandlers::default());
+            handlers.terminate_after_last_window_closed.store(true, Ordering::SeqCst);
+            Delegate(unsafe { new_application_delegate(&handlers) })
+        })
+        .0
+}
+
+/// Get the handlers stored on an application delegate
+#[cfg(not(feature = "test-mock"))]
+unsafe fn delegate_handlers(delegate: &Object) -> Option<&Arc<LifecycleHandlers>> {
+    let slot = unsafe { *delegate.get_ivar::<*mut c_void>("rustHandlers") } as *const Arc<LifecycleHandlers>;
+    unsafe { slot.as_ref() }
+}
+
+/// Lazily register the `NSApplicationDelegate` class that runs lifecycle handlers
+#[cfg(not(feature = "test-mock"))]
+fn application_delegate_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutApplicationDelegate", class!(NSObject))
+            .expect("CocoanutApplicationDelegate already registered");
+        decl.add_ivar::<*mut c_void>("rustHandlers");
+
+        extern "C" fn did_finish_launching(this: &Object, _cmd: Sel, _notification: *mut Object) {
+            if let Some(handlers) = unsafe { delegate_handlers(this) } {
+                LifecycleHandlers::notify(&handlers.on_launch);
+            }
+        }
+
+        extern "C" fn will_terminate(this: &Object, _cmd: Sel, _notification: *mut Object) {
+            if let Some(handlers) = unsafe { delegate_handlers(this) } {
+                LifecycleHandlers::notify(&handlers.on_will_terminate);
+            }
+        }
+
+        extern "C" fn should_handle_reopen(this: &Object, _cmd: Sel, _app: *mut Object, _has_visible_windows: BOOL) -> BOOL {
+            if let Some(handlers) = unsafe { delegate_handlers(this) } {
+                LifecycleHandlers::notify(&handlers.on_reopen);
+            }
+            // Let AppKit perform its usual reopen behavior as well
+            YES
+        }
+
+        extern "C" fn should_terminate_after_last_window_closed(this: &Object, _cmd: Sel, _app: *mut Object) -> BOOL {
+            match unsafe { delegate_handlers(this) } {
+                Some(handlers) if handlers.terminate_after_last_window_closed.load(Ordering::SeqCst) => YES,
+                _ => NO,
+            }
+        }
+
+        extern "C" fn open_urls(this: &Object, _cmd: Sel, _app: *mut Object, urls: *mut Object) {
+            unsafe {
+                let Some(handlers) = delegate_handlers(this) else {
+                    return;
+                };
+                let count: usize = msg_send![urls, count];
+                let mut paths = Vec::with_capacity(count);
+                for index in 0..count {
+                    let url: *mut Object = msg_send![urls, objectAtIndex: index];
+                    let is_file: BOOL = msg_send![url, isFileURL];
+                    if is_file != YES {
+                        continue;
+                    }
+                    let path: *mut Object = msg_send![url, path];
+                    if let Ok(path) = crate::core::utils::ns_string_to_string(path) {
+                        paths.push(PathBuf::from(path));
+                    }
+                }
+                if !paths.is_empty() {
+                    handlers.open_files(paths);
+                }
+            }
+        }
+
+        unsafe {
+            decl.add_method(
+                sel!(applicationDidFinishLaunching:),
+                did_finish_launching as extern "C" fn(&Object, Sel, *mut Object),
+            );
+            decl.add_method(
+                sel!(applicationWillTerminate:),
+                will_terminate as extern "C" fn(&Object, Sel, *mut Object),
+            );
+            decl.add_method(
+                sel!(applicationShouldHandleReopen:hasVisibleWindows:),
+                should_handle_reopen as extern "C" fn(&Object, Sel, *mut Object, BOOL) -> BOOL,
+            );
+            decl.add_method(
+                sel!(application:openURLs:),
+                open_urls as extern "C" fn(&Object, Sel, *mut Object, *mut Object),
+            );
+            decl.add_method(
+                sel!(applicationShouldTerminateAfterLastWindowClosed:),
+                should_terminate_after_last_window_closed as extern "C" fn(&Object, Sel, *mut Object) -> BOOL,
+            );
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutApplicationDelegate").expect("CocoanutApplicationDelegate not registered")
+}
+
 unsafe impl Send for Application {}
 unsafe impl Sync for Application {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lifecycle_handlers_dispatch() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let handlers = LifecycleHandlers::default();
+
+        // Nothing is set yet
+        LifecycleHandlers::notify(&handlers.on_launch);
+        handlers.open_files(vec![PathBuf::from("/tmp/ignored.txt")]);
+
+        {
+            let events = Arc::clone(&events);
+            *handlers.on_launch.lock().unwrap() = Some(Arc::new(move || events.lock().unwrap().push("launch".to_string())));
+        }
+        {
+            let events = Arc::clone(&events);
+            *handlers.on_reopen.lock().unwrap() = Some(Arc::new(move || events.lock().unwrap().push("reopen".to_string())));
+        }
+        {
+            let events = Arc::clone(&events);
+            *handlers.on_open_files.lock().unwrap() = Some(Arc::new(move |paths: Vec<PathBuf>| {
+                for path in paths {
+                    events.lock().unwrap().push(path.display().to_string());
+                }
+            }));
+        }
+
+        LifecycleHandlers::notify(&handlers.on_launch);
+        LifecycleHandlers::notify(&handlers.on_reopen);
+        LifecycleHandlers::notify(&handlers.on_will_terminate);
+        handlers.open_files(vec![PathBuf::from("/tmp/a.txt"), PathBuf::from("/tmp/b.txt")]);
+
+        assert_eq!(*events.lock().unwrap(), vec!["launch", "reopen", "/tmp/a.txt", "/tmp/b.txt"]);
+    }
+
+    #[test]
+    fn test_activation_policy_values() {
+        assert_eq!(ActivationPolicy::default(), ActivationPolicy::Regular);
+        assert_eq!(ActivationPolicy::Regular.to_ns_policy(), 0);
+        assert_eq!(ActivationPolicy::Accessory.to_ns_policy(), 1);
+        assert_eq!(ActivationPolicy::Prohibited.to_ns_policy(), 2);
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_application_delegate_class_responds_to_lifecycle_selectors() {
+        let class = application_delegate_class();
+        for selector in [
+            sel!(applicationDidFinishLaunching:),
+            sel!(applicationWillTerminate:),
+            sel!(applicationShouldHandleReopen:hasVisibleWindows:),
+            sel!(application:openURLs:),
+            sel!(applicationShouldTerminateAfterLastWindowClosed:),
+        ] {
+            assert!(class.instance_method(selector).is_some());
+        }
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_quit_after_last_window_delegate() {
+        let delegate = quit_after_last_window_delegate();
+        assert_eq!(delegate, quit_after_last_window_delegate());
+        let handlers = unsafe { delegate_handlers(&*delegate) }.unwrap();
+        assert!(handlers.terminate_after_last_window_closed.load(Ordering::SeqCst));
+    }
+}
@@ -3,12 +3,16 @@
 //! This module provides async/await support for UI operations, allowing
 //! non-blocking updates and better user experience.
 
+use crate::components::basic::Button;
 use crate::core::error::{CocoanutError, Result};
+use crate::window::Window;
 use futures::future::{BoxFuture, FutureExt};
+use std::future::Future;
 use std::pin::Pin;
-use std::sync::Arc;
-use tokio::sync::mpsc;
-use tokio::task;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc, watch};
+use tokio::task::{self, JoinHandle};
 
 /// Trait for async UI operations
 pub trait AsyncUI {
@@ -22,6 +26,7 @@ pub trait AsyncUI {
 /// Async UI executor that runs operations on the main thread
 pub struct AsyncUIExecutor {
     sender: mpsc::UnboundedSender<BoxFuture<'static, ()>>,
+    debouncer: Debouncer,
 }
 
 impl AsyncUIExecutor {
@@ -36,7 +41,7 @@ impl AsyncUIExecutor {
             }
         });
         
-        Self { sender }
+        Self { sender, debouncer: Debouncer::new() }
     }
     
     /// Execute a UI operation on the main thread
@@ -55,6 +60,74 @@ impl AsyncUIExecutor {
         self.sender.send(future.boxed())?;
         rx.await?
     }
+
+    /// Run `task` once `delay` has passed without another call
+    ///
+    /// Each call aborts the task started by the previous call, whether it
+    /// is still waiting or already running, and restarts the timer. This is
+    /// what a type-to-search field needs. The executor has one debounced
+    /// slot; use a [`Debouncer`] per field for independent debouncing.
+    pub fn debounced_task<F, Fut>(&self, delay: Duration, task: F)
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.debouncer.call(delay, task);
+    }
+}
+
+/// Runs only the last of a burst of tasks, after a quiet period
+///
+/// Must be used inside a Tokio runtime. Dropping the debouncer aborts the
+/// pending task.
+pub struct Debouncer {
+    pending: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl Debouncer {
+    /// Create a debouncer with no pending task
+    pub fn new() -> Self {
+        Self { pending: Mutex::new(None) }
+    }
+
+    /// Abort the pending task and schedule `task` to run after `delay`
+    pub fn call<F, Fut>(&self, delay: Duration, task: F)
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let handle = tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            task().await;
+        });
+        let previous = self
+            .pending
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .replace(handle);
+        if let Some(previous) = previous {
+            previous.abort();
+        }
+    }
+
+    /// Abort the pending task, if any
+    pub fn cancel(&self) {
+        if let Some(pending) = self.pending.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).take() {
+            pending.abort();
+        }
+    }
+}
+
+impl Default for Debouncer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Debouncer {
+    fn drop(&mut self) {
+        self.cancel();
+    }
 }
 
 impl Default for AsyncUIExecutor {
@@ -66,12 +139,43 @@ impl Default for AsyncUIExecutor {
 /// Async window operations
 pub struct AsyncWindow {
     executor: Arc<AsyncUIExecutor>,
+    closed: watch::Receiver<bool>,
 }
 
 impl AsyncWindow {
     /// Create a new async window
+    ///
+    /// It is not attached to a window, so [`AsyncWindow::closed`] fails at
+    /// once; use [`AsyncWindow::with_window`] to wait for a window to close.
     pub fn new(executor: Arc<AsyncUIExecutor>) -> Self {
-        Self { executor }
+        let (_, closed) = watch::channel(false);
+        Self { executor, closed }
+    }
+
+    /// Create an async window that reports when `window` closes
+    ///
+    /// Replaces the window's `on_close` handler.
+    pub fn with_window(executor: Arc<AsyncUIExecutor>, window: &mut Window) -> Result<Self> {
+        let (sender, closed) = watch::channel(false);
+        window.on_close(move || {
+            sender.send_replace(true);
+        })?;
+        Ok(Self { executor, closed })
+    }
+
+    /// Wait until the window has closed
+    ///
+    /// Resolves immediately if it already has. Fails if the window is
+    /// dropped without closing or the async window has no window.
+    pub fn closed(&self) -> impl Future<Output = Result<()>> + Send + 'static {
+        let mut closed = self.closed.clone();
+        async move {
+            closed
+                .wait_for(|closed| *closed)
+                .await
+                .map(|_| ())
+                .map_err(|_| CocoanutError::EventError("Window was dropped before it closed".to_string()))
+        }
     }
     
     /// Show window asynchronously
@@ -104,12 +208,46 @@ impl AsyncWindow {
 /// Async button operations
 pub struct AsyncButton {
     executor: Arc<AsyncUIExecutor>,
+    clicks: broadcast::Sender<()>,
 }
 
 impl AsyncButton {
     /// Create a new async button
+    ///
+    /// It is not attached to a button, so [`AsyncButton::clicked`] never
+    /// resolves; use [`AsyncButton::with_button`] for that.
     pub fn new(executor: Arc<AsyncUIExecutor>) -> Self {
-        Self { executor }
+        let (clicks, _) = broadcast::channel(1);
+        Self { executor, clicks }
+    }
+
+    /// Create an async button that reports clicks on `button`
+    ///
+    /// Replaces the button's `on_click` handler.
+    pub fn with_button(executor: Arc<AsyncUIExecutor>, button: &mut Button) -> Self {
+        let (clicks, _) = broadcast::channel(1);
+        let sender = clicks.clone();
+        button.on_click(move || {
+            // Nobody may be waiting; that is not an error
+            let _ = sender.send(());
+        });
+        Self { executor, clicks }
+    }
+
+    /// Wait for the next click
+    ///
+    /// Only clicks after this call count, so call it before the click can
+    /// happen, e.g. before presenting the dialog the button is in.
+    pub fn clicked(&self) -> impl Future<Output = Result<()>> + Send + 'static {
+        let mut clicks = self.clicks.subscribe();
+        async move {
+            match clicks.recv().await {
+                Ok(()) | Err(broadcast::error::RecvError::Lagged(_)) => Ok(()),
+                Err(broadcast::error::RecvError::Closed) => Err(CocoanutError::EventError(
+                    "Button was dropped before it was clicked".to_string()
+                )),
+            }
+        }
     }
     
     /// Set button title asynchronously
@@ -233,4 +371,92 @@ mod tests {
             assert_eq!(result.unwrap(), 42);
         });
     }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_async_button_clicked_resolves_on_click() {
+        let rt = Runtime::new().unwrap();
+        rt.block_on(async {
+            let executor = Arc::new(AsyncUIExecutor::new());
+            let mut button = Button::new("OK").unwrap();
+            let async_button = AsyncButton::with_button(executor, &mut button);
+
+            let clicked = tokio::spawn(async_button.clicked());
+            tokio::task::yield_now().await;
+            assert!(!clicked.is_finished());
+
+            button.perform_click();
+            let result = tokio::time::timeout(std::time::Duration::from_secs(1), clicked).await;
+            assert!(result.unwrap().unwrap().is_ok());
+        });
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_async_window_closed_resolves_on_close() {
+        let rt = Runtime::new().unwrap();
+        rt.block_on(async {
+            let executor = Arc::new(AsyncUIExecutor::new());
+            let mut window = Window::new("Dialog", 300.0, 200.0).unwrap();
+            let async_window = AsyncWindow::with_window(executor, &mut window).unwrap();
+
+            let closed = tokio::spawn(async_window.closed());
+            tokio::task::yield_now().await;
+            assert!(!closed.is_finished());
+
+            window.close().unwrap();
+            let result = tokio::time::timeout(std::time::Duration::from_secs(1), closed).await;
+            assert!(result.unwrap().unwrap().is_ok());
+
+            // Already closed windows resolve immediately
+            assert!(async_window.closed().await.is_ok());
+            drop(window);
+            assert!(AsyncWindow::new(Arc::new(AsyncUIExecutor::new())).closed().await.is_err());
+        });
+    }
+
+    #[test]
+    fn test_debounced_task_runs_last_call_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        /// Counts the futures dropped without running
+        struct DropCounter(Arc<AtomicUsize>);
+
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        // A paused clock only moves when the test advances it
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .start_paused(true)
+            .build()
+            .unwrap();
+        rt.block_on(async {
+            let executor = AsyncUIExecutor::new();
+            let runs = Arc::new(Mutex::new(Vec::new()));
+            let drops = Arc::new(AtomicUsize::new(0));
+
+            for query in ["c", "ca", "caf"] {
+                let runs = Arc::clone(&runs);
+                let guard = DropCounter(Arc::clone(&drops));
+                executor.debounced_task(Duration::from_millis(100), move || async move {
+                    runs.lock().unwrap().push(query);
+                    std::mem::forget(guard);
+                });
+                tokio::time::advance(Duration::from_millis(10)).await;
+            }
+
+            tokio::time::advance(Duration::from_millis(80)).await;
+            tokio::task::yield_now().await;
+            assert!(runs.lock().unwrap().is_empty());
+
+            tokio::time::advance(Duration::from_millis(20)).await;
+            tokio::task::yield_now().await;
+            assert_eq!(*runs.lock().unwrap(), ["caf"]);
+            assert_eq!(drops.load(Ordering::SeqCst), 2);
+        });
+    }
 }
@@ -2,16 +2,39 @@
 //!
 //! Includes SegmentedControl, Stepper, and Switch controls.
 
+use super::toggle::{Toggle, ToggleCallback, ToggleKind};
 use crate::core::error::Result;
+use crate::core::traits::Drawable;
+use crate::systems::target_action::TargetAction;
+use objc::runtime::Object;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
 
 // ============================================================================
 // SEGMENTED CONTROL
 // ============================================================================
 
+/// Callback run with the index of the segment the user selected
+pub type SegmentCallback = Arc<dyn Fn(usize) + Send + Sync>;
+
+/// Selection shared by the control and its action closure
+struct SegmentState {
+    selected: usize,
+    on_change: Option<SegmentCallback>,
+}
+
 /// A segmented control for multiple choice selection
+///
+/// Segments show a text label, an image, or both.
 pub struct SegmentedControl {
+    ns_control: *mut Object,
     segments: Vec<String>,
-    selected_index: usize,
+    images: Vec<Option<PathBuf>>,
+    state: Arc<Mutex<SegmentState>>,
+    action: TargetAction,
 }
 
 impl SegmentedControl {
@@ -22,31 +45,109 @@ impl SegmentedControl {
 
     /// Create a new segmented control with segments
     pub fn new(segments: Vec<String>) -> Result<Self> {
+        let images = vec![None; segments.len()];
+        Self::with_images(segments, images)
+    }
+
+    /// Create the control from labels and the image path of each segment
+    fn with_images(segments: Vec<String>, images: Vec<Option<PathBuf>>) -> Result<Self> {
         if segments.is_empty() {
             return Err(crate::core::error::CocoanutError::InvalidParameter(
                 "Segments cannot be empty".to_string()
             ));
         }
+
+        // Load every image before creating the control, so a bad file
+        // leaves nothing behind
+        let mut ns_images = Vec::with_capacity(images.len());
+        for path in &images {
+            let ns_image = match path {
+                Some(path) => match crate::components::basic::image_view::load_ns_image(path) {
+                    Ok(ns_image) => ns_image,
+                    Err(error) => {
+                        release_images(&ns_images);
+                        return Err(error);
+                    }
+                },
+                None => std::ptr::null_mut(),
+            };
+            ns_images.push(ns_image);
+        }
+
+        #[cfg(not(feature = "test-mock"))]
+        let ns_control = unsafe { create_segmented_control(&segments, &ns_images) };
+        #[cfg(feature = "test-mock")]
+        let ns_control: Result<*mut Object> = Ok(std::ptr::null_mut());
+        release_images(&ns_images);
+        let ns_control = ns_control?;
+
+        let state = Arc::new(Mutex::new(SegmentState {
+            selected: 0,
+            on_change: None,
+        }));
+
+        // Pointers are not `Send`; the closure only runs on the main thread
+        let control = ns_control as usize;
+        let action = {
+            let state = Arc::clone(&state);
+            TargetAction::new(move || {
+                let index = clicked_segment(&state, control as *mut Object);
+                let handler = {
+                    let mut state = state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                    state.selected = index;
+                    state.on_change.clone()
+                };
+                if let Some(handler) = handler {
+                    handler(index);
+                }
+            })
+        };
+        action.attach_to(ns_control);
+
         Ok(SegmentedControl {
+            ns_control,
             segments,
-            selected_index: 0,
+            images,
+            state,
+            action,
         })
     }
 
     /// Get the segments
+    ///
+    /// Image-only segments have an empty label.
     pub fn segments(&self) -> &[String] {
         &self.segments
     }
 
+    /// Get the number of segments
+    pub fn segment_count(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Get the image path of the segment at `index`, if it has one
+    pub fn segment_image(&self, index: usize) -> Option<&Path> {
+        self.images.get(index)?.as_deref()
+    }
+
     /// Get the selected index
     pub fn selected_index(&self) -> usize {
-        self.selected_index
+        self.lock_state().selected
     }
 
     /// Set the selected index
+    ///
+    /// This does not call the `on_change` handler, which only reports
+    /// selections made by the user.
     pub fn set_selected_index(&mut self, index: usize) -> Result<()> {
         if index < self.segments.len() {
-            self.selected_index = index;
+            self.lock_state().selected = index;
+            #[cfg(not(feature = "test-mock"))]
+            if !self.ns_control.is_null() {
+                unsafe {
+                    let _: () = msg_send![self.ns_control, setSelectedSegment: index as i64];
+                }
+            }
             Ok(())
         } else {
             Err(crate::core::error::CocoanutError::InvalidParameter(
@@ -54,11 +155,142 @@ impl SegmentedControl {
             ))
         }
     }
+
+    /// Run `handler` with the segment index whenever the user selects a
+    /// segment
+    pub fn on_change<F>(&mut self, handler: F)
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.lock_state().on_change = Some(Arc::new(handler));
+    }
+
+    /// Check if a change handler is set
+    pub fn has_change_handler(&self) -> bool {
+        self.lock_state().on_change.is_some()
+    }
+
+    /// Select the segment at `index` as the user would, calling the
+    /// `on_change` handler
+    pub fn perform_select(&mut self, index: usize) -> Result<()> {
+        self.set_selected_index(index)?;
+        self.action.invoke();
+        Ok(())
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, SegmentState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Drawable for SegmentedControl {
+    fn as_view(&self) -> *mut Object {
+        self.ns_control
+    }
+
+    fn set_visible(&self, visible: bool) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_control.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_control, setHidden: !visible];
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = visible;
+        Ok(())
+    }
+
+    fn is_visible(&self) -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_control.is_null() {
+            unsafe {
+                let hidden: bool = msg_send![self.ns_control, isHidden];
+                return !hidden;
+            }
+        }
+        true
+    }
+}
+
+impl Drop for SegmentedControl {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_control.is_null() {
+            unsafe {
+                let nil: *mut Object = std::ptr::null_mut();
+                let _: () = msg_send![self.ns_control, setTarget: nil];
+                let _: () = msg_send![self.ns_control, release];
+            }
+        }
+    }
+}
+
+unsafe impl Send for SegmentedControl {}
+unsafe impl Sync for SegmentedControl {}
+
+/// The index of the segment the user just selected
+///
+/// Mock builds have no control; `perform_select` stores the index in `state`.
+fn clicked_segment(state: &Mutex<SegmentState>, ns_control: *mut Object) -> usize {
+    #[cfg(not(feature = "test-mock"))]
+    if !ns_control.is_null() {
+        let index: i64 = unsafe { msg_send![ns_control, selectedSegment] };
+        return index.max(0) as usize;
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = ns_control;
+    state.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).selected
+}
+
+/// Create an `NSSegmentedControl` with a segment per label
+///
+/// Null entries of `images` leave their segment without an image.
+#[cfg(not(feature = "test-mock"))]
+unsafe fn create_segmented_control(labels: &[String], images: &[*mut Object]) -> Result<*mut Object> {
+    unsafe {
+        let control: *mut Object = msg_send![class!(NSSegmentedControl), alloc];
+        let control: *mut Object = msg_send![control, init];
+        if control.is_null() {
+            return Err(crate::core::error::CocoanutError::ControlCreationFailed(
+                "Failed to create NSSegmentedControl".to_string()
+            ));
+        }
+        let _: () = msg_send![control, setSegmentCount: labels.len() as i64];
+        for (index, (label, image)) in labels.iter().zip(images).enumerate() {
+            let segment = index as i64;
+            let ns_label = match crate::core::utils::string_to_ns_string(label) {
+                Ok(ns_label) => ns_label,
+                Err(error) => {
+                    let _: () = msg_send![control, release];
+                    return Err(error);
+                }
+            };
+            let _: () = msg_send![control, setLabel: ns_label forSegment: segment];
+            if !image.is_null() {
+                let _: () = msg_send![control, setImage: *image forSegment: segment];
+            }
+        }
+        let _: () = msg_send![control, setSelectedSegment: 0i64];
+        Ok(control)
+    }
+}
+
+/// Release the images loaded for a control's segments
+fn release_images(images: &[*mut Object]) {
+    #[cfg(not(feature = "test-mock"))]
+    for image in images.iter().filter(|image| !image.is_null()) {
+        unsafe {
+            let _: () = msg_send![*image, release];
+        }
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = images;
 }
 
 /// Builder for SegmentedControl
 pub struct SegmentedControlBuilder {
     segments: Vec<String>,
+    images: Vec<Option<PathBuf>>,
     selected_index: usize,
 }
 
@@ -67,6 +299,7 @@ impl SegmentedControlBuilder {
     pub fn new() -> Self {
         Self {
             segments: Vec::new(),
+            images: Vec::new(),
             selected_index: 0,
         }
     }
@@ -74,6 +307,23 @@ impl SegmentedControlBuilder {
     /// Add a segment
     pub fn segment(mut self, label: impl Into<String>) -> Self {
         self.segments.push(label.into());
+        self.images.push(None);
+        self
+    }
+
+    /// Add a segment showing the image at `path`
+    ///
+    /// The image is loaded by [`build`](Self::build).
+    pub fn segment_image(mut self, path: impl AsRef<Path>) -> Self {
+        self.segments.push(String::new());
+        self.images.push(Some(path.as_ref().to_path_buf()));
+        self
+    }
+
+    /// Add a segment showing both a label and the image at `path`
+    pub fn segment_with_image(mut self, label: impl Into<String>, path: impl AsRef<Path>) -> Self {
+        self.segments.push(label.into());
+        self.images.push(Some(path.as_ref().to_path_buf()));
         self
     }
 
@@ -84,8 +334,13 @@ impl SegmentedControlBuilder {
     }
 
     /// Build the segmented control
+    ///
+    /// Returns an error if a segment image cannot be loaded or the selected
+    /// index is out of bounds.
     pub fn build(self) -> Result<SegmentedControl> {
-        SegmentedControl::new(self.segments)
+        let mut control = SegmentedControl::with_images(self.segments, self.images)?;
+        control.set_selected_index(self.selected_index)?;
+        Ok(control)
     }
 }
 
@@ -207,9 +462,13 @@ impl Default for StepperBuilder {
 // ============================================================================
 
 /// A switch control for on/off toggling
+///
+/// Backed by an `NSSwitch`, or by a checkbox before macOS 10.15. Clones
+/// share the switch.
+#[derive(Clone)]
 pub struct Switch {
     label: String,
-    enabled: bool,
+    toggle: Arc<Toggle>,
 }
 
 impl Switch {
@@ -222,7 +481,7 @@ impl Switch {
     pub fn new(label: &str) -> Result<Self> {
         Ok(Switch {
             label: label.to_string(),
-            enabled: false,
+            toggle: Arc::new(Toggle::new(ToggleKind::Switch, label, false)?),
         })
     }
 
@@ -233,20 +492,57 @@ impl Switch {
 
     /// Check if the switch is enabled
     pub fn is_enabled(&self) -> bool {
-        self.enabled
+        self.toggle.is_on()
     }
 
     /// Set the enabled state
+    ///
+    /// This does not call the `on_change` handler, which only reports
+    /// toggles made by the user.
     pub fn set_enabled(&mut self, enabled: bool) -> Result<()> {
-        self.enabled = enabled;
+        self.toggle.set_on(enabled);
         Ok(())
     }
+
+    /// Run `handler` with the new state whenever the user flips the switch
+    pub fn on_change<F>(&mut self, handler: F)
+    where
+        F: Fn(bool) + Send + Sync + 'static,
+    {
+        self.toggle.set_on_change(Some(Arc::new(handler)));
+    }
+
+    /// Check if a change handler is set
+    pub fn has_change_handler(&self) -> bool {
+        self.toggle.has_on_change()
+    }
+
+    /// Flip the switch as if the user had clicked it
+    pub fn perform_click(&self) {
+        self.toggle.perform_click();
+    }
+}
+
+impl Drawable for Switch {
+    fn as_view(&self) -> *mut Object {
+        self.toggle.ns_view()
+    }
+
+    fn set_visible(&self, visible: bool) -> Result<()> {
+        self.toggle.set_visible(visible);
+        Ok(())
+    }
+
+    fn is_visible(&self) -> bool {
+        self.toggle.is_visible()
+    }
 }
 
 /// Builder for Switch
 pub struct SwitchBuilder {
     label: String,
     enabled: bool,
+    on_change: Option<ToggleCallback>,
 }
 
 impl SwitchBuilder {
@@ -255,6 +551,7 @@ impl SwitchBuilder {
         Self {
             label: String::new(),
             enabled: false,
+            on_change: None,
         }
     }
 
@@ -270,11 +567,27 @@ impl SwitchBuilder {
         self
     }
 
+    /// Set a callback run with the new state when the user flips the switch
+    pub fn on_change<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(bool) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(callback));
+        self
+    }
+
+    /// Get the on_change callback if set
+    pub fn get_on_change(&self) -> Option<&ToggleCallback> {
+        self.on_change.as_ref()
+    }
+
     /// Build the switch
     pub fn build(self) -> Result<Switch> {
+        let toggle = Toggle::new(ToggleKind::Switch, &self.label, self.enabled)?;
+        toggle.set_on_change(self.on_change);
         Ok(Switch {
             label: self.label,
-            enabled: self.enabled,
+            toggle: Arc::new(toggle),
         })
     }
 }
@@ -317,12 +630,76 @@ mod tests {
         assert_eq!(control.selected_index(), 1);
     }
 
+    #[test]
+    fn test_segmented_control_builder_selected() {
+        let control = SegmentedControlBuilder::new()
+            .segment("A")
+            .segment("B")
+            .selected(1)
+            .build()
+            .unwrap();
+        assert_eq!(control.selected_index(), 1);
+
+        let result = SegmentedControlBuilder::new().segment("A").selected(1).build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_segmented_control_on_change() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut control = SegmentedControl::new(vec!["A".to_string(), "B".to_string(), "C".to_string()]).unwrap();
+        let picked = Arc::new(AtomicUsize::new(usize::MAX));
+        let seen = Arc::clone(&picked);
+        control.on_change(move |index| seen.store(index, Ordering::SeqCst));
+        assert!(control.has_change_handler());
+
+        control.set_selected_index(1).unwrap();
+        assert_eq!(picked.load(Ordering::SeqCst), usize::MAX);
+
+        control.perform_select(2).unwrap();
+        assert_eq!(control.selected_index(), 2);
+        assert_eq!(picked.load(Ordering::SeqCst), 2);
+        assert!(control.perform_select(3).is_err());
+    }
+
     #[test]
     fn test_segmented_control_empty() {
         let result = SegmentedControl::new(vec![]);
         assert!(result.is_err());
     }
 
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_segmented_control_with_images() {
+        let path = std::env::temp_dir().join(format!("cocoanut-segment-{}.png", std::process::id()));
+        std::fs::write(&path, b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR").unwrap();
+
+        let control = SegmentedControlBuilder::new()
+            .segment("List")
+            .segment_image(&path)
+            .segment_with_image("Grid", &path)
+            .build();
+        std::fs::remove_file(&path).unwrap();
+        let control = control.unwrap();
+
+        assert_eq!(control.segment_count(), 3);
+        assert_eq!(control.segments(), ["List", "", "Grid"]);
+        assert_eq!(control.segment_image(0), None);
+        assert_eq!(control.segment_image(1), Some(path.as_path()));
+        assert_eq!(control.segment_image(2), Some(path.as_path()));
+        assert_eq!(control.segment_image(3), None);
+    }
+
+    #[test]
+    fn test_segmented_control_missing_image() {
+        let result = SegmentedControlBuilder::new()
+            .segment("A")
+            .segment_image("/nonexistent/segment.png")
+            .build();
+        assert!(matches!(result, Err(crate::core::error::CocoanutError::FileNotFound(_))));
+    }
+
     // Stepper Tests
     #[test]
     fn test_stepper_creation() {
@@ -382,6 +759,10 @@ mod tests {
         let mut switch = Switch::new("Test").unwrap();
         switch.set_enabled(true).unwrap();
         assert!(switch.is_enabled());
+
+        let mut copy = switch.clone();
+        copy.set_enabled(false).unwrap();
+        assert!(!switch.is_enabled());
     }
 
     #[test]
@@ -393,4 +774,25 @@ mod tests {
         switch.set_enabled(false).unwrap();
         assert!(!switch.is_enabled());
     }
+
+    #[test]
+    fn test_switch_builder_on_change() {
+        use std::sync::Mutex;
+
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let builder = {
+            let changes = Arc::clone(&changes);
+            SwitchBuilder::new()
+                .label("Wi-Fi")
+                .enabled(true)
+                .on_change(move |on| changes.lock().unwrap().push(on))
+        };
+        assert!(builder.get_on_change().is_some());
+
+        let switch = builder.build().unwrap();
+        assert!(switch.has_change_handler());
+        switch.perform_click();
+        assert!(!switch.is_enabled());
+        assert_eq!(*changes.lock().unwrap(), vec![false]);
+    }
 }
@@ -0,0 +1,212 @@
+//! Count badges drawn over other components
+//!
+//! A [`Badge`] is a small red bubble pinned to the top-right corner of any
+//! [`Drawable`], like the unread count on a mailbox.
+
+use crate::core::error::Result;
+use crate::core::traits::Drawable;
+use objc::runtime::Object;
+
+#[cfg(not(feature = "test-mock"))]
+use crate::core::error::CocoanutError;
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+
+/// Counts above this are shown as `"99+"`
+const MAX_SHOWN_COUNT: u32 = 99;
+
+/// Height of the bubble; it is at least as wide as it is tall
+#[cfg(not(feature = "test-mock"))]
+const BADGE_HEIGHT: f64 = 16.0;
+
+/// A count bubble on the top-right corner of a component
+///
+/// The badge is removed from the component when dropped, so keep it for as
+/// long as it should be shown.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::advanced_controls::Badge;
+/// use cocoanut::prelude::*;
+///
+/// # fn main() -> cocoanut::Result<()> {
+/// let inbox = Switch::new("Inbox")?;
+/// let mut unread = Badge::attach(&inbox, 3)?;
+/// unread.set_count(0)?; // hides the badge
+/// # Ok(())
+/// # }
+/// ```
+pub struct Badge {
+    ns_view: *mut Object,
+    count: u32,
+}
+
+impl Badge {
+    /// Pin a badge showing `count` to `target`
+    ///
+    /// A count of zero hides the badge.
+    pub fn attach(target: &dyn Drawable, count: u32) -> Result<Self> {
+        #[cfg(not(feature = "test-mock"))]
+        let ns_view = unsafe { create_badge_view(target.as_view())? };
+        #[cfg(feature = "test-mock")]
+        let ns_view: *mut Object = {
+            let _ = target;
+            std::ptr::null_mut()
+        };
+
+        let mut badge = Badge { ns_view, count: 0 };
+        badge.set_count(count)?;
+        Ok(badge)
+    }
+
+    /// Get the count
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Change the count, hiding the badge at zero
+    pub fn set_count(&mut self, count: u32) -> Result<()> {
+        self.count = count;
+
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let text = crate::core::utils::string_to_ns_string(&self.text())?;
+            let _: () = msg_send![self.ns_view, setStringValue: text];
+            let _: () = msg_send![self.ns_view, setHidden: count == 0];
+            layout_badge(self.ns_view);
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = self.ns_view;
+        Ok(())
+    }
+
+    /// Get the text in the bubble, empty when hidden
+    pub fn text(&self) -> String {
+        match self.count {
+            0 => String::new(),
+            count if count > MAX_SHOWN_COUNT => format!("{}+", MAX_SHOWN_COUNT),
+            count => count.to_string(),
+        }
+    }
+
+    /// Check if the badge is shown
+    pub fn is_shown(&self) -> bool {
+        self.count > 0
+    }
+}
+
+impl Drop for Badge {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_view, removeFromSuperview];
+            let _: () = msg_send![self.ns_view, release];
+        }
+    }
+}
+
+unsafe impl Send for Badge {}
+unsafe impl Sync for Badge {}
+
+/// Create the bubble label and add it to `target`
+#[cfg(not(feature = "test-mock"))]
+unsafe fn create_badge_view(target: *mut Object) -> Result<*mut Object> {
+    unsafe {
+        if target.is_null() {
+            return Err(CocoanutError::InvalidParameter(
+                "Cannot attach a badge to a component without a native view".to_string()
+            ));
+        }
+        let empty = crate::core::utils::string_to_ns_string("")?;
+        let label: *mut Object = msg_send![class!(NSTextField), labelWithString: empty];
+        if label.is_null() {
+            return Err(CocoanutError::ControlCreationFailed("Failed to create badge".to_string()));
+        }
+        let label: *mut Object = msg_send![label, retain];
+        let background: *mut Object = msg_send![class!(NSColor), systemRedColor];
+        let foreground: *mut Object = msg_send![class!(NSColor), whiteColor];
+        let font: *mut Object = msg_send![class!(NSFont), boldSystemFontOfSize: 10.0f64];
+        let _: () = msg_send![label, setDrawsBackground: true];
+        let _: () = msg_send![label, setBackgroundColor: background];
+        let _: () = msg_send![label, setTextColor: foreground];
+        let _: () = msg_send![label, setFont: font];
+        let _: () = msg_send![label, setAlignment: crate::core::utils::NS_TEXT_ALIGNMENT_CENTER];
+        let _: () = msg_send![label, setWantsLayer: true];
+        let layer: *mut Object = msg_send![label, layer];
+        let _: () = msg_send![layer, setCornerRadius: BADGE_HEIGHT / 2.0];
+        let _: () = msg_send![layer, setMasksToBounds: true];
+        let _: () = msg_send![target, addSubview: label];
+        Ok(label)
+    }
+}
+
+/// Size the bubble to its text and pin it to the top-right of its superview
+#[cfg(not(feature = "test-mock"))]
+unsafe fn layout_badge(label: *mut Object) {
+    use crate::features::drawing::Rect;
+    use crate::features::zero_cost::ZeroCostRect;
+    use cocoa::foundation::{NSRect, NSSize};
+
+    unsafe {
+        let superview: *mut Object = msg_send![label, superview];
+        if superview.is_null() {
+            return;
+        }
+        let fitting: NSSize = msg_send![label, fittingSize];
+        let bounds: NSRect = msg_send![superview, bounds];
+        let flipped: bool = msg_send![superview, isFlipped];
+        let width = (fitting.width + 8.0).max(BADGE_HEIGHT);
+        let x = bounds.size.width - width;
+        let y = if flipped { 0.0 } else { bounds.size.height - BADGE_HEIGHT };
+        let frame: NSRect = ZeroCostRect::from(Rect::from_xywh(x, y, width, BADGE_HEIGHT)).into();
+        let _: () = msg_send![label, setFrame: frame];
+        // Follow the top-right corner when the target resizes
+        // (NSViewMinXMargin plus NSViewMaxYMargin or NSViewMinYMargin)
+        let vertical: u64 = if flipped { 32 } else { 8 };
+        let _: () = msg_send![label, setAutoresizingMask: 1u64 | vertical];
+    }
+}
+
+#[cfg(all(test, feature = "test-mock"))]
+mod tests {
+    use super::*;
+
+    struct Target;
+
+    impl Drawable for Target {
+        fn as_view(&self) -> *mut Object {
+            std::ptr::null_mut()
+        }
+
+        fn set_visible(&self, _visible: bool) -> Result<()> {
+            Ok(())
+        }
+
+        fn is_visible(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_zero_count_hides_badge() {
+        let mut badge = Badge::attach(&Target, 0).unwrap();
+        assert!(!badge.is_shown());
+        assert_eq!(badge.text(), "");
+
+        badge.set_count(7).unwrap();
+        assert!(badge.is_shown());
+        assert_eq!(badge.count(), 7);
+        assert_eq!(badge.text(), "7");
+
+        badge.set_count(0).unwrap();
+        assert!(!badge.is_shown());
+    }
+
+    #[test]
+    fn test_large_counts_are_capped() {
+        let badge = Badge::attach(&Target, 250).unwrap();
+        assert_eq!(badge.text(), "99+");
+        assert_eq!(badge.count(), 250);
+    }
+}
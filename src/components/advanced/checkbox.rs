@@ -2,12 +2,19 @@
 //!
 //! Provides a simple on/off toggle control with builder pattern support.
 
+use super::toggle::{Toggle, ToggleCallback, ToggleKind};
 use crate::core::error::Result;
+use crate::core::traits::Drawable;
+use objc::runtime::Object;
+use std::sync::Arc;
 
 /// A checkbox control for boolean selection
+///
+/// Backed by an `NSButton` of the checkbox type. Clones share the button.
+#[derive(Clone)]
 pub struct Checkbox {
     label: String,
-    checked: bool,
+    toggle: Arc<Toggle>,
 }
 
 impl Checkbox {
@@ -20,7 +27,7 @@ impl Checkbox {
     pub fn new(label: &str) -> Result<Self> {
         Ok(Checkbox {
             label: label.to_string(),
-            checked: false,
+            toggle: Arc::new(Toggle::new(ToggleKind::Checkbox, label, false)?),
         })
     }
 
@@ -31,20 +38,57 @@ impl Checkbox {
 
     /// Check if the checkbox is checked
     pub fn is_checked(&self) -> bool {
-        self.checked
+        self.toggle.is_on()
     }
 
     /// Set the checked state
+    ///
+    /// This does not call the `on_change` handler, which only reports
+    /// toggles made by the user.
     pub fn set_checked(&mut self, checked: bool) -> Result<()> {
-        self.checked = checked;
+        self.toggle.set_on(checked);
         Ok(())
     }
+
+    /// Run `handler` with the new state whenever the user toggles the checkbox
+    pub fn on_change<F>(&mut self, handler: F)
+    where
+        F: Fn(bool) + Send + Sync + 'static,
+    {
+        self.toggle.set_on_change(Some(Arc::new(handler)));
+    }
+
+    /// Check if a change handler is set
+    pub fn has_change_handler(&self) -> bool {
+        self.toggle.has_on_change()
+    }
+
+    /// Toggle the checkbox as if the user had clicked it
+    pub fn perform_click(&self) {
+        self.toggle.perform_click();
+    }
+}
+
+impl Drawable for Checkbox {
+    fn as_view(&self) -> *mut Object {
+        self.toggle.ns_view()
+    }
+
+    fn set_visible(&self, visible: bool) -> Result<()> {
+        self.toggle.set_visible(visible);
+        Ok(())
+    }
+
+    fn is_visible(&self) -> bool {
+        self.toggle.is_visible()
+    }
 }
 
 /// Builder for Checkbox controls
 pub struct CheckboxBuilder {
     label: String,
     checked: bool,
+    on_change: Option<ToggleCallback>,
 }
 
 impl CheckboxBuilder {
@@ -53,6 +97,7 @@ impl CheckboxBuilder {
         Self {
             label: String::new(),
             checked: false,
+            on_change: None,
         }
     }
 
@@ -68,11 +113,27 @@ impl CheckboxBuilder {
         self
     }
 
+    /// Set a callback run with the new state when the user toggles the checkbox
+    pub fn on_change<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(bool) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(callback));
+        self
+    }
+
+    /// Get the on_change callback if set
+    pub fn get_on_change(&self) -> Option<&ToggleCallback> {
+        self.on_change.as_ref()
+    }
+
     /// Build the checkbox
     pub fn build(self) -> Result<Checkbox> {
+        let toggle = Toggle::new(ToggleKind::Checkbox, &self.label, self.checked)?;
+        toggle.set_on_change(self.on_change);
         Ok(Checkbox {
             label: self.label,
-            checked: self.checked,
+            toggle: Arc::new(toggle),
         })
     }
 }
@@ -140,4 +201,28 @@ mod tests {
         assert_eq!(checkbox.label(), "Fluent");
         assert!(checkbox.is_checked());
     }
+
+    #[test]
+    fn test_checkbox_builder_on_change() {
+        use std::sync::Mutex;
+
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let builder = {
+            let changes = Arc::clone(&changes);
+            CheckboxBuilder::new()
+                .label("Subscribe")
+                .on_change(move |checked| changes.lock().unwrap().push(checked))
+        };
+        assert!(builder.get_on_change().is_some());
+
+        let mut checkbox = builder.build().unwrap();
+        assert!(checkbox.has_change_handler());
+
+        // Programmatic changes are not reported
+        checkbox.set_checked(true).unwrap();
+        checkbox.perform_click();
+        checkbox.perform_click();
+        assert!(checkbox.is_checked());
+        assert_eq!(*changes.lock().unwrap(), vec![false, true]);
+    }
 }
@@ -0,0 +1,391 @@
+//! Level indicator control for macOS GUI applications
+//!
+//! Wraps `NSLevelIndicator`, which shows a value between zero and a maximum
+//! as a capacity bar, a relevancy bar or a row of rating stars.
+
+use crate::core::error::{CocoanutError, Result};
+use crate::core::traits::Drawable;
+use crate::systems::target_action::TargetAction;
+use objc::runtime::Object;
+use std::sync::{Arc, Mutex};
+
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+
+/// Callback run with the new value when the user changes a level indicator
+pub type LevelCallback = Arc<dyn Fn(f64) + Send + Sync>;
+
+/// How a level indicator draws its value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LevelIndicatorStyle {
+    /// A bar of thin lines, as used for search relevancy
+    Relevancy,
+    /// A single continuous bar
+    #[default]
+    ContinuousCapacity,
+    /// A bar split into one segment per unit
+    DiscreteCapacity,
+    /// A row of stars
+    Rating,
+}
+
+impl LevelIndicatorStyle {
+    /// The matching `NSLevelIndicatorStyle`
+    pub fn to_ns_style(self) -> u64 {
+        match self {
+            LevelIndicatorStyle::Relevancy => 0,
+            LevelIndicatorStyle::ContinuousCapacity => 1,
+            LevelIndicatorStyle::DiscreteCapacity => 2,
+            LevelIndicatorStyle::Rating => 3,
+        }
+    }
+}
+
+/// Value shared between the indicator and its action closure
+struct LevelState {
+    value: f64,
+    max: f64,
+    on_change: Option<LevelCallback>,
+}
+
+/// A level indicator showing a value from zero to a maximum
+///
+/// With the [`LevelIndicatorStyle::Rating`] style and editing turned on,
+/// users set the value by clicking the stars.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::advanced_controls::{LevelIndicator, LevelIndicatorStyle};
+///
+/// let review = LevelIndicator::builder()
+///     .style(LevelIndicatorStyle::Rating)
+///     .max(5.0)
+///     .value(3.0)
+///     .editable(true)
+///     .on_change(|stars| println!("rated {} stars", stars))
+///     .build()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct LevelIndicator {
+    ns_view: *mut Object,
+    style: LevelIndicatorStyle,
+    editable: bool,
+    state: Arc<Mutex<LevelState>>,
+    /// The indicator does not retain its target, so the action lives here
+    action: TargetAction,
+}
+
+impl LevelIndicator {
+    /// Create a new level indicator builder
+    pub fn builder() -> LevelIndicatorBuilder {
+        LevelIndicatorBuilder::new()
+    }
+
+    /// Create a read-only level indicator with a value of zero
+    pub fn new(style: LevelIndicatorStyle, max: f64) -> Result<Self> {
+        LevelIndicatorBuilder::new().style(style).max(max).build()
+    }
+
+    /// Get the drawing style
+    pub fn style(&self) -> LevelIndicatorStyle {
+        self.style
+    }
+
+    /// Check if users can change the value
+    pub fn is_editable(&self) -> bool {
+        self.editable
+    }
+
+    /// Get the maximum value
+    pub fn max_value(&self) -> f64 {
+        self.lock_state().max
+    }
+
+    /// Get the current value
+    pub fn value(&self) -> f64 {
+        self.lock_state().value
+    }
+
+    /// Set the value, clamped to zero and the maximum
+    ///
+    /// This does not call the `on_change` handler, which only reports
+    /// changes made by the user.
+    pub fn set_value(&mut self, value: f64) -> Result<()> {
+        if value.is_nan() {
+            return Err(CocoanutError::InvalidParameter("Value must be a number".to_string()));
+        }
+        let value = {
+            let mut state = self.lock_state();
+            state.value = value.clamp(0.0, state.max);
+            state.value
+        };
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_view, setDoubleValue: value];
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = value;
+        Ok(())
+    }
+
+    /// Run `handler` with the new value whenever the user changes it
+    pub fn on_change<F>(&mut self, handler: F)
+    where
+        F: Fn(f64) + Send + Sync + 'static,
+    {
+        self.lock_state().on_change = Some(Arc::new(handler));
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, LevelState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Set the value as a click on the indicator would, calling the
+    /// `on_change` handler
+    pub fn perform_set_value(&mut self, value: f64) -> Result<()> {
+        self.set_value(value)?;
+        self.action.invoke();
+        Ok(())
+    }
+}
+
+impl Drawable for LevelIndicator {
+    fn as_view(&self) -> *mut Object {
+        self.ns_view
+    }
+
+    fn set_visible(&self, visible: bool) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_view, setHidden: !visible];
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = visible;
+        Ok(())
+    }
+
+    fn is_visible(&self) -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let hidden: bool = msg_send![self.ns_view, isHidden];
+            !hidden
+        }
+
+        #[cfg(feature = "test-mock")]
+        true
+    }
+}
+
+impl Drop for LevelIndicator {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let nil: *mut Object = std::ptr::null_mut();
+            let _: () = msg_send![self.ns_view, setTarget: nil];
+            let _: () = msg_send![self.ns_view, release];
+        }
+    }
+}
+
+unsafe impl Send for LevelIndicator {}
+unsafe impl Sync for LevelIndicator {}
+
+/// Builder for LevelIndicator controls
+pub struct LevelIndicatorBuilder {
+    style: LevelIndicatorStyle,
+    max: f64,
+    value: f64,
+    editable: bool,
+    on_change: Option<LevelCallback>,
+}
+
+impl LevelIndicatorBuilder {
+    /// Create a new level indicator builder
+    pub fn new() -> Self {
+        Self {
+            style: LevelIndicatorStyle::default(),
+            max: 1.0,
+            value: 0.0,
+            editable: false,
+            on_change: None,
+        }
+    }
+
+    /// Set the drawing style
+    pub fn style(mut self, style: LevelIndicatorStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the maximum value
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set the initial value, clamped to zero and the maximum
+    pub fn value(mut self, value: f64) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Let users change the value by clicking or dragging
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+
+    /// Set a callback run with the new value when the user changes it
+    pub fn on_change<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(f64) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(callback));
+        self
+    }
+
+    /// Build the level indicator
+    pub fn build(self) -> Result<LevelIndicator> {
+        if self.max.is_nan() || self.max <= 0.0 {
+            return Err(CocoanutError::InvalidParameter(
+                format!("Maximum must be positive, got {}", self.max)
+            ));
+        }
+        if self.value.is_nan() {
+            return Err(CocoanutError::InvalidParameter("Value must be a number".to_string()));
+        }
+        let value = self.value.clamp(0.0, self.max);
+
+        #[cfg(not(feature = "test-mock"))]
+        let ns_view = unsafe { create_indicator(self.style, self.max, value, self.editable)? };
+        #[cfg(feature = "test-mock")]
+        let ns_view: *mut Object = std::ptr::null_mut();
+
+        let state = Arc::new(Mutex::new(LevelState { value, max: self.max, on_change: self.on_change }));
+
+        // Pointers are not `Send`; the closure only runs on the main thread
+        let view = ns_view as usize;
+        let action = {
+            let state = Arc::clone(&state);
+            TargetAction::new(move || {
+                let (value, handler) = {
+                    let mut state = state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                    let clicked = clicked_value(&state, view as *mut Object);
+                    state.value = clicked.clamp(0.0, state.max);
+                    (state.value, state.on_change.clone())
+                };
+                if let Some(handler) = handler {
+                    handler(value);
+                }
+            })
+        };
+        action.attach_to(ns_view);
+
+        Ok(LevelIndicator {
+            ns_view,
+            style: self.style,
+            editable: self.editable,
+            state,
+            action,
+        })
+    }
+}
+
+impl Default for LevelIndicatorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The value a click left the indicator at
+///
+/// Mock builds have no indicator; the simulated click stores the value in
+/// the state.
+fn clicked_value(state: &LevelState, ns_view: *mut Object) -> f64 {
+    #[cfg(not(feature = "test-mock"))]
+    if !ns_view.is_null() {
+        return unsafe { msg_send![ns_view, doubleValue] };
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = ns_view;
+    state.value
+}
+
+/// Create the native indicator with its range and value
+#[cfg(not(feature = "test-mock"))]
+unsafe fn create_indicator(style: LevelIndicatorStyle, max: f64, value: f64, editable: bool) -> Result<*mut Object> {
+    use crate::features::drawing::Rect;
+    use crate::features::zero_cost::ZeroCostRect;
+    use cocoa::foundation::NSRect;
+
+    unsafe {
+        let frame: NSRect = ZeroCostRect::from(Rect::from_xywh(0.0, 0.0, 120.0, 18.0)).into();
+        let ns_view: *mut Object = msg_send![class!(NSLevelIndicator), alloc];
+        let ns_view: *mut Object = msg_send![ns_view, initWithFrame: frame];
+        if ns_view.is_null() {
+            return Err(CocoanutError::ControlCreationFailed(
+                "Failed to create NSLevelIndicator".to_string()
+            ));
+        }
+        let _: () = msg_send![ns_view, setLevelIndicatorStyle: style.to_ns_style()];
+        let _: () = msg_send![ns_view, setMinValue: 0.0f64];
+        let _: () = msg_send![ns_view, setMaxValue: max];
+        let _: () = msg_send![ns_view, setDoubleValue: value];
+        let _: () = msg_send![ns_view, setEditable: editable];
+        Ok(ns_view)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_values() {
+        assert_eq!(LevelIndicatorStyle::Relevancy.to_ns_style(), 0);
+        assert_eq!(LevelIndicatorStyle::Rating.to_ns_style(), 3);
+    }
+
+    #[test]
+    fn test_builder_rejects_bad_maximum() {
+        assert!(LevelIndicator::builder().max(0.0).build().is_err());
+        assert!(LevelIndicator::builder().max(f64::NAN).build().is_err());
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_value_is_clamped() {
+        let mut rating = LevelIndicator::builder()
+            .style(LevelIndicatorStyle::Rating)
+            .max(5.0)
+            .value(9.0)
+            .editable(true)
+            .build()
+            .unwrap();
+        assert_eq!(rating.style(), LevelIndicatorStyle::Rating);
+        assert!(rating.is_editable());
+        assert_eq!(rating.value(), 5.0);
+
+        rating.set_value(-2.0).unwrap();
+        assert_eq!(rating.value(), 0.0);
+        rating.set_value(3.5).unwrap();
+        assert_eq!(rating.value(), 3.5);
+        assert!(rating.set_value(f64::NAN).is_err());
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_click_reports_clamped_value() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut rating = LevelIndicator::new(LevelIndicatorStyle::Rating, 5.0).unwrap();
+        {
+            let seen = Arc::clone(&seen);
+            rating.on_change(move |stars| seen.lock().unwrap().push(stars));
+        }
+        rating.perform_set_value(4.0).unwrap();
+        rating.perform_set_value(7.0).unwrap();
+        assert_eq!(*seen.lock().unwrap(), vec![4.0, 5.0]);
+        assert_eq!(rating.value(), 5.0);
+    }
+}
@@ -3,8 +3,18 @@ pub mod checkbox;
 pub mod radio;
 pub mod slider;
 pub mod advanced_controls;
+pub mod badge;
+pub mod level_indicator;
+pub mod number_field;
+pub mod popup_button;
+mod toggle;
 
 pub use checkbox::*;
 pub use radio::*;
 pub use slider::*;
 pub use advanced_controls::*;
+pub use badge::*;
+pub use level_indicator::*;
+pub use number_field::*;
+pub use popup_button::*;
+pub use toggle::ToggleCallback;
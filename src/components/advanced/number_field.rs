@@ -0,0 +1,439 @@
+//! Numeric entry field with up/down arrows
+//!
+//! A [`NumberField`] pairs an editable [`TextField`] with an `NSStepper` in
+//! one container view and keeps the two in sync through shared state.
+
+use crate::components::basic::TextField;
+use crate::core::error::{CocoanutError, Result};
+use crate::core::traits::Drawable;
+use crate::systems::target_action::TargetAction;
+use objc::runtime::Object;
+use std::sync::{Arc, Mutex};
+
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+
+/// Callback run with the new value when the user changes a number field
+pub type NumberCallback = Arc<dyn Fn(f64) + Send + Sync>;
+
+/// Range, step and value shared by the field, the stepper and their handlers
+struct NumberState {
+    value: f64,
+    min: f64,
+    max: f64,
+    step: f64,
+    on_change: Option<NumberCallback>,
+}
+
+impl NumberState {
+    fn clamp(&self, value: f64) -> f64 {
+        value.clamp(self.min, self.max)
+    }
+}
+
+/// A text field with stepper arrows for entering a number
+///
+/// Typing a number moves the stepper to it; a number outside the range is
+/// replaced by the nearest limit. Clicking the arrows rewrites the text.
+/// Text that is not a finite number is ignored until it parses.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::advanced_controls::NumberField;
+///
+/// # fn main() -> cocoanut::Result<()> {
+/// let mut quantity = NumberField::new(1.0)?.range(1.0, 99.0)?.step(1.0)?;
+/// quantity.on_change(|value| println!("quantity: {}", value));
+/// # Ok(())
+/// # }
+/// ```
+pub struct NumberField {
+    ns_view: *mut Object,
+    ns_stepper: *mut Object,
+    field: TextField,
+    state: Arc<Mutex<NumberState>>,
+    stepper_action: TargetAction,
+}
+
+impl NumberField {
+    /// Create a field showing `value`, with a range of 0 to 100 and a step of 1
+    ///
+    /// Fails if `value` is not a finite number.
+    pub fn new(value: f64) -> Result<Self> {
+        if !value.is_finite() {
+            return Err(CocoanutError::InvalidParameter(format!("Value {} must be a finite number", value)));
+        }
+        let state = Arc::new(Mutex::new(NumberState {
+            value: 0.0,
+            min: 0.0,
+            max: 100.0,
+            step: 1.0,
+            on_change: None,
+        }));
+        let value = value.clamp(0.0, 100.0);
+        state.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).value = value;
+
+        let mut field = TextField::new(&format_value(value))?;
+
+        #[cfg(not(feature = "test-mock"))]
+        let (ns_view, ns_stepper) = unsafe { create_views(field.as_view())? };
+        #[cfg(feature = "test-mock")]
+        let (ns_view, ns_stepper): (*mut Object, *mut Object) = (std::ptr::null_mut(), std::ptr::null_mut());
+
+        // Pointers are not `Send`; the handlers only run on the main thread
+        let stepper = ns_stepper as usize;
+        {
+            let state = Arc::clone(&state);
+            let write_back = field.text_writer();
+            field.on_change(move |text| {
+                let Some(typed) = text.trim().parse::<f64>().ok().filter(|typed| typed.is_finite()) else {
+                    return;
+                };
+                let value = changed(&state, typed);
+                set_stepper_value(stepper as *mut Object, value);
+                if value != typed {
+                    write_back(&format_value(value));
+                }
+            });
+        }
+
+        let stepper_action = {
+            let state = Arc::clone(&state);
+            let field = Mutex::new(field.clone());
+            TargetAction::new(move || {
+                let stepped = stepper_value(&state, stepper as *mut Object);
+                let value = changed(&state, stepped);
+                let _ = field
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .set_text(&format_value(value));
+            })
+        };
+        stepper_action.attach_to(ns_stepper);
+
+        let number_field = NumberField { ns_view, ns_stepper, field, state, stepper_action };
+        number_field.sync_stepper();
+        Ok(number_field)
+    }
+
+    /// Limit the value to `min..=max`, clamping the current value
+    pub fn range(mut self, min: f64, max: f64) -> Result<Self> {
+        if min > max || min.is_nan() || max.is_nan() {
+            return Err(CocoanutError::InvalidParameter(
+                format!("Invalid range [{}, {}]", min, max)
+            ));
+        }
+        {
+            let mut state = self.lock_state();
+            state.min = min;
+            state.max = max;
+        }
+        let value = self.value();
+        self.set_value(value)?;
+        self.sync_stepper();
+        Ok(self)
+    }
+
+    /// Change the value by `step` per arrow click
+    pub fn step(self, step: f64) -> Result<Self> {
+        if step <= 0.0 || step.is_nan() {
+            return Err(CocoanutError::InvalidParameter(
+                format!("Step must be positive, got {}", step)
+            ));
+        }
+        self.lock_state().step = step;
+        self.sync_stepper();
+        Ok(self)
+    }
+
+    /// Get the current value
+    pub fn value(&self) -> f64 {
+        self.lock_state().value
+    }
+
+    /// Set the value, clamped to the range
+    ///
+    /// This does not call the `on_change` handler, which only reports
+    /// changes made by the user.
+    pub fn set_value(&mut self, value: f64) -> Result<()> {
+        if value.is_nan() {
+            return Err(CocoanutError::InvalidParameter("Value must be a number".to_string()));
+        }
+        let value = {
+            let mut state = self.lock_state();
+            state.value = state.clamp(value);
+            state.value
+        };
+        set_stepper_value(self.ns_stepper, value);
+        self.field.set_text(&format_value(value))
+    }
+
+    /// Get the text shown in the field
+    pub fn text(&self) -> String {
+        self.field.current_text()
+    }
+
+    /// Run `handler` with the new value whenever the user types or steps
+    pub fn on_change<F>(&mut self, handler: F)
+    where
+        F: Fn(f64) + Send + Sync + 'static,
+    {
+        self.lock_state().on_change = Some(Arc::new(handler));
+    }
+
+    /// Click the up arrow as the user would
+    pub fn perform_increment(&self) {
+        self.perform_step(1.0);
+    }
+
+    /// Click the down arrow as the user would
+    pub fn perform_decrement(&self) {
+        self.perform_step(-1.0);
+    }
+
+    fn perform_step(&self, direction: f64) {
+        #[cfg(not(feature = "test-mock"))]
+        {
+            let (value, step) = {
+                let state = self.lock_state();
+                (state.value, state.step)
+            };
+            set_stepper_value(self.ns_stepper, value + direction * step);
+        }
+        #[cfg(feature = "test-mock")]
+        {
+            let mut state = self.lock_state();
+            state.value = state.clamp(state.value + direction * state.step);
+        }
+        self.stepper_action.invoke();
+    }
+
+    /// Copy the range, step and value to the native stepper
+    fn sync_stepper(&self) {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_stepper.is_null() {
+            let state = self.lock_state();
+            unsafe {
+                let _: () = msg_send![self.ns_stepper, setMinValue: state.min];
+                let _: () = msg_send![self.ns_stepper, setMaxValue: state.max];
+                let _: () = msg_send![self.ns_stepper, setIncrement: state.step];
+                let _: () = msg_send![self.ns_stepper, setDoubleValue: state.value];
+            }
+        }
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, NumberState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Type `text` into the field as the user would
+    #[cfg(all(test, feature = "test-mock"))]
+    fn simulate_typing(&self, text: &str) {
+        self.field.simulate_change(text);
+    }
+}
+
+impl Drawable for NumberField {
+    fn as_view(&self) -> *mut Object {
+        self.ns_view
+    }
+
+    fn set_visible(&self, visible: bool) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_view, setHidden: !visible];
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = visible;
+        Ok(())
+    }
+
+    fn is_visible(&self) -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let hidden: bool = msg_send![self.ns_view, isHidden];
+                return !hidden;
+            }
+        }
+        true
+    }
+}
+
+impl Drop for NumberField {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            if !self.ns_stepper.is_null() {
+                let nil: *mut Object = std::ptr::null_mut();
+                let _: () = msg_send![self.ns_stepper, setTarget: nil];
+            }
+            if !self.ns_view.is_null() {
+                let _: () = msg_send![self.ns_view, release];
+            }
+        }
+    }
+}
+
+unsafe impl Send for NumberField {}
+unsafe impl Sync for NumberField {}
+
+/// Store a value the user entered and notify the handler
+///
+/// Returns the value after clamping.
+fn changed(state: &Mutex<NumberState>, value: f64) -> f64 {
+    let (value, handler) = {
+        let mut state = state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        state.value = state.clamp(value);
+        (state.value, state.on_change.clone())
+    };
+    if let Some(handler) = handler {
+        handler(value);
+    }
+    value
+}
+
+/// The value the stepper was just moved to
+///
+/// Mock builds have no stepper; `perform_step` stores the value in `state`.
+fn stepper_value(state: &Mutex<NumberState>, ns_stepper: *mut Object) -> f64 {
+    #[cfg(not(feature = "test-mock"))]
+    if !ns_stepper.is_null() {
+        return unsafe { msg_send![ns_stepper, doubleValue] };
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = ns_stepper;
+    state.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).value
+}
+
+fn set_stepper_value(ns_stepper: *mut Object, value: f64) {
+    #[cfg(not(feature = "test-mock"))]
+    if !ns_stepper.is_null() {
+        unsafe {
+            let _: () = msg_send![ns_stepper, setDoubleValue: value];
+        }
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = (ns_stepper, value);
+}
+
+/// Format a value without float noise such as `0.30000000000000004`
+fn format_value(value: f64) -> String {
+    let rounded = (value * 1e9).round() / 1e9;
+    if rounded == 0.0 { "0".to_string() } else { rounded.to_string() }
+}
+
+/// Create the container view holding `ns_field` and a new stepper
+///
+/// Returns the container, which owns the stepper, and the stepper.
+#[cfg(not(feature = "test-mock"))]
+unsafe fn create_views(ns_field: *mut Object) -> Result<(*mut Object, *mut Object)> {
+    use crate::features::drawing::Rect;
+    use crate::features::zero_cost::ZeroCostRect;
+    use cocoa::foundation::NSRect;
+
+    const WIDTH: f64 = 120.0;
+    const HEIGHT: f64 = 24.0;
+    const STEPPER_WIDTH: f64 = 19.0;
+
+    let frame = |rect: Rect| -> NSRect { ZeroCostRect::from(rect).into() };
+
+    unsafe {
+        let ns_view: *mut Object = msg_send![class!(NSView), alloc];
+        let ns_view: *mut Object = msg_send![ns_view, initWithFrame: frame(Rect::from_xywh(0.0, 0.0, WIDTH, HEIGHT))];
+        let ns_stepper: *mut Object = msg_send![class!(NSStepper), alloc];
+        let ns_stepper: *mut Object =
+            msg_send![ns_stepper, initWithFrame: frame(Rect::from_xywh(WIDTH - STEPPER_WIDTH, 0.0, STEPPER_WIDTH, HEIGHT))];
+        if ns_view.is_null() || ns_stepper.is_null() {
+            return Err(CocoanutError::ControlCreationFailed("Failed to create number field".to_string()));
+        }
+        let _: () = msg_send![ns_stepper, setValueWraps: false];
+        let _: () = msg_send![ns_stepper, setAutorepeat: true];
+
+        let field_frame = frame(Rect::from_xywh(0.0, 0.0, WIDTH - STEPPER_WIDTH - 2.0, HEIGHT));
+        let _: () = msg_send![ns_field, setFrame: field_frame];
+        let _: () = msg_send![ns_view, addSubview: ns_field];
+        let _: () = msg_send![ns_view, addSubview: ns_stepper];
+        // The container keeps the stepper alive from here on
+        let _: () = msg_send![ns_stepper, release];
+        Ok((ns_view, ns_stepper))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_number_field_defaults() {
+        let field = NumberField::new(5.0).unwrap();
+        assert_eq!(field.value(), 5.0);
+        assert_eq!(field.text(), "5");
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_range_clamps_current_value() {
+        let field = NumberField::new(80.0).unwrap().range(0.0, 10.0).unwrap();
+        assert_eq!(field.value(), 10.0);
+        assert_eq!(field.text(), "10");
+        assert!(NumberField::new(0.0).unwrap().range(5.0, 1.0).is_err());
+        assert!(NumberField::new(0.0).unwrap().step(0.0).is_err());
+    }
+
+    #[test]
+    fn test_new_rejects_non_finite_values() {
+        assert!(NumberField::new(f64::NAN).is_err());
+        assert!(NumberField::new(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(0.1 + 0.2), "0.3");
+        assert_eq!(format_value(-0.0), "0");
+        assert_eq!(format_value(2.5), "2.5");
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_typed_value_is_clamped() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut field = NumberField::new(1.0).unwrap().range(0.0, 10.0).unwrap();
+        {
+            let seen = Arc::clone(&seen);
+            field.on_change(move |value| seen.lock().unwrap().push(value));
+        }
+
+        field.simulate_typing("250");
+        assert_eq!(field.value(), 10.0);
+        assert_eq!(field.text(), "10");
+        field.simulate_typing("-3");
+        assert_eq!(field.value(), 0.0);
+        assert_eq!(field.text(), "0");
+        field.simulate_typing("abc");
+        assert_eq!(field.value(), 0.0);
+        field.simulate_typing("inf");
+        assert_eq!(field.value(), 0.0);
+        assert_eq!(*seen.lock().unwrap(), vec![10.0, 0.0]);
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_stepper_updates_text() {
+        let mut field = NumberField::new(0.5).unwrap().range(0.0, 1.0).unwrap().step(0.25).unwrap();
+        field.perform_increment();
+        assert_eq!(field.value(), 0.75);
+        assert_eq!(field.text(), "0.75");
+        field.perform_increment();
+        field.perform_increment();
+        assert_eq!(field.text(), "1");
+
+        field.set_value(0.1).unwrap();
+        field.perform_decrement();
+        assert_eq!(field.value(), 0.0);
+    }
+}
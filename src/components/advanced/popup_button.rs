@@ -0,0 +1,325 @@
+//! Drop-down menus of choices
+//!
+//! A [`PopUpButton`] wraps `NSPopUpButton`. In pop-up mode the button shows
+//! the selected item; in pull-down mode it always shows its first item, like
+//! a menu title, and reports the item the user picks.
+
+use crate::core::error::{CocoanutError, Result};
+use crate::core::traits::Drawable;
+use crate::systems::target_action::TargetAction;
+use objc::runtime::Object;
+use std::sync::{Arc, Mutex};
+
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+
+/// Callback run with the index of the item the user picked
+pub type PopUpCallback = Arc<dyn Fn(usize) + Send + Sync>;
+
+/// Items and selection shared by the button and its action closure
+struct PopUpState {
+    items: Vec<String>,
+    selected: usize,
+    on_change: Option<PopUpCallback>,
+}
+
+/// A button that shows a menu of items to choose from
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::advanced_controls::PopUpButton;
+///
+/// # fn main() -> cocoanut::Result<()> {
+/// let mut sort = PopUpButton::new()?.items(&["Name", "Date", "Size"])?;
+/// sort.select(1)?;
+/// sort.on_change(|index| println!("sort by item {}", index));
+/// # Ok(())
+/// # }
+/// ```
+pub struct PopUpButton {
+    ns_view: *mut Object,
+    state: Arc<Mutex<PopUpState>>,
+    pull_down: bool,
+    action: TargetAction,
+}
+
+impl PopUpButton {
+    /// Create an empty pop-up button
+    pub fn new() -> Result<Self> {
+        let state = Arc::new(Mutex::new(PopUpState {
+            items: Vec::new(),
+            selected: 0,
+            on_change: None,
+        }));
+
+        #[cfg(not(feature = "test-mock"))]
+        let ns_view = unsafe { create_popup_button()? };
+        #[cfg(feature = "test-mock")]
+        let ns_view: *mut Object = std::ptr::null_mut();
+
+        // Pointers are not `Send`; the closure only runs on the main thread
+        let view = ns_view as usize;
+        let action = {
+            let state = Arc::clone(&state);
+            TargetAction::new(move || {
+                let index = picked_index(&state, view as *mut Object);
+                let handler = {
+                    let mut state = state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                    state.selected = index;
+                    state.on_change.clone()
+                };
+                if let Some(handler) = handler {
+                    handler(index);
+                }
+            })
+        };
+        action.attach_to(ns_view);
+
+        Ok(PopUpButton { ns_view, state, pull_down: false, action })
+    }
+
+    /// Replace the items, selecting the first one
+    pub fn items(mut self, items: &[&str]) -> Result<Self> {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_view, removeAllItems];
+            }
+        }
+        {
+            let mut state = self.lock_state();
+            state.items.clear();
+            state.selected = 0;
+        }
+        for item in items {
+            self.add_item(item)?;
+        }
+        Ok(self)
+    }
+
+    /// Switch between a pull-down menu, which always shows its first item,
+    /// and a pop-up menu, which shows the selected item
+    pub fn pull_down(mut self, pull_down: bool) -> Self {
+        self.pull_down = pull_down;
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_view, setPullsDown: pull_down];
+            }
+        }
+        self
+    }
+
+    /// Check if the button is a pull-down menu
+    pub fn is_pull_down(&self) -> bool {
+        self.pull_down
+    }
+
+    /// Add an item to the end of the menu
+    pub fn add_item(&mut self, title: &str) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                // `addItemWithTitle:` drops items whose title is already in
+                // the menu, so add through the menu itself
+                let menu: *mut Object = msg_send![self.ns_view, menu];
+                let ns_title = crate::core::utils::string_to_ns_string(title)?;
+                let empty = crate::core::utils::string_to_ns_string("")?;
+                let nil: *mut Object = std::ptr::null_mut();
+                let _: *mut Object = msg_send![menu, addItemWithTitle: ns_title action: nil keyEquivalent: empty];
+            }
+        }
+        self.lock_state().items.push(title.to_string());
+        Ok(())
+    }
+
+    /// Get the number of items
+    pub fn item_count(&self) -> usize {
+        self.lock_state().items.len()
+    }
+
+    /// Get the item titles
+    pub fn item_titles(&self) -> Vec<String> {
+        self.lock_state().items.clone()
+    }
+
+    /// Select the item at `index`
+    ///
+    /// This does not call the `on_change` handler, which only reports
+    /// choices made by the user.
+    pub fn select(&mut self, index: usize) -> Result<()> {
+        let mut state = self.lock_state();
+        if index >= state.items.len() {
+            return Err(CocoanutError::InvalidParameter(
+                format!("Item index {} out of bounds for {} items", index, state.items.len())
+            ));
+        }
+        state.selected = index;
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_view, selectItemAtIndex: index as i64];
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the index of the selected item, 0 when there are no items
+    pub fn selected_index(&self) -> usize {
+        self.lock_state().selected
+    }
+
+    /// Get the title of the selected item, empty when there are no items
+    pub fn selected_title(&self) -> String {
+        let state = self.lock_state();
+        state.items.get(state.selected).cloned().unwrap_or_default()
+    }
+
+    /// Run `handler` with the item index whenever the user picks an item
+    pub fn on_change<F>(&mut self, handler: F)
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.lock_state().on_change = Some(Arc::new(handler));
+    }
+
+    /// Pick the item at `index` as the user would, calling the `on_change`
+    /// handler
+    pub fn perform_select(&mut self, index: usize) -> Result<()> {
+        self.select(index)?;
+        self.action.invoke();
+        Ok(())
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, PopUpState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Drawable for PopUpButton {
+    fn as_view(&self) -> *mut Object {
+        self.ns_view
+    }
+
+    fn set_visible(&self, visible: bool) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_view, setHidden: !visible];
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = visible;
+        Ok(())
+    }
+
+    fn is_visible(&self) -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let hidden: bool = msg_send![self.ns_view, isHidden];
+                return !hidden;
+            }
+        }
+        true
+    }
+}
+
+impl Drop for PopUpButton {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let nil: *mut Object = std::ptr::null_mut();
+                let _: () = msg_send![self.ns_view, setTarget: nil];
+                let _: () = msg_send![self.ns_view, release];
+            }
+        }
+    }
+}
+
+unsafe impl Send for PopUpButton {}
+unsafe impl Sync for PopUpButton {}
+
+/// The index of the item the user just picked
+///
+/// Mock builds have no menu; `perform_select` stores the index in `state`.
+fn picked_index(state: &Mutex<PopUpState>, ns_view: *mut Object) -> usize {
+    #[cfg(not(feature = "test-mock"))]
+    if !ns_view.is_null() {
+        let index: i64 = unsafe { msg_send![ns_view, indexOfSelectedItem] };
+        return index.max(0) as usize;
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = ns_view;
+    state.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).selected
+}
+
+/// Create an empty `NSPopUpButton` in pop-up mode
+#[cfg(not(feature = "test-mock"))]
+unsafe fn create_popup_button() -> Result<*mut Object> {
+    use crate::features::drawing::Rect;
+    use crate::features::zero_cost::ZeroCostRect;
+    use cocoa::foundation::NSRect;
+
+    unsafe {
+        let frame: NSRect = ZeroCostRect::from(Rect::from_xywh(0.0, 0.0, 200.0, 26.0)).into();
+        let ns_view: *mut Object = msg_send![class!(NSPopUpButton), alloc];
+        let ns_view: *mut Object = msg_send![ns_view, initWithFrame: frame pullsDown: false];
+        if ns_view.is_null() {
+            return Err(CocoanutError::ControlCreationFailed("Failed to create NSPopUpButton".to_string()));
+        }
+        Ok(ns_view)
+    }
+}
+
+#[cfg(all(test, feature = "test-mock"))]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_select_updates_selected_title() {
+        let mut popup = PopUpButton::new().unwrap().items(&["Name", "Date", "Size"]).unwrap();
+        assert_eq!(popup.item_count(), 3);
+        assert_eq!(popup.selected_index(), 0);
+        assert_eq!(popup.selected_title(), "Name");
+
+        popup.select(2).unwrap();
+        assert_eq!(popup.selected_index(), 2);
+        assert_eq!(popup.selected_title(), "Size");
+
+        assert!(popup.select(3).is_err());
+        assert_eq!(popup.selected_title(), "Size");
+    }
+
+    #[test]
+    fn test_add_item_and_pull_down() {
+        let mut popup = PopUpButton::new().unwrap().pull_down(true);
+        assert!(popup.is_pull_down());
+        assert_eq!(popup.selected_title(), "");
+
+        popup.add_item("Actions").unwrap();
+        popup.add_item("Duplicate").unwrap();
+        popup.add_item("Duplicate").unwrap();
+        assert_eq!(popup.item_titles(), ["Actions", "Duplicate", "Duplicate"]);
+    }
+
+    #[test]
+    fn test_on_change_reports_user_choices() {
+        let mut popup = PopUpButton::new().unwrap().items(&["A", "B"]).unwrap();
+        let picked = Arc::new(AtomicUsize::new(usize::MAX));
+        {
+            let picked = Arc::clone(&picked);
+            popup.on_change(move |index| picked.store(index, Ordering::SeqCst));
+        }
+
+        popup.select(1).unwrap();
+        assert_eq!(picked.load(Ordering::SeqCst), usize::MAX);
+
+        popup.perform_select(0).unwrap();
+        assert_eq!(picked.load(Ordering::SeqCst), 0);
+        assert_eq!(popup.selected_title(), "A");
+    }
+}
@@ -2,13 +2,21 @@
 //!
 //! Provides mutually exclusive selection controls with builder pattern support.
 
+use super::toggle::{Toggle, ToggleCallback, ToggleKind};
 use crate::core::error::Result;
+use crate::core::traits::Drawable;
+use objc::runtime::Object;
+use std::sync::Arc;
 
 /// A radio button control for exclusive selection
+///
+/// Backed by an `NSButton` of the radio type. AppKit makes radio buttons that
+/// share a superview mutually exclusive. Clones share the button.
+#[derive(Clone)]
 pub struct RadioButton {
     label: String,
-    selected: bool,
     group_id: String,
+    toggle: Arc<Toggle>,
 }
 
 impl RadioButton {
@@ -21,8 +29,8 @@ impl RadioButton {
     pub fn new(label: &str, group_id: &str) -> Result<Self> {
         Ok(RadioButton {
             label: label.to_string(),
-            selected: false,
             group_id: group_id.to_string(),
+            toggle: Arc::new(Toggle::new(ToggleKind::Radio, label, false)?),
         })
     }
 
@@ -33,12 +41,15 @@ impl RadioButton {
 
     /// Check if the radio button is selected
     pub fn is_selected(&self) -> bool {
-        self.selected
+        self.toggle.is_on()
     }
 
     /// Set the selected state
+    ///
+    /// This does not call the `on_change` handler, which only reports
+    /// selections made by the user.
     pub fn set_selected(&mut self, selected: bool) -> Result<()> {
-        self.selected = selected;
+        self.toggle.set_on(selected);
         Ok(())
     }
 
@@ -46,6 +57,41 @@ impl RadioButton {
     pub fn group_id(&self) -> &str {
         &self.group_id
     }
+
+    /// Run `handler` with `true` whenever the user selects this button
+    ///
+    /// Buttons deselected by the selection of another one are not reported.
+    pub fn on_change<F>(&mut self, handler: F)
+    where
+        F: Fn(bool) + Send + Sync + 'static,
+    {
+        self.toggle.set_on_change(Some(Arc::new(handler)));
+    }
+
+    /// Check if a change handler is set
+    pub fn has_change_handler(&self) -> bool {
+        self.toggle.has_on_change()
+    }
+
+    /// Select the button as if the user had clicked it
+    pub fn perform_click(&self) {
+        self.toggle.perform_click();
+    }
+}
+
+impl Drawable for RadioButton {
+    fn as_view(&self) -> *mut Object {
+        self.toggle.ns_view()
+    }
+
+    fn set_visible(&self, visible: bool) -> Result<()> {
+        self.toggle.set_visible(visible);
+        Ok(())
+    }
+
+    fn is_visible(&self) -> bool {
+        self.toggle.is_visible()
+    }
 }
 
 /// Builder for RadioButton controls
@@ -53,6 +99,7 @@ pub struct RadioButtonBuilder {
     label: String,
     selected: bool,
     group_id: String,
+    on_change: Option<ToggleCallback>,
 }
 
 impl RadioButtonBuilder {
@@ -62,6 +109,7 @@ impl RadioButtonBuilder {
             label: String::new(),
             selected: false,
             group_id: String::new(),
+            on_change: None,
         }
     }
 
@@ -83,12 +131,28 @@ impl RadioButtonBuilder {
         self
     }
 
+    /// Set a callback run with `true` when the user selects the button
+    pub fn on_change<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(bool) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(callback));
+        self
+    }
+
+    /// Get the on_change callback if set
+    pub fn get_on_change(&self) -> Option<&ToggleCallback> {
+        self.on_change.as_ref()
+    }
+
     /// Build the radio button
     pub fn build(self) -> Result<RadioButton> {
+        let toggle = Toggle::new(ToggleKind::Radio, &self.label, self.selected)?;
+        toggle.set_on_change(self.on_change);
         Ok(RadioButton {
             label: self.label,
-            selected: self.selected,
             group_id: self.group_id,
+            toggle: Arc::new(toggle),
         })
     }
 }
@@ -132,6 +196,10 @@ mod tests {
         
         radio.set_selected(true).unwrap();
         assert!(radio.is_selected());
+
+        let mut copy = radio.clone();
+        copy.set_selected(false).unwrap();
+        assert!(!radio.is_selected());
     }
 
     #[test]
@@ -158,4 +226,25 @@ mod tests {
         assert_eq!(radio.group_id(), "fluent_group");
         assert!(radio.is_selected());
     }
+
+    #[test]
+    fn test_radio_button_builder_on_change() {
+        use std::sync::Mutex;
+
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let builder = {
+            let changes = Arc::clone(&changes);
+            RadioButtonBuilder::new()
+                .label("Large")
+                .group_id("size")
+                .on_change(move |selected| changes.lock().unwrap().push(selected))
+        };
+        assert!(builder.get_on_change().is_some());
+
+        let radio = builder.build().unwrap();
+        assert!(radio.has_change_handler());
+        radio.perform_click();
+        assert!(radio.is_selected());
+        assert_eq!(*changes.lock().unwrap(), vec![true]);
+    }
 }
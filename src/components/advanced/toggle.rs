@@ -0,0 +1,255 @@
+//! Two-state controls shared by `Checkbox`, `RadioButton` and `Switch`
+//!
+//! A `Toggle` owns the native control and a [`TargetAction`] whose closure
+//! reads the control's new state and passes it to the change handler.
+
+use crate::core::error::Result;
+use crate::systems::target_action::TargetAction;
+use objc::runtime::Object;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+#[cfg(not(feature = "test-mock"))]
+use crate::core::error::CocoanutError;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::Class;
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+
+/// Callback run with the new state when the user toggles a control
+pub type ToggleCallback = Arc<dyn Fn(bool) + Send + Sync>;
+
+/// Which native control backs a toggle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ToggleKind {
+    /// `NSButton` of type `NSButtonTypeSwitch`
+    Checkbox,
+    /// `NSButton` of type `NSButtonTypeRadio`
+    Radio,
+    /// `NSSwitch`, or a checkbox before macOS 10.15
+    Switch,
+}
+
+/// State shared between a toggle and its action closure
+struct ToggleState {
+    kind: ToggleKind,
+    on: AtomicBool,
+    on_change: Mutex<Option<ToggleCallback>>,
+}
+
+impl ToggleState {
+    /// Record a state change made by the user and notify the handler
+    ///
+    /// Radio buttons only report being selected; AppKit deselects the other
+    /// buttons of the group without sending their actions.
+    fn toggled(&self, on: bool) {
+        self.on.store(on, Ordering::SeqCst);
+        if self.kind == ToggleKind::Radio && !on {
+            return;
+        }
+        let handler = self
+            .on_change
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone();
+        if let Some(handler) = handler {
+            handler(on);
+        }
+    }
+}
+
+/// A native two-state control wired to a change handler
+pub(crate) struct Toggle {
+    ns_view: *mut Object,
+    state: Arc<ToggleState>,
+    action: TargetAction,
+}
+
+impl Toggle {
+    /// Create the native control for `kind`
+    pub(crate) fn new(kind: ToggleKind, label: &str, on: bool) -> Result<Self> {
+        let state = Arc::new(ToggleState {
+            kind,
+            on: AtomicBool::new(on),
+            on_change: Mutex::new(None),
+        });
+
+        #[cfg(not(feature = "test-mock"))]
+        let ns_view = unsafe { create_control(kind, label, on)? };
+        #[cfg(feature = "test-mock")]
+        let ns_view: *mut Object = {
+            let _ = label;
+            std::ptr::null_mut()
+        };
+
+        // Pointers are not `Send`; the closure only runs on the main thread
+        let view = ns_view as usize;
+        let action = {
+            let state = Arc::clone(&state);
+            TargetAction::new(move || {
+                let on = clicked_state(&state, view as *mut Object);
+                state.toggled(on);
+            })
+        };
+        action.attach_to(ns_view);
+
+        Ok(Toggle { ns_view, state, action })
+    }
+
+    /// Get the current state
+    pub(crate) fn is_on(&self) -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            return unsafe { read_state(self.ns_view) };
+        }
+        self.state.on.load(Ordering::SeqCst)
+    }
+
+    /// Set the state without notifying the handler
+    pub(crate) fn set_on(&self, on: bool) {
+        self.state.on.store(on, Ordering::SeqCst);
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_view, setState: on as i64];
+            }
+        }
+    }
+
+    /// Set or clear the change handler
+    pub(crate) fn set_on_change(&self, handler: Option<ToggleCallback>) {
+        *self
+            .state
+            .on_change
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = handler;
+    }
+
+    /// Check if a change handler is set
+    pub(crate) fn has_on_change(&self) -> bool {
+        self.state
+            .on_change
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .is_some()
+    }
+
+    /// Click the control as the user would
+    pub(crate) fn perform_click(&self) {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let nil: *mut Object = std::ptr::null_mut();
+                let _: () = msg_send![self.ns_view, performClick: nil];
+            }
+            return;
+        }
+        self.action.invoke();
+    }
+
+    /// Get the native control
+    pub(crate) fn ns_view(&self) -> *mut Object {
+        self.ns_view
+    }
+
+    /// Show or hide the control
+    pub(crate) fn set_visible(&self, visible: bool) {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_view, setHidden: !visible];
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = visible;
+    }
+
+    /// Check if the control is shown
+    pub(crate) fn is_visible(&self) -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let hidden: bool = msg_send![self.ns_view, isHidden];
+                return !hidden;
+            }
+        }
+        true
+    }
+}
+
+impl Drop for Toggle {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let nil: *mut Object = std::ptr::null_mut();
+                let _: () = msg_send![self.ns_view, setTarget: nil];
+                let _: () = msg_send![self.ns_view, release];
+            }
+        }
+    }
+}
+
+unsafe impl Send for Toggle {}
+unsafe impl Sync for Toggle {}
+
+/// The state a click leaves the control in
+///
+/// AppKit has already flipped the native control when the action is sent.
+/// Mock builds have no control, so the click is simulated: radio buttons
+/// become selected and the others flip.
+fn clicked_state(state: &ToggleState, ns_view: *mut Object) -> bool {
+    #[cfg(not(feature = "test-mock"))]
+    if !ns_view.is_null() {
+        return unsafe { read_state(ns_view) };
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = ns_view;
+    state.kind == ToggleKind::Radio || !state.on.load(Ordering::SeqCst)
+}
+
+/// Read `NSControlStateValueOn` from a button or switch
+#[cfg(not(feature = "test-mock"))]
+unsafe fn read_state(ns_view: *mut Object) -> bool {
+    let state: i64 = unsafe { msg_send![ns_view, state] };
+    state == 1
+}
+
+/// Create the native control for `kind` with its label and initial state
+#[cfg(not(feature = "test-mock"))]
+unsafe fn create_control(kind: ToggleKind, label: &str, on: bool) -> Result<*mut Object> {
+    use cocoa::foundation::{NSPoint, NSRect, NSSize};
+
+    unsafe {
+        let title = crate::core::utils::string_to_ns_string(label)?;
+        let frame = NSRect {
+            origin: NSPoint { x: 0.0, y: 0.0 },
+            size: NSSize { width: 200.0, height: 24.0 },
+        };
+        let switch_class = Class::get("NSSwitch").filter(|_| kind == ToggleKind::Switch);
+        let ns_view: *mut Object = match switch_class {
+            Some(class) => {
+                let ns_switch: *mut Object = msg_send![class, alloc];
+                msg_send![ns_switch, initWithFrame: frame]
+            }
+            None => {
+                let ns_button: *mut Object = msg_send![class!(NSButton), alloc];
+                let ns_button: *mut Object = msg_send![ns_button, initWithFrame: frame];
+                if !ns_button.is_null() {
+                    // NSButtonTypeRadio / NSButtonTypeSwitch
+                    let button_type: u64 = if kind == ToggleKind::Radio { 4 } else { 3 };
+                    let _: () = msg_send![ns_button, setButtonType: button_type];
+                    let _: () = msg_send![ns_button, setTitle: title];
+                }
+                ns_button
+            }
+        };
+        if ns_view.is_null() {
+            return Err(CocoanutError::ControlCreationFailed(
+                format!("Failed to create {:?} control", kind)
+            ));
+        }
+        let _: () = msg_send![ns_view, setState: on as i64];
+        Ok(ns_view)
+    }
+}
@@ -1,26 +1,281 @@
 //! UI controls for macOS GUI applications
+//!
+//! Each control owns the single reference returned by `alloc`/`init` and
+//! releases it on drop. Clones retain the same object, so every copy stays
+//! valid until it is dropped. Views that add a control to a window take their
+//! own reference, so dropping the Rust value after adding it is fine.
 
 use crate::core::error::{CocoanutError, Result};
+use crate::core::traits::{Drawable, Positionable};
+use crate::systems::target_action::TargetAction;
+use crate::utils::MemoryManager;
+use crate::features::macos::macos_integration::{
+    set_view_accessibility_role, set_view_accessibility_value, AccessibilityRole,
+};
 use objc::runtime::Object;
 use objc::{msg_send, sel, sel_impl};
 use std::ffi::CString;
+use std::sync::{Arc, Mutex};
+
+#[cfg(not(feature = "test-mock"))]
+use crate::features::zero_cost::ZeroCostRect;
+#[cfg(feature = "test-mock")]
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+#[cfg(not(feature = "test-mock"))]
+use objc::declare::ClassDecl;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Class, Sel};
+#[cfg(not(feature = "test-mock"))]
+use objc::class;
+#[cfg(not(feature = "test-mock"))]
+use std::ffi::c_void;
+#[cfg(not(feature = "test-mock"))]
+use std::sync::Once;
+
+type TextChangeHandler = Arc<dyn Fn(String) + Send + Sync>;
+type CharacterCountHandler = Arc<dyn Fn(CharacterCount) + Send + Sync>;
 
 /// A macOS button control
 pub struct Button {
     ns_button: *mut Object,
     title: String,
+    accessibility_role: AccessibilityRole,
+    click_action: Option<TargetAction>,
+    #[cfg(feature = "test-mock")]
+    mock_view: Arc<MockView>,
 }
 
 /// A macOS label control
+///
+/// Clones share the native label and its current text.
 pub struct Label {
     ns_label: *mut Object,
     text: String,
+    /// The text including changes made through clones
+    current_text: Arc<Mutex<String>>,
+    accessibility_role: AccessibilityRole,
+    #[cfg(feature = "test-mock")]
+    mock_view: Arc<MockView>,
 }
 
 /// A macOS text field control
+///
+/// Clones share the native field and its current text.
 pub struct TextField {
     ns_text_field: *mut Object,
     text: String,
+    /// The text including the user's edits
+    current_text: Arc<Mutex<String>>,
+    accessibility_role: AccessibilityRole,
+    change_delegate: Option<Arc<ChangeDelegate>>,
+    number_format: Option<NumberFormat>,
+    character_count: Arc<Mutex<Option<(usize, CharacterCountHandler)>>>,
+    #[cfg(feature = "test-mock")]
+    mock_view: Arc<MockView>,
+}
+
+/// The view state mock builds keep in place of a native view, shared
+/// between clones as the native view would be
+#[cfg(feature = "test-mock")]
+struct MockView {
+    /// Opacity as `f32` bits
+    alpha: AtomicU32,
+    hidden: AtomicBool,
+}
+
+#[cfg(feature = "test-mock")]
+impl Default for MockView {
+    fn default() -> Self {
+        MockView { alpha: AtomicU32::new(1.0f32.to_bits()), hidden: AtomicBool::new(false) }
+    }
+}
+
+/// The length of a text field's text against a limit
+///
+/// Lengths count characters rather than bytes, so "héllo" is 5 long.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CharacterCount {
+    /// Characters in the text
+    pub count: usize,
+    /// Most characters allowed
+    pub max: usize,
+}
+
+impl CharacterCount {
+    /// Count the characters in `text` against `max`
+    pub fn new(text: &str, max: usize) -> Self {
+        CharacterCount { count: text.chars().count(), max }
+    }
+
+    /// Check if the text is longer than allowed
+    pub fn is_over_limit(&self) -> bool {
+        self.count > self.max
+    }
+}
+
+impl std::fmt::Display for CharacterCount {
+    /// Show the count as `count/max`, e.g. `12/140`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.count, self.max)
+    }
+}
+
+/// How a text field formats and parses numbers
+///
+/// Set with [`TextField::set_number_format`]; the field then wraps an
+/// `NSNumberFormatter` that rejects non-numeric input and formats the value
+/// when editing ends.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumberFormat {
+    /// Whole numbers with grouping separators
+    Integer,
+    /// Numbers with exactly this many fraction digits
+    Decimal(usize),
+    /// Amounts in the ISO 4217 currency, e.g. `"USD"`
+    Currency(String),
+    /// Fractions shown as percentages, so 0.25 reads as 25%
+    Percent,
+}
+
+impl NumberFormat {
+    /// Parse `text` as written in this format
+    ///
+    /// Grouping separators and surrounding whitespace are ignored, as are the
+    /// currency code or symbol for [`NumberFormat::Currency`] and the percent
+    /// sign for [`NumberFormat::Percent`], which also divides by 100.
+    pub fn parse(&self, text: &str) -> Option<f64> {
+        let mut digits: String = text.chars().filter(|c| !c.is_whitespace() && *c != ',').collect();
+        match self {
+            NumberFormat::Currency(code) => {
+                digits = digits.replace(code.as_str(), "");
+                digits = digits
+                    .trim_matches(|c: char| !c.is_alphanumeric() && c != '-' && c != '.')
+                    .to_string();
+            }
+            NumberFormat::Percent => {
+                digits = digits.strip_suffix('%').unwrap_or(&digits).to_string();
+            }
+            NumberFormat::Integer | NumberFormat::Decimal(_) => {}
+        }
+        if !digits.chars().any(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let value: f64 = digits.parse().ok()?;
+        match self {
+            NumberFormat::Integer if value.fract() != 0.0 => None,
+            NumberFormat::Percent => Some(value / 100.0),
+            _ => Some(value),
+        }
+    }
+
+    /// Create an autoreleased `NSNumberFormatter` for this format
+    #[cfg(not(feature = "test-mock"))]
+    unsafe fn ns_formatter(&self) -> Result<*mut Object> {
+        // NSNumberFormatterStyle values
+        const DECIMAL: u64 = 1;
+        const CURRENCY: u64 = 2;
+        const PERCENT: u64 = 3;
+
+        unsafe {
+            let formatter: *mut Object = msg_send![class!(NSNumberFormatter), new];
+            if formatter.is_null() {
+                return Err(CocoanutError::ObjcNull("NSNumberFormatter"));
+            }
+            let formatter: *mut Object = msg_send![formatter, autorelease];
+            match self {
+                NumberFormat::Integer => {
+                    let _: () = msg_send![formatter, setNumberStyle: DECIMAL];
+                    let _: () = msg_send![formatter, setAllowsFloats: false];
+                    let _: () = msg_send![formatter, setMaximumFractionDigits: 0u64];
+                }
+                NumberFormat::Decimal(places) => {
+                    let _: () = msg_send![formatter, setNumberStyle: DECIMAL];
+                    let _: () = msg_send![formatter, setMinimumFractionDigits: *places as u64];
+                    let _: () = msg_send![formatter, setMaximumFractionDigits: *places as u64];
+                }
+                NumberFormat::Currency(code) => {
+                    let _: () = msg_send![formatter, setNumberStyle: CURRENCY];
+                    let code = crate::core::utils::string_to_ns_string(code)?;
+                    let _: () = msg_send![formatter, setCurrencyCode: code];
+                }
+                NumberFormat::Percent => {
+                    let _: () = msg_send![formatter, setNumberStyle: PERCENT];
+                }
+            }
+            // Accept input typed without the grouping or currency symbols
+            let _: () = msg_send![formatter, setLenient: true];
+            Ok(formatter)
+        }
+    }
+}
+
+/// `NSTextFieldDelegate` that reports edits to a handler
+///
+/// Shared between clones of a `TextField`; the last one to drop detaches the
+/// delegate from the field and frees the handler.
+struct ChangeDelegate {
+    #[cfg(not(feature = "test-mock"))]
+    ns_text_field: *mut Object,
+    delegate: *mut Object,
+    /// Whether the delegate runs an `on_change` handler, not only the
+    /// character count
+    has_handler: bool,
+    /// Lets mock tests call the handler the delegate would run
+    #[cfg(all(test, feature = "test-mock"))]
+    handler: TextChangeHandler,
+}
+
+impl ChangeDelegate {
+    /// Create the delegate and install it on `ns_text_field`
+    fn new(ns_text_field: *mut Object, handler: TextChangeHandler) -> Self {
+        #[cfg(not(feature = "test-mock"))]
+        let delegate = if ns_text_field.is_null() {
+            std::ptr::null_mut()
+        } else {
+            unsafe {
+                let slot = Box::into_raw(Box::new(Arc::clone(&handler))) as *mut c_void;
+                let delegate: *mut Object = msg_send![text_field_delegate_class(), new];
+                (*delegate).set_ivar::<*mut c_void>("rustHandler", slot);
+                let _: () = msg_send![ns_text_field, setDelegate: delegate];
+                delegate
+            }
+        };
+        #[cfg(feature = "test-mock")]
+        let delegate = {
+            let _ = (ns_text_field, &handler);
+            std::ptr::null_mut()
+        };
+
+        ChangeDelegate {
+            #[cfg(not(feature = "test-mock"))]
+            ns_text_field,
+            delegate,
+            has_handler: true,
+            #[cfg(all(test, feature = "test-mock"))]
+            handler,
+        }
+    }
+}
+
+impl Drop for ChangeDelegate {
+    fn drop(&mut self) {
+        if !self.delegate.is_null() {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                let current: *mut Object = msg_send![self.ns_text_field, delegate];
+                if current == self.delegate {
+                    let nil: *mut Object = std::ptr::null_mut();
+                    let _: () = msg_send![self.ns_text_field, setDelegate: nil];
+                }
+                let slot = *(*self.delegate).get_ivar::<*mut c_void>("rustHandler");
+                (*self.delegate).set_ivar::<*mut c_void>("rustHandler", std::ptr::null_mut());
+                if !slot.is_null() {
+                    drop(Box::from_raw(slot as *mut TextChangeHandler));
+                }
+                let _: () = msg_send![self.delegate, release];
+            }
+        }
+    }
 }
 
 impl Button {
@@ -57,14 +312,21 @@ impl Button {
     pub fn new(title: &str) -> Result<Self> {
         #[cfg(feature = "test-mock")]
         {
+            // Stand-in for the reference alloc/init hands out
+            MemoryManager::retain(std::ptr::null_mut())?;
             return Ok(Button {
                 ns_button: std::ptr::null_mut(),
                 title: title.to_string(),
+                accessibility_role: AccessibilityRole::Button,
+                click_action: None,
+                mock_view: Arc::default(),
             });
         }
         
         #[cfg(not(feature = "test-mock"))]
         unsafe {
+            crate::core::utils::debug_assert_main_thread("Button");
+
             use cocoa::foundation::{NSRect, NSPoint, NSSize};
             
             let button_class = objc::class!(NSButton);
@@ -98,9 +360,13 @@ impl Button {
             // Set button style
             let _: () = msg_send![ns_button, setButtonType: 0]; // NSButtonTypeMomentaryPushIn
             
+            set_view_accessibility_role(ns_button, AccessibilityRole::Button)?;
+            
             Ok(Button {
                 ns_button,
                 title: title.to_string(),
+                accessibility_role: AccessibilityRole::Button,
+                click_action: None,
             })
         }
     }
@@ -130,6 +396,25 @@ impl Button {
         }
     }
     
+    /// Run `handler` whenever the button is clicked
+    ///
+    /// Replaces any previously set handler.
+    pub fn on_click<F>(&mut self, handler: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let action = TargetAction::new(handler);
+        action.attach_to(self.ns_button);
+        self.click_action = Some(action);
+    }
+
+    /// Run the click handler, if any, as if the button had been clicked
+    pub fn perform_click(&self) {
+        if let Some(action) = &self.click_action {
+            action.invoke();
+        }
+    }
+
     /// Get the underlying NSButton pointer
     pub(crate) fn ns_button(&self) -> *mut Object {
         self.ns_button
@@ -139,6 +424,30 @@ impl Button {
     pub fn as_view(&self) -> *mut Object {
         self.ns_button
     }
+
+    /// Set the help tag shown when the pointer rests on the button
+    ///
+    /// An empty string removes the tooltip.
+    pub fn set_tooltip(&self, tooltip: &str) -> Result<()> {
+        crate::core::utils::set_view_tooltip(self.ns_button, tooltip)
+    }
+    
+    /// Get the accessibility role
+    pub fn accessibility_role(&self) -> AccessibilityRole {
+        self.accessibility_role
+    }
+    
+    /// Set the accessibility role reported to VoiceOver
+    pub fn set_accessibility_role(&mut self, role: AccessibilityRole) -> Result<()> {
+        set_view_accessibility_role(self.ns_button, role)?;
+        self.accessibility_role = role;
+        Ok(())
+    }
+    
+    /// Set the accessibility value reported to VoiceOver
+    pub fn set_accessibility_value(&mut self, value: &str) -> Result<()> {
+        set_view_accessibility_value(self.ns_button, value)
+    }
 }
 
 impl Label {
@@ -163,14 +472,21 @@ impl Label {
     pub fn new(text: &str) -> Result<Self> {
         #[cfg(feature = "test-mock")]
         {
+            // Stand-in for the reference alloc/init hands out
+            MemoryManager::retain(std::ptr::null_mut())?;
             return Ok(Label {
                 ns_label: std::ptr::null_mut(),
                 text: text.to_string(),
+                current_text: Arc::new(Mutex::new(text.to_string())),
+                accessibility_role: AccessibilityRole::StaticText,
+                mock_view: Arc::default(),
             });
         }
         
         #[cfg(not(feature = "test-mock"))]
         unsafe {
+            crate::core::utils::debug_assert_main_thread("Label");
+
             use cocoa::foundation::{NSRect, NSPoint, NSSize};
             
             let label_class = objc::class!(NSTextField);
@@ -205,23 +521,35 @@ impl Label {
             let _: () = msg_send![ns_label, setEditable: false];
             let _: () = msg_send![ns_label, setSelectable: false];
             
+            set_view_accessibility_role(ns_label, AccessibilityRole::StaticText)?;
+            
             Ok(Label {
                 ns_label,
                 text: text.to_string(),
+                current_text: Arc::new(Mutex::new(text.to_string())),
+                accessibility_role: AccessibilityRole::StaticText,
             })
         }
     }
     
-    /// Get the label text
+    /// Get the label text, as created or last set on this label
+    ///
+    /// Use [`Label::current_text`] to include changes made through clones.
     pub fn text(&self) -> &str {
         &self.text
     }
+
+    /// Get the label text including changes made through clones of the
+    /// label, such as a character count label updated by a [`TextField`]
+    pub fn current_text(&self) -> String {
+        self.current_text.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+    }
     
     /// Set the label text
     pub fn set_text(&mut self, text: &str) -> Result<()> {
         #[cfg(feature = "test-mock")]
         {
-            self.text = text.to_string();
+            self.store_text(text);
             return Ok(());
         }
         
@@ -232,10 +560,15 @@ impl Label {
             let ns_string_class = objc::class!(NSString);
             let text_nsstring: *mut Object = msg_send![ns_string_class, stringWithUTF8String: text_cstr.as_ptr()];
             let _: () = msg_send![self.ns_label, setStringValue: text_nsstring];
-            self.text = text.to_string();
+            self.store_text(text);
             Ok(())
         }
     }
+
+    fn store_text(&mut self, text: &str) {
+        self.text = text.to_string();
+        *self.current_text.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = text.to_string();
+    }
     
     /// Get the underlying NSTextField pointer
     pub(crate) fn ns_label(&self) -> *mut Object {
@@ -246,6 +579,30 @@ impl Label {
     pub fn as_view(&self) -> *mut Object {
         self.ns_label
     }
+
+    /// Set the help tag shown when the pointer rests on the label
+    ///
+    /// An empty string removes the tooltip.
+    pub fn set_tooltip(&self, tooltip: &str) -> Result<()> {
+        crate::core::utils::set_view_tooltip(self.ns_label, tooltip)
+    }
+    
+    /// Get the accessibility role
+    pub fn accessibility_role(&self) -> AccessibilityRole {
+        self.accessibility_role
+    }
+    
+    /// Set the accessibility role reported to VoiceOver
+    pub fn set_accessibility_role(&mut self, role: AccessibilityRole) -> Result<()> {
+        set_view_accessibility_role(self.ns_label, role)?;
+        self.accessibility_role = role;
+        Ok(())
+    }
+    
+    /// Set the accessibility value reported to VoiceOver
+    pub fn set_accessibility_value(&mut self, value: &str) -> Result<()> {
+        set_view_accessibility_value(self.ns_label, value)
+    }
 }
 
 impl TextField {
@@ -270,14 +627,24 @@ impl TextField {
     pub fn new(text: &str) -> Result<Self> {
         #[cfg(feature = "test-mock")]
         {
+            // Stand-in for the reference alloc/init hands out
+            MemoryManager::retain(std::ptr::null_mut())?;
             return Ok(TextField {
                 ns_text_field: std::ptr::null_mut(),
                 text: text.to_string(),
+                current_text: Arc::new(Mutex::new(text.to_string())),
+                accessibility_role: AccessibilityRole::TextField,
+                change_delegate: None,
+                number_format: None,
+                character_count: Arc::new(Mutex::new(None)),
+                mock_view: Arc::default(),
             });
         }
         
         #[cfg(not(feature = "test-mock"))]
         unsafe {
+            crate::core::utils::debug_assert_main_thread("TextField");
+
             use cocoa::foundation::{NSRect, NSPoint, NSSize};
             
             let text_field_class = objc::class!(NSTextField);
@@ -312,23 +679,50 @@ impl TextField {
             let _: () = msg_send![ns_text_field, setEditable: true];
             let _: () = msg_send![ns_text_field, setSelectable: true];
             
+            set_view_accessibility_role(ns_text_field, AccessibilityRole::TextField)?;
+            
             Ok(TextField {
                 ns_text_field,
                 text: text.to_string(),
+                current_text: Arc::new(Mutex::new(text.to_string())),
+                accessibility_role: AccessibilityRole::TextField,
+                change_delegate: None,
+                number_format: None,
+                character_count: Arc::new(Mutex::new(None)),
             })
         }
     }
     
-    /// Get the text field content
+    /// Get the text field content, as created or last set with
+    /// [`TextField::set_text`]
+    ///
+    /// Use [`TextField::current_text`] to include the user's edits.
     pub fn text(&self) -> &str {
         &self.text
     }
+
+    /// Get the text field content including the user's edits
+    ///
+    /// Read from the native field, so edits show up without a change
+    /// handler.
+    pub fn current_text(&self) -> String {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_text_field.is_null() {
+            unsafe {
+                let ns_text: *mut Object = msg_send![self.ns_text_field, stringValue];
+                if let Ok(text) = crate::core::utils::ns_string_to_string(ns_text) {
+                    return text;
+                }
+            }
+        }
+        self.current_text.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+    }
     
     /// Set the text field content
     pub fn set_text(&mut self, text: &str) -> Result<()> {
         #[cfg(feature = "test-mock")]
         {
-            self.text = text.to_string();
+            self.store_text(text);
             return Ok(());
         }
         
@@ -339,11 +733,167 @@ impl TextField {
             let ns_string_class = objc::class!(NSString);
             let text_nsstring: *mut Object = msg_send![ns_string_class, stringWithUTF8String: text_cstr.as_ptr()];
             let _: () = msg_send![self.ns_text_field, setStringValue: text_nsstring];
-            self.text = text.to_string();
+            self.store_text(text);
             Ok(())
         }
     }
     
+    /// Run `handler` with the new text whenever the user edits the field
+    ///
+    /// The handler is called for each keystroke through the field's
+    /// `NSTextFieldDelegate`, replacing any previously set handler. Changes
+    /// made with [`TextField::set_text`] are not reported.
+    pub fn on_change<F>(&mut self, handler: F)
+    where
+        F: Fn(String) + Send + Sync + 'static,
+    {
+        self.install_change_delegate(Some(Arc::new(handler)));
+    }
+
+    /// Run `handler` with the length of the text against `max` whenever the
+    /// user edits the field
+    ///
+    /// This works alongside [`TextField::on_change`], replacing any previously
+    /// set count handler.
+    pub fn on_character_count<F>(&mut self, max: usize, handler: F)
+    where
+        F: Fn(CharacterCount) + Send + Sync + 'static,
+    {
+        *self.lock_character_count() = Some((max, Arc::new(handler)));
+        if self.change_delegate.is_none() {
+            self.install_change_delegate(None);
+        }
+    }
+
+    /// Show the length of the text as `count/max` in `label` as the user
+    /// types, in red once the text is longer than `max`
+    pub fn set_character_count_label(&mut self, label: &Label, max: usize) -> Result<()> {
+        let mut label = label.clone();
+        show_character_count(&mut label, CharacterCount::new(&self.current_text(), max))?;
+        let label = Mutex::new(label);
+        self.on_character_count(max, move |count| {
+            let mut label = label.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            let _ = show_character_count(&mut label, count);
+        });
+        Ok(())
+    }
+
+    /// Get the length of the text against the limit set with
+    /// [`TextField::on_character_count`], if any
+    pub fn character_count(&self) -> Option<CharacterCount> {
+        let max = self.lock_character_count().as_ref().map(|(max, _)| *max)?;
+        Some(CharacterCount::new(&self.current_text(), max))
+    }
+
+    /// Replace the field's delegate with one storing each edit, updating the
+    /// character count and then running `handler`
+    fn install_change_delegate(&mut self, handler: Option<TextChangeHandler>) {
+        // Drop the old delegate first so it does not detach the new one
+        self.change_delegate = None;
+        let has_handler = handler.is_some();
+        let text = Arc::clone(&self.current_text);
+        let character_count = Arc::clone(&self.character_count);
+        let on_edit = move |new_text: String| {
+            *text.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = new_text.clone();
+            let counter = character_count.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone();
+            if let Some((max, counter)) = counter {
+                counter(CharacterCount::new(&new_text, max));
+            }
+            if let Some(handler) = &handler {
+                handler(new_text);
+            }
+        };
+        let mut change_delegate = ChangeDelegate::new(self.ns_text_field, Arc::new(on_edit));
+        change_delegate.has_handler = has_handler;
+        self.change_delegate = Some(Arc::new(change_delegate));
+    }
+
+    fn lock_character_count(&self) -> std::sync::MutexGuard<'_, Option<(usize, CharacterCountHandler)>> {
+        self.character_count.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Get a function replacing the shown text, for change handlers that
+    /// cannot hold the field itself
+    ///
+    /// The function must not outlive the field; a field's own handlers never
+    /// do.
+    pub(crate) fn text_writer(&self) -> impl Fn(&str) + Send + Sync + 'static {
+        // Pointers are not `Send`; change handlers run on the main thread
+        let ns_text_field = self.ns_text_field as usize;
+        let current_text = Arc::clone(&self.current_text);
+        move |text: &str| {
+            #[cfg(not(feature = "test-mock"))]
+            if let Ok(ns_text) = crate::core::utils::string_to_ns_string(text) {
+                unsafe {
+                    let _: () = msg_send![ns_text_field as *mut Object, setStringValue: ns_text];
+                }
+            }
+            #[cfg(feature = "test-mock")]
+            let _ = ns_text_field;
+            *current_text.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = text.to_string();
+        }
+    }
+
+    /// Run the change handler as the delegate would after a user edit
+    #[cfg(all(test, feature = "test-mock"))]
+    pub(crate) fn simulate_change(&self, text: &str) {
+        if let Some(change_delegate) = &self.change_delegate {
+            (change_delegate.handler)(text.to_string());
+        }
+    }
+
+    fn store_text(&mut self, text: &str) {
+        self.text = text.to_string();
+        *self.current_text.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = text.to_string();
+    }
+
+    /// Check if a change handler is set
+    pub fn has_change_handler(&self) -> bool {
+        self.change_delegate.as_ref().is_some_and(|change_delegate| change_delegate.has_handler)
+    }
+
+    /// Only accept numbers in `format`, formatting the value when editing
+    /// ends
+    pub fn set_number_format(&mut self, format: NumberFormat) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let formatter = format.ns_formatter()?;
+            let _: () = msg_send![self.ns_text_field, setFormatter: formatter];
+        }
+        self.number_format = Some(format);
+        Ok(())
+    }
+
+    /// Get the format set with [`TextField::set_number_format`], if any
+    pub fn number_format(&self) -> Option<&NumberFormat> {
+        self.number_format.as_ref()
+    }
+
+    /// Get the field's text as a number
+    ///
+    /// The text is read through the field's number format, if one is set.
+    /// `None` if the text is not a number.
+    pub fn number_value(&self) -> Option<f64> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let formatter: *mut Object = msg_send![self.ns_text_field, formatter];
+            if !formatter.is_null() {
+                let ns_text: *mut Object = msg_send![self.ns_text_field, stringValue];
+                let number: *mut Object = msg_send![formatter, numberFromString: ns_text];
+                if number.is_null() {
+                    return None;
+                }
+                let value: f64 = msg_send![number, doubleValue];
+                return Some(value);
+            }
+        }
+        let text = self.current_text();
+        match &self.number_format {
+            Some(format) => format.parse(&text),
+            None => text.trim().parse().ok(),
+        }
+    }
+
     /// Get the underlying NSTextField pointer
     pub(crate) fn ns_text_field(&self) -> *mut Object {
         self.ns_text_field
@@ -353,38 +903,415 @@ impl TextField {
     pub fn as_view(&self) -> *mut Object {
         self.ns_text_field
     }
+
+    /// Set the help tag shown when the pointer rests on the text field
+    ///
+    /// An empty string removes the tooltip.
+    pub fn set_tooltip(&self, tooltip: &str) -> Result<()> {
+        crate::core::utils::set_view_tooltip(self.ns_text_field, tooltip)
+    }
+    
+    /// Get the accessibility role
+    pub fn accessibility_role(&self) -> AccessibilityRole {
+        self.accessibility_role
+    }
+    
+    /// Set the accessibility role reported to VoiceOver
+    pub fn set_accessibility_role(&mut self, role: AccessibilityRole) -> Result<()> {
+        set_view_accessibility_role(self.ns_text_field, role)?;
+        self.accessibility_role = role;
+        Ok(())
+    }
+    
+    /// Set the accessibility value reported to VoiceOver
+    pub fn set_accessibility_value(&mut self, value: &str) -> Result<()> {
+        set_view_accessibility_value(self.ns_text_field, value)
+    }
+}
+
+impl Clone for Button {
+    /// Share the same NSObject, taking an extra reference for the copy
+    fn clone(&self) -> Self {
+        let _ = MemoryManager::retain(self.ns_button);
+        Button {
+            ns_button: self.ns_button,
+            title: self.title.clone(),
+            accessibility_role: self.accessibility_role,
+            click_action: self.click_action.clone(),
+            #[cfg(feature = "test-mock")]
+            mock_view: Arc::clone(&self.mock_view),
+        }
+    }
 }
 
 impl Drop for Button {
     fn drop(&mut self) {
-        #[cfg(not(feature = "test-mock"))]
-        unsafe {
-            let _: () = msg_send![self.ns_button, release];
+        let _ = MemoryManager::release(self.ns_button);
+    }
+}
+
+impl Clone for Label {
+    /// Share the same NSObject, taking an extra reference for the copy
+    fn clone(&self) -> Self {
+        let _ = MemoryManager::retain(self.ns_label);
+        Label {
+            ns_label: self.ns_label,
+            text: self.text.clone(),
+            current_text: Arc::clone(&self.current_text),
+            accessibility_role: self.accessibility_role,
+            #[cfg(feature = "test-mock")]
+            mock_view: Arc::clone(&self.mock_view),
         }
     }
 }
 
 impl Drop for Label {
     fn drop(&mut self) {
-        #[cfg(not(feature = "test-mock"))]
-        unsafe {
-            let _: () = msg_send![self.ns_label, release];
+        let _ = MemoryManager::release(self.ns_label);
+    }
+}
+
+impl Clone for TextField {
+    /// Share the same NSObject, taking an extra reference for the copy
+    fn clone(&self) -> Self {
+        let _ = MemoryManager::retain(self.ns_text_field);
+        TextField {
+            ns_text_field: self.ns_text_field,
+            text: self.text.clone(),
+            current_text: Arc::clone(&self.current_text),
+            accessibility_role: self.accessibility_role,
+            change_delegate: self.change_delegate.clone(),
+            number_format: self.number_format.clone(),
+            character_count: Arc::clone(&self.character_count),
+            #[cfg(feature = "test-mock")]
+            mock_view: Arc::clone(&self.mock_view),
         }
     }
 }
 
 impl Drop for TextField {
     fn drop(&mut self) {
-        #[cfg(not(feature = "test-mock"))]
-        unsafe {
-            let _: () = msg_send![self.ns_text_field, release];
+        let _ = MemoryManager::release(self.ns_text_field);
+    }
+}
+
+/// Implement [`Drawable`] and [`Positionable`] for a control through its
+/// native view field
+macro_rules! impl_view_traits {
+    ($control:ty, $field:ident) => {
+        impl Drawable for $control {
+            fn as_view(&self) -> *mut Object {
+                self.$field
+            }
+
+            fn set_visible(&self, visible: bool) -> Result<()> {
+                #[cfg(not(feature = "test-mock"))]
+                unsafe {
+                    let _: () = msg_send![self.$field, setHidden: !visible];
+                }
+                #[cfg(feature = "test-mock")]
+                self.mock_view.hidden.store(!visible, Ordering::Relaxed);
+                Ok(())
+            }
+
+            fn is_visible(&self) -> bool {
+                #[cfg(not(feature = "test-mock"))]
+                unsafe {
+                    let hidden: bool = msg_send![self.$field, isHidden];
+                    !hidden
+                }
+                #[cfg(feature = "test-mock")]
+                !self.mock_view.hidden.load(Ordering::Relaxed)
+            }
+
+            fn set_alpha(&self, alpha: f32) -> Result<()> {
+                crate::core::utils::set_view_alpha(self.$field, alpha)?;
+                #[cfg(feature = "test-mock")]
+                self.mock_view.alpha.store(alpha.to_bits(), Ordering::Relaxed);
+                Ok(())
+            }
+
+            fn alpha(&self) -> f32 {
+                #[cfg(feature = "test-mock")]
+                return f32::from_bits(self.mock_view.alpha.load(Ordering::Relaxed));
+                #[cfg(not(feature = "test-mock"))]
+                crate::core::utils::view_alpha(self.$field)
+            }
+        }
+
+        impl Positionable for $control {
+            fn set_frame(&self, x: f64, y: f64, width: f64, height: f64) -> Result<()> {
+                #[cfg(not(feature = "test-mock"))]
+                unsafe {
+                    let frame: cocoa::foundation::NSRect = ZeroCostRect::from_xywh(x, y, width, height).into();
+                    let _: () = msg_send![self.$field, setFrame: frame];
+                }
+                #[cfg(feature = "test-mock")]
+                let _ = (x, y, width, height);
+                Ok(())
+            }
+
+            fn frame(&self) -> (f64, f64, f64, f64) {
+                #[cfg(not(feature = "test-mock"))]
+                unsafe {
+                    let frame: cocoa::foundation::NSRect = msg_send![self.$field, frame];
+                    (frame.origin.x, frame.origin.y, frame.size.width, frame.size.height)
+                }
+                #[cfg(feature = "test-mock")]
+                (0.0, 0.0, 100.0, 40.0)
+            }
+
+            fn intrinsic_content_size(&self) -> (f64, f64) {
+                let (_, _, width, height) = self.frame();
+                let (intrinsic_width, intrinsic_height) = crate::core::utils::view_intrinsic_size(self.$field);
+                (intrinsic_width.unwrap_or(width), intrinsic_height.unwrap_or(height))
+            }
+
+            fn size_to_fit(&self) -> Result<()> {
+                crate::core::utils::size_view_to_fit(self.$field);
+                Ok(())
+            }
         }
+    };
+}
+
+impl_view_traits!(Button, ns_button);
+impl_view_traits!(Label, ns_label);
+impl_view_traits!(TextField, ns_text_field);
+
+/// Show `count` in `label`, in red once it is over the limit
+fn show_character_count(label: &mut Label, count: CharacterCount) -> Result<()> {
+    label.set_text(&count.to_string())?;
+    #[cfg(not(feature = "test-mock"))]
+    unsafe {
+        let color: *mut Object = if count.is_over_limit() {
+            msg_send![class!(NSColor), systemRedColor]
+        } else {
+            msg_send![class!(NSColor), secondaryLabelColor]
+        };
+        let _: () = msg_send![label.ns_label, setTextColor: color];
     }
+    Ok(())
 }
 
+/// Lazily register the `NSTextFieldDelegate` class reporting edits
+#[cfg(not(feature = "test-mock"))]
+fn text_field_delegate_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutTextFieldDelegate", class!(NSObject))
+            .expect("CocoanutTextFieldDelegate already registered");
+        decl.add_ivar::<*mut c_void>("rustHandler");
+
+        extern "C" fn control_text_did_change(this: &Object, _cmd: Sel, notification: *mut Object) {
+            unsafe {
+                let slot = *this.get_ivar::<*mut c_void>("rustHandler") as *const TextChangeHandler;
+                let Some(handler) = slot.as_ref() else {
+                    return;
+                };
+                let field: *mut Object = msg_send![notification, object];
+                let ns_text: *mut Object = msg_send![field, stringValue];
+                if let Ok(text) = crate::core::utils::ns_string_to_string(ns_text) {
+                    handler(text);
+                }
+            }
+        }
+
+        unsafe {
+            decl.add_method(
+                sel!(controlTextDidChange:),
+                control_text_did_change as extern "C" fn(&Object, Sel, *mut Object),
+            );
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutTextFieldDelegate").expect("CocoanutTextFieldDelegate not registered")
+}
+
+unsafe impl Send for ChangeDelegate {}
+unsafe impl Sync for ChangeDelegate {}
 unsafe impl Send for Button {}
 unsafe impl Sync for Button {}
 unsafe impl Send for Label {}
 unsafe impl Sync for Label {}
 unsafe impl Send for TextField {}
 unsafe impl Sync for TextField {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_change_delegate_reports_field_text() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let handler: TextChangeHandler = {
+            let seen = Arc::clone(&seen);
+            Arc::new(move |text| seen.lock().unwrap().push(text))
+        };
+
+        unsafe {
+            let field: *mut Object = msg_send![class!(NSTextField), new];
+            let change_delegate = ChangeDelegate::new(field, handler);
+            let delegate: *mut Object = msg_send![field, delegate];
+            assert_eq!(delegate, change_delegate.delegate);
+
+            let text = crate::core::utils::string_to_ns_string("query").unwrap();
+            let _: () = msg_send![field, setStringValue: text];
+            let name = crate::core::utils::string_to_ns_string("NSControlTextDidChangeNotification").unwrap();
+            let notification: *mut Object = msg_send![class!(NSNotification), notificationWithName: name object: field];
+            let _: () = msg_send![delegate, controlTextDidChange: notification];
+
+            drop(change_delegate);
+            let delegate: *mut Object = msg_send![field, delegate];
+            assert!(delegate.is_null());
+            let _: () = msg_send![field, release];
+        }
+        assert_eq!(*seen.lock().unwrap(), vec!["query".to_string()]);
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_text_field_change_handler_is_stored() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut field = TextField::new("").unwrap();
+        assert!(!field.has_change_handler());
+        {
+            let seen = Arc::clone(&seen);
+            field.on_change(move |text| seen.lock().unwrap().push(text));
+        }
+        assert!(field.has_change_handler());
+        assert!(field.clone().has_change_handler());
+
+        field.simulate_change("search");
+        assert_eq!(*seen.lock().unwrap(), vec!["search".to_string()]);
+        assert_eq!(field.current_text(), "search");
+        assert_eq!(field.text(), "");
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_prelude_controls_are_drawable() {
+        let label = Label::new("Sidebar").unwrap();
+        let views: [&dyn crate::core::traits::View; 3] =
+            [&label, &Button::new("OK").unwrap(), &TextField::new("").unwrap()];
+        for view in views {
+            view.set_alpha(0.5).unwrap();
+            assert_eq!(view.alpha(), 0.5);
+            assert!(view.set_alpha(2.0).is_err());
+            view.set_visible(false).unwrap();
+            assert!(!view.is_visible());
+        }
+
+        // Clones share the view they wrap
+        assert!(!label.clone().is_visible());
+    }
+
+    #[test]
+    fn test_number_format_parse() {
+        assert_eq!(NumberFormat::Integer.parse("1,234"), Some(1234.0));
+        assert_eq!(NumberFormat::Integer.parse("1.5"), None);
+        assert_eq!(NumberFormat::Decimal(2).parse("abc"), None);
+        assert_eq!(NumberFormat::Decimal(2).parse("12abc"), None);
+        assert_eq!(NumberFormat::Currency("USD".to_string()).parse("$1,234.50"), Some(1234.5));
+        assert_eq!(NumberFormat::Currency("EUR".to_string()).parse("EUR 9.99"), Some(9.99));
+        assert_eq!(NumberFormat::Percent.parse("25%"), Some(0.25));
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_text_field_number_value() {
+        let mut field = crate::builder::TextFieldBuilder::new()
+            .text("1,234.50")
+            .number_formatter(NumberFormat::Decimal(2))
+            .build()
+            .unwrap();
+        assert_eq!(field.number_format(), Some(&NumberFormat::Decimal(2)));
+        assert_eq!(field.number_value(), Some(1234.5));
+
+        field.set_text("twelve").unwrap();
+        assert_eq!(field.number_value(), None);
+
+        let plain = TextField::new(" 42 ").unwrap();
+        assert_eq!(plain.number_value(), Some(42.0));
+    }
+
+    #[test]
+    fn test_character_count_counts_characters() {
+        let count = CharacterCount::new("héllo 👋", 140);
+        assert_eq!(count.count, 7);
+        assert_eq!(count.to_string(), "7/140");
+        assert!(!count.is_over_limit());
+        assert!(CharacterCount::new("日本語です", 4).is_over_limit());
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_text_field_reports_character_count() {
+        let counts = Arc::new(Mutex::new(Vec::new()));
+        let mut field = TextField::new("").unwrap();
+        {
+            let counts = Arc::clone(&counts);
+            field.on_character_count(5, move |count| counts.lock().unwrap().push(count.count));
+        }
+        assert!(!field.has_change_handler());
+
+        field.simulate_change("naïve");
+        field.simulate_change("café 🎉");
+        assert_eq!(*counts.lock().unwrap(), vec![5, 6]);
+        assert_eq!(field.character_count(), Some(CharacterCount { count: 6, max: 5 }));
+
+        // A later change handler keeps the count running
+        field.on_change(|_| {});
+        field.simulate_change("ok");
+        assert_eq!(counts.lock().unwrap().last(), Some(&2));
+
+        let label = Label::new("").unwrap();
+        assert!(field.set_character_count_label(&label, 3).is_ok());
+        assert_eq!(field.character_count(), Some(CharacterCount { count: 2, max: 3 }));
+        assert_eq!(label.current_text(), "2/3");
+        field.simulate_change("naïve");
+        assert_eq!(label.current_text(), "5/3");
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_current_text_reads_native_field() {
+        let field = TextField::new("12").unwrap();
+        unsafe {
+            // As typing would, without a change handler installed
+            let typed = crate::core::utils::string_to_ns_string("42.5").unwrap();
+            let _: () = msg_send![field.ns_text_field(), setStringValue: typed];
+        }
+        assert_eq!(field.text(), "12");
+        assert_eq!(field.current_text(), "42.5");
+        assert_eq!(field.number_value(), Some(42.5));
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_character_count_label_turns_red_over_limit() {
+        let mut field = TextField::new("naïve").unwrap();
+        let label = Label::new("").unwrap();
+        field.set_character_count_label(&label, 3).unwrap();
+        assert_eq!(label.current_text(), "5/3");
+
+        unsafe {
+            let color: *mut Object = msg_send![label.ns_label(), textColor];
+            let red: *mut Object = msg_send![class!(NSColor), systemRedColor];
+            let is_red: bool = msg_send![color, isEqual: red];
+            assert!(is_red);
+
+            let mut field = TextField::new("ok").unwrap();
+            field.set_character_count_label(&label, 3).unwrap();
+            let color: *mut Object = msg_send![label.ns_label(), textColor];
+            let secondary: *mut Object = msg_send![class!(NSColor), secondaryLabelColor];
+            let is_secondary: bool = msg_send![color, isEqual: secondary];
+            assert!(is_secondary);
+        }
+        assert_eq!(label.current_text(), "2/3");
+    }
+}
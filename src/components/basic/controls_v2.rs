@@ -1,12 +1,18 @@
 //! Refactored UI controls using traits and capability-facing design
 //!
 //! This module provides concise, trait-based UI controls that are test-friendly and modular.
+//! Each control owns the reference returned by `alloc`/`init` and releases it on drop.
 
 use crate::core::error::{CocoanutError, Result};
 use crate::core::traits::{Drawable, Textual, Positionable};
+use crate::utils::MemoryManager;
+#[cfg(not(feature = "test-mock"))]
+use crate::features::zero_cost::ZeroCostRect;
 use objc::runtime::Object;
 use objc::{msg_send, sel, sel_impl};
 use std::ffi::CString;
+#[cfg(feature = "test-mock")]
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 
 /// Macro to reduce boilerplate for NSString creation
 macro_rules! ns_string {
@@ -23,17 +29,37 @@ macro_rules! ns_string {
 struct ControlBase {
     ns_view: *mut Object,
     id: String,
+    /// Opacity as `f32` bits; mock builds have no view to hold it
+    #[cfg(feature = "test-mock")]
+    alpha: AtomicU32,
+    /// Hidden state mock builds report
+    #[cfg(feature = "test-mock")]
+    hidden: AtomicBool,
 }
 
 impl ControlBase {
+    /// Take ownership of `ns_view`'s alloc/init reference
     fn new(ns_view: *mut Object, id: &str) -> Self {
+        // Mock builds have no object, so record the reference it would hold
+        #[cfg(feature = "test-mock")]
+        let _ = MemoryManager::retain(ns_view);
         Self {
             ns_view,
             id: id.to_string(),
+            #[cfg(feature = "test-mock")]
+            alpha: AtomicU32::new(1.0f32.to_bits()),
+            #[cfg(feature = "test-mock")]
+            hidden: AtomicBool::new(false),
         }
     }
 }
 
+impl Drop for ControlBase {
+    fn drop(&mut self) {
+        let _ = MemoryManager::release(self.ns_view);
+    }
+}
+
 impl Drawable for ControlBase {
     fn as_view(&self) -> *mut Object {
         self.ns_view
@@ -44,6 +70,8 @@ impl Drawable for ControlBase {
         unsafe {
             let _: () = msg_send![self.ns_view, setHidden: !visible];
         }
+        #[cfg(feature = "test-mock")]
+        self.hidden.store(!visible, Ordering::Relaxed);
         Ok(())
     }
 
@@ -54,7 +82,21 @@ impl Drawable for ControlBase {
             !hidden
         }
         #[cfg(feature = "test-mock")]
-        true
+        !self.hidden.load(Ordering::Relaxed)
+    }
+
+    fn set_alpha(&self, alpha: f32) -> Result<()> {
+        crate::core::utils::set_view_alpha(self.ns_view, alpha)?;
+        #[cfg(feature = "test-mock")]
+        self.alpha.store(alpha.to_bits(), Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn alpha(&self) -> f32 {
+        #[cfg(feature = "test-mock")]
+        return f32::from_bits(self.alpha.load(Ordering::Relaxed));
+        #[cfg(not(feature = "test-mock"))]
+        crate::core::utils::view_alpha(self.ns_view)
     }
 }
 
@@ -62,11 +104,7 @@ impl Positionable for ControlBase {
     fn set_frame(&self, x: f64, y: f64, width: f64, height: f64) -> Result<()> {
         #[cfg(not(feature = "test-mock"))]
         unsafe {
-            use cocoa::foundation::{NSRect, NSPoint, NSSize};
-            let frame = NSRect {
-                origin: NSPoint { x, y },
-                size: NSSize { width, height },
-            };
+            let frame: cocoa::foundation::NSRect = ZeroCostRect::from_xywh(x, y, width, height).into();
             let _: () = msg_send![self.ns_view, setFrame: frame];
         }
         Ok(())
@@ -82,6 +120,17 @@ impl Positionable for ControlBase {
         #[cfg(feature = "test-mock")]
         (0.0, 0.0, 100.0, 40.0)
     }
+
+    fn intrinsic_content_size(&self) -> (f64, f64) {
+        let (_, _, width, height) = self.frame();
+        let (intrinsic_width, intrinsic_height) = crate::core::utils::view_intrinsic_size(self.ns_view);
+        (intrinsic_width.unwrap_or(width), intrinsic_height.unwrap_or(height))
+    }
+
+    fn size_to_fit(&self) -> Result<()> {
+        crate::core::utils::size_view_to_fit(self.ns_view);
+        Ok(())
+    }
 }
 
 /// Button control
@@ -147,6 +196,18 @@ impl Drawable for Button {
     fn is_visible(&self) -> bool {
         self.base.is_visible()
     }
+
+    fn set_alpha(&self, alpha: f32) -> Result<()> {
+        self.base.set_alpha(alpha)
+    }
+
+    fn alpha(&self) -> f32 {
+        self.base.alpha()
+    }
+
+    fn layer(&self) -> Option<*mut Object> {
+        self.base.layer()
+    }
 }
 
 impl Positionable for Button {
@@ -157,6 +218,14 @@ impl Positionable for Button {
     fn frame(&self) -> (f64, f64, f64, f64) {
         self.base.frame()
     }
+
+    fn intrinsic_content_size(&self) -> (f64, f64) {
+        self.base.intrinsic_content_size()
+    }
+
+    fn size_to_fit(&self) -> Result<()> {
+        self.base.size_to_fit()
+    }
 }
 
 impl Textual for Button {
@@ -175,36 +244,32 @@ impl Textual for Button {
     }
 }
 
-/// Button builder
-#[derive(Default)]
-pub struct ButtonBuilder {
-    title: String,
-    width: Option<f64>,
-    height: Option<f64>,
+crate::define_builder! {
+    /// Button builder
+    pub struct ButtonBuilder -> Button {
+        /// Set button title
+        title: String = String::new(),
+    }
+    internal {
+        width: Option<f64> = None,
+        height: Option<f64> = None,
+    }
+    build(self) {
+        let button = Button::new(&self.title)?;
+        if let (Some(w), Some(h)) = (self.width, self.height) {
+            button.set_frame(0.0, 0.0, w, h)?;
+        }
+        Ok(button)
+    }
 }
 
 impl ButtonBuilder {
-    /// Set button title
-    pub fn title(mut self, title: &str) -> Self {
-        self.title = title.to_string();
-        self
-    }
-
     /// Set button size
     pub fn size(mut self, width: f64, height: f64) -> Self {
         self.width = Some(width);
         self.height = Some(height);
         self
     }
-
-    /// Build the button
-    pub fn build(self) -> Result<Button> {
-        let button = Button::new(&self.title)?;
-        if let (Some(w), Some(h)) = (self.width, self.height) {
-            button.set_frame(0.0, 0.0, w, h)?;
-        }
-        Ok(button)
-    }
 }
 
 /// Label control
@@ -272,6 +337,18 @@ impl Drawable for Label {
     fn is_visible(&self) -> bool {
         self.base.is_visible()
     }
+
+    fn set_alpha(&self, alpha: f32) -> Result<()> {
+        self.base.set_alpha(alpha)
+    }
+
+    fn alpha(&self) -> f32 {
+        self.base.alpha()
+    }
+
+    fn layer(&self) -> Option<*mut Object> {
+        self.base.layer()
+    }
 }
 
 impl Positionable for Label {
@@ -282,6 +359,14 @@ impl Positionable for Label {
     fn frame(&self) -> (f64, f64, f64, f64) {
         self.base.frame()
     }
+
+    fn intrinsic_content_size(&self) -> (f64, f64) {
+        self.base.intrinsic_content_size()
+    }
+
+    fn size_to_fit(&self) -> Result<()> {
+        self.base.size_to_fit()
+    }
 }
 
 impl Textual for Label {
@@ -300,36 +385,32 @@ impl Textual for Label {
     }
 }
 
-/// Label builder
-#[derive(Default)]
-pub struct LabelBuilder {
-    text: String,
-    width: Option<f64>,
-    height: Option<f64>,
+crate::define_builder! {
+    /// Label builder
+    pub struct LabelBuilder -> Label {
+        /// Set label text
+        text: String = String::new(),
+    }
+    internal {
+        width: Option<f64> = None,
+        height: Option<f64> = None,
+    }
+    build(self) {
+        let label = Label::new(&self.text)?;
+        if let (Some(w), Some(h)) = (self.width, self.height) {
+            label.set_frame(0.0, 0.0, w, h)?;
+        }
+        Ok(label)
+    }
 }
 
 impl LabelBuilder {
-    /// Set label text
-    pub fn text(mut self, text: &str) -> Self {
-        self.text = text.to_string();
-        self
-    }
-
     /// Set label size
     pub fn size(mut self, width: f64, height: f64) -> Self {
         self.width = Some(width);
         self.height = Some(height);
         self
     }
-
-    /// Build the label
-    pub fn build(self) -> Result<Label> {
-        let label = Label::new(&self.text)?;
-        if let (Some(w), Some(h)) = (self.width, self.height) {
-            label.set_frame(0.0, 0.0, w, h)?;
-        }
-        Ok(label)
-    }
 }
 
 /// TextField control
@@ -394,6 +475,18 @@ impl Drawable for TextField {
     fn is_visible(&self) -> bool {
         self.base.is_visible()
     }
+
+    fn set_alpha(&self, alpha: f32) -> Result<()> {
+        self.base.set_alpha(alpha)
+    }
+
+    fn alpha(&self) -> f32 {
+        self.base.alpha()
+    }
+
+    fn layer(&self) -> Option<*mut Object> {
+        self.base.layer()
+    }
 }
 
 impl Positionable for TextField {
@@ -404,6 +497,14 @@ impl Positionable for TextField {
     fn frame(&self) -> (f64, f64, f64, f64) {
         self.base.frame()
     }
+
+    fn intrinsic_content_size(&self) -> (f64, f64) {
+        self.base.intrinsic_content_size()
+    }
+
+    fn size_to_fit(&self) -> Result<()> {
+        self.base.size_to_fit()
+    }
 }
 
 impl Textual for TextField {
@@ -484,4 +585,67 @@ mod tests {
         assert_eq!(builder.text, "Input");
         assert_eq!(builder.width, Some(300.0));
     }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_controls_store_alpha() {
+        let button = Button::new("Fade").unwrap();
+        let label = Label::new("Fade").unwrap();
+        assert_eq!(button.alpha(), 1.0);
+
+        button.set_alpha(0.25).unwrap();
+        label.set_alpha(0.0).unwrap();
+        assert_eq!(button.alpha(), 0.25);
+        assert_eq!(label.alpha(), 0.0);
+
+        assert!(button.set_alpha(1.5).is_err());
+        assert!(button.set_alpha(f32::NAN).is_err());
+        assert_eq!(button.alpha(), 0.25);
+
+        let boxed: Box<dyn Drawable> = Box::new(button);
+        assert_eq!(boxed.alpha(), 0.25);
+        assert_eq!(boxed.layer(), None);
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_controls_size_to_fit() {
+        let button = Button::new("Save").unwrap();
+        let (width, height) = button.intrinsic_content_size();
+        assert!(width > 0.0 && height > 0.0);
+        button.size_to_fit().unwrap();
+
+        let label = Label::new("Name").unwrap();
+        let (width, height) = label.intrinsic_content_size();
+        assert!(width > 0.0 && height > 0.0);
+        label.size_to_fit().unwrap();
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_controls_fade_visibility() {
+        use std::time::Duration;
+
+        let label = Label::new("Saved").unwrap();
+        label.fade_out(Duration::from_millis(200)).unwrap();
+        assert!(!label.is_visible());
+
+        label.set_alpha(0.5).unwrap();
+        label.fade_in(Duration::from_millis(200)).unwrap();
+        assert!(label.is_visible());
+        assert_eq!(label.alpha(), 1.0);
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_controls_release_on_drop() {
+        let before = MemoryManager::mock_balance();
+        {
+            let _button = Button::new("Leak Check").unwrap();
+            let _label = Label::new("Leak Check").unwrap();
+            let _field = TextField::new("Leak Check").unwrap();
+            assert_eq!(MemoryManager::mock_balance(), before + 3);
+        }
+        assert_eq!(MemoryManager::mock_balance(), before);
+    }
 }
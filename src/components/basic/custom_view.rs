@@ -0,0 +1,600 @@
+//! A plain view that hands raw input to Rust closures
+//!
+//! [`CustomView`] is an `NSView` subclass for games, editors and canvases
+//! that need input the standard controls don't report. It accepts first
+//! responder status, so it receives key presses once focused or clicked.
+//! Its coordinates are flipped, with the origin at the top left.
+
+use crate::core::error::Result;
+use crate::core::traits::Drawable;
+use crate::systems::events::{KeyEvent, KeyHandler, MouseEvent};
+use objc::runtime::Object;
+use std::sync::{Arc, Mutex};
+
+#[cfg(not(feature = "test-mock"))]
+use crate::core::error::CocoanutError;
+#[cfg(not(feature = "test-mock"))]
+use objc::declare::ClassDecl;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Class, Sel, BOOL, YES};
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+#[cfg(not(feature = "test-mock"))]
+use std::ffi::c_void;
+#[cfg(not(feature = "test-mock"))]
+use std::sync::Once;
+
+/// Key-down handler; returns `true` when it handled the key
+pub type KeyDownHandler = Arc<dyn Fn(KeyEvent) -> bool + Send + Sync>;
+
+/// Mouse event handler
+pub type MouseHandler = Arc<dyn Fn(MouseEvent) + Send + Sync>;
+
+/// Scroll handler, given the horizontal and vertical deltas in points
+pub type ScrollHandler = Arc<dyn Fn(f64, f64) + Send + Sync>;
+
+/// Magnify handler, given the change in scale
+pub type MagnifyHandler = Arc<dyn Fn(f64) + Send + Sync>;
+
+/// Points scrolled per line by wheel mice, which report whole lines
+#[cfg(not(feature = "test-mock"))]
+const SCROLL_LINE_HEIGHT: f64 = 10.0;
+
+/// Input handlers shared between a [`CustomView`] and its native view
+#[derive(Default)]
+struct Handlers {
+    key_down: Option<KeyDownHandler>,
+    mouse_down: Option<MouseHandler>,
+    mouse_up: Option<MouseHandler>,
+    mouse_dragged: Option<MouseHandler>,
+    mouse_moved: Option<MouseHandler>,
+    scroll: Option<ScrollHandler>,
+    magnify: Option<MagnifyHandler>,
+}
+
+/// Which mouse handler an event goes to
+#[cfg(any(test, not(feature = "test-mock")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MouseAction {
+    Down,
+    Up,
+    Dragged,
+    Moved,
+}
+
+#[cfg(any(test, not(feature = "test-mock")))]
+impl MouseAction {
+    fn handler(self, handlers: &Handlers) -> Option<MouseHandler> {
+        match self {
+            MouseAction::Down => handlers.mouse_down.clone(),
+            MouseAction::Up => handlers.mouse_up.clone(),
+            MouseAction::Dragged => handlers.mouse_dragged.clone(),
+            MouseAction::Moved => handlers.mouse_moved.clone(),
+        }
+    }
+}
+
+/// A view that reports raw keyboard, mouse, scroll and pinch input
+///
+/// Mouse handlers receive presses, releases and drags of every button;
+/// check [`MouseEvent::button`] to tell them apart.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::controls::CustomView;
+/// use cocoanut::events::KeyHandler;
+///
+/// # fn main() -> cocoanut::Result<()> {
+/// let mut canvas = CustomView::new(640.0, 480.0)?;
+/// canvas.on_key_down(|key| {
+///     if key.modifiers().command {
+///         return false; // leave menu shortcuts to the menu bar
+///     }
+///     println!("pressed {:?}", key.characters());
+///     true
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct CustomView {
+    ns_view: *mut Object,
+    handlers: Arc<Mutex<Handlers>>,
+    tracks_mouse: bool,
+}
+
+impl CustomView {
+    /// Create a view of the given size
+    pub fn new(width: f64, height: f64) -> Result<Self> {
+        let handlers = Arc::new(Mutex::new(Handlers::default()));
+
+        #[cfg(not(feature = "test-mock"))]
+        let ns_view = unsafe { create_view(width, height, &handlers)? };
+        #[cfg(feature = "test-mock")]
+        let ns_view: *mut Object = {
+            let _ = (width, height);
+            std::ptr::null_mut()
+        };
+
+        Ok(CustomView { ns_view, handlers, tracks_mouse: false })
+    }
+
+    /// Make the view its window's first responder, so key presses go to it
+    pub fn focus(&self) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let window: *mut Object = msg_send![self.ns_view, window];
+            if window.is_null() {
+                return Err(CocoanutError::InvalidParameter(
+                    "Cannot focus a view that is not in a window".to_string()
+                ));
+            }
+            let accepted: BOOL = msg_send![window, makeFirstResponder: self.ns_view];
+            if accepted != YES {
+                return Err(CocoanutError::SystemError("Window refused to focus the view".to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Run `handler` when a mouse button is pressed over the view
+    pub fn on_mouse_down<F>(&mut self, handler: F)
+    where
+        F: Fn(MouseEvent) + Send + Sync + 'static,
+    {
+        self.lock_handlers().mouse_down = Some(Arc::new(handler));
+    }
+
+    /// Run `handler` when a mouse button pressed over the view is released
+    pub fn on_mouse_up<F>(&mut self, handler: F)
+    where
+        F: Fn(MouseEvent) + Send + Sync + 'static,
+    {
+        self.lock_handlers().mouse_up = Some(Arc::new(handler));
+    }
+
+    /// Run `handler` as the mouse moves with a button held after a press
+    /// over the view
+    pub fn on_mouse_dragged<F>(&mut self, handler: F)
+    where
+        F: Fn(MouseEvent) + Send + Sync + 'static,
+    {
+        self.lock_handlers().mouse_dragged = Some(Arc::new(handler));
+    }
+
+    /// Run `handler` as the mouse moves over the view with no button held
+    ///
+    /// This adds a tracking area to the view, since AppKit does not send
+    /// mouse-moved events otherwise.
+    pub fn on_mouse_moved<F>(&mut self, handler: F)
+    where
+        F: Fn(MouseEvent) + Send + Sync + 'static,
+    {
+        self.lock_handlers().mouse_moved = Some(Arc::new(handler));
+        if !self.tracks_mouse {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                add_tracking_area(self.ns_view);
+            }
+            self.tracks_mouse = true;
+        }
+    }
+
+    /// Run `handler` with the deltas when the user scrolls over the view
+    ///
+    /// Trackpads and the Magic Mouse report precise deltas in points,
+    /// including momentum scrolling after the fingers lift; wheel mice
+    /// report whole lines, converted to points. Positive deltas move the
+    /// content right and down, following the user's scrolling direction.
+    pub fn on_scroll<F>(&mut self, handler: F)
+    where
+        F: Fn(f64, f64) + Send + Sync + 'static,
+    {
+        self.lock_handlers().scroll = Some(Arc::new(handler));
+    }
+
+    /// Run `handler` with the change in scale as the user pinches over the
+    /// view
+    ///
+    /// Changes are relative: multiply the current zoom by `1.0 + change`.
+    pub fn on_magnify<F>(&mut self, handler: F)
+    where
+        F: Fn(f64) + Send + Sync + 'static,
+    {
+        self.lock_handlers().magnify = Some(Arc::new(handler));
+    }
+
+    /// Check if the view reports mouse movement
+    pub fn tracks_mouse(&self) -> bool {
+        self.tracks_mouse
+    }
+
+    fn lock_handlers(&self) -> std::sync::MutexGuard<'_, Handlers> {
+        self.handlers.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Deliver a key press as AppKit would
+    #[cfg(all(test, feature = "test-mock"))]
+    fn simulate_key_down(&self, event: KeyEvent) -> bool {
+        dispatch_key_down(&self.handlers, event)
+    }
+
+    /// Deliver a mouse event as AppKit would
+    #[cfg(all(test, feature = "test-mock"))]
+    fn simulate_mouse(&self, action: MouseAction, event: MouseEvent) -> bool {
+        dispatch_mouse(&self.handlers, action, event)
+    }
+
+    /// Deliver a scroll as AppKit would
+    #[cfg(all(test, feature = "test-mock"))]
+    fn simulate_scroll(&self, dx: f64, dy: f64) -> bool {
+        dispatch_scroll(&self.handlers, dx, dy)
+    }
+
+    /// Deliver a pinch as AppKit would
+    #[cfg(all(test, feature = "test-mock"))]
+    fn simulate_magnify(&self, change: f64) -> bool {
+        dispatch_magnify(&self.handlers, change)
+    }
+}
+
+impl KeyHandler for CustomView {
+    fn on_key_down<F>(&mut self, handler: F) -> Result<()>
+    where
+        F: Fn(KeyEvent) -> bool + Send + Sync + 'static,
+    {
+        self.lock_handlers().key_down = Some(Arc::new(handler));
+        Ok(())
+    }
+}
+
+impl Drawable for CustomView {
+    fn as_view(&self) -> *mut Object {
+        self.ns_view
+    }
+
+    fn set_visible(&self, visible: bool) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_view, setHidden: !visible];
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = visible;
+        Ok(())
+    }
+
+    fn is_visible(&self) -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let hidden: bool = msg_send![self.ns_view, isHidden];
+            !hidden
+        }
+
+        #[cfg(feature = "test-mock")]
+        true
+    }
+}
+
+impl Drop for CustomView {
+    fn drop(&mut self) {
+        // The view may outlive this struct inside its superview; detach the
+        // handlers so later events fall through to NSView
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let view = &mut *self.ns_view;
+            let slot = *view.get_ivar::<*mut c_void>("rustHandlers");
+            view.set_ivar::<*mut c_void>("rustHandlers", std::ptr::null_mut());
+            if !slot.is_null() {
+                drop(Arc::from_raw(slot as *const Mutex<Handlers>));
+            }
+            let _: () = msg_send![self.ns_view, release];
+        }
+    }
+}
+
+unsafe impl Send for CustomView {}
+unsafe impl Sync for CustomView {}
+
+/// Run the key-down handler, returning whether it handled the key
+#[cfg(any(test, not(feature = "test-mock")))]
+fn dispatch_key_down(handlers: &Mutex<Handlers>, event: KeyEvent) -> bool {
+    // Clone the handler out so it can replace itself without deadlocking
+    let handler = handlers.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).key_down.clone();
+    handler.is_some_and(|handler| handler(event))
+}
+
+/// Run the mouse handler for `action`, returning whether there was one
+#[cfg(any(test, not(feature = "test-mock")))]
+fn dispatch_mouse(handlers: &Mutex<Handlers>, action: MouseAction, event: MouseEvent) -> bool {
+    let handler = action.handler(&handlers.lock().unwrap_or_else(|poisoned| poisoned.into_inner()));
+    handler.map(|handler| handler(event)).is_some()
+}
+
+/// Run the scroll handler, returning whether there was one
+#[cfg(any(test, not(feature = "test-mock")))]
+fn dispatch_scroll(handlers: &Mutex<Handlers>, dx: f64, dy: f64) -> bool {
+    let handler = handlers.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).scroll.clone();
+    handler.map(|handler| handler(dx, dy)).is_some()
+}
+
+/// Run the magnify handler, returning whether there was one
+#[cfg(any(test, not(feature = "test-mock")))]
+fn dispatch_magnify(handlers: &Mutex<Handlers>, change: f64) -> bool {
+    let handler = handlers.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).magnify.clone();
+    handler.map(|handler| handler(change)).is_some()
+}
+
+/// Create the native view, holding a reference to `handlers`
+#[cfg(not(feature = "test-mock"))]
+unsafe fn create_view(width: f64, height: f64, handlers: &Arc<Mutex<Handlers>>) -> Result<*mut Object> {
+    use crate::features::drawing::Rect;
+    use crate::features::zero_cost::ZeroCostRect;
+    use cocoa::foundation::NSRect;
+
+    unsafe {
+        let frame: NSRect = ZeroCostRect::from(Rect::from_xywh(0.0, 0.0, width, height)).into();
+        let ns_view: *mut Object = msg_send![custom_view_class(), alloc];
+        let ns_view: *mut Object = msg_send![ns_view, initWithFrame: frame];
+        if ns_view.is_null() {
+            return Err(CocoanutError::ControlCreationFailed("Failed to create custom view".to_string()));
+        }
+        let slot = Arc::into_raw(Arc::clone(handlers)) as *mut c_void;
+        (*ns_view).set_ivar::<*mut c_void>("rustHandlers", slot);
+        Ok(ns_view)
+    }
+}
+
+/// Ask AppKit for mouse-moved events over the whole view
+#[cfg(not(feature = "test-mock"))]
+unsafe fn add_tracking_area(ns_view: *mut Object) {
+    use cocoa::foundation::{NSPoint, NSRect, NSSize};
+
+    // NSTrackingMouseMoved | NSTrackingActiveInActiveApp | NSTrackingInVisibleRect
+    const OPTIONS: u64 = 0x02 | 0x40 | 0x200;
+
+    unsafe {
+        let nil: *mut Object = std::ptr::null_mut();
+        let area: *mut Object = msg_send![class!(NSTrackingArea), alloc];
+        let area: *mut Object = msg_send![area, initWithRect: NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(0.0, 0.0))
+                                                      options: OPTIONS
+                                                        owner: ns_view
+                                                     userInfo: nil];
+        let _: () = msg_send![ns_view, addTrackingArea: area];
+        let _: () = msg_send![area, release];
+    }
+}
+
+/// Forward a mouse `NSEvent` to the handler for `action`
+///
+/// Returns `false` when no handler took it, so NSView's default runs.
+#[cfg(not(feature = "test-mock"))]
+unsafe fn handle_mouse(this: &Object, event: *mut Object, action: MouseAction) -> bool {
+    unsafe {
+        let view = this as *const Object as *mut Object;
+        if action == MouseAction::Down {
+            let window: *mut Object = msg_send![view, window];
+            if !window.is_null() {
+                let _: BOOL = msg_send![window, makeFirstResponder: view];
+            }
+        }
+        match handlers_of(this) {
+            Some(handlers) => {
+                let event = MouseEvent::from_ns_event(event, view, action != MouseAction::Moved);
+                dispatch_mouse(handlers, action, event)
+            }
+            None => false,
+        }
+    }
+}
+
+/// The handlers of a `CocoanutCustomView`, if still attached
+#[cfg(not(feature = "test-mock"))]
+unsafe fn handlers_of(this: &Object) -> Option<&Mutex<Handlers>> {
+    unsafe { (*this.get_ivar::<*mut c_void>("rustHandlers") as *const Mutex<Handlers>).as_ref() }
+}
+
+/// Lazily register the `NSView` subclass forwarding input to the handlers
+#[cfg(not(feature = "test-mock"))]
+fn custom_view_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutCustomView", class!(NSView))
+            .expect("CocoanutCustomView already registered");
+        decl.add_ivar::<*mut c_void>("rustHandlers");
+
+        extern "C" fn accepts_first_responder(_this: &Object, _cmd: Sel) -> BOOL {
+            YES
+        }
+
+        extern "C" fn key_down(this: &Object, _cmd: Sel, event: *mut Object) {
+            unsafe {
+                let handled = match handlers_of(this) {
+                    Some(handlers) => dispatch_key_down(handlers, KeyEvent::from_ns_event(event)),
+                    None => false,
+                };
+                if !handled {
+                    let _: () = msg_send![super(this, class!(NSView)), keyDown: event];
+                }
+            }
+        }
+
+        extern "C" fn is_flipped(_this: &Object, _cmd: Sel) -> BOOL {
+            YES
+        }
+
+        macro_rules! mouse_methods {
+            ($($name:ident => $sel:ident, $action:expr;)*) => {
+                $(
+                    extern "C" fn $name(this: &Object, _cmd: Sel, event: *mut Object) {
+                        unsafe {
+                            if !handle_mouse(this, event, $action) {
+                                let _: () = msg_send![super(this, class!(NSView)), $sel: event];
+                            }
+                        }
+                    }
+                    unsafe {
+                        decl.add_method(sel!($sel:), $name as extern "C" fn(&Object, Sel, *mut Object));
+                    }
+                )*
+            };
+        }
+
+        mouse_methods! {
+            mouse_down => mouseDown, MouseAction::Down;
+            right_mouse_down => rightMouseDown, MouseAction::Down;
+            other_mouse_down => otherMouseDown, MouseAction::Down;
+            mouse_up => mouseUp, MouseAction::Up;
+            right_mouse_up => rightMouseUp, MouseAction::Up;
+            other_mouse_up => otherMouseUp, MouseAction::Up;
+            mouse_dragged => mouseDragged, MouseAction::Dragged;
+            right_mouse_dragged => rightMouseDragged, MouseAction::Dragged;
+            other_mouse_dragged => otherMouseDragged, MouseAction::Dragged;
+            mouse_moved => mouseMoved, MouseAction::Moved;
+        }
+
+        extern "C" fn scroll_wheel(this: &Object, _cmd: Sel, event: *mut Object) {
+            unsafe {
+                let handled = match handlers_of(this) {
+                    Some(handlers) => {
+                        let precise: BOOL = msg_send![event, hasPreciseScrollingDeltas];
+                        let scale = if precise == YES { 1.0 } else { SCROLL_LINE_HEIGHT };
+                        let dx: f64 = msg_send![event, scrollingDeltaX];
+                        let dy: f64 = msg_send![event, scrollingDeltaY];
+                        dispatch_scroll(handlers, dx * scale, dy * scale)
+                    }
+                    None => false,
+                };
+                if !handled {
+                    let _: () = msg_send![super(this, class!(NSView)), scrollWheel: event];
+                }
+            }
+        }
+
+        extern "C" fn magnify_with_event(this: &Object, _cmd: Sel, event: *mut Object) {
+            unsafe {
+                let handled = match handlers_of(this) {
+                    Some(handlers) => {
+                        let change: f64 = msg_send![event, magnification];
+                        dispatch_magnify(handlers, change)
+                    }
+                    None => false,
+                };
+                if !handled {
+                    let _: () = msg_send![super(this, class!(NSView)), magnifyWithEvent: event];
+                }
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(scrollWheel:), scroll_wheel as extern "C" fn(&Object, Sel, *mut Object));
+            decl.add_method(
+                sel!(magnifyWithEvent:),
+                magnify_with_event as extern "C" fn(&Object, Sel, *mut Object),
+            );
+            decl.add_method(
+                sel!(acceptsFirstResponder),
+                accepts_first_responder as extern "C" fn(&Object, Sel) -> BOOL,
+            );
+            decl.add_method(sel!(isFlipped), is_flipped as extern "C" fn(&Object, Sel) -> BOOL);
+            decl.add_method(sel!(keyDown:), key_down as extern "C" fn(&Object, Sel, *mut Object));
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutCustomView").expect("CocoanutCustomView not registered")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "test-mock")]
+    use crate::features::drawing::Point;
+    #[cfg(feature = "test-mock")]
+    use crate::systems::events::{Modifiers, MouseButton};
+
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_view_class_handles_gestures() {
+        let view_class = custom_view_class();
+        for selector in [sel!(scrollWheel:), sel!(magnifyWithEvent:), sel!(mouseMoved:), sel!(keyDown:)] {
+            let ours = view_class.instance_method(selector).unwrap().implementation();
+            let inherited = class!(NSView).instance_method(selector).unwrap().implementation();
+            assert!(ours as usize != inherited as usize, "{:?} is not overridden", selector);
+        }
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_key_down_reports_handled() {
+        let mut view = CustomView::new(100.0, 100.0).unwrap();
+        let escape = KeyEvent::new(53, "\u{1b}", Modifiers::NONE);
+        assert!(!view.simulate_key_down(escape.clone()));
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        {
+            let seen = Arc::clone(&seen);
+            view.on_key_down(move |key| {
+                seen.lock().unwrap().push(key.key_code());
+                !key.modifiers().command
+            })
+            .unwrap();
+        }
+        assert!(view.simulate_key_down(escape));
+        let save = KeyEvent::new(1, "s", Modifiers { command: true, ..Modifiers::NONE });
+        assert!(!view.simulate_key_down(save));
+        assert_eq!(*seen.lock().unwrap(), vec![53, 1]);
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_mouse_handlers_by_action() {
+        let mut view = CustomView::new(100.0, 100.0).unwrap();
+        let click = MouseEvent::new(Point::new(5.0, 6.0), MouseButton::Left, 1, Modifiers::NONE);
+        assert!(!view.simulate_mouse(MouseAction::Down, click));
+
+        let strokes = Arc::new(Mutex::new(Vec::new()));
+        {
+            let strokes = Arc::clone(&strokes);
+            view.on_mouse_dragged(move |event| strokes.lock().unwrap().push(event.location()));
+        }
+        view.on_mouse_down(|_| {});
+        assert!(view.simulate_mouse(MouseAction::Down, click));
+        assert!(view.simulate_mouse(MouseAction::Dragged, click));
+        assert!(!view.simulate_mouse(MouseAction::Up, click));
+        assert_eq!(*strokes.lock().unwrap(), vec![Point::new(5.0, 6.0)]);
+
+        assert!(!view.tracks_mouse());
+        view.on_mouse_moved(|_| {});
+        assert!(view.tracks_mouse());
+        assert!(view.simulate_mouse(MouseAction::Moved, click));
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_scroll_and_magnify_handlers_are_stored() {
+        let mut view = CustomView::new(100.0, 100.0).unwrap();
+        assert!(!view.simulate_scroll(0.0, 4.0));
+        assert!(!view.simulate_magnify(0.5));
+
+        let zoom = Arc::new(Mutex::new(1.0));
+        let offset = Arc::new(Mutex::new((0.0, 0.0)));
+        {
+            let zoom = Arc::clone(&zoom);
+            view.on_magnify(move |change| *zoom.lock().unwrap() *= 1.0 + change);
+        }
+        {
+            let offset = Arc::clone(&offset);
+            view.on_scroll(move |dx, dy| {
+                let mut offset = offset.lock().unwrap();
+                offset.0 += dx;
+                offset.1 += dy;
+            });
+        }
+        assert!(view.simulate_scroll(1.5, -3.0));
+        assert!(view.simulate_scroll(0.5, 1.0));
+        assert!(view.simulate_magnify(0.5));
+        assert_eq!(*offset.lock().unwrap(), (2.0, -2.0));
+        assert_eq!(*zoom.lock().unwrap(), 1.5);
+    }
+}
@@ -0,0 +1,616 @@
+//! Image view control for displaying images
+//!
+//! Wraps `NSImageView` and supports loading images from files or raw bytes.
+
+use crate::core::error::{CocoanutError, Result};
+use crate::core::traits::{Drawable, Positionable};
+#[cfg(not(feature = "test-mock"))]
+use crate::features::zero_cost::ZeroCostRect;
+use crate::features::macos::{Appearance, DarkModeManager};
+use objc::runtime::Object;
+#[cfg(not(feature = "test-mock"))]
+use objc::{msg_send, sel, sel_impl};
+use std::path::{Path, PathBuf};
+
+/// How an image is scaled to fit the image view's bounds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageScaling {
+    /// Stretch the image to fill the bounds, ignoring its aspect ratio
+    AxesIndependently,
+    /// Shrink the image to fit while preserving its aspect ratio, never enlarging it
+    ProportionallyDown,
+    /// Draw the image at its natural size
+    None,
+}
+
+impl ImageScaling {
+    /// The corresponding `NSImageScaling` value
+    #[cfg(any(test, not(feature = "test-mock")))]
+    pub(crate) fn ns_value(&self) -> u64 {
+        match self {
+            ImageScaling::ProportionallyDown => 0,
+            ImageScaling::AxesIndependently => 1,
+            ImageScaling::None => 2,
+        }
+    }
+}
+
+/// Image files shown under the light and dark appearances
+#[derive(Debug, Clone, PartialEq)]
+struct AppearanceImages {
+    light: PathBuf,
+    dark: Option<PathBuf>,
+}
+
+/// A macOS image view control
+pub struct ImageView {
+    ns_image_view: *mut Object,
+    scaling: ImageScaling,
+    images: Option<AppearanceImages>,
+}
+
+impl ImageView {
+    /// Create a new image view builder
+    pub fn builder() -> ImageViewBuilder {
+        ImageViewBuilder::new()
+    }
+
+    /// Create an image view from an image file on disk
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to a PNG, JPEG, GIF, TIFF or other supported image
+    ///
+    /// # Returns
+    ///
+    /// Returns an error if the file cannot be read or is not a decodable image
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let bytes = read_image_file(path.as_ref())?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Create an image view from encoded image bytes
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Encoded image data (PNG, JPEG, GIF, TIFF, ...)
+    ///
+    /// # Returns
+    ///
+    /// Returns `CocoanutError::InvalidParameter` if the bytes are not a decodable image
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            use cocoa::foundation::{NSRect, NSPoint, NSSize};
+
+            let ns_image = decode_ns_image(bytes)?;
+            let image_size: NSSize = msg_send![ns_image, size];
+
+            let frame = NSRect {
+                origin: NSPoint { x: 0.0, y: 0.0 },
+                size: image_size,
+            };
+
+            let image_view_class = objc::class!(NSImageView);
+            let ns_image_view: *mut Object = msg_send![image_view_class, alloc];
+            let ns_image_view: *mut Object = msg_send![ns_image_view, initWithFrame: frame];
+
+            if ns_image_view.is_null() {
+                let _: () = msg_send![ns_image, release];
+                return Err(CocoanutError::ControlCreationFailed(
+                    "Failed to create NSImageView".to_string()
+                ));
+            }
+
+            let scaling = ImageScaling::ProportionallyDown;
+            let _: () = msg_send![ns_image_view, setImage: ns_image];
+            let _: () = msg_send![ns_image_view, setImageScaling: scaling.ns_value()];
+            let _: () = msg_send![ns_image, release];
+
+            Ok(ImageView {
+                ns_image_view,
+                scaling,
+                images: None,
+            })
+        }
+
+        #[cfg(feature = "test-mock")]
+        {
+            check_image_bytes(bytes)?;
+            Ok(ImageView {
+                ns_image_view: std::ptr::null_mut(),
+                scaling: ImageScaling::ProportionallyDown,
+                images: None,
+            })
+        }
+    }
+
+    /// Set how the image is scaled to fit the view
+    pub fn scaling(mut self, scaling: ImageScaling) -> Self {
+        self.set_scaling(scaling);
+        self
+    }
+
+    /// Change how the image is scaled to fit the view
+    pub fn set_scaling(&mut self, scaling: ImageScaling) {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_image_view, setImageScaling: scaling.ns_value()];
+        }
+        self.scaling = scaling;
+    }
+
+    /// Get the current scaling mode
+    pub fn get_scaling(&self) -> ImageScaling {
+        self.scaling
+    }
+
+    /// Replace the displayed image with new encoded image bytes
+    ///
+    /// The view keeps its current frame and scaling mode.
+    pub fn set_image(&mut self, bytes: &[u8]) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_image = decode_ns_image(bytes)?;
+            let _: () = msg_send![self.ns_image_view, setImage: ns_image];
+            let _: () = msg_send![ns_image, release];
+        }
+
+        #[cfg(feature = "test-mock")]
+        check_image_bytes(bytes)?;
+
+        self.images = None;
+        Ok(())
+    }
+
+    /// Replace the displayed image with the contents of an image file
+    pub fn set_image_file(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let bytes = read_image_file(path.as_ref())?;
+        self.set_image(&bytes)
+    }
+
+    /// Show the image at `light` under the light appearance and the one at
+    /// `dark` under the dark appearance
+    ///
+    /// The image swaps as soon as the system appearance changes. Without a
+    /// dark variant the light image is shown under both. Returns an error,
+    /// leaving the current image, if either file cannot be loaded.
+    pub fn set_images(&mut self, light: impl AsRef<Path>, dark: Option<&Path>) -> Result<()> {
+        let light = light.as_ref().to_path_buf();
+        let dark = dark.map(Path::to_path_buf);
+
+        let ns_light = load_ns_image(&light)?;
+        let ns_dark = match &dark {
+            Some(path) => match load_ns_image(path) {
+                Ok(ns_dark) => ns_dark,
+                Err(error) => {
+                    release_ns_image(ns_light);
+                    return Err(error);
+                }
+            },
+            None => std::ptr::null_mut(),
+        };
+
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            // The dynamic image owns both variants from here on
+            let ns_image = appearance_image(ns_light, ns_dark);
+            let _: () = msg_send![self.ns_image_view, setImage: ns_image];
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = (ns_light, ns_dark);
+
+        self.images = Some(AppearanceImages { light, dark });
+        Ok(())
+    }
+
+    /// Get the image file shown under the light appearance, if set with
+    /// [`set_images`](Self::set_images)
+    pub fn light_image_path(&self) -> Option<&Path> {
+        self.images.as_ref().map(|images| images.light.as_path())
+    }
+
+    /// Get the image file shown under the dark appearance, if one was given
+    pub fn dark_image_path(&self) -> Option<&Path> {
+        self.images.as_ref()?.dark.as_deref()
+    }
+
+    /// Get the image file shown under the system's current appearance
+    ///
+    /// `None` unless the image was set with [`set_images`](Self::set_images).
+    pub fn active_image_path(&self) -> Option<&Path> {
+        let images = self.images.as_ref()?;
+        match (DarkModeManager::current_appearance(), &images.dark) {
+            (Appearance::Dark, Some(dark)) => Some(dark),
+            _ => Some(&images.light),
+        }
+    }
+}
+
+impl Drawable for ImageView {
+    fn as_view(&self) -> *mut Object {
+        self.ns_image_view
+    }
+
+    fn set_visible(&self, visible: bool) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_image_view, setHidden: !visible];
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = visible;
+        Ok(())
+    }
+
+    fn is_visible(&self) -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let hidden: bool = msg_send![self.ns_image_view, isHidden];
+            !hidden
+        }
+        #[cfg(feature = "test-mock")]
+        true
+    }
+}
+
+impl Positionable for ImageView {
+    fn set_frame(&self, x: f64, y: f64, width: f64, height: f64) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let frame: cocoa::foundation::NSRect = ZeroCostRect::from_xywh(x, y, width, height).into();
+            let _: () = msg_send![self.ns_image_view, setFrame: frame];
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = (x, y, width, height);
+        Ok(())
+    }
+
+    fn frame(&self) -> (f64, f64, f64, f64) {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            use cocoa::foundation::NSRect;
+            let frame: NSRect = msg_send![self.ns_image_view, frame];
+            (frame.origin.x, frame.origin.y, frame.size.width, frame.size.height)
+        }
+        #[cfg(feature = "test-mock")]
+        (0.0, 0.0, 100.0, 100.0)
+    }
+
+    /// The size of the displayed image
+    fn intrinsic_content_size(&self) -> (f64, f64) {
+        let (_, _, width, height) = self.frame();
+        let (intrinsic_width, intrinsic_height) = crate::core::utils::view_intrinsic_size(self.ns_image_view);
+        (intrinsic_width.unwrap_or(width), intrinsic_height.unwrap_or(height))
+    }
+}
+
+impl Drop for ImageView {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_image_view, release];
+        }
+    }
+}
+
+/// Builder for ImageView controls
+pub struct ImageViewBuilder {
+    bytes: Option<Vec<u8>>,
+    path: Option<std::path::PathBuf>,
+    scaling: ImageScaling,
+    width: Option<f64>,
+    height: Option<f64>,
+}
+
+impl ImageViewBuilder {
+    /// Create a new image view builder
+    pub fn new() -> Self {
+        Self {
+            bytes: None,
+            path: None,
+            scaling: ImageScaling::ProportionallyDown,
+            width: None,
+            height: None,
+        }
+    }
+
+    /// Load the image from a file
+    pub fn file(mut self, path: impl AsRef<Path>) -> Self {
+        self.path = Some(path.as_ref().to_path_buf());
+        self.bytes = None;
+        self
+    }
+
+    /// Load the image from encoded bytes
+    pub fn bytes(mut self, bytes: impl Into<Vec<u8>>) -> Self {
+        self.bytes = Some(bytes.into());
+        self.path = None;
+        self
+    }
+
+    /// Set the scaling mode
+    pub fn scaling(mut self, scaling: ImageScaling) -> Self {
+        self.scaling = scaling;
+        self
+    }
+
+    /// Set the image view size, e.g. for a fixed thumbnail size
+    pub fn size(mut self, width: f64, height: f64) -> Self {
+        self.width = Some(width);
+        self.height = Some(height);
+        self
+    }
+
+    /// Build the image view
+    pub fn build(self) -> Result<ImageView> {
+        let mut view = match (self.bytes, self.path) {
+            (Some(bytes), _) => ImageView::from_bytes(&bytes)?,
+            (None, Some(path)) => ImageView::from_file(path)?,
+            (None, None) => {
+                return Err(CocoanutError::InvalidParameter(
+                    "ImageView requires an image file or image bytes".to_string()
+                ));
+            }
+        };
+        view.set_scaling(self.scaling);
+        if let (Some(w), Some(h)) = (self.width, self.height) {
+            view.set_frame(0.0, 0.0, w, h)?;
+        }
+        Ok(view)
+    }
+}
+
+impl Default for ImageViewBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Load the image file at `path` into a retained `NSImage`
+///
+/// Returns null in mock builds, after checking the file holds an image.
+pub(crate) fn load_ns_image(path: &Path) -> Result<*mut Object> {
+    let bytes = read_image_file(path)?;
+    #[cfg(not(feature = "test-mock"))]
+    unsafe {
+        decode_ns_image(&bytes)
+    }
+    #[cfg(feature = "test-mock")]
+    {
+        check_image_bytes(&bytes)?;
+        Ok(std::ptr::null_mut())
+    }
+}
+
+/// Release an image returned by [`load_ns_image`]
+fn release_ns_image(ns_image: *mut Object) {
+    #[cfg(not(feature = "test-mock"))]
+    if !ns_image.is_null() {
+        unsafe {
+            let _: () = msg_send![ns_image, release];
+        }
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = ns_image;
+}
+
+/// Retained light and dark variants, released with the image drawing them
+#[cfg(not(feature = "test-mock"))]
+struct ImageVariants {
+    light: *mut Object,
+    dark: *mut Object,
+}
+
+#[cfg(not(feature = "test-mock"))]
+impl Drop for ImageVariants {
+    fn drop(&mut self) {
+        release_ns_image(self.light);
+        release_ns_image(self.dark);
+    }
+}
+
+/// An image the size of `light` that draws `dark` instead whenever it is
+/// drawn under a dark appearance
+///
+/// Takes ownership of both retained images; `dark` may be null. The
+/// returned image is autoreleased.
+#[cfg(not(feature = "test-mock"))]
+unsafe fn appearance_image(light: *mut Object, dark: *mut Object) -> *mut Object {
+    use block::ConcreteBlock;
+    use cocoa::foundation::{NSRect, NSSize};
+
+    unsafe {
+        let size: NSSize = msg_send![light, size];
+        let variants = ImageVariants { light, dark };
+        let draw = ConcreteBlock::new(move |rect: NSRect| -> bool {
+            let image = if !variants.dark.is_null() && drawing_dark() { variants.dark } else { variants.light };
+            let _: () = msg_send![image, drawInRect: rect];
+            true
+        })
+        .copy();
+        msg_send![objc::class!(NSImage), imageWithSize: size flipped: false drawingHandler: &*draw]
+    }
+}
+
+/// Whether the image being drawn is under a dark appearance
+#[cfg(not(feature = "test-mock"))]
+fn drawing_dark() -> bool {
+    unsafe {
+        let appearance_class = objc::class!(NSAppearance);
+        let has_drawing_appearance: bool =
+            msg_send![appearance_class, respondsToSelector: sel!(currentDrawingAppearance)];
+        let appearance: *mut Object = if has_drawing_appearance {
+            msg_send![appearance_class, currentDrawingAppearance]
+        } else {
+            msg_send![appearance_class, currentAppearance]
+        };
+        if appearance.is_null() {
+            return false;
+        }
+        let (Ok(light), Ok(dark)) = (
+            crate::core::utils::string_to_ns_string(Appearance::Light.ns_appearance_name()),
+            crate::core::utils::string_to_ns_string(Appearance::Dark.ns_appearance_name()),
+        ) else {
+            return false;
+        };
+        let names: *mut Object = msg_send![objc::class!(NSMutableArray), arrayWithCapacity: 2usize];
+        let _: () = msg_send![names, addObject: light];
+        let _: () = msg_send![names, addObject: dark];
+        let best: *mut Object = msg_send![appearance, bestMatchFromAppearancesWithNames: names];
+        !best.is_null() && msg_send![best, isEqualToString: dark]
+    }
+}
+
+fn read_image_file(path: &Path) -> Result<Vec<u8>> {
+    std::fs::read(path).map_err(|e| match e.kind() {
+        std::io::ErrorKind::NotFound => CocoanutError::FileNotFound(path.to_path_buf()),
+        _ => CocoanutError::Io(e),
+    })
+}
+
+/// Decode image bytes into a retained `NSImage`
+#[cfg(not(feature = "test-mock"))]
+unsafe fn decode_ns_image(bytes: &[u8]) -> Result<*mut Object> {
+    if bytes.is_empty() {
+        return Err(CocoanutError::InvalidParameter(
+            "Image data is empty".to_string()
+        ));
+    }
+
+    unsafe {
+        let data_class = objc::class!(NSData);
+        let ns_data: *mut Object = msg_send![
+            data_class,
+            dataWithBytes: bytes.as_ptr() as *const std::ffi::c_void
+            length: bytes.len() as u64
+        ];
+
+        let image_class = objc::class!(NSImage);
+        let ns_image: *mut Object = msg_send![image_class, alloc];
+        let ns_image: *mut Object = msg_send![ns_image, initWithData: ns_data];
+
+        if ns_image.is_null() {
+            return Err(CocoanutError::InvalidParameter(
+                "Data is not a decodable image".to_string()
+            ));
+        }
+        Ok(ns_image)
+    }
+}
+
+/// Check the bytes start with a known image signature (mock builds cannot decode)
+#[cfg(feature = "test-mock")]
+fn check_image_bytes(bytes: &[u8]) -> Result<()> {
+    const SIGNATURES: &[&[u8]] = &[
+        b"\x89PNG\r\n\x1a\n",
+        b"\xFF\xD8\xFF",
+        b"GIF87a",
+        b"GIF89a",
+        b"II*\0",
+        b"MM\0*",
+        b"BM",
+    ];
+    let is_webp = bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP";
+
+    if SIGNATURES.iter().any(|sig| bytes.starts_with(sig)) || is_webp {
+        Ok(())
+    } else {
+        Err(CocoanutError::InvalidParameter(
+            "Data is not a decodable image".to_string()
+        ))
+    }
+}
+
+unsafe impl Send for ImageView {}
+unsafe impl Sync for ImageView {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_scaling_ns_values() {
+        assert_eq!(ImageScaling::ProportionallyDown.ns_value(), 0);
+        assert_eq!(ImageScaling::AxesIndependently.ns_value(), 1);
+        assert_eq!(ImageScaling::None.ns_value(), 2);
+    }
+
+    #[test]
+    fn test_image_view_builder() {
+        let builder = ImageViewBuilder::new()
+            .bytes(vec![1, 2, 3])
+            .scaling(ImageScaling::AxesIndependently)
+            .size(64.0, 64.0);
+        assert_eq!(builder.bytes, Some(vec![1, 2, 3]));
+        assert_eq!(builder.scaling, ImageScaling::AxesIndependently);
+        assert_eq!(builder.width, Some(64.0));
+    }
+
+    #[test]
+    fn test_image_view_builder_requires_source() {
+        assert!(ImageViewBuilder::new().build().is_err());
+    }
+
+    #[test]
+    fn test_image_view_missing_file() {
+        let result = ImageView::from_file("/nonexistent/thumbnail.png");
+        assert!(matches!(result, Err(CocoanutError::FileNotFound(path)) if path == Path::new("/nonexistent/thumbnail.png")));
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_image_view_rejects_invalid_bytes() {
+        let result = ImageView::from_bytes(b"not an image");
+        assert!(matches!(result, Err(CocoanutError::InvalidParameter(_))));
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_image_view_appearance_images() {
+        let dir = std::env::temp_dir();
+        let light = dir.join(format!("cocoanut-light-{}.png", std::process::id()));
+        let dark = dir.join(format!("cocoanut-dark-{}.png", std::process::id()));
+        let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+        std::fs::write(&light, png).unwrap();
+        std::fs::write(&dark, png).unwrap();
+
+        let mut view = ImageView::from_bytes(png).unwrap();
+        assert_eq!(view.active_image_path(), None);
+        view.set_images(&light, Some(&dark)).unwrap();
+        assert_eq!(view.light_image_path(), Some(light.as_path()));
+        assert_eq!(view.dark_image_path(), Some(dark.as_path()));
+
+        DarkModeManager::simulate_appearance(Appearance::Light);
+        assert_eq!(view.active_image_path(), Some(light.as_path()));
+        DarkModeManager::simulate_appearance(Appearance::Dark);
+        assert_eq!(view.active_image_path(), Some(dark.as_path()));
+
+        // Without a dark variant the light image is used under both
+        view.set_images(&light, None).unwrap();
+        assert_eq!(view.dark_image_path(), None);
+        assert_eq!(view.active_image_path(), Some(light.as_path()));
+
+        // A missing variant keeps the current images
+        assert!(view.set_images(&light, Some(Path::new("/nonexistent/dark.png"))).is_err());
+        assert_eq!(view.light_image_path(), Some(light.as_path()));
+
+        view.set_image(png).unwrap();
+        assert_eq!(view.active_image_path(), None);
+
+        DarkModeManager::simulate_appearance(Appearance::Light);
+        std::fs::remove_file(&light).unwrap();
+        std::fs::remove_file(&dark).unwrap();
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_image_view_scaling() {
+        let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+        let mut view = ImageView::from_bytes(png)
+            .unwrap()
+            .scaling(ImageScaling::None);
+        assert_eq!(view.get_scaling(), ImageScaling::None);
+        assert!(view.set_image(b"bogus").is_err());
+        assert!(view.set_image(png).is_ok());
+    }
+}
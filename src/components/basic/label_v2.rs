@@ -92,4 +92,27 @@ mod tests {
         label.set_text("World").unwrap();
         assert_eq!(label.get_text(), "World");
     }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_label_string_property_round_trip() {
+        let label = LabelV2::new("Hello").unwrap();
+        assert_eq!(label.string_property("stringValue"), None);
+
+        label.set_string_property("setStringValue:", "Héllo, wörld").unwrap();
+        assert_eq!(label.string_property("stringValue").as_deref(), Some("Héllo, wörld"));
+
+        label.set_bool_property("setEditable:", false).unwrap();
+        label.set_f64_property("setAlphaValue:", 0.5).unwrap();
+        assert!(label.set_string_property("stringValue", "x").is_err());
+        assert!(label.set_bool_property("setFrame:size:", true).is_err());
+        assert_eq!(label.string_property("setStringValue:"), None);
+
+        // Each label keeps its own properties; handles share them
+        let other = LabelV2::new("Other").unwrap();
+        assert_eq!(other.string_property("stringValue"), None);
+        other.set_string_property("setStringValue:", "Other").unwrap();
+        assert_eq!(label.string_property("stringValue").as_deref(), Some("Héllo, wörld"));
+        assert_eq!(label.clone_as_handle().string_property("stringValue").as_deref(), Some("Héllo, wörld"));
+    }
 }
@@ -2,12 +2,18 @@
 
 pub mod button;
 pub mod controls_v2;
+pub mod custom_view;
 pub mod button_v2;
 pub mod label_v2;
 pub mod textfield_v2;
+pub mod image_view;
+pub mod text_view;
 
-pub use button::{Button, Label, TextField};
+pub use button::{Button, CharacterCount, Label, NumberFormat, TextField};
 pub use controls_v2::{ButtonBuilder, LabelBuilder, TextFieldBuilder};
 pub use button_v2::ButtonV2;
 pub use label_v2::LabelV2;
 pub use textfield_v2::TextFieldV2;
+pub use image_view::{ImageView, ImageViewBuilder, ImageScaling};
+pub use text_view::{TextView, TextViewBuilder};
+pub use custom_view::CustomView;
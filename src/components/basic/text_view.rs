@@ -0,0 +1,448 @@
+//! Multi-line text view for editing longer text
+//!
+//! Wraps `NSTextView`, optionally inside an `NSScrollView` so text longer
+//! than the view can be scrolled.
+
+use crate::core::error::Result;
+use crate::core::traits::Drawable;
+use crate::features::drawing::Font;
+use objc::runtime::Object;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[cfg(not(feature = "test-mock"))]
+use crate::core::error::CocoanutError;
+#[cfg(not(feature = "test-mock"))]
+use objc::declare::ClassDecl;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Class, Sel};
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+#[cfg(not(feature = "test-mock"))]
+use std::ffi::c_void;
+#[cfg(not(feature = "test-mock"))]
+use std::sync::Once;
+
+type ChangeHandler = Rc<dyn Fn(&str)>;
+
+/// Text shared between a `TextView` and its `NSTextView` delegate
+struct TextContent {
+    text: RefCell<String>,
+    on_change: RefCell<Option<ChangeHandler>>,
+}
+
+impl TextContent {
+    /// Record text edited by the user and notify the handler
+    ///
+    /// Nothing stays borrowed while the handler runs, so it may set the
+    /// text or replace itself.
+    #[cfg(any(test, not(feature = "test-mock")))]
+    fn edited(&self, text: String) {
+        *self.text.borrow_mut() = text.clone();
+        let handler = self.on_change.borrow().clone();
+        if let Some(handler) = handler {
+            handler(&text);
+        }
+    }
+}
+
+/// A macOS multi-line text view
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::components::basic::TextView;
+///
+/// let mut notes = TextView::builder()
+///     .text("Meeting notes")
+///     .editable(true)
+///     .scrollable(true)
+///     .build()?;
+/// notes.on_change(|text| println!("{} characters", text.len()));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct TextView {
+    ns_text_view: *mut Object,
+    ns_scroll_view: *mut Object,
+    delegate: *mut Object,
+    editable: bool,
+    scrollable: bool,
+    font: Font,
+    content: Rc<TextContent>,
+}
+
+impl TextView {
+    /// Create a new text view builder
+    pub fn builder() -> TextViewBuilder {
+        TextViewBuilder::new()
+    }
+
+    /// Create an editable text view showing `text`
+    pub fn new(text: &str) -> Result<Self> {
+        TextViewBuilder::new().text(text).build()
+    }
+
+    /// Get the current text
+    pub fn text(&self) -> String {
+        self.content.text.borrow().clone()
+    }
+
+    /// Replace the text
+    ///
+    /// This does not call the `on_change` handler, which only reports edits
+    /// made by the user.
+    pub fn set_text(&mut self, text: &str) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_text = crate::core::utils::string_to_ns_string(text)?;
+            let _: () = msg_send![self.ns_text_view, setString: ns_text];
+        }
+        *self.content.text.borrow_mut() = text.to_string();
+        Ok(())
+    }
+
+    /// Allow or prevent the user from editing the text
+    pub fn set_editable(&mut self, editable: bool) {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_text_view, setEditable: editable];
+        }
+        self.editable = editable;
+    }
+
+    /// Check if the user can edit the text
+    pub fn is_editable(&self) -> bool {
+        self.editable
+    }
+
+    /// Change the font of the whole text
+    pub fn set_font(&mut self, font: Font) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_text_view, setFont: font.to_ns_font()?];
+        }
+        self.font = font;
+        Ok(())
+    }
+
+    /// Get the font
+    pub fn font(&self) -> &Font {
+        &self.font
+    }
+
+    /// Check if the text view is wrapped in a scroll view
+    pub fn is_scrollable(&self) -> bool {
+        self.scrollable
+    }
+
+    /// Set a handler called with the new text whenever the user edits it
+    pub fn on_change<F>(&mut self, handler: F)
+    where
+        F: Fn(&str) + 'static,
+    {
+        *self.content.on_change.borrow_mut() = Some(Rc::new(handler));
+    }
+
+    /// Get the underlying NSTextView pointer
+    pub fn ns_text_view(&self) -> *mut Object {
+        self.ns_text_view
+    }
+}
+
+impl Drawable for TextView {
+    /// The scroll view when scrollable, otherwise the text view itself
+    fn as_view(&self) -> *mut Object {
+        if self.ns_scroll_view.is_null() {
+            self.ns_text_view
+        } else {
+            self.ns_scroll_view
+        }
+    }
+
+    fn set_visible(&self, visible: bool) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.as_view(), setHidden: !visible];
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = visible;
+        Ok(())
+    }
+
+    fn is_visible(&self) -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let hidden: bool = msg_send![self.as_view(), isHidden];
+            !hidden
+        }
+        #[cfg(feature = "test-mock")]
+        true
+    }
+}
+
+impl Drop for TextView {
+    fn drop(&mut self) {
+        if !self.delegate.is_null() {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                let _: () = msg_send![self.ns_text_view, setDelegate: std::ptr::null_mut::<Object>()];
+                let slot = *(*self.delegate).get_ivar::<*mut c_void>("rustContent");
+                (*self.delegate).set_ivar::<*mut c_void>("rustContent", std::ptr::null_mut());
+                if !slot.is_null() {
+                    drop(Box::from_raw(slot as *mut Rc<TextContent>));
+                }
+                let _: () = msg_send![self.delegate, release];
+            }
+        }
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            if !self.ns_text_view.is_null() {
+                let _: () = msg_send![self.ns_text_view, release];
+            }
+            if !self.ns_scroll_view.is_null() {
+                let _: () = msg_send![self.ns_scroll_view, release];
+            }
+        }
+    }
+}
+
+/// Builder for TextView
+pub struct TextViewBuilder {
+    text: String,
+    editable: bool,
+    scrollable: bool,
+    font: Font,
+    width: f64,
+    height: f64,
+}
+
+impl TextViewBuilder {
+    /// Create a new text view builder
+    pub fn new() -> Self {
+        Self {
+            text: String::new(),
+            editable: true,
+            scrollable: false,
+            font: Font::system(13.0),
+            width: 400.0,
+            height: 100.0,
+        }
+    }
+
+    /// Set the initial text
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    /// Allow or prevent the user from editing the text
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+
+    /// Wrap the text view in a vertically scrolling scroll view
+    pub fn scrollable(mut self, scrollable: bool) -> Self {
+        self.scrollable = scrollable;
+        self
+    }
+
+    /// Set the font
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Set the size
+    pub fn size(mut self, width: f64, height: f64) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Build the text view
+    pub fn build(self) -> Result<TextView> {
+        let content = Rc::new(TextContent {
+            text: RefCell::new(self.text),
+            on_change: RefCell::new(None),
+        });
+
+        #[cfg(not(feature = "test-mock"))]
+        let (ns_text_view, ns_scroll_view, delegate) = unsafe {
+            use cocoa::foundation::{NSPoint, NSRect, NSSize};
+
+            let frame = NSRect {
+                origin: NSPoint { x: 0.0, y: 0.0 },
+                size: NSSize { width: self.width, height: self.height },
+            };
+            let ns_scroll_view: *mut Object = if self.scrollable {
+                let ns_scroll_view: *mut Object = msg_send![class!(NSScrollView), alloc];
+                let ns_scroll_view: *mut Object = msg_send![ns_scroll_view, initWithFrame: frame];
+                if ns_scroll_view.is_null() {
+                    return Err(CocoanutError::ControlCreationFailed(
+                        "Failed to create NSScrollView".to_string()
+                    ));
+                }
+                let _: () = msg_send![ns_scroll_view, setHasVerticalScroller: true];
+                ns_scroll_view
+            } else {
+                std::ptr::null_mut()
+            };
+
+            let text_frame = if ns_scroll_view.is_null() {
+                frame
+            } else {
+                let size: NSSize = msg_send![ns_scroll_view, contentSize];
+                NSRect { origin: NSPoint { x: 0.0, y: 0.0 }, size }
+            };
+            let ns_text_view: *mut Object = msg_send![class!(NSTextView), alloc];
+            let ns_text_view: *mut Object = msg_send![ns_text_view, initWithFrame: text_frame];
+            if ns_text_view.is_null() {
+                return Err(CocoanutError::ControlCreationFailed(
+                    "Failed to create NSTextView".to_string()
+                ));
+            }
+            let _: () = msg_send![ns_text_view, setEditable: self.editable];
+            let _: () = msg_send![ns_text_view, setSelectable: true];
+            let _: () = msg_send![ns_text_view, setFont: self.font.to_ns_font()?];
+            let ns_text = crate::core::utils::string_to_ns_string(&content.text.borrow())?;
+            let _: () = msg_send![ns_text_view, setString: ns_text];
+
+            if !ns_scroll_view.is_null() {
+                // Grow vertically with the text and wrap lines at the scroll view's width
+                let _: () = msg_send![ns_text_view, setVerticallyResizable: true];
+                let _: () = msg_send![ns_text_view, setHorizontallyResizable: false];
+                let _: () = msg_send![ns_text_view, setMaxSize: NSSize { width: f64::MAX, height: f64::MAX }];
+                let _: () = msg_send![ns_text_view, setAutoresizingMask: 2u64]; // NSViewWidthSizable
+                let container: *mut Object = msg_send![ns_text_view, textContainer];
+                let _: () = msg_send![container, setWidthTracksTextView: true];
+                let _: () = msg_send![ns_scroll_view, setDocumentView: ns_text_view];
+            }
+
+            let slot = Box::into_raw(Box::new(Rc::clone(&content))) as *mut c_void;
+            let delegate: *mut Object = msg_send![text_view_delegate_class(), new];
+            (*delegate).set_ivar::<*mut c_void>("rustContent", slot);
+            let _: () = msg_send![ns_text_view, setDelegate: delegate];
+            (ns_text_view, ns_scroll_view, delegate)
+        };
+        #[cfg(feature = "test-mock")]
+        let (ns_text_view, ns_scroll_view, delegate) =
+            (std::ptr::null_mut(), std::ptr::null_mut(), std::ptr::null_mut());
+
+        Ok(TextView {
+            ns_text_view,
+            ns_scroll_view,
+            delegate,
+            editable: self.editable,
+            scrollable: self.scrollable,
+            font: self.font,
+            content,
+        })
+    }
+}
+
+impl Default for TextViewBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Lazily register the `NSTextViewDelegate` class reporting user edits
+#[cfg(not(feature = "test-mock"))]
+fn text_view_delegate_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutTextViewDelegate", class!(NSObject))
+            .expect("CocoanutTextViewDelegate already registered");
+        decl.add_ivar::<*mut c_void>("rustContent");
+
+        extern "C" fn text_did_change(this: &Object, _cmd: Sel, notification: *mut Object) {
+            unsafe {
+                let slot = *this.get_ivar::<*mut c_void>("rustContent") as *const Rc<TextContent>;
+                let Some(content) = slot.as_ref() else {
+                    return;
+                };
+                let text_view: *mut Object = msg_send![notification, object];
+                let ns_text: *mut Object = msg_send![text_view, string];
+                if let Ok(text) = crate::core::utils::ns_string_to_string(ns_text) {
+                    content.edited(text);
+                }
+            }
+        }
+
+        unsafe {
+            decl.add_method(
+                sel!(textDidChange:),
+                text_did_change as extern "C" fn(&Object, Sel, *mut Object),
+            );
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutTextViewDelegate").expect("CocoanutTextViewDelegate not registered")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_view_defaults() {
+        let text_view = TextView::new("Hello").unwrap();
+        assert_eq!(text_view.text(), "Hello");
+        assert!(text_view.is_editable());
+        assert!(!text_view.is_scrollable());
+        assert_eq!(text_view.font(), &Font::system(13.0));
+    }
+
+    #[test]
+    fn test_text_view_editable_scrollable_round_trip() {
+        let mut text_view = TextView::builder()
+            .editable(true)
+            .scrollable(true)
+            .font(Font::monospaced(12.0))
+            .build()
+            .unwrap();
+        assert!(text_view.is_editable());
+        assert!(text_view.is_scrollable());
+        assert!(text_view.font().is_monospaced());
+
+        text_view.set_text("First line\nSecond line").unwrap();
+        assert_eq!(text_view.text(), "First line\nSecond line");
+
+        text_view.set_editable(false);
+        assert!(!text_view.is_editable());
+    }
+
+    #[test]
+    fn test_text_view_change_handler() {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let mut text_view = TextView::new("").unwrap();
+        {
+            let changes = Rc::clone(&changes);
+            text_view.on_change(move |text| changes.borrow_mut().push(text.to_string()));
+        }
+
+        // Programmatic changes are not reported
+        text_view.set_text("draft").unwrap();
+        text_view.content.edited("draft!".to_string());
+        assert_eq!(text_view.text(), "draft!");
+        assert_eq!(*changes.borrow(), vec!["draft!"]);
+    }
+
+    #[test]
+    fn test_text_view_handler_may_edit_content() {
+        let text_view = TextView::new("").unwrap();
+        let content = Rc::downgrade(&text_view.content);
+        *text_view.content.on_change.borrow_mut() = Some(Rc::new(move |text: &str| {
+            let content = content.upgrade().unwrap();
+            *content.text.borrow_mut() = text.to_uppercase();
+            *content.on_change.borrow_mut() = None;
+        }));
+
+        text_view.content.edited("shout".to_string());
+        assert_eq!(text_view.text(), "SHOUT");
+        assert!(text_view.content.on_change.borrow().is_none());
+    }
+}
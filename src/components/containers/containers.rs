@@ -3,17 +3,61 @@
 //! Includes ScrollView, TabView, SplitView, and GroupBox containers.
 
 use crate::core::error::Result;
+use crate::core::traits::{Drawable, Positionable, View};
+use crate::features::drawing::Point;
+use objc::runtime::Object;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+#[cfg(not(feature = "test-mock"))]
+use crate::features::zero_cost::ZeroCostRect;
+#[cfg(not(feature = "test-mock"))]
+use objc::declare::ClassDecl;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Class, Sel};
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+#[cfg(not(feature = "test-mock"))]
+use std::ffi::c_void;
+#[cfg(not(feature = "test-mock"))]
+use std::sync::Once;
 
 // ============================================================================
 // SCROLL VIEW
 // ============================================================================
 
 /// A scroll view container for scrollable content
+///
+/// The scroll view hosts a single document view and reports scrolling,
+/// whether it comes from the user or from [`ScrollView::scroll_to`].
 pub struct ScrollView {
+    ns_scroll_view: *mut Object,
+    observer: *mut Object,
     width: f64,
     height: f64,
     scrollable_width: f64,
     scrollable_height: f64,
+    document_view: Option<Box<dyn Drawable>>,
+    scroll: Rc<ScrollState>,
+}
+
+type ScrollHandler = Box<dyn Fn(f64, f64)>;
+
+/// Scroll offset shared between a `ScrollView` and its bounds observer
+struct ScrollState {
+    offset: Cell<Point>,
+    on_scroll: RefCell<Option<ScrollHandler>>,
+}
+
+impl ScrollState {
+    /// Record a new content offset, notifying the handler if it changed
+    fn scrolled(&self, offset: Point) {
+        if self.offset.replace(offset) != offset
+            && let Some(handler) = self.on_scroll.borrow().as_ref()
+        {
+            handler(offset.x, offset.y);
+        }
+    }
 }
 
 impl ScrollView {
@@ -24,11 +68,60 @@ impl ScrollView {
 
     /// Create a new scroll view
     pub fn new(width: f64, height: f64) -> Result<Self> {
+        Self::with_content_size(width, height, width, height)
+    }
+
+    fn with_content_size(width: f64, height: f64, scrollable_width: f64, scrollable_height: f64) -> Result<Self> {
+        let scroll = Rc::new(ScrollState {
+            offset: Cell::new(Point { x: 0.0, y: 0.0 }),
+            on_scroll: RefCell::new(None),
+        });
+
+        #[cfg(not(feature = "test-mock"))]
+        let (ns_scroll_view, observer) = unsafe {
+            use cocoa::foundation::{NSPoint, NSRect, NSSize};
+
+            let frame = NSRect {
+                origin: NSPoint { x: 0.0, y: 0.0 },
+                size: NSSize { width, height },
+            };
+            let ns_scroll_view: *mut Object = msg_send![class!(NSScrollView), alloc];
+            let ns_scroll_view: *mut Object = msg_send![ns_scroll_view, initWithFrame: frame];
+            if ns_scroll_view.is_null() {
+                return Err(crate::core::error::CocoanutError::ControlCreationFailed(
+                    "Failed to create NSScrollView".to_string()
+                ));
+            }
+            let _: () = msg_send![ns_scroll_view, setHasVerticalScroller: true];
+            let _: () = msg_send![ns_scroll_view, setHasHorizontalScroller: true];
+
+            // The clip view's bounds origin is the scroll offset
+            let clip_view: *mut Object = msg_send![ns_scroll_view, contentView];
+            let _: () = msg_send![clip_view, setPostsBoundsChangedNotifications: true];
+
+            let name = crate::core::utils::string_to_ns_string("NSViewBoundsDidChangeNotification")?;
+            let slot = Box::into_raw(Box::new(Rc::clone(&scroll))) as *mut c_void;
+            let observer: *mut Object = msg_send![scroll_observer_class(), new];
+            (*observer).set_ivar::<*mut c_void>("rustScroll", slot);
+            let center: *mut Object = msg_send![class!(NSNotificationCenter), defaultCenter];
+            let _: () = msg_send![center, addObserver: observer
+                                          selector: sel!(boundsDidChange:)
+                                              name: name
+                                            object: clip_view];
+            (ns_scroll_view, observer)
+        };
+        #[cfg(feature = "test-mock")]
+        let (ns_scroll_view, observer) = (std::ptr::null_mut(), std::ptr::null_mut());
+
         Ok(ScrollView {
+            ns_scroll_view,
+            observer,
             width,
             height,
-            scrollable_width: width,
-            scrollable_height: height,
+            scrollable_width,
+            scrollable_height,
+            document_view: None,
+            scroll,
         })
     }
 
@@ -41,6 +134,128 @@ impl ScrollView {
     pub fn scrollable_size(&self) -> (f64, f64) {
         (self.scrollable_width, self.scrollable_height)
     }
+
+    /// Set the view that is scrolled inside the scroll view
+    ///
+    /// The scroll view keeps the component alive and replaces any previous
+    /// document view.
+    pub fn set_document_view(&mut self, view: Box<dyn Drawable>) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_scroll_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_scroll_view, setDocumentView: view.as_view()];
+            }
+        }
+        self.document_view = Some(view);
+        Ok(())
+    }
+
+    /// Get the document view, if one has been set
+    pub fn document_view(&self) -> Option<&dyn Drawable> {
+        self.document_view.as_deref()
+    }
+
+    /// Set a handler called with the new horizontal and vertical offsets whenever the content scrolls
+    pub fn on_scroll<F>(&mut self, handler: F)
+    where
+        F: Fn(f64, f64) + 'static,
+    {
+        *self.scroll.on_scroll.borrow_mut() = Some(Box::new(handler));
+    }
+
+    /// Get the current scroll offset of the content
+    pub fn content_offset(&self) -> Point {
+        self.scroll.offset.get()
+    }
+
+    /// Scroll the content so that `point` is at the origin of the visible area
+    ///
+    /// As everywhere in AppKit, the origin is the bottom-left corner unless
+    /// the document view is flipped. The offset is clamped so the visible area
+    /// stays within the scrollable content.
+    pub fn scroll_to(&mut self, point: Point) -> Result<()> {
+        let offset = Point {
+            x: point.x.clamp(0.0, (self.scrollable_width - self.width).max(0.0)),
+            y: point.y.clamp(0.0, (self.scrollable_height - self.height).max(0.0)),
+        };
+        self.scroll.scrolled(offset);
+
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_scroll_view.is_null() {
+            unsafe {
+                use cocoa::foundation::NSPoint;
+
+                let clip_view: *mut Object = msg_send![self.ns_scroll_view, contentView];
+                let _: () = msg_send![clip_view, scrollToPoint: NSPoint { x: offset.x, y: offset.y }];
+                let _: () = msg_send![self.ns_scroll_view, reflectScrolledClipView: clip_view];
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the underlying NSScrollView pointer
+    pub fn ns_scroll_view(&self) -> *mut Object {
+        self.ns_scroll_view
+    }
+}
+
+impl Drop for ScrollView {
+    fn drop(&mut self) {
+        if !self.observer.is_null() {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                let center: *mut Object = msg_send![class!(NSNotificationCenter), defaultCenter];
+                let _: () = msg_send![center, removeObserver: self.observer];
+                let slot = *(*self.observer).get_ivar::<*mut c_void>("rustScroll");
+                (*self.observer).set_ivar::<*mut c_void>("rustScroll", std::ptr::null_mut());
+                if !slot.is_null() {
+                    drop(Box::from_raw(slot as *mut Rc<ScrollState>));
+                }
+                let _: () = msg_send![self.observer, release];
+            }
+        }
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_scroll_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_scroll_view, release];
+            }
+        }
+    }
+}
+
+/// Lazily register the ObjC class observing clip view bounds changes
+#[cfg(not(feature = "test-mock"))]
+fn scroll_observer_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutScrollObserver", class!(NSObject))
+            .expect("CocoanutScrollObserver already registered");
+        decl.add_ivar::<*mut c_void>("rustScroll");
+
+        extern "C" fn bounds_did_change(this: &Object, _cmd: Sel, notification: *mut Object) {
+            unsafe {
+                use cocoa::foundation::NSRect;
+
+                let slot = *this.get_ivar::<*mut c_void>("rustScroll") as *const Rc<ScrollState>;
+                if let Some(scroll) = slot.as_ref() {
+                    let clip_view: *mut Object = msg_send![notification, object];
+                    let bounds: NSRect = msg_send![clip_view, bounds];
+                    scroll.scrolled(Point { x: bounds.origin.x, y: bounds.origin.y });
+                }
+            }
+        }
+
+        unsafe {
+            decl.add_method(
+                sel!(boundsDidChange:),
+                bounds_did_change as extern "C" fn(&Object, Sel, *mut Object),
+            );
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutScrollObserver").expect("CocoanutScrollObserver not registered")
 }
 
 /// Builder for ScrollView
@@ -78,12 +293,7 @@ impl ScrollViewBuilder {
 
     /// Build the scroll view
     pub fn build(self) -> Result<ScrollView> {
-        Ok(ScrollView {
-            width: self.width,
-            height: self.height,
-            scrollable_width: self.scrollable_width,
-            scrollable_height: self.scrollable_height,
-        })
+        ScrollView::with_content_size(self.width, self.height, self.scrollable_width, self.scrollable_height)
     }
 }
 
@@ -98,9 +308,37 @@ impl Default for ScrollViewBuilder {
 // ============================================================================
 
 /// A tab view container for tabbed interfaces
+///
+/// Tabs can be added and removed at runtime; the tab titles kept here and
+/// the items of the backing `NSTabView` always stay in the same order.
 pub struct TabView {
+    ns_tab_view: *mut Object,
+    delegate: *mut Object,
     tabs: Vec<String>,
-    selected_tab: usize,
+    selection: Rc<TabSelection>,
+}
+
+type TabChangeHandler = Box<dyn Fn(usize)>;
+
+/// Selection state shared between a `TabView` and its `NSTabView` delegate
+struct TabSelection {
+    selected: Cell<usize>,
+    on_change: RefCell<Option<TabChangeHandler>>,
+}
+
+impl TabSelection {
+    /// Record a new selected index, notifying the handler if it changed
+    fn select(&self, index: usize) {
+        if self.selected.replace(index) != index {
+            self.notify(index);
+        }
+    }
+
+    fn notify(&self, index: usize) {
+        if let Some(handler) = self.on_change.borrow().as_ref() {
+            handler(index);
+        }
+    }
 }
 
 impl TabView {
@@ -116,10 +354,44 @@ impl TabView {
                 "Tabs cannot be empty".to_string()
             ));
         }
-        Ok(TabView {
-            tabs,
-            selected_tab: 0,
-        })
+        let mut tab_view = TabView {
+            ns_tab_view: std::ptr::null_mut(),
+            delegate: std::ptr::null_mut(),
+            tabs: Vec::new(),
+            selection: Rc::new(TabSelection {
+                selected: Cell::new(0),
+                on_change: RefCell::new(None),
+            }),
+        };
+
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            use cocoa::foundation::{NSPoint, NSRect, NSSize};
+
+            let frame = NSRect {
+                origin: NSPoint { x: 0.0, y: 0.0 },
+                size: NSSize { width: 350.0, height: 200.0 },
+            };
+            let ns_tab_view: *mut Object = msg_send![class!(NSTabView), alloc];
+            let ns_tab_view: *mut Object = msg_send![ns_tab_view, initWithFrame: frame];
+            if ns_tab_view.is_null() {
+                return Err(crate::core::error::CocoanutError::ControlCreationFailed(
+                    "Failed to create NSTabView".to_string()
+                ));
+            }
+            tab_view.ns_tab_view = ns_tab_view;
+
+            let slot = Box::into_raw(Box::new(Rc::clone(&tab_view.selection))) as *mut c_void;
+            let delegate: *mut Object = msg_send![tab_view_delegate_class(), new];
+            (*delegate).set_ivar::<*mut c_void>("rustSelection", slot);
+            tab_view.delegate = delegate;
+        }
+
+        for title in &tabs {
+            tab_view.add_tab(title)?;
+        }
+        tab_view.attach_delegate();
+        Ok(tab_view)
     }
 
     /// Get the tabs
@@ -129,13 +401,19 @@ impl TabView {
 
     /// Get the selected tab index
     pub fn selected_tab(&self) -> usize {
-        self.selected_tab
+        self.selection.selected.get()
     }
 
     /// Set the selected tab
     pub fn set_selected_tab(&mut self, index: usize) -> Result<()> {
         if index < self.tabs.len() {
-            self.selected_tab = index;
+            self.selection.select(index);
+            #[cfg(not(feature = "test-mock"))]
+            if !self.ns_tab_view.is_null() {
+                unsafe {
+                    let _: () = msg_send![self.ns_tab_view, selectTabViewItemAtIndex: index as i64];
+                }
+            }
             Ok(())
         } else {
             Err(crate::core::error::CocoanutError::InvalidParameter(
@@ -143,6 +421,187 @@ impl TabView {
             ))
         }
     }
+
+    /// Append a tab
+    ///
+    /// # Returns
+    ///
+    /// Returns the index of the new tab
+    pub fn add_tab(&mut self, title: &str) -> Result<usize> {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_tab_view.is_null() {
+            unsafe {
+                let identifier = crate::core::utils::string_to_ns_string(&self.tabs.len().to_string())?;
+                let item: *mut Object = msg_send![class!(NSTabViewItem), alloc];
+                let item: *mut Object = msg_send![item, initWithIdentifier: identifier];
+                let _: () = msg_send![item, setLabel: crate::core::utils::string_to_ns_string(title)?];
+                let _: () = msg_send![self.ns_tab_view, addTabViewItem: item];
+                let _: () = msg_send![item, release];
+            }
+        }
+        self.tabs.push(title.to_string());
+        Ok(self.tabs.len() - 1)
+    }
+
+    /// Remove a tab
+    ///
+    /// If the selected tab is removed, the tab that took its place is
+    /// selected, or the previous tab when the last one was removed.
+    /// The only remaining tab cannot be removed.
+    pub fn remove_tab(&mut self, index: usize) -> Result<()> {
+        self.check_index(index)?;
+        if self.tabs.len() == 1 {
+            return Err(crate::core::error::CocoanutError::InvalidParameter(
+                "Cannot remove the only tab".to_string()
+            ));
+        }
+
+        // NSTabView picks its own replacement selection; keep the delegate
+        // from reporting it while the item is removed
+        self.detach_delegate();
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_tab_view.is_null() {
+            unsafe {
+                let item: *mut Object = msg_send![self.ns_tab_view, tabViewItemAtIndex: index as i64];
+                let _: () = msg_send![self.ns_tab_view, removeTabViewItem: item];
+            }
+        }
+        self.tabs.remove(index);
+
+        let selected = self.selected_tab();
+        let result = if index < selected {
+            // The same tab stays selected at a shifted index
+            self.selection.selected.set(selected - 1);
+            self.set_selected_tab(selected - 1)
+        } else if index == selected {
+            // A different tab is now selected even if the index is unchanged
+            let replacement = index.min(self.tabs.len() - 1);
+            self.selection.selected.set(replacement);
+            self.selection.notify(replacement);
+            self.set_selected_tab(replacement)
+        } else {
+            Ok(())
+        };
+        self.attach_delegate();
+        result
+    }
+
+    /// Get the title of a tab
+    pub fn tab_title(&self, index: usize) -> Option<&str> {
+        self.tabs.get(index).map(String::as_str)
+    }
+
+    /// Set the title of a tab
+    pub fn set_tab_title(&mut self, index: usize, title: &str) -> Result<()> {
+        self.check_index(index)?;
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_tab_view.is_null() {
+            unsafe {
+                let item: *mut Object = msg_send![self.ns_tab_view, tabViewItemAtIndex: index as i64];
+                let _: () = msg_send![item, setLabel: crate::core::utils::string_to_ns_string(title)?];
+            }
+        }
+        self.tabs[index] = title.to_string();
+        Ok(())
+    }
+
+    /// Set a handler called with the new index whenever a different tab is selected
+    pub fn on_tab_change<F>(&mut self, handler: F)
+    where
+        F: Fn(usize) + 'static,
+    {
+        *self.selection.on_change.borrow_mut() = Some(Box::new(handler));
+    }
+
+    /// Get the underlying NSTabView pointer
+    pub fn ns_tab_view(&self) -> *mut Object {
+        self.ns_tab_view
+    }
+
+    fn check_index(&self, index: usize) -> Result<()> {
+        if index < self.tabs.len() {
+            Ok(())
+        } else {
+            Err(crate::core::error::CocoanutError::InvalidParameter(
+                format!("Tab index {} out of bounds", index)
+            ))
+        }
+    }
+
+    fn attach_delegate(&self) {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_tab_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_tab_view, setDelegate: self.delegate];
+            }
+        }
+    }
+
+    fn detach_delegate(&self) {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_tab_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_tab_view, setDelegate: std::ptr::null_mut::<Object>()];
+            }
+        }
+    }
+}
+
+impl Drop for TabView {
+    fn drop(&mut self) {
+        self.detach_delegate();
+        if !self.delegate.is_null() {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                let slot = *(*self.delegate).get_ivar::<*mut c_void>("rustSelection");
+                (*self.delegate).set_ivar::<*mut c_void>("rustSelection", std::ptr::null_mut());
+                if !slot.is_null() {
+                    drop(Box::from_raw(slot as *mut Rc<TabSelection>));
+                }
+                let _: () = msg_send![self.delegate, release];
+            }
+        }
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_tab_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_tab_view, release];
+            }
+        }
+    }
+}
+
+/// Lazily register the `NSTabViewDelegate` class that reports selection changes
+#[cfg(not(feature = "test-mock"))]
+fn tab_view_delegate_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutTabViewDelegate", class!(NSObject))
+            .expect("CocoanutTabViewDelegate already registered");
+        decl.add_ivar::<*mut c_void>("rustSelection");
+
+        extern "C" fn did_select(this: &Object, _cmd: Sel, tab_view: *mut Object, item: *mut Object) {
+            unsafe {
+                let slot = *this.get_ivar::<*mut c_void>("rustSelection") as *const Rc<TabSelection>;
+                if let Some(selection) = slot.as_ref() {
+                    let index: i64 = msg_send![tab_view, indexOfTabViewItem: item];
+                    if index >= 0 {
+                        selection.select(index as usize);
+                    }
+                }
+            }
+        }
+
+        unsafe {
+            decl.add_method(
+                sel!(tabView:didSelectTabViewItem:),
+                did_select as extern "C" fn(&Object, Sel, *mut Object, *mut Object),
+            );
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutTabViewDelegate").expect("CocoanutTabViewDelegate not registered")
 }
 
 /// Builder for TabView
@@ -189,9 +648,18 @@ impl Default for TabViewBuilder {
 // ============================================================================
 
 /// A split view container for resizable panes
+///
+/// Panes are laid out in the order they are added. A pane can be collapsed
+/// and expanded again, and given a minimum size that dragging a divider
+/// will not go below.
 pub struct SplitView {
+    ns_split_view: *mut Object,
+    delegate: *mut Object,
     orientation: SplitOrientation,
     divider_position: f64,
+    panes: Vec<Box<dyn Drawable>>,
+    collapsed: Vec<bool>,
+    layout: Rc<SplitLayout>,
 }
 
 /// Split view orientation
@@ -203,6 +671,27 @@ pub enum SplitOrientation {
     Horizontal,
 }
 
+type DividerHandler = Box<dyn Fn(usize, f64)>;
+
+/// Pane constraints shared between a `SplitView` and its `NSSplitView` delegate
+struct SplitLayout {
+    min_sizes: RefCell<Vec<f64>>,
+    on_divider_moved: RefCell<Option<DividerHandler>>,
+}
+
+impl SplitLayout {
+    fn min_size(&self, index: usize) -> f64 {
+        self.min_sizes.borrow().get(index).copied().unwrap_or(0.0)
+    }
+
+    #[cfg(any(test, not(feature = "test-mock")))]
+    fn divider_moved(&self, index: usize, position: f64) {
+        if let Some(handler) = self.on_divider_moved.borrow().as_ref() {
+            handler(index, position);
+        }
+    }
+}
+
 impl SplitView {
     /// Create a new split view builder
     pub fn builder() -> SplitViewBuilder {
@@ -211,9 +700,46 @@ impl SplitView {
 
     /// Create a new split view
     pub fn new(orientation: SplitOrientation) -> Result<Self> {
+        let layout = Rc::new(SplitLayout {
+            min_sizes: RefCell::new(Vec::new()),
+            on_divider_moved: RefCell::new(None),
+        });
+
+        #[cfg(not(feature = "test-mock"))]
+        let (ns_split_view, delegate) = unsafe {
+            use cocoa::foundation::{NSPoint, NSRect, NSSize};
+
+            let frame = NSRect {
+                origin: NSPoint { x: 0.0, y: 0.0 },
+                size: NSSize { width: 350.0, height: 200.0 },
+            };
+            let ns_split_view: *mut Object = msg_send![class!(NSSplitView), alloc];
+            let ns_split_view: *mut Object = msg_send![ns_split_view, initWithFrame: frame];
+            if ns_split_view.is_null() {
+                return Err(crate::core::error::CocoanutError::ControlCreationFailed(
+                    "Failed to create NSSplitView".to_string()
+                ));
+            }
+            let _: () = msg_send![ns_split_view, setVertical: orientation == SplitOrientation::Vertical];
+            let _: () = msg_send![ns_split_view, setDividerStyle: 2i64]; // NSSplitViewDividerStyleThin
+
+            let slot = Box::into_raw(Box::new(Rc::clone(&layout))) as *mut c_void;
+            let delegate: *mut Object = msg_send![split_view_delegate_class(), new];
+            (*delegate).set_ivar::<*mut c_void>("rustLayout", slot);
+            let _: () = msg_send![ns_split_view, setDelegate: delegate];
+            (ns_split_view, delegate)
+        };
+        #[cfg(feature = "test-mock")]
+        let (ns_split_view, delegate) = (std::ptr::null_mut(), std::ptr::null_mut());
+
         Ok(SplitView {
+            ns_split_view,
+            delegate,
             orientation,
             divider_position: 0.5,
+            panes: Vec::new(),
+            collapsed: Vec::new(),
+            layout,
         })
     }
 
@@ -238,6 +764,212 @@ impl SplitView {
             ))
         }
     }
+
+    /// Append a pane after the existing ones
+    ///
+    /// # Returns
+    ///
+    /// Returns the index of the new pane
+    pub fn add_pane(&mut self, pane: Box<dyn Drawable>) -> Result<usize> {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_split_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_split_view, addArrangedSubview: pane.as_view()];
+            }
+        }
+        self.panes.push(pane);
+        self.collapsed.push(false);
+        self.layout.min_sizes.borrow_mut().push(0.0);
+        Ok(self.panes.len() - 1)
+    }
+
+    /// Get the number of panes
+    pub fn pane_count(&self) -> usize {
+        self.panes.len()
+    }
+
+    /// Get a pane by index
+    pub fn pane(&self, index: usize) -> Option<&dyn Drawable> {
+        self.panes.get(index).map(|pane| pane.as_ref())
+    }
+
+    /// Collapse or expand a pane
+    pub fn set_pane_collapsed(&mut self, index: usize, collapsed: bool) -> Result<()> {
+        self.check_pane(index)?;
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_split_view.is_null() {
+            unsafe {
+                // NSSplitView treats hidden arranged subviews as collapsed
+                let _: () = msg_send![self.panes[index].as_view(), setHidden: collapsed];
+                let _: () = msg_send![self.ns_split_view, adjustSubviews];
+            }
+        }
+        self.collapsed[index] = collapsed;
+        Ok(())
+    }
+
+    /// Check whether a pane is collapsed
+    pub fn is_pane_collapsed(&self, index: usize) -> bool {
+        self.collapsed.get(index).copied().unwrap_or(false)
+    }
+
+    /// Set the size in points that dragging a divider cannot shrink a pane below
+    pub fn min_pane_size(&mut self, index: usize, size: f64) -> Result<()> {
+        self.check_pane(index)?;
+        if size < 0.0 {
+            return Err(crate::core::error::CocoanutError::InvalidParameter(
+                "Minimum pane size cannot be negative".to_string()
+            ));
+        }
+        self.layout.min_sizes.borrow_mut()[index] = size;
+        Ok(())
+    }
+
+    /// Get the minimum size of a pane in points
+    pub fn pane_min_size(&self, index: usize) -> f64 {
+        self.layout.min_size(index)
+    }
+
+    /// Set a handler called with the divider index and its new position
+    /// in points whenever the user drags a divider
+    pub fn on_divider_moved<F>(&mut self, handler: F)
+    where
+        F: Fn(usize, f64) + 'static,
+    {
+        *self.layout.on_divider_moved.borrow_mut() = Some(Box::new(handler));
+    }
+
+    /// Get the underlying NSSplitView pointer
+    pub fn ns_split_view(&self) -> *mut Object {
+        self.ns_split_view
+    }
+
+    fn check_pane(&self, index: usize) -> Result<()> {
+        if index < self.panes.len() {
+            Ok(())
+        } else {
+            Err(crate::core::error::CocoanutError::InvalidParameter(
+                format!("Pane index {} out of bounds", index)
+            ))
+        }
+    }
+}
+
+impl Drop for SplitView {
+    fn drop(&mut self) {
+        if !self.delegate.is_null() {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                let _: () = msg_send![self.ns_split_view, setDelegate: std::ptr::null_mut::<Object>()];
+                let slot = *(*self.delegate).get_ivar::<*mut c_void>("rustLayout");
+                (*self.delegate).set_ivar::<*mut c_void>("rustLayout", std::ptr::null_mut());
+                if !slot.is_null() {
+                    drop(Box::from_raw(slot as *mut Rc<SplitLayout>));
+                }
+                let _: () = msg_send![self.delegate, release];
+            }
+        }
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_split_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_split_view, release];
+            }
+        }
+    }
+}
+
+/// Lazily register the `NSSplitViewDelegate` class enforcing minimum pane sizes
+#[cfg(not(feature = "test-mock"))]
+fn split_view_delegate_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    /// The leading edge and length of a pane along the split axis
+    unsafe fn pane_extent(split_view: *mut Object, index: i64) -> Option<(f64, f64)> {
+        use cocoa::foundation::NSRect;
+
+        unsafe {
+            let subviews: *mut Object = msg_send![split_view, arrangedSubviews];
+            let count: i64 = msg_send![subviews, count];
+            if index < 0 || index >= count {
+                return None;
+            }
+            let pane: *mut Object = msg_send![subviews, objectAtIndex: index];
+            let frame: NSRect = msg_send![pane, frame];
+            let vertical: bool = msg_send![split_view, isVertical];
+            Some(if vertical {
+                (frame.origin.x, frame.size.width)
+            } else {
+                (frame.origin.y, frame.size.height)
+            })
+        }
+    }
+
+    unsafe fn layout(this: &Object) -> Option<&Rc<SplitLayout>> {
+        unsafe { (*this.get_ivar::<*mut c_void>("rustLayout") as *const Rc<SplitLayout>).as_ref() }
+    }
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutSplitViewDelegate", class!(NSObject))
+            .expect("CocoanutSplitViewDelegate already registered");
+        decl.add_ivar::<*mut c_void>("rustLayout");
+
+        extern "C" fn constrain_min(this: &Object, _cmd: Sel, split_view: *mut Object, proposed: f64, divider: i64) -> f64 {
+            unsafe {
+                match (layout(this), pane_extent(split_view, divider)) {
+                    (Some(layout), Some((start, _))) => proposed.max(start + layout.min_size(divider as usize)),
+                    _ => proposed,
+                }
+            }
+        }
+
+        extern "C" fn constrain_max(this: &Object, _cmd: Sel, split_view: *mut Object, proposed: f64, divider: i64) -> f64 {
+            unsafe {
+                match (layout(this), pane_extent(split_view, divider + 1)) {
+                    (Some(layout), Some((start, length))) => {
+                        proposed.min(start + length - layout.min_size(divider as usize + 1))
+                    }
+                    _ => proposed,
+                }
+            }
+        }
+
+        extern "C" fn did_resize(this: &Object, _cmd: Sel, notification: *mut Object) {
+            unsafe {
+                // Only divider drags carry the divider index in the user info
+                let user_info: *mut Object = msg_send![notification, userInfo];
+                let Ok(key) = crate::core::utils::string_to_ns_string("NSSplitViewDividerIndex") else {
+                    return;
+                };
+                let index: *mut Object = msg_send![user_info, objectForKey: key];
+                if index.is_null() {
+                    return;
+                }
+                let index: i64 = msg_send![index, integerValue];
+                let split_view: *mut Object = msg_send![notification, object];
+                if let (Some(layout), Some((start, length))) = (layout(this), pane_extent(split_view, index)) {
+                    layout.divider_moved(index as usize, start + length);
+                }
+            }
+        }
+
+        unsafe {
+            decl.add_method(
+                sel!(splitView:constrainMinCoordinate:ofSubviewAt:),
+                constrain_min as extern "C" fn(&Object, Sel, *mut Object, f64, i64) -> f64,
+            );
+            decl.add_method(
+                sel!(splitView:constrainMaxCoordinate:ofSubviewAt:),
+                constrain_max as extern "C" fn(&Object, Sel, *mut Object, f64, i64) -> f64,
+            );
+            decl.add_method(
+                sel!(splitViewDidResizeSubviews:),
+                did_resize as extern "C" fn(&Object, Sel, *mut Object),
+            );
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutSplitViewDelegate").expect("CocoanutSplitViewDelegate not registered")
 }
 
 /// Builder for SplitView
@@ -269,10 +1001,9 @@ impl SplitViewBuilder {
 
     /// Build the split view
     pub fn build(self) -> Result<SplitView> {
-        Ok(SplitView {
-            orientation: self.orientation,
-            divider_position: self.divider_position,
-        })
+        let mut split = SplitView::new(self.orientation)?;
+        split.divider_position = self.divider_position;
+        Ok(split)
     }
 }
 
@@ -287,11 +1018,24 @@ impl Default for SplitViewBuilder {
 // ============================================================================
 
 /// A group box container for grouping related controls
+///
+/// The box holds a single content view, which is laid out to fill the
+/// box's content rect: the frame inset by [`GroupBox::CONTENT_MARGIN`] on
+/// every side and by [`GroupBox::TITLE_HEIGHT`] below the title.
 pub struct GroupBox {
+    ns_box: *mut Object,
     title: String,
+    frame: Cell<(f64, f64, f64, f64)>,
+    content_view: Option<Box<dyn View>>,
 }
 
 impl GroupBox {
+    /// Space between the box border and its content, in points
+    pub const CONTENT_MARGIN: f64 = 5.0;
+
+    /// Height reserved for the title above the content, in points
+    pub const TITLE_HEIGHT: f64 = 16.0;
+
     /// Create a new group box builder
     pub fn builder() -> GroupBoxBuilder {
         GroupBoxBuilder::new()
@@ -299,8 +1043,29 @@ impl GroupBox {
 
     /// Create a new group box
     pub fn new(title: &str) -> Result<Self> {
+        let frame = (0.0, 0.0, 350.0, 200.0);
+
+        #[cfg(not(feature = "test-mock"))]
+        let ns_box = unsafe {
+            let rect: cocoa::foundation::NSRect = ZeroCostRect::from_xywh(frame.0, frame.1, frame.2, frame.3).into();
+            let ns_box: *mut Object = msg_send![class!(NSBox), alloc];
+            let ns_box: *mut Object = msg_send![ns_box, initWithFrame: rect];
+            if ns_box.is_null() {
+                return Err(crate::core::error::CocoanutError::ControlCreationFailed(
+                    "Failed to create NSBox".to_string()
+                ));
+            }
+            let _: () = msg_send![ns_box, setTitle: crate::core::utils::string_to_ns_string(title)?];
+            ns_box
+        };
+        #[cfg(feature = "test-mock")]
+        let ns_box = std::ptr::null_mut();
+
         Ok(GroupBox {
+            ns_box,
             title: title.to_string(),
+            frame: Cell::new(frame),
+            content_view: None,
         })
     }
 
@@ -308,6 +1073,108 @@ impl GroupBox {
     pub fn title(&self) -> &str {
         &self.title
     }
+
+    /// Set the component shown inside the box
+    ///
+    /// The box keeps the component alive, replaces any previous content view
+    /// and resizes the component to [`GroupBox::content_frame`].
+    pub fn set_content_view(&mut self, view: Box<dyn View>) -> Result<()> {
+        let (x, y, width, height) = self.content_frame();
+        view.set_frame(x, y, width, height)?;
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_box.is_null() {
+            unsafe {
+                if let Some(previous) = &self.content_view {
+                    let _: () = msg_send![previous.as_view(), removeFromSuperview];
+                }
+                // `setContentView:` would let NSBox resize the view to its own
+                // content rect, so add it directly where `content_frame` says
+                let _: () = msg_send![self.ns_box, addSubview: view.as_view()];
+            }
+        }
+        self.content_view = Some(view);
+        Ok(())
+    }
+
+    /// Get the content view, if one has been set
+    pub fn content_view(&self) -> Option<&dyn View> {
+        self.content_view.as_deref()
+    }
+
+    /// Get the frame available to the content view, in the box's coordinates
+    pub fn content_frame(&self) -> (f64, f64, f64, f64) {
+        let (_, _, width, height) = self.frame.get();
+        let title_height = if self.title.is_empty() { 0.0 } else { Self::TITLE_HEIGHT };
+        (
+            Self::CONTENT_MARGIN,
+            Self::CONTENT_MARGIN,
+            (width - 2.0 * Self::CONTENT_MARGIN).max(0.0),
+            (height - 2.0 * Self::CONTENT_MARGIN - title_height).max(0.0),
+        )
+    }
+}
+
+impl Drop for GroupBox {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_box.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_box, release];
+            }
+        }
+    }
+}
+
+impl Drawable for GroupBox {
+    fn as_view(&self) -> *mut Object {
+        self.ns_box
+    }
+
+    fn set_visible(&self, visible: bool) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_box.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_box, setHidden: !visible];
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = visible;
+        Ok(())
+    }
+
+    fn is_visible(&self) -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_box.is_null() {
+            unsafe {
+                let hidden: bool = msg_send![self.ns_box, isHidden];
+                return !hidden;
+            }
+        }
+        true
+    }
+}
+
+impl Positionable for GroupBox {
+    fn set_frame(&self, x: f64, y: f64, width: f64, height: f64) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_box.is_null() {
+            unsafe {
+                let rect: cocoa::foundation::NSRect = ZeroCostRect::from_xywh(x, y, width, height).into();
+                let _: () = msg_send![self.ns_box, setFrame: rect];
+            }
+        }
+        self.frame.set((x, y, width, height));
+
+        if let Some(view) = &self.content_view {
+            let (x, y, width, height) = self.content_frame();
+            view.set_frame(x, y, width, height)?;
+        }
+        Ok(())
+    }
+
+    fn frame(&self) -> (f64, f64, f64, f64) {
+        self.frame.get()
+    }
 }
 
 /// Builder for GroupBox
@@ -331,9 +1198,7 @@ impl GroupBoxBuilder {
 
     /// Build the group box
     pub fn build(self) -> Result<GroupBox> {
-        Ok(GroupBox {
-            title: self.title,
-        })
+        GroupBox::new(&self.title)
     }
 }
 
@@ -347,6 +1212,37 @@ impl Default for GroupBoxBuilder {
 mod tests {
     use super::*;
 
+    /// A content component without a native view
+    #[derive(Default)]
+    struct TestView {
+        frame: Rc<Cell<(f64, f64, f64, f64)>>,
+    }
+
+    impl Drawable for TestView {
+        fn as_view(&self) -> *mut Object {
+            std::ptr::null_mut()
+        }
+
+        fn set_visible(&self, _visible: bool) -> Result<()> {
+            Ok(())
+        }
+
+        fn is_visible(&self) -> bool {
+            true
+        }
+    }
+
+    impl Positionable for TestView {
+        fn set_frame(&self, x: f64, y: f64, width: f64, height: f64) -> Result<()> {
+            self.frame.set((x, y, width, height));
+            Ok(())
+        }
+
+        fn frame(&self) -> (f64, f64, f64, f64) {
+            self.frame.get()
+        }
+    }
+
     // ScrollView Tests
     #[test]
     fn test_scroll_view_creation() {
@@ -366,6 +1262,39 @@ mod tests {
         assert_eq!(scroll.scrollable_size(), (500.0, 800.0));
     }
 
+    #[test]
+    fn test_scroll_view_scroll_to() {
+        let offsets = Rc::new(RefCell::new(Vec::new()));
+        let mut scroll = ScrollViewBuilder::new()
+            .size(400.0, 300.0)
+            .content_size(400.0, 1000.0)
+            .build()
+            .unwrap();
+        {
+            let offsets = Rc::clone(&offsets);
+            scroll.on_scroll(move |x, y| offsets.borrow_mut().push((x, y)));
+        }
+
+        scroll.scroll_to(Point { x: 0.0, y: 250.0 }).unwrap();
+        assert_eq!(scroll.content_offset(), Point { x: 0.0, y: 250.0 });
+
+        // Offsets are clamped to the scrollable content
+        scroll.scroll_to(Point { x: 50.0, y: 5000.0 }).unwrap();
+        assert_eq!(scroll.content_offset(), Point { x: 0.0, y: 700.0 });
+
+        // Scrolling to the current offset does not report a change
+        scroll.scroll_to(Point { x: 0.0, y: 700.0 }).unwrap();
+        assert_eq!(*offsets.borrow(), vec![(0.0, 250.0), (0.0, 700.0)]);
+    }
+
+    #[test]
+    fn test_scroll_view_document_view() {
+        let mut scroll = ScrollView::new(400.0, 300.0).unwrap();
+        assert!(scroll.document_view().is_none());
+        scroll.set_document_view(Box::new(TestView::default())).unwrap();
+        assert!(scroll.document_view().is_some());
+    }
+
     // TabView Tests
     #[test]
     fn test_tab_view_creation() {
@@ -394,6 +1323,48 @@ mod tests {
         assert_eq!(tabs.selected_tab(), 1);
     }
 
+    #[test]
+    fn test_tab_view_add_and_remove_tabs() {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let mut tabs = TabView::new(vec!["Home".to_string()]).unwrap();
+        {
+            let changes = Rc::clone(&changes);
+            tabs.on_tab_change(move |index| changes.borrow_mut().push(index));
+        }
+
+        assert_eq!(tabs.add_tab("One").unwrap(), 1);
+        assert_eq!(tabs.add_tab("Two").unwrap(), 2);
+        assert_eq!(tabs.add_tab("Three").unwrap(), 3);
+        tabs.set_selected_tab(2).unwrap();
+
+        // Removing the selected middle tab selects the tab that took its place
+        tabs.remove_tab(2).unwrap();
+        assert_eq!(tabs.tabs(), ["Home", "One", "Three"]);
+        assert_eq!(tabs.selected_tab(), 2);
+        assert_eq!(tabs.tab_title(2), Some("Three"));
+
+        // Removing a tab before the selection keeps the same tab selected
+        tabs.remove_tab(0).unwrap();
+        assert_eq!(tabs.selected_tab(), 1);
+        assert_eq!(tabs.tab_title(1), Some("Three"));
+
+        // Removing the selected last tab falls back to the previous one
+        tabs.remove_tab(1).unwrap();
+        assert_eq!(tabs.selected_tab(), 0);
+        assert!(tabs.remove_tab(0).is_err());
+
+        assert_eq!(*changes.borrow(), vec![2, 2, 0]);
+    }
+
+    #[test]
+    fn test_tab_view_titles() {
+        let mut tabs = TabView::new(vec!["A".to_string()]).unwrap();
+        tabs.set_tab_title(0, "Renamed").unwrap();
+        assert_eq!(tabs.tab_title(0), Some("Renamed"));
+        assert_eq!(tabs.tab_title(1), None);
+        assert!(tabs.set_tab_title(1, "Missing").is_err());
+    }
+
     // SplitView Tests
     #[test]
     fn test_split_view_creation() {
@@ -420,6 +1391,46 @@ mod tests {
         assert_eq!(split.divider_position(), 0.7);
     }
 
+    #[test]
+    fn test_split_view_collapse_pane() {
+        let mut split = SplitView::new(SplitOrientation::Vertical).unwrap();
+        assert_eq!(split.add_pane(Box::new(TestView::default())).unwrap(), 0);
+        assert_eq!(split.add_pane(Box::new(TestView::default())).unwrap(), 1);
+        assert_eq!(split.add_pane(Box::new(TestView::default())).unwrap(), 2);
+        assert_eq!(split.pane_count(), 3);
+
+        split.set_pane_collapsed(0, true).unwrap();
+        assert!(split.is_pane_collapsed(0));
+        assert!(!split.is_pane_collapsed(1));
+
+        split.set_pane_collapsed(0, false).unwrap();
+        assert!(!split.is_pane_collapsed(0));
+        assert!(split.set_pane_collapsed(3, true).is_err());
+    }
+
+    #[test]
+    fn test_split_view_min_pane_size() {
+        let mut split = SplitView::new(SplitOrientation::Horizontal).unwrap();
+        split.add_pane(Box::new(TestView::default())).unwrap();
+        split.min_pane_size(0, 120.0).unwrap();
+        assert_eq!(split.pane_min_size(0), 120.0);
+        assert!(split.min_pane_size(0, -1.0).is_err());
+        assert!(split.min_pane_size(1, 50.0).is_err());
+    }
+
+    #[test]
+    fn test_split_view_divider_moved_handler() {
+        let moves = Rc::new(RefCell::new(Vec::new()));
+        let mut split = SplitView::new(SplitOrientation::Vertical).unwrap();
+        {
+            let moves = Rc::clone(&moves);
+            split.on_divider_moved(move |index, position| moves.borrow_mut().push((index, position)));
+        }
+
+        split.layout.divider_moved(1, 240.0);
+        assert_eq!(*moves.borrow(), vec![(1, 240.0)]);
+    }
+
     // GroupBox Tests
     #[test]
     fn test_group_box_creation() {
@@ -436,4 +1447,33 @@ mod tests {
         
         assert_eq!(group.title(), "Preferences");
     }
+
+    #[test]
+    fn test_group_box_content_view() {
+        let mut group = GroupBox::new("Account").unwrap();
+        group.set_frame(20.0, 20.0, 300.0, 120.0).unwrap();
+
+        let content = TestView::default();
+        let content_frame = Rc::clone(&content.frame);
+        group.set_content_view(Box::new(content)).unwrap();
+        assert!(group.content_view().is_some());
+
+        // The content is inset from the box on every side and below the title
+        let (x, y, width, height) = content_frame.get();
+        assert_eq!((x, y), (GroupBox::CONTENT_MARGIN, GroupBox::CONTENT_MARGIN));
+        assert_eq!(width, 300.0 - 2.0 * GroupBox::CONTENT_MARGIN);
+        assert_eq!(height, 120.0 - 2.0 * GroupBox::CONTENT_MARGIN - GroupBox::TITLE_HEIGHT);
+
+        // Resizing the box lays the content out again
+        group.set_frame(20.0, 20.0, 400.0, 200.0).unwrap();
+        assert_eq!(content_frame.get(), group.content_frame());
+        assert_eq!(content_frame.get().2, 400.0 - 2.0 * GroupBox::CONTENT_MARGIN);
+    }
+
+    #[test]
+    fn test_group_box_untitled_content_frame() {
+        let group = GroupBox::new("").unwrap();
+        let (_, _, _, height) = group.content_frame();
+        assert_eq!(height, group.frame().3 - 2.0 * GroupBox::CONTENT_MARGIN);
+    }
 }
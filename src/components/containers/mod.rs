@@ -1,3 +1,5 @@
 //! Container views
 pub mod containers;
+pub mod popover;
 pub use containers::*;
+pub use popover::*;
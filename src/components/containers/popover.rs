@@ -0,0 +1,296 @@
+//! Popovers anchored to a control (`NSPopover`)
+//!
+//! A `Popover` shows any [`Drawable`] in a bubble pointing at another view,
+//! the way inline editors and pickers appear throughout macOS.
+
+use crate::core::error::Result;
+use crate::core::traits::Drawable;
+use objc::runtime::Object;
+
+#[cfg(not(feature = "test-mock"))]
+use crate::core::error::CocoanutError;
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+
+/// Side of the anchor view a popover prefers to appear on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Edge {
+    /// Left of the anchor
+    Left,
+    /// Below the anchor
+    #[default]
+    Bottom,
+    /// Right of the anchor
+    Right,
+    /// Above the anchor
+    Top,
+}
+
+impl Edge {
+    /// The matching `NSRectEdge`, for an unflipped anchor view
+    pub fn to_ns_rect_edge(self) -> u64 {
+        match self {
+            Edge::Left => 0,   // NSRectEdgeMinX
+            Edge::Bottom => 1, // NSRectEdgeMinY
+            Edge::Right => 2,  // NSRectEdgeMaxX
+            Edge::Top => 3,    // NSRectEdgeMaxY
+        }
+    }
+}
+
+/// When a popover closes on its own
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PopoverBehavior {
+    /// Only closed by [`Popover::close`]
+    ApplicationDefined,
+    /// Closed when the user interacts with anything outside it
+    #[default]
+    Transient,
+    /// Closed when the user interacts with a window other than its own
+    Semitransient,
+}
+
+impl PopoverBehavior {
+    /// The matching `NSPopoverBehavior`
+    pub fn to_ns_behavior(self) -> i64 {
+        match self {
+            PopoverBehavior::ApplicationDefined => 0,
+            PopoverBehavior::Transient => 1,
+            PopoverBehavior::Semitransient => 2,
+        }
+    }
+}
+
+/// A popover showing a content view next to an anchor view
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::containers::{Edge, Popover, PopoverBehavior};
+/// use cocoanut::prelude::*;
+///
+/// # fn main() -> cocoanut::Result<()> {
+/// let anchor = Switch::new("Volume")?;
+/// let content = Switch::new("Mute")?;
+/// let mut popover = Popover::new(&content)?
+///     .preferred_edge(Edge::Top)
+///     .behavior(PopoverBehavior::Semitransient);
+/// popover.show_relative_to(&anchor)?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Popover {
+    ns_popover: *mut Object,
+    ns_controller: *mut Object,
+    edge: Edge,
+    behavior: PopoverBehavior,
+    anchor: Option<*mut Object>,
+    #[cfg(feature = "test-mock")]
+    shown: bool,
+}
+
+impl Popover {
+    /// Create a popover showing `content`, sized to the content's frame
+    pub fn new(content: &dyn Drawable) -> Result<Self> {
+        let behavior = PopoverBehavior::default();
+
+        #[cfg(not(feature = "test-mock"))]
+        let (ns_popover, ns_controller) = unsafe {
+            use cocoa::foundation::NSRect;
+
+            let ns_view = content.as_view();
+            if ns_view.is_null() {
+                return Err(CocoanutError::InvalidParameter(
+                    "Popover content has no native view".to_string()
+                ));
+            }
+            let ns_controller: *mut Object = msg_send![class!(NSViewController), new];
+            let ns_popover: *mut Object = msg_send![class!(NSPopover), new];
+            if ns_controller.is_null() || ns_popover.is_null() {
+                // Messages to nil do nothing, so release whichever was created
+                let _: () = msg_send![ns_controller, release];
+                let _: () = msg_send![ns_popover, release];
+                return Err(CocoanutError::ControlCreationFailed("Failed to create NSPopover".to_string()));
+            }
+            let _: () = msg_send![ns_controller, setView: ns_view];
+            let frame: NSRect = msg_send![ns_view, frame];
+            let _: () = msg_send![ns_popover, setContentViewController: ns_controller];
+            let _: () = msg_send![ns_popover, setContentSize: frame.size];
+            let _: () = msg_send![ns_popover, setBehavior: behavior.to_ns_behavior()];
+            (ns_popover, ns_controller)
+        };
+        #[cfg(feature = "test-mock")]
+        let (ns_popover, ns_controller): (*mut Object, *mut Object) = {
+            let _ = content;
+            (std::ptr::null_mut(), std::ptr::null_mut())
+        };
+
+        Ok(Popover {
+            ns_popover,
+            ns_controller,
+            edge: Edge::default(),
+            behavior,
+            anchor: None,
+            #[cfg(feature = "test-mock")]
+            shown: false,
+        })
+    }
+
+    /// Set the side of the anchor to show on, if there is room
+    pub fn preferred_edge(mut self, edge: Edge) -> Self {
+        self.edge = edge;
+        self
+    }
+
+    /// Set when the popover closes on its own
+    pub fn behavior(mut self, behavior: PopoverBehavior) -> Self {
+        self.behavior = behavior;
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_popover, setBehavior: behavior.to_ns_behavior()];
+        }
+        self
+    }
+
+    /// Get the preferred edge
+    pub fn edge(&self) -> Edge {
+        self.edge
+    }
+
+    /// Get the close behavior
+    pub fn get_behavior(&self) -> PopoverBehavior {
+        self.behavior
+    }
+
+    /// Show the popover pointing at `anchor`
+    ///
+    /// Showing an open popover moves it to the new anchor.
+    pub fn show_relative_to(&mut self, anchor: &dyn Drawable) -> Result<()> {
+        let anchor_view = anchor.as_view();
+
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            use cocoa::foundation::NSRect;
+
+            if anchor_view.is_null() {
+                return Err(CocoanutError::InvalidParameter(
+                    "Popover anchor has no native view".to_string()
+                ));
+            }
+            let bounds: NSRect = msg_send![anchor_view, bounds];
+            let _: () = msg_send![self.ns_popover, showRelativeToRect: bounds
+                                                              ofView: anchor_view
+                                                       preferredEdge: self.edge.to_ns_rect_edge()];
+        }
+        #[cfg(feature = "test-mock")]
+        {
+            self.shown = true;
+        }
+
+        self.anchor = Some(anchor_view);
+        Ok(())
+    }
+
+    /// Close the popover if it is shown
+    pub fn close(&mut self) {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_popover, close];
+        }
+        #[cfg(feature = "test-mock")]
+        {
+            self.shown = false;
+        }
+
+        self.anchor = None;
+    }
+
+    /// Check if the popover is on screen
+    ///
+    /// Transient popovers can close without [`Popover::close`] being called.
+    pub fn is_shown(&self) -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            msg_send![self.ns_popover, isShown]
+        }
+
+        #[cfg(feature = "test-mock")]
+        self.shown
+    }
+
+    /// The view the popover was last shown relative to, while shown
+    pub fn anchor(&self) -> Option<*mut Object> {
+        self.anchor.filter(|_| self.is_shown())
+    }
+}
+
+impl Drop for Popover {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_popover, close];
+            let _: () = msg_send![self.ns_popover, release];
+            let _: () = msg_send![self.ns_controller, release];
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = (self.ns_popover, self.ns_controller);
+    }
+}
+
+unsafe impl Send for Popover {}
+unsafe impl Sync for Popover {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A stand-in view with a recognizable pointer
+    #[cfg(feature = "test-mock")]
+    struct TestView(usize);
+
+    #[cfg(feature = "test-mock")]
+    impl Drawable for TestView {
+        fn as_view(&self) -> *mut Object {
+            self.0 as *mut Object
+        }
+
+        fn set_visible(&self, _visible: bool) -> Result<()> {
+            Ok(())
+        }
+
+        fn is_visible(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_native_values() {
+        assert_eq!(Edge::Left.to_ns_rect_edge(), 0);
+        assert_eq!(Edge::Top.to_ns_rect_edge(), 3);
+        assert_eq!(PopoverBehavior::default().to_ns_behavior(), 1);
+        assert_eq!(PopoverBehavior::Semitransient.to_ns_behavior(), 2);
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_show_and_close() {
+        let content = TestView(0x10);
+        let anchor = TestView(0x20);
+        let mut popover = Popover::new(&content)
+            .unwrap()
+            .preferred_edge(Edge::Right)
+            .behavior(PopoverBehavior::ApplicationDefined);
+        assert_eq!(popover.edge(), Edge::Right);
+        assert_eq!(popover.get_behavior(), PopoverBehavior::ApplicationDefined);
+        assert!(!popover.is_shown());
+        assert_eq!(popover.anchor(), None);
+
+        popover.show_relative_to(&anchor).unwrap();
+        assert!(popover.is_shown());
+        assert_eq!(popover.anchor(), Some(anchor.as_view()));
+
+        popover.close();
+        assert!(!popover.is_shown());
+        assert_eq!(popover.anchor(), None);
+    }
+}
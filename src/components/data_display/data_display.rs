@@ -1,17 +1,102 @@
 //! Data display components for macOS GUI applications
 //!
-//! Includes TableView, OutlineView, and CollectionView for displaying data.
+//! Includes TableView, DataTable, OutlineView, and CollectionView for displaying data.
 
 use crate::core::error::Result;
+use crate::core::traits::Drawable;
+use objc::runtime::Object;
+use std::sync::Arc;
+
+#[cfg(not(feature = "test-mock"))]
+use objc::declare::ClassDecl;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Class, Sel};
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+#[cfg(not(feature = "test-mock"))]
+use std::ffi::c_void;
+#[cfg(not(feature = "test-mock"))]
+use std::sync::Once;
 
 // ============================================================================
 // TABLE VIEW
 // ============================================================================
 
+/// What a table or collection cell shows
+#[derive(Debug, Clone, PartialEq)]
+pub enum CellContent {
+    /// A line of text
+    Text(String),
+    /// Nothing
+    Empty,
+}
+
+impl CellContent {
+    /// The text shown, empty for [`CellContent::Empty`]
+    pub fn text(&self) -> &str {
+        match self {
+            CellContent::Text(text) => text,
+            CellContent::Empty => "",
+        }
+    }
+}
+
+impl From<String> for CellContent {
+    fn from(text: String) -> Self {
+        CellContent::Text(text)
+    }
+}
+
+impl From<&str> for CellContent {
+    fn from(text: &str) -> Self {
+        CellContent::Text(text.to_string())
+    }
+}
+
+/// Builds the content of the cell at a row and column on demand
+pub type CellProvider = Arc<dyn Fn(usize, usize) -> CellContent + Send + Sync>;
+
+/// Identifier native table cells are reused under
+#[cfg(not(feature = "test-mock"))]
+const TABLE_CELL_IDENTIFIER: &str = "CocoanutTableCell";
+
+/// Rows and cell provider of a `TableView`
+///
+/// The table boxes its content so the data source can read it through a
+/// stable pointer; it is only changed through `&mut TableView`.
+struct TableContent {
+    rows: Vec<Vec<String>>,
+    provider: Option<(usize, CellProvider)>,
+}
+
+impl TableContent {
+    fn row_count(&self) -> usize {
+        match &self.provider {
+            Some((row_count, _)) => *row_count,
+            None => self.rows.len(),
+        }
+    }
+
+    fn cell(&self, row: usize, column: usize) -> Option<CellContent> {
+        match &self.provider {
+            Some((row_count, provider)) => (row < *row_count).then(|| provider(row, column)),
+            None => self.rows.get(row)?.get(column).cloned().map(CellContent::Text),
+        }
+    }
+}
+
 /// A table view for displaying tabular data
+///
+/// The native `NSTableView` asks for cells only as rows scroll into view
+/// and reuses the cell views of rows that scrolled out, so even very long
+/// tables stay cheap. For large data sets, supply cells on demand with
+/// [`TableView::set_cell_provider`] instead of adding every row up front.
 pub struct TableView {
     columns: Vec<String>,
-    rows: Vec<Vec<String>>,
+    content: Box<TableContent>,
+    ns_view: *mut Object,
+    ns_table_view: *mut Object,
+    data_source: *mut Object,
 }
 
 impl TableView {
@@ -27,9 +112,23 @@ impl TableView {
                 "Columns cannot be empty".to_string()
             ));
         }
+        let content = Box::new(TableContent {
+            rows: Vec::new(),
+            provider: None,
+        });
+
+        #[cfg(not(feature = "test-mock"))]
+        let (ns_view, ns_table_view, data_source) = unsafe { create_table_view(&columns, &content)? };
+        #[cfg(feature = "test-mock")]
+        let (ns_view, ns_table_view, data_source) =
+            (std::ptr::null_mut(), std::ptr::null_mut(), std::ptr::null_mut());
+
         Ok(TableView {
             columns,
-            rows: Vec::new(),
+            content,
+            ns_view,
+            ns_table_view,
+            data_source,
         })
     }
 
@@ -38,9 +137,9 @@ impl TableView {
         &self.columns
     }
 
-    /// Get the rows
+    /// Get the rows added with [`TableView::add_row`]
     pub fn rows(&self) -> &[Vec<String>] {
-        &self.rows
+        &self.content.rows
     }
 
     /// Add a row
@@ -50,13 +149,257 @@ impl TableView {
                 format!("Row length {} doesn't match column count {}", row.len(), self.columns.len())
             ));
         }
-        self.rows.push(row);
+        self.content.rows.push(row);
+        self.reload_data();
         Ok(())
     }
 
     /// Get row count
     pub fn row_count(&self) -> usize {
-        self.rows.len()
+        self.content.row_count()
+    }
+
+    /// Build cells on demand with `provider`, called with a row and column
+    /// index, for a table of `row_count` rows
+    ///
+    /// The provider only runs for cells that are asked for, so a table can
+    /// show millions of rows without materializing them. While a provider
+    /// is set it supplies every cell and rows added with
+    /// [`TableView::add_row`] are not shown.
+    pub fn set_cell_provider<F>(&mut self, row_count: usize, provider: F)
+    where
+        F: Fn(usize, usize) -> CellContent + Send + Sync + 'static,
+    {
+        self.content.provider = Some((row_count, Arc::new(provider)));
+        self.reload_data();
+    }
+
+    /// Go back to showing the rows added with [`TableView::add_row`]
+    pub fn clear_cell_provider(&mut self) {
+        self.content.provider = None;
+        self.reload_data();
+    }
+
+    /// Get the content of the cell at `row` and `column`, or `None` if out
+    /// of bounds
+    pub fn cell_content(&self, row: usize, column: usize) -> Option<CellContent> {
+        if column >= self.columns.len() {
+            return None;
+        }
+        self.content.cell(row, column)
+    }
+
+    /// Ask the native table to fetch its rows again
+    ///
+    /// Only rows on screen are rebuilt.
+    pub fn reload_data(&self) {
+        if !self.ns_table_view.is_null() {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                let _: () = msg_send![self.ns_table_view, reloadData];
+            }
+        }
+    }
+
+    /// Serialize the columns and rows as CSV
+    ///
+    /// The first record holds the column names. Records end with CRLF, and
+    /// cells containing commas, quotes or line breaks are quoted as
+    /// RFC 4180 describes. Every cell of a provided table is built.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        let header: Vec<_> = self.columns.iter().map(|column| csv_field(column)).collect();
+        csv.push_str(&header.join(","));
+        csv.push_str("\r\n");
+        for row in 0..self.row_count() {
+            let cells: Vec<_> = (0..self.columns.len())
+                .map(|column| {
+                    let cell = self.content.cell(row, column).unwrap_or(CellContent::Empty);
+                    csv_field(cell.text()).into_owned()
+                })
+                .collect();
+            csv.push_str(&cells.join(","));
+            csv.push_str("\r\n");
+        }
+        csv
+    }
+
+    /// Write the table to `path` as CSV, replacing any existing file
+    pub fn export_csv(&self, path: impl AsRef<std::path::Path>) -> Result<()> {
+        std::fs::write(path, self.to_csv())?;
+        Ok(())
+    }
+}
+
+impl Drawable for TableView {
+    fn as_view(&self) -> *mut Object {
+        self.ns_view
+    }
+
+    fn set_visible(&self, visible: bool) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_view, setHidden: !visible];
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = visible;
+        Ok(())
+    }
+
+    fn is_visible(&self) -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let hidden: bool = msg_send![self.ns_view, isHidden];
+                return !hidden;
+            }
+        }
+        true
+    }
+}
+
+impl Drop for TableView {
+    fn drop(&mut self) {
+        if !self.data_source.is_null() {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                let nil: *mut Object = std::ptr::null_mut();
+                let _: () = msg_send![self.ns_table_view, setDelegate: nil];
+                let _: () = msg_send![self.ns_table_view, setDataSource: nil];
+                (*self.data_source).set_ivar::<*mut c_void>("rustContent", std::ptr::null_mut());
+                let _: () = msg_send![self.data_source, release];
+                let _: () = msg_send![self.ns_view, release];
+            }
+        }
+    }
+}
+
+/// Create an `NSTableView` with one column per title inside an
+/// `NSScrollView`, with a data source reading from `content`
+///
+/// Returns the scroll view, the table view and the data source.
+#[cfg(not(feature = "test-mock"))]
+unsafe fn create_table_view(
+    columns: &[String],
+    content: &TableContent,
+) -> Result<(*mut Object, *mut Object, *mut Object)> {
+    use crate::core::utils::string_to_ns_string;
+    use cocoa::foundation::{NSPoint, NSRect, NSSize};
+
+    unsafe {
+        let frame = NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(400.0, 300.0));
+        let scroll_view: *mut Object = msg_send![class!(NSScrollView), alloc];
+        let scroll_view: *mut Object = msg_send![scroll_view, initWithFrame: frame];
+        let table_view: *mut Object = msg_send![class!(NSTableView), alloc];
+        let table_view: *mut Object = msg_send![table_view, initWithFrame: frame];
+        if scroll_view.is_null() || table_view.is_null() {
+            return Err(crate::core::error::CocoanutError::ControlCreationFailed(
+                "Failed to create NSTableView".to_string()
+            ));
+        }
+
+        // Column identifiers hold the column index the provider is asked for
+        for (index, title) in columns.iter().enumerate() {
+            let column: *mut Object = msg_send![class!(NSTableColumn), alloc];
+            let column: *mut Object = msg_send![column, initWithIdentifier: string_to_ns_string(&index.to_string())?];
+            let header: *mut Object = msg_send![column, headerCell];
+            let _: () = msg_send![header, setStringValue: string_to_ns_string(title)?];
+            let _: () = msg_send![table_view, addTableColumn: column];
+            let _: () = msg_send![column, release];
+        }
+
+        let slot = content as *const TableContent as *mut c_void;
+        let data_source: *mut Object = msg_send![table_data_source_class(), new];
+        (*data_source).set_ivar::<*mut c_void>("rustContent", slot);
+        let _: () = msg_send![table_view, setDataSource: data_source];
+        let _: () = msg_send![table_view, setDelegate: data_source];
+
+        let _: () = msg_send![scroll_view, setHasVerticalScroller: true];
+        let _: () = msg_send![scroll_view, setDocumentView: table_view];
+        let _: () = msg_send![table_view, release];
+        Ok((scroll_view, table_view, data_source))
+    }
+}
+
+/// Lazily register the `NSTableViewDataSource` and delegate class that
+/// builds cells from a [`TableContent`], reusing cell views by identifier
+#[cfg(not(feature = "test-mock"))]
+fn table_data_source_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutTableDataSource", class!(NSObject))
+            .expect("CocoanutTableDataSource already registered");
+        decl.add_ivar::<*mut c_void>("rustContent");
+
+        extern "C" fn number_of_rows(this: &Object, _cmd: Sel, _table_view: *mut Object) -> i64 {
+            unsafe {
+                let slot = *this.get_ivar::<*mut c_void>("rustContent") as *const TableContent;
+                slot.as_ref().map_or(0, |content| content.row_count() as i64)
+            }
+        }
+
+        extern "C" fn view_for_cell(
+            this: &Object,
+            _cmd: Sel,
+            table_view: *mut Object,
+            column: *mut Object,
+            row: i64,
+        ) -> *mut Object {
+            use crate::core::utils::{ns_string_to_string, string_to_ns_string};
+
+            unsafe {
+                let slot = *this.get_ivar::<*mut c_void>("rustContent") as *const TableContent;
+                let Some(content) = slot.as_ref() else {
+                    return std::ptr::null_mut();
+                };
+                let identifier: *mut Object = msg_send![column, identifier];
+                let Some(column) = ns_string_to_string(identifier).ok().and_then(|index| index.parse().ok()) else {
+                    return std::ptr::null_mut();
+                };
+                let (Ok(text), Ok(cell_identifier)) = (
+                    string_to_ns_string(content.cell(row.max(0) as usize, column).unwrap_or(CellContent::Empty).text()),
+                    string_to_ns_string(TABLE_CELL_IDENTIFIER),
+                ) else {
+                    return std::ptr::null_mut();
+                };
+
+                let nil: *mut Object = std::ptr::null_mut();
+                let mut cell: *mut Object = msg_send![table_view, makeViewWithIdentifier: cell_identifier owner: nil];
+                if cell.is_null() {
+                    cell = msg_send![class!(NSTextField), labelWithString: text];
+                    let _: () = msg_send![cell, setIdentifier: cell_identifier];
+                } else {
+                    let _: () = msg_send![cell, setStringValue: text];
+                }
+                cell
+            }
+        }
+
+        unsafe {
+            decl.add_method(
+                sel!(numberOfRowsInTableView:),
+                number_of_rows as extern "C" fn(&Object, Sel, *mut Object) -> i64,
+            );
+            decl.add_method(
+                sel!(tableView:viewForTableColumn:row:),
+                view_for_cell as extern "C" fn(&Object, Sel, *mut Object, *mut Object, i64) -> *mut Object,
+            );
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutTableDataSource").expect("CocoanutTableDataSource not registered")
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break
+fn csv_field(cell: &str) -> std::borrow::Cow<'_, str> {
+    if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\"")).into()
+    } else {
+        cell.into()
     }
 }
 
@@ -99,13 +442,177 @@ impl Default for TableViewBuilder {
     }
 }
 
+// ============================================================================
+// DATA TABLE
+// ============================================================================
+
+/// Projection from a row to the text of one of its cells
+pub type CellProjection<T> = Box<dyn Fn(&T) -> String + Send + Sync>;
+
+/// Predicate deciding which rows a [`DataTable`] shows
+pub type RowFilter<T> = Box<dyn Fn(&T) -> bool + Send + Sync>;
+
+/// A column of a [`DataTable`]
+struct DataColumn<T> {
+    header: String,
+    project: CellProjection<T>,
+}
+
+/// A table over typed rows
+///
+/// Each column projects a row to the text shown in its cell. A filter hides
+/// rows without removing them from the model, so clearing it shows them
+/// again.
+///
+/// # Example
+///
+/// ```rust
+/// use cocoanut::data_display::DataTable;
+///
+/// struct Person { name: String, age: u32 }
+///
+/// let mut table = DataTable::new()
+///     .column("Name", |p: &Person| p.name.clone())
+///     .column("Age", |p: &Person| p.age.to_string());
+/// table.set_rows(vec![
+///     Person { name: "Alice".into(), age: 30 },
+///     Person { name: "Bob".into(), age: 17 },
+/// ]);
+/// table.set_filter(|p| p.age >= 18);
+/// assert_eq!(table.visible_row_count(), 1);
+/// assert_eq!(table.row_count(), 2);
+/// ```
+pub struct DataTable<T> {
+    columns: Vec<DataColumn<T>>,
+    rows: Vec<T>,
+    filter: Option<RowFilter<T>>,
+    /// Indices into `rows` of the rows passing the filter
+    visible: Vec<usize>,
+}
+
+impl<T> DataTable<T> {
+    /// Create a table with no columns or rows
+    pub fn new() -> Self {
+        DataTable {
+            columns: Vec::new(),
+            rows: Vec::new(),
+            filter: None,
+            visible: Vec::new(),
+        }
+    }
+
+    /// Add a column whose cells show `project` applied to each row
+    pub fn column<F>(mut self, header: impl Into<String>, project: F) -> Self
+    where
+        F: Fn(&T) -> String + Send + Sync + 'static,
+    {
+        self.columns.push(DataColumn { header: header.into(), project: Box::new(project) });
+        self
+    }
+
+    /// Get the column headers
+    pub fn headers(&self) -> Vec<&str> {
+        self.columns.iter().map(|column| column.header.as_str()).collect()
+    }
+
+    /// Replace the rows of the model
+    pub fn set_rows(&mut self, rows: Vec<T>) {
+        self.rows = rows;
+        self.refilter();
+    }
+
+    /// Add a row to the end of the model
+    pub fn push_row(&mut self, row: T) {
+        if self.filter.as_ref().is_none_or(|filter| filter(&row)) {
+            self.visible.push(self.rows.len());
+        }
+        self.rows.push(row);
+    }
+
+    /// Get every row of the model, including filtered-out ones
+    pub fn rows(&self) -> &[T] {
+        &self.rows
+    }
+
+    /// Get the number of rows in the model, including filtered-out ones
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Show only the rows for which `filter` returns `true`
+    pub fn set_filter<F>(&mut self, filter: F)
+    where
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        self.filter = Some(Box::new(filter));
+        self.refilter();
+    }
+
+    /// Show every row again
+    pub fn clear_filter(&mut self) {
+        self.filter = None;
+        self.refilter();
+    }
+
+    /// Check if a filter is set
+    pub fn is_filtered(&self) -> bool {
+        self.filter.is_some()
+    }
+
+    /// Get the rows passing the filter, in model order
+    pub fn visible_rows(&self) -> impl Iterator<Item = &T> {
+        self.visible.iter().map(|&index| &self.rows[index])
+    }
+
+    /// Get the number of rows passing the filter
+    pub fn visible_row_count(&self) -> usize {
+        self.visible.len()
+    }
+
+    /// Get the text of a cell, indexed by visible row and column
+    pub fn cell(&self, row: usize, column: usize) -> Option<String> {
+        let row = &self.rows[*self.visible.get(row)?];
+        Some((self.columns.get(column)?.project)(row))
+    }
+
+    /// Build a [`TableView`] of the visible rows
+    pub fn to_table_view(&self) -> Result<TableView> {
+        let mut table = TableView::new(self.columns.iter().map(|column| column.header.clone()).collect())?;
+        for row in self.visible_rows() {
+            table.add_row(self.columns.iter().map(|column| (column.project)(row)).collect())?;
+        }
+        Ok(table)
+    }
+
+    fn refilter(&mut self) {
+        self.visible = match &self.filter {
+            Some(filter) => (0..self.rows.len()).filter(|&index| filter(&self.rows[index])).collect(),
+            None => (0..self.rows.len()).collect(),
+        };
+    }
+}
+
+impl<T> Default for DataTable<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // ============================================================================
 // OUTLINE VIEW
 // ============================================================================
 
+/// Callback run with the index path of an item expanded or collapsed
+pub type OutlineCallback = Arc<dyn Fn(&[usize]) + Send + Sync>;
+
 /// An outline view for displaying hierarchical data
+///
+/// Items are addressed by index paths: `[1, 0]` is the first child of the
+/// second root item.
 pub struct OutlineView {
     root_items: Vec<OutlineItem>,
+    on_expand: Option<OutlineCallback>,
+    on_collapse: Option<OutlineCallback>,
 }
 
 /// An item in an outline view
@@ -113,6 +620,7 @@ pub struct OutlineView {
 pub struct OutlineItem {
     title: String,
     children: Vec<OutlineItem>,
+    expanded: bool,
 }
 
 impl OutlineItem {
@@ -121,6 +629,7 @@ impl OutlineItem {
         OutlineItem {
             title: title.into(),
             children: Vec::new(),
+            expanded: false,
         }
     }
 
@@ -138,6 +647,11 @@ impl OutlineItem {
     pub fn children(&self) -> &[OutlineItem] {
         &self.children
     }
+
+    /// Check if the item's children are shown
+    pub fn is_expanded(&self) -> bool {
+        self.expanded
+    }
 }
 
 impl OutlineView {
@@ -150,6 +664,8 @@ impl OutlineView {
     pub fn new() -> Result<Self> {
         Ok(OutlineView {
             root_items: Vec::new(),
+            on_expand: None,
+            on_collapse: None,
         })
     }
 
@@ -162,6 +678,107 @@ impl OutlineView {
     pub fn items(&self) -> &[OutlineItem] {
         &self.root_items
     }
+
+    /// Get the item at `item_path`
+    pub fn item(&self, item_path: &[usize]) -> Option<&OutlineItem> {
+        let (first, rest) = item_path.split_first()?;
+        rest.iter()
+            .try_fold(self.root_items.get(*first)?, |item, &index| item.children.get(index))
+    }
+
+    /// Show the children of the item at `item_path`
+    ///
+    /// Items without children cannot be expanded, and are left as they are.
+    /// Returns an error if no item is at `item_path`.
+    pub fn expand(&mut self, item_path: &[usize]) -> Result<()> {
+        self.set_expanded(item_path, true)
+    }
+
+    /// Hide the children of the item at `item_path`
+    ///
+    /// Returns an error if no item is at `item_path`.
+    pub fn collapse(&mut self, item_path: &[usize]) -> Result<()> {
+        self.set_expanded(item_path, false)
+    }
+
+    /// Expand every item that has children
+    pub fn expand_all(&mut self) {
+        self.set_all_expanded(true);
+    }
+
+    /// Collapse every item
+    pub fn collapse_all(&mut self) {
+        self.set_all_expanded(false);
+    }
+
+    /// Check if the item at `item_path` is expanded
+    ///
+    /// `false` if no item is at `item_path`.
+    pub fn is_expanded(&self, item_path: &[usize]) -> bool {
+        self.item(item_path).is_some_and(OutlineItem::is_expanded)
+    }
+
+    /// Run `handler` with the item path whenever an item is expanded
+    pub fn on_expand<F>(&mut self, handler: F)
+    where
+        F: Fn(&[usize]) + Send + Sync + 'static,
+    {
+        self.on_expand = Some(Arc::new(handler));
+    }
+
+    /// Run `handler` with the item path whenever an item is collapsed
+    pub fn on_collapse<F>(&mut self, handler: F)
+    where
+        F: Fn(&[usize]) + Send + Sync + 'static,
+    {
+        self.on_collapse = Some(Arc::new(handler));
+    }
+
+    fn set_expanded(&mut self, item_path: &[usize], expanded: bool) -> Result<()> {
+        let item = item_path
+            .split_first()
+            .and_then(|(first, rest)| {
+                rest.iter().try_fold(self.root_items.get_mut(*first)?, |item, &index| {
+                    item.children.get_mut(index)
+                })
+            })
+            .ok_or_else(|| crate::core::error::CocoanutError::InvalidParameter(
+                format!("No outline item at {:?}", item_path)
+            ))?;
+        if item.expanded == expanded || (expanded && item.children.is_empty()) {
+            return Ok(());
+        }
+        item.expanded = expanded;
+        self.notify(item_path, expanded);
+        Ok(())
+    }
+
+    fn set_all_expanded(&mut self, expanded: bool) {
+        fn visit(items: &mut [OutlineItem], path: &mut Vec<usize>, expanded: bool, changed: &mut Vec<Vec<usize>>) {
+            for (index, item) in items.iter_mut().enumerate() {
+                path.push(index);
+                if item.expanded != expanded && !(expanded && item.children.is_empty()) {
+                    item.expanded = expanded;
+                    changed.push(path.clone());
+                }
+                visit(&mut item.children, path, expanded, changed);
+                path.pop();
+            }
+        }
+
+        let mut changed = Vec::new();
+        visit(&mut self.root_items, &mut Vec::new(), expanded, &mut changed);
+        for path in changed {
+            self.notify(&path, expanded);
+        }
+    }
+
+    fn notify(&self, item_path: &[usize], expanded: bool) {
+        let handler = if expanded { &self.on_expand } else { &self.on_collapse };
+        if let Some(handler) = handler {
+            handler(item_path);
+        }
+    }
 }
 
 /// Builder for OutlineView
@@ -203,10 +820,19 @@ impl Default for OutlineViewBuilder {
 // COLLECTION VIEW
 // ============================================================================
 
+/// Builds the content of the collection item at an index on demand
+pub type ItemProvider = Arc<dyn Fn(usize) -> CellContent + Send + Sync>;
+
 /// A collection view for displaying items in a grid layout
+///
+/// This models the grid's items only and creates no native
+/// `NSCollectionView`, so there are no item views to reuse yet. Items from a
+/// cell provider are built one at a time as
+/// [`CollectionView::item_content`] asks for them.
 pub struct CollectionView {
     items: Vec<String>,
     columns: usize,
+    provider: Option<(usize, ItemProvider)>,
 }
 
 impl CollectionView {
@@ -225,6 +851,7 @@ impl CollectionView {
         Ok(CollectionView {
             items: Vec::new(),
             columns,
+            provider: None,
         })
     }
 
@@ -245,7 +872,35 @@ impl CollectionView {
 
     /// Get item count
     pub fn item_count(&self) -> usize {
-        self.items.len()
+        match &self.provider {
+            Some((item_count, _)) => *item_count,
+            None => self.items.len(),
+        }
+    }
+
+    /// Build items on demand with `provider`, called with an item index, for
+    /// a collection of `item_count` items
+    ///
+    /// While a provider is set it supplies every item and items added with
+    /// [`CollectionView::add_item`] are not shown.
+    pub fn set_cell_provider<F>(&mut self, item_count: usize, provider: F)
+    where
+        F: Fn(usize) -> CellContent + Send + Sync + 'static,
+    {
+        self.provider = Some((item_count, Arc::new(provider)));
+    }
+
+    /// Go back to showing the items added with [`CollectionView::add_item`]
+    pub fn clear_cell_provider(&mut self) {
+        self.provider = None;
+    }
+
+    /// Get the content of the item at `index`, or `None` if out of bounds
+    pub fn item_content(&self, index: usize) -> Option<CellContent> {
+        match &self.provider {
+            Some((item_count, provider)) => (index < *item_count).then(|| provider(index)),
+            None => self.items.get(index).map(|item| CellContent::Text(item.clone())),
+        }
     }
 }
 
@@ -351,6 +1006,156 @@ mod tests {
         assert_eq!(table.rows().len(), 1);
     }
 
+    #[test]
+    fn test_table_view_cell_provider_is_lazy() {
+        use std::sync::Mutex;
+
+        let mut table = TableView::new(vec!["Index".to_string(), "Square".to_string()]).unwrap();
+        table.add_row(vec!["stored".to_string(), "row".to_string()]).unwrap();
+        let requested = Arc::new(Mutex::new(Vec::new()));
+        {
+            let requested = Arc::clone(&requested);
+            table.set_cell_provider(1_000_000, move |row, column| {
+                requested.lock().unwrap().push((row, column));
+                match column {
+                    0 => CellContent::from(row.to_string()),
+                    _ => CellContent::from((row * row).to_string()),
+                }
+            });
+        }
+        assert_eq!(table.row_count(), 1_000_000);
+        assert!(requested.lock().unwrap().is_empty());
+
+        assert_eq!(table.cell_content(12, 1), Some(CellContent::from("144")));
+        assert_eq!(*requested.lock().unwrap(), [(12, 1)]);
+        assert_eq!(table.cell_content(1_000_000, 0), None);
+        assert_eq!(table.cell_content(0, 2), None);
+        assert_eq!(requested.lock().unwrap().len(), 1);
+
+        table.clear_cell_provider();
+        assert_eq!(table.row_count(), 1);
+        assert_eq!(table.cell_content(0, 0), Some(CellContent::from("stored")));
+    }
+
+    /// Parse RFC 4180 CSV back into records
+    fn parse_csv(csv: &str) -> Vec<Vec<String>> {
+        let mut records = Vec::new();
+        let mut record = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut chars = csv.chars().peekable();
+        while let Some(c) = chars.next() {
+            match (quoted, c) {
+                (true, '"') if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                (true, '"') => quoted = false,
+                (true, c) => field.push(c),
+                (false, '"') => quoted = true,
+                (false, ',') => record.push(std::mem::take(&mut field)),
+                (false, '\r') => {}
+                (false, '\n') => {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                }
+                (false, c) => field.push(c),
+            }
+        }
+        records
+    }
+
+    #[test]
+    fn test_table_view_to_csv() {
+        let mut table = TableView::new(vec!["Name".to_string(), "Age".to_string()]).unwrap();
+        table.add_row(vec!["Alice".to_string(), "30".to_string()]).unwrap();
+        assert_eq!(table.to_csv(), "Name,Age\r\nAlice,30\r\n");
+    }
+
+    #[test]
+    fn test_table_view_csv_round_trip() {
+        let row = vec![
+            "Smith, John".to_string(),
+            "He said \"hi\"".to_string(),
+            "line one\nline two".to_string(),
+        ];
+        let mut table = TableView::new(vec!["Name".to_string(), "Quote".to_string(), "Note".to_string()]).unwrap();
+        table.add_row(row.clone()).unwrap();
+
+        let csv = table.to_csv();
+        assert!(csv.contains("\"Smith, John\",\"He said \"\"hi\"\"\""));
+        assert_eq!(parse_csv(&csv), [table.columns().to_vec(), row]);
+    }
+
+    #[test]
+    fn test_table_view_export_csv() {
+        let path = std::env::temp_dir().join(format!("cocoanut-table-{}.csv", std::process::id()));
+        let mut table = TableView::new(vec!["A".to_string()]).unwrap();
+        table.add_row(vec!["1".to_string()]).unwrap();
+        table.export_csv(&path).unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(written, table.to_csv());
+
+        assert!(table.export_csv("/nonexistent/dir/table.csv").is_err());
+    }
+
+    // DataTable Tests
+    struct Person {
+        name: String,
+        age: u32,
+    }
+
+    fn people() -> DataTable<Person> {
+        let mut table = DataTable::new()
+            .column("Name", |p: &Person| p.name.clone())
+            .column("Age", |p: &Person| p.age.to_string());
+        table.set_rows(vec![
+            Person { name: "Alice".to_string(), age: 30 },
+            Person { name: "Bob".to_string(), age: 17 },
+            Person { name: "Carol".to_string(), age: 42 },
+        ]);
+        table
+    }
+
+    #[test]
+    fn test_data_table_projects_cells() {
+        let table = people();
+        assert_eq!(table.headers(), ["Name", "Age"]);
+        assert_eq!(table.visible_row_count(), 3);
+        assert_eq!(table.cell(1, 0).as_deref(), Some("Bob"));
+        assert_eq!(table.cell(2, 1).as_deref(), Some("42"));
+        assert_eq!(table.cell(3, 0), None);
+        assert_eq!(table.cell(0, 2), None);
+    }
+
+    #[test]
+    fn test_data_table_filter_keeps_model() {
+        let mut table = people();
+        table.set_filter(|p| p.age >= 18);
+        assert_eq!(table.visible_row_count(), 2);
+        assert_eq!(table.row_count(), 3);
+        assert_eq!(table.cell(1, 0).as_deref(), Some("Carol"));
+
+        table.push_row(Person { name: "Dan".to_string(), age: 12 });
+        table.push_row(Person { name: "Eve".to_string(), age: 25 });
+        assert_eq!(table.visible_row_count(), 3);
+        assert_eq!(table.row_count(), 5);
+
+        table.clear_filter();
+        assert!(!table.is_filtered());
+        assert_eq!(table.visible_row_count(), 5);
+    }
+
+    #[test]
+    fn test_data_table_to_table_view() {
+        let mut table = people();
+        table.set_filter(|p| p.name.starts_with('C'));
+        let view = table.to_table_view().unwrap();
+        assert_eq!(view.columns(), ["Name", "Age"]);
+        assert_eq!(view.rows(), [vec!["Carol".to_string(), "42".to_string()]]);
+    }
+
     // OutlineView Tests
     #[test]
     fn test_outline_item_creation() {
@@ -395,6 +1200,71 @@ mod tests {
         assert_eq!(view.items().len(), 2);
     }
 
+    fn folders() -> OutlineView {
+        let mut documents = OutlineItem::new("Documents");
+        let mut work = OutlineItem::new("Work");
+        work.add_child(OutlineItem::new("Report"));
+        documents.add_child(work);
+        documents.add_child(OutlineItem::new("Notes"));
+        OutlineViewBuilder::new()
+            .item(documents)
+            .item(OutlineItem::new("Trash"))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_outline_view_expand_and_collapse() {
+        let mut view = folders();
+        assert!(!view.is_expanded(&[0]));
+
+        view.expand(&[0]).unwrap();
+        assert!(view.is_expanded(&[0]));
+        assert!(!view.is_expanded(&[0, 0]));
+
+        view.collapse(&[0]).unwrap();
+        assert!(!view.is_expanded(&[0]));
+
+        // Leaves stay collapsed; bad paths are errors
+        view.expand(&[1]).unwrap();
+        assert!(!view.is_expanded(&[1]));
+        assert!(view.expand(&[0, 5]).is_err());
+        assert!(view.collapse(&[]).is_err());
+        assert!(!view.is_expanded(&[3]));
+    }
+
+    #[test]
+    fn test_outline_view_expand_all() {
+        let mut view = folders();
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        {
+            let events = Arc::clone(&events);
+            view.on_expand(move |path| events.lock().unwrap().push(("expand", path.to_vec())));
+        }
+        {
+            let events = Arc::clone(&events);
+            view.on_collapse(move |path| events.lock().unwrap().push(("collapse", path.to_vec())));
+        }
+
+        view.expand_all();
+        assert!(view.is_expanded(&[0]));
+        assert!(view.is_expanded(&[0, 0]));
+        assert!(!view.is_expanded(&[0, 1]));
+        assert_eq!(view.item(&[0, 0, 0]).map(OutlineItem::title), Some("Report"));
+
+        view.collapse(&[0, 0]).unwrap();
+        view.collapse(&[0, 0]).unwrap();
+        view.collapse_all();
+        assert!(!view.is_expanded(&[0]));
+
+        assert_eq!(*events.lock().unwrap(), [
+            ("expand", vec![0]),
+            ("expand", vec![0, 0]),
+            ("collapse", vec![0, 0]),
+            ("collapse", vec![0]),
+        ]);
+    }
+
     // CollectionView Tests
     #[test]
     fn test_collection_view_creation() {
@@ -411,6 +1281,26 @@ mod tests {
         assert_eq!(view.item_count(), 2);
     }
 
+    #[test]
+    fn test_collection_view_cell_provider_is_lazy() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut view = CollectionView::new(4).unwrap();
+        let calls = Arc::new(AtomicUsize::new(0));
+        {
+            let calls = Arc::clone(&calls);
+            view.set_cell_provider(500, move |index| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                CellContent::from(format!("Photo {}", index))
+            });
+        }
+        assert_eq!(view.item_count(), 500);
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        assert_eq!(view.item_content(42), Some(CellContent::from("Photo 42")));
+        assert_eq!(view.item_content(500), None);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
     #[test]
     fn test_collection_view_builder() {
         let view = CollectionViewBuilder::new()
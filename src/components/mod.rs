@@ -7,7 +7,7 @@ pub mod advanced;
 pub mod containers;
 pub mod data_display;
 
-pub use basic::{Button, Label, TextField};
+pub use basic::{Button, Label, TextField, ImageView, TextView};
 pub use advanced::{Checkbox, RadioButton, Slider, SegmentedControl, Stepper, Switch};
 pub use containers::{ScrollView, TabView, SplitView, GroupBox};
 pub use data_display::{TableView, OutlineView, CollectionView};
@@ -1,12 +1,17 @@
 //! Error types for the Cocoanut crate
 
+use std::path::PathBuf;
 use thiserror::Error;
 
 /// Result type alias for Cocoanut operations
 pub type Result<T> = std::result::Result<T, CocoanutError>;
 
 /// Errors that can occur in Cocoanut operations
+///
+/// New variants may be added in minor releases, so matches must include a
+/// wildcard arm.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum CocoanutError {
     /// Failed to initialize the application
     #[error("Failed to initialize application: {0}")]
@@ -44,6 +49,30 @@ pub enum CocoanutError {
     #[error("Event handling error: {0}")]
     EventError(String),
     
+    /// A file that was expected to exist could not be found
+    #[error("File not found: {}", .0.display())]
+    FileNotFound(PathBuf),
+    
+    /// An Objective-C call returned or was given nil; names what was nil
+    #[error("Unexpected nil Objective-C object: {0}")]
+    ObjcNull(&'static str),
+    
+    /// Color components or a color description were invalid
+    #[error("Invalid color: {0}")]
+    InvalidColor(String),
+    
+    /// AppKit was used from a thread other than the main thread
+    #[error("AppKit must be used from the main thread")]
+    ThreadViolation,
+    
+    /// I/O error
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    
+    /// Text was not valid UTF-8
+    #[error("Invalid UTF-8: {0}")]
+    InvalidUtf8(#[from] std::str::Utf8Error),
+    
     /// Generic error with message
     #[error("Cocoanut error: {0}")]
     Generic(String),
@@ -78,3 +107,41 @@ impl From<std::ffi::NulError> for CocoanutError {
         CocoanutError::InvalidParameter(format!("Null byte in string: {}", err))
     }
 }
+
+/// Extension methods for annotating errors with the operation that failed
+///
+/// The wrapped error becomes a [`CocoanutError::SystemError`] whose message is
+/// the context followed by the original error's message.
+///
+/// ```
+/// use cocoanut::{CocoanutError, Result, ResultExt};
+///
+/// let result: Result<()> = Err(CocoanutError::ObjcNull("NSWindow"));
+/// let err = result.context("creating main window").unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "System error: creating main window: Unexpected nil Objective-C object: NSWindow"
+/// );
+/// ```
+pub trait ResultExt<T> {
+    /// Prepend a static description of the failed operation
+    fn context(self, context: &'static str) -> Result<T>;
+
+    /// Prepend a lazily built description, only evaluated on error
+    fn with_context<F>(self, f: F) -> Result<T>
+    where
+        F: FnOnce() -> String;
+}
+
+impl<T, E: std::fmt::Display> ResultExt<T> for std::result::Result<T, E> {
+    fn context(self, context: &'static str) -> Result<T> {
+        self.map_err(|err| CocoanutError::SystemError(format!("{}: {}", context, err)))
+    }
+
+    fn with_context<F>(self, f: F) -> Result<T>
+    where
+        F: FnOnce() -> String,
+    {
+        self.map_err(|err| CocoanutError::SystemError(format!("{}: {}", f(), err)))
+    }
+}
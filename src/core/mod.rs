@@ -8,7 +8,7 @@ pub mod layout;
 pub mod delegate;
 pub mod layout_anchors;
 
-pub use error::{CocoanutError, Result};
+pub use error::{CocoanutError, Result, ResultExt};
 pub use traits::*;
 pub use utils::*;
 pub use objc_access::ObjcAccess;
@@ -1,7 +1,17 @@
 //! ObjcAccess trait - Unified interface for accessing backing Objective-C objects
+//!
+//! Besides raw access, the trait offers typed property helpers that take the
+//! selector by name and handle the nil checks and NSString conversions that
+//! would otherwise be repeated around every `msg_send!`.
 
+use crate::core::error::{CocoanutError, Result};
 use objc::runtime::Object;
 
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Sel, BOOL, NO, YES};
+#[cfg(not(feature = "test-mock"))]
+use objc::{Encode, EncodeArguments, Message, MessageArguments};
+
 /// Trait for types that wrap Objective-C objects and need to provide access to them.
 pub trait ObjcAccess {
     /// Provides mutable access to the backing Objective-C object.
@@ -9,4 +19,219 @@ pub trait ObjcAccess {
 
     /// Provides immutable access to the backing Objective-C object.
     fn get_from_backing_obj<F: Fn(&Object) -> R, R>(&self, handler: F) -> R;
+
+    /// Send the one-argument `setter` (e.g. `"setTitle:"`) with `value` as
+    /// an NSString
+    ///
+    /// Fails if the backing object is nil or has no `setter` method taking
+    /// an object.
+    fn set_string_property(&self, setter: &str, value: &str) -> Result<()> {
+        check_setter(setter)?;
+        with_backing_obj(self, |obj| {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                let value = crate::core::utils::string_to_ns_string(value)?;
+                send::<_, ()>(obj, setter, (value,))
+            }
+            #[cfg(feature = "test-mock")]
+            {
+                mock::set(obj, setter, value);
+                Ok(())
+            }
+        })
+    }
+
+    /// Send the one-argument `setter` (e.g. `"setEnabled:"`) with a `BOOL`
+    ///
+    /// Fails if the backing object is nil or has no `setter` method taking
+    /// a `BOOL`.
+    fn set_bool_property(&self, setter: &str, value: bool) -> Result<()> {
+        check_setter(setter)?;
+        with_backing_obj(self, |obj| {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                let value: BOOL = if value { YES } else { NO };
+                send::<_, ()>(obj, setter, (value,))
+            }
+            #[cfg(feature = "test-mock")]
+            {
+                let _ = (obj, value);
+                Ok(())
+            }
+        })
+    }
+
+    /// Send the one-argument `setter` (e.g. `"setAlphaValue:"`) with a
+    /// `CGFloat`
+    ///
+    /// Fails if the backing object is nil or has no `setter` method taking
+    /// a `CGFloat`.
+    fn set_f64_property(&self, setter: &str, value: f64) -> Result<()> {
+        check_setter(setter)?;
+        with_backing_obj(self, |obj| {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                send::<_, ()>(obj, setter, (value,))
+            }
+            #[cfg(feature = "test-mock")]
+            {
+                let _ = (obj, value);
+                Ok(())
+            }
+        })
+    }
+
+    /// Send the no-argument `getter` (e.g. `"title"`) and convert the
+    /// NSString it returns
+    ///
+    /// `None` if the backing object is nil, has no `getter` method returning
+    /// an object, or returns nil. Mock builds return the value last set through
+    /// [`set_string_property`](Self::set_string_property) on the matching
+    /// setter.
+    fn string_property(&self, getter: &str) -> Option<String> {
+        if getter.is_empty() || getter.contains(':') {
+            return None;
+        }
+        with_backing_obj(self, |obj| {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                let value: *mut Object = send(obj, getter, ())?;
+                crate::core::utils::ns_string_to_string(value)
+            }
+            #[cfg(feature = "test-mock")]
+            mock::get(obj, getter).ok_or(CocoanutError::ObjcNull("string property"))
+        })
+        .ok()
+    }
+
+    /// Send the no-argument `getter` (e.g. `"isEnabled"`) returning a `BOOL`
+    ///
+    /// `None` if the backing object is nil or has no `getter` method
+    /// returning a `BOOL`, and always in mock builds.
+    fn bool_property(&self, getter: &str) -> Option<bool> {
+        if getter.is_empty() || getter.contains(':') {
+            return None;
+        }
+        with_backing_obj(self, |obj| {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                let value: BOOL = send(obj, getter, ())?;
+                Ok(value != NO)
+            }
+            #[cfg(feature = "test-mock")]
+            {
+                let _ = obj;
+                Err(CocoanutError::ObjcNull("bool property"))
+            }
+        })
+        .ok()
+    }
+
+    /// Send the no-argument `getter` (e.g. `"alphaValue"`) returning a
+    /// `CGFloat`
+    ///
+    /// `None` if the backing object is nil or has no `getter` method
+    /// returning a `CGFloat`, and always in mock builds.
+    fn f64_property(&self, getter: &str) -> Option<f64> {
+        if getter.is_empty() || getter.contains(':') {
+            return None;
+        }
+        with_backing_obj(self, |obj| {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                send(obj, getter, ())
+            }
+            #[cfg(feature = "test-mock")]
+            {
+                let _ = obj;
+                Err(CocoanutError::ObjcNull("f64 property"))
+            }
+        })
+        .ok()
+    }
+}
+
+/// Run `f` with the backing object, failing if it is nil
+///
+/// Mock builds have no objects, so there `f` sees whatever pointer the
+/// wrapper holds, such as the placeholder of an
+/// [`ObjcProperty`](crate::utils::ObjcProperty).
+fn with_backing_obj<A, T, F>(access: &A, f: F) -> Result<T>
+where
+    A: ObjcAccess + ?Sized,
+    F: Fn(*mut Object) -> Result<T>,
+{
+    let result = std::cell::RefCell::new(None);
+    access.with_backing_obj_mut(|obj| {
+        #[cfg(not(feature = "test-mock"))]
+        if obj.is_null() {
+            *result.borrow_mut() = Some(Err(CocoanutError::ObjcNull("backing object")));
+            return;
+        }
+        *result.borrow_mut() = Some(f(obj));
+    });
+    result.into_inner().unwrap_or(Err(CocoanutError::ObjcNull("backing object")))
+}
+
+/// Check that `setter` names a one-argument selector
+fn check_setter(setter: &str) -> Result<()> {
+    if setter.len() < 2 || !setter.ends_with(':') || setter.matches(':').count() != 1 {
+        return Err(CocoanutError::InvalidParameter(
+            format!("'{}' is not a one-argument setter selector", setter)
+        ));
+    }
+    Ok(())
+}
+
+/// Send `selector` to `obj` if its class has a method for it whose type
+/// encoding matches the arguments `A` and return type `R`
+///
+/// Checking the encoding keeps a selector name from being sent with
+/// arguments the method does not take. `obj` must be a live object.
+#[cfg(not(feature = "test-mock"))]
+unsafe fn send<A, R>(obj: *mut Object, selector: &str, args: A) -> Result<R>
+where
+    A: MessageArguments + EncodeArguments,
+    R: std::any::Any + Encode,
+{
+    let sel = Sel::register(selector);
+    unsafe {
+        (*obj).verify_message::<A, R>(sel).map_err(|error| {
+            CocoanutError::InvalidParameter(format!("Cannot send {}: {}", selector, error))
+        })?;
+        (*obj).send_message(sel, args).map_err(|error| CocoanutError::SystemError(error.to_string()))
+    }
+}
+
+/// String properties recorded in mock builds, per object and test thread
+#[cfg(feature = "test-mock")]
+mod mock {
+    use objc::runtime::Object;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    thread_local! {
+        static PROPERTIES: RefCell<HashMap<(usize, String), String>> = RefCell::new(HashMap::new());
+    }
+
+    /// The getter a setter pairs with: `setTitle:` becomes `title`
+    fn getter_for(setter: &str) -> String {
+        let name = setter.trim_end_matches(':');
+        let name = name.strip_prefix("set").unwrap_or(name);
+        let mut chars = name.chars();
+        chars
+            .next()
+            .map(|first| first.to_lowercase().chain(chars).collect())
+            .unwrap_or_default()
+    }
+
+    pub(super) fn set(obj: *mut Object, setter: &str, value: &str) {
+        PROPERTIES.with(|properties| {
+            properties.borrow_mut().insert((obj as usize, getter_for(setter)), value.to_string());
+        });
+    }
+
+    pub(super) fn get(obj: *mut Object, getter: &str) -> Option<String> {
+        PROPERTIES.with(|properties| properties.borrow().get(&(obj as usize, getter.to_string())).cloned())
+    }
 }
@@ -4,6 +4,7 @@
 
 use crate::core::error::Result;
 use objc::runtime::Object;
+use std::time::Duration;
 
 /// Trait for components that can be displayed in a window
 pub trait Drawable {
@@ -15,6 +16,84 @@ pub trait Drawable {
 
     /// Get visibility state
     fn is_visible(&self) -> bool;
+
+    /// Set the help tag shown when the pointer rests on the view
+    ///
+    /// An empty string removes the tooltip.
+    fn set_tooltip(&self, tooltip: &str) -> Result<()> {
+        crate::core::utils::set_view_tooltip(self.as_view(), tooltip)
+    }
+
+    /// Set the view's opacity, from 0.0 (transparent) to 1.0 (opaque)
+    fn set_alpha(&self, alpha: f32) -> Result<()> {
+        crate::core::utils::set_view_alpha(self.as_view(), alpha)
+    }
+
+    /// Get the view's opacity
+    fn alpha(&self) -> f32 {
+        crate::core::utils::view_alpha(self.as_view())
+    }
+
+    /// Get the view's `CALayer`, making the view layer-backed first
+    ///
+    /// `None` when there is no native view, as in mock builds.
+    fn layer(&self) -> Option<*mut Object> {
+        crate::core::utils::view_layer(self.as_view())
+    }
+
+    /// Show the view, fading it in from transparent over `duration`
+    ///
+    /// With Reduce Motion on the view appears at once.
+    fn fade_in(&self, duration: Duration) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        if crate::systems::essential_features::fade_view(self.as_view(), true, duration)? {
+            return Ok(());
+        }
+        let _ = duration;
+        self.set_alpha(1.0)?;
+        self.set_visible(true)
+    }
+
+    /// Fade the view out over `duration`, hiding it at the end
+    ///
+    /// The view is left fully opaque once hidden, so showing it again needs
+    /// no alpha reset. A [`fade_in`](Self::fade_in) started before the fade
+    /// ends keeps the view shown. With Reduce Motion on it hides at once.
+    fn fade_out(&self, duration: Duration) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        if crate::systems::essential_features::fade_view(self.as_view(), false, duration)? {
+            return Ok(());
+        }
+        let _ = duration;
+        self.set_visible(false)
+    }
+
+    /// Cover the view with a spinning progress indicator while it loads
+    ///
+    /// Clicks on the view are ignored until [`hide_spinner`](Self::hide_spinner).
+    /// Showing the spinner again while it is up does nothing.
+    fn show_spinner(&self) -> Result<()> {
+        crate::systems::feedback::show_spinner_overlay(self.as_view())
+    }
+
+    /// Remove the spinner shown with [`show_spinner`](Self::show_spinner)
+    fn hide_spinner(&self) -> Result<()> {
+        crate::systems::feedback::hide_spinner_overlay(self.as_view())
+    }
+
+    /// Check if the view is covered by a spinner
+    fn is_showing_spinner(&self) -> bool {
+        crate::systems::feedback::has_spinner_overlay(self.as_view())
+    }
+
+    /// Enable or disable the view and every control inside it, such as a
+    /// whole section of a form
+    ///
+    /// Views that cannot be disabled are skipped.
+    fn set_enabled_recursive(&self, enabled: bool) -> Result<()> {
+        crate::core::utils::set_view_enabled_recursive(self.as_view(), enabled);
+        Ok(())
+    }
 }
 
 /// Trait for components with text content
@@ -33,6 +112,98 @@ pub trait Positionable {
 
     /// Get the frame
     fn frame(&self) -> (f64, f64, f64, f64);
+
+    /// The natural `(width, height)` of the content, such as a control's text
+    ///
+    /// Defaults to the current frame size for views without one.
+    fn intrinsic_content_size(&self) -> (f64, f64) {
+        let (_, _, width, height) = self.frame();
+        (width, height)
+    }
+
+    /// Resize to [`intrinsic_content_size`](Self::intrinsic_content_size),
+    /// keeping the origin
+    fn size_to_fit(&self) -> Result<()> {
+        let (x, y, _, _) = self.frame();
+        let (width, height) = self.intrinsic_content_size();
+        self.set_frame(x, y, width, height)
+    }
+}
+
+/// A component that can be both displayed and positioned
+///
+/// Implemented automatically for every `Drawable + Positionable` type, so
+/// heterogeneous controls can be stored as `Box<dyn View>`.
+pub trait View: Drawable + Positionable {}
+
+impl<T: Drawable + Positionable> View for T {}
+
+impl<T: Drawable + ?Sized> Drawable for Box<T> {
+    fn as_view(&self) -> *mut Object {
+        (**self).as_view()
+    }
+
+    fn set_visible(&self, visible: bool) -> Result<()> {
+        (**self).set_visible(visible)
+    }
+
+    fn is_visible(&self) -> bool {
+        (**self).is_visible()
+    }
+
+    fn set_alpha(&self, alpha: f32) -> Result<()> {
+        (**self).set_alpha(alpha)
+    }
+
+    fn alpha(&self) -> f32 {
+        (**self).alpha()
+    }
+
+    fn layer(&self) -> Option<*mut Object> {
+        (**self).layer()
+    }
+
+    fn fade_in(&self, duration: Duration) -> Result<()> {
+        (**self).fade_in(duration)
+    }
+
+    fn fade_out(&self, duration: Duration) -> Result<()> {
+        (**self).fade_out(duration)
+    }
+
+    fn show_spinner(&self) -> Result<()> {
+        (**self).show_spinner()
+    }
+
+    fn hide_spinner(&self) -> Result<()> {
+        (**self).hide_spinner()
+    }
+
+    fn is_showing_spinner(&self) -> bool {
+        (**self).is_showing_spinner()
+    }
+
+    fn set_enabled_recursive(&self, enabled: bool) -> Result<()> {
+        (**self).set_enabled_recursive(enabled)
+    }
+}
+
+impl<T: Positionable + ?Sized> Positionable for Box<T> {
+    fn set_frame(&self, x: f64, y: f64, width: f64, height: f64) -> Result<()> {
+        (**self).set_frame(x, y, width, height)
+    }
+
+    fn frame(&self) -> (f64, f64, f64, f64) {
+        (**self).frame()
+    }
+
+    fn intrinsic_content_size(&self) -> (f64, f64) {
+        (**self).intrinsic_content_size()
+    }
+
+    fn size_to_fit(&self) -> Result<()> {
+        (**self).size_to_fit()
+    }
 }
 
 /// Trait for clickable components
@@ -102,4 +273,60 @@ mod tests {
     fn test_positionable_trait_exists() {
         fn assert_positionable<T: Positionable>() {}
     }
+
+    /// Wraps a view pointer
+    struct Section(*mut Object);
+
+    impl Drawable for Section {
+        fn as_view(&self) -> *mut Object {
+            self.0
+        }
+
+        fn set_visible(&self, _visible: bool) -> Result<()> {
+            Ok(())
+        }
+
+        fn is_visible(&self) -> bool {
+            true
+        }
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_set_enabled_recursive() {
+        // Mock builds never dereference the view pointer
+        let section = Section(0x1000 as *mut Object);
+        assert!(section.set_enabled_recursive(false).is_ok());
+        assert!(section.set_enabled_recursive(true).is_ok());
+        assert!(Section(std::ptr::null_mut()).set_enabled_recursive(false).is_ok());
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_set_enabled_recursive_reaches_nested_controls() {
+        use objc::{class, msg_send, sel, sel_impl};
+
+        unsafe {
+            let section: *mut Object = msg_send![class!(NSView), new];
+            let group: *mut Object = msg_send![class!(NSView), new];
+            let button: *mut Object = msg_send![class!(NSButton), new];
+            let field: *mut Object = msg_send![class!(NSTextField), new];
+            let _: () = msg_send![group, addSubview: button];
+            let _: () = msg_send![section, addSubview: group];
+            let _: () = msg_send![section, addSubview: field];
+
+            Section(section).set_enabled_recursive(false).unwrap();
+            let button_enabled: bool = msg_send![button, isEnabled];
+            let field_enabled: bool = msg_send![field, isEnabled];
+            assert!(!button_enabled && !field_enabled);
+
+            Section(section).set_enabled_recursive(true).unwrap();
+            let button_enabled: bool = msg_send![button, isEnabled];
+            assert!(button_enabled);
+
+            for view in [button, field, group, section] {
+                let _: () = msg_send![view, release];
+            }
+        }
+    }
 }
@@ -4,6 +4,7 @@ use crate::core::error::{CocoanutError, Result};
 use objc::runtime::Object;
 use objc::{sel, sel_impl};
 use std::ffi::CString;
+use std::marker::PhantomData;
 
 /// Convert a Rust string to a C string for Objective-C calls
 /// 
@@ -31,17 +32,11 @@ pub fn string_to_cstring(s: &str) -> Result<CString> {
 /// Returns a `Result<String>` containing the Rust string
 pub unsafe fn cstring_to_string(c_str: *const i8) -> Result<String> {
     if c_str.is_null() {
-        return Err(CocoanutError::InvalidParameter(
-            "Cannot convert null C string pointer to Rust string".to_string()
-        ));
+        return Err(CocoanutError::ObjcNull("C string"));
     }
     
     let c_str = unsafe { std::ffi::CStr::from_ptr(c_str) };
-    c_str.to_str()
-        .map(|s| s.to_string())
-        .map_err(|e| CocoanutError::InvalidParameter(
-            format!("Failed to convert C string to valid UTF-8: {}", e)
-        ))
+    Ok(c_str.to_str()?.to_string())
 }
 
 /// Check if an Objective-C object is null
@@ -95,9 +90,7 @@ pub unsafe fn retain_object(obj: *mut Object) -> *mut Object {
 /// Returns a `Result<String>` containing the class name
 pub unsafe fn get_class_name(obj: *mut Object) -> Result<String> {
     if obj.is_null() {
-        return Err(CocoanutError::InvalidParameter(
-            "Cannot get class name from null Objective-C object pointer".to_string()
-        ));
+        return Err(CocoanutError::ObjcNull("object passed to get_class_name"));
     }
     
     let class: *mut Object = objc::msg_send![obj, class];
@@ -107,12 +100,51 @@ pub unsafe fn get_class_name(obj: *mut Object) -> Result<String> {
 
 /// Check if the current thread is the main thread
 pub fn is_main_thread() -> bool {
-    unsafe {
-        let main_thread: *mut Object = objc::msg_send![objc::class!(NSThread), mainThread];
-        let current_thread: *mut Object = objc::msg_send![objc::class!(NSThread), currentThread];
-        let is_main: bool = objc::msg_send![current_thread, isEqual: main_thread];
-        is_main
+    unsafe { libc::pthread_main_np() != 0 }
+}
+
+/// Proof that the current thread is the main thread
+///
+/// AppKit objects may only be created and mutated on the main thread. The
+/// marker is zero-sized and neither `Send` nor `Sync`, so holding one means
+/// the code is still running where it was obtained.
+#[derive(Debug, Clone, Copy)]
+pub struct MainThreadMarker {
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl MainThreadMarker {
+    /// Obtain a marker, or `None` when called off the main thread
+    pub fn new() -> Option<Self> {
+        if is_main_thread() {
+            // SAFETY: just checked
+            Some(unsafe { Self::new_unchecked() })
+        } else {
+            None
+        }
     }
+
+    /// Obtain a marker without checking the current thread
+    ///
+    /// # Safety
+    ///
+    /// The caller must be on the main thread.
+    pub unsafe fn new_unchecked() -> Self {
+        MainThreadMarker { _not_send: PhantomData }
+    }
+}
+
+/// Panic in debug builds when `what` is created off the main thread
+///
+/// Creating AppKit objects from another thread (for example a tokio worker)
+/// corrupts state silently, so this turns the mistake into a loud failure.
+#[cfg(not(feature = "test-mock"))]
+pub(crate) fn debug_assert_main_thread(what: &str) {
+    debug_assert!(
+        is_main_thread(),
+        "{} must be created on the main thread; use dispatch_to_main_thread",
+        what
+    );
 }
 
 /// Execute code on the main thread
@@ -138,6 +170,171 @@ where
     Ok(f())
 }
 
+/// Run `f` inside an autorelease pool, draining it before returning `f`'s
+/// result
+///
+/// AppKit hands back many autoreleased temporaries (strings, colors,
+/// images), which normally live until the run loop's pool drains. Wrap
+/// bulk creation, such as filling a table with thousands of rows, in this
+/// so they are freed as soon as the batch finishes instead of piling up.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::prelude::*;
+/// use cocoanut::utils::with_autorelease_pool;
+///
+/// # fn main() -> cocoanut::Result<()> {
+/// let labels = with_autorelease_pool(|| {
+///     (0..1000).map(|row| Label::new(&format!("Row {}", row))).collect::<Result<Vec<_>>>()
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn with_autorelease_pool<R, F>(f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    #[cfg(not(feature = "test-mock"))]
+    return objc::rc::autoreleasepool(f);
+
+    // Mock builds create no Objective-C objects
+    #[cfg(feature = "test-mock")]
+    f()
+}
+
+/// Set or clear (with an empty string) the `toolTip` of a view
+///
+/// Null views are ignored.
+pub(crate) fn set_view_tooltip(view: *mut Object, tooltip: &str) -> Result<()> {
+    #[cfg(not(feature = "test-mock"))]
+    if !view.is_null() {
+        let tooltip = if tooltip.is_empty() {
+            std::ptr::null_mut()
+        } else {
+            string_to_ns_string(tooltip)?
+        };
+        unsafe {
+            let _: () = objc::msg_send![view, setToolTip: tooltip];
+        }
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = (view, tooltip);
+    Ok(())
+}
+
+/// Set the `alphaValue` of a view, which must be between 0.0 and 1.0
+///
+/// Null views are ignored.
+pub(crate) fn set_view_alpha(view: *mut Object, alpha: f32) -> Result<()> {
+    if !(0.0..=1.0).contains(&alpha) {
+        return Err(CocoanutError::InvalidParameter(
+            format!("Alpha {} must be between 0.0 and 1.0", alpha)
+        ));
+    }
+    #[cfg(not(feature = "test-mock"))]
+    if !view.is_null() {
+        unsafe {
+            let _: () = objc::msg_send![view, setAlphaValue: alpha as f64];
+        }
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = view;
+    Ok(())
+}
+
+/// Get the `alphaValue` of a view, 1.0 for null views
+pub(crate) fn view_alpha(view: *mut Object) -> f32 {
+    #[cfg(not(feature = "test-mock"))]
+    if !view.is_null() {
+        let alpha: f64 = unsafe { objc::msg_send![view, alphaValue] };
+        return alpha as f32;
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = view;
+    1.0
+}
+
+/// Make `view` layer-backed and return its `CALayer`
+///
+/// `None` for null views.
+pub(crate) fn view_layer(view: *mut Object) -> Option<*mut Object> {
+    #[cfg(not(feature = "test-mock"))]
+    if !view.is_null() {
+        unsafe {
+            let _: () = objc::msg_send![view, setWantsLayer: true];
+            let layer: *mut Object = objc::msg_send![view, layer];
+            return (!layer.is_null()).then_some(layer);
+        }
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = view;
+    None
+}
+
+/// The `intrinsicContentSize` of a view
+///
+/// Dimensions without an intrinsic metric, and null views, are `None`.
+pub(crate) fn view_intrinsic_size(view: *mut Object) -> (Option<f64>, Option<f64>) {
+    #[cfg(not(feature = "test-mock"))]
+    if !view.is_null() {
+        // `NSViewNoIntrinsicMetric` is -1
+        let size: cocoa::foundation::NSSize = unsafe { objc::msg_send![view, intrinsicContentSize] };
+        let metric = |value: f64| (value >= 0.0).then_some(value);
+        return (metric(size.width), metric(size.height));
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = view;
+    (None, None)
+}
+
+/// Send `sizeToFit` to a control, ignoring null views and views that are
+/// not controls
+pub(crate) fn size_view_to_fit(view: *mut Object) {
+    #[cfg(not(feature = "test-mock"))]
+    if !view.is_null() {
+        unsafe {
+            let fits: bool = objc::msg_send![view, respondsToSelector: sel!(sizeToFit)];
+            if fits {
+                let _: () = objc::msg_send![view, sizeToFit];
+            }
+        }
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = view;
+}
+
+/// Send `setEnabled:` to `view` and every view below it that responds to it
+///
+/// Views that cannot be enabled, like plain `NSView`s, are skipped but their
+/// subviews are still visited. Null views are ignored.
+pub(crate) fn set_view_enabled_recursive(view: *mut Object, enabled: bool) {
+    #[cfg(not(feature = "test-mock"))]
+    if !view.is_null() {
+        unsafe {
+            let responds: bool = objc::msg_send![view, respondsToSelector: sel!(setEnabled:)];
+            if responds {
+                let _: () = objc::msg_send![view, setEnabled: enabled];
+            }
+            let subviews: *mut Object = objc::msg_send![view, subviews];
+            let count: usize = objc::msg_send![subviews, count];
+            for index in 0..count {
+                let subview: *mut Object = objc::msg_send![subviews, objectAtIndex: index];
+                set_view_enabled_recursive(subview, enabled);
+            }
+        }
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = (view, enabled);
+}
+
+/// `NSTextAlignmentCenter`; AppKit uses the iOS values on Apple silicon
+#[cfg(all(not(feature = "test-mock"), target_arch = "aarch64"))]
+pub(crate) const NS_TEXT_ALIGNMENT_CENTER: u64 = 1;
+/// `NSTextAlignmentCenter`
+#[cfg(all(not(feature = "test-mock"), not(target_arch = "aarch64")))]
+pub(crate) const NS_TEXT_ALIGNMENT_CENTER: u64 = 2;
+
 /// Convert a Rust string to an NSString
 /// 
 /// # Arguments
@@ -177,9 +374,7 @@ pub fn string_to_ns_string(s: &str) -> Result<*mut Object> {
 /// Returns a `Result<String>` containing the Rust string
 pub unsafe fn ns_string_to_string(ns_string: *mut Object) -> Result<String> {
     if ns_string.is_null() {
-        return Err(CocoanutError::InvalidParameter(
-            "Cannot convert null NSString pointer to Rust string".to_string()
-        ));
+        return Err(CocoanutError::ObjcNull("NSString"));
     }
     
     let c_str: *const i8 = objc::msg_send![ns_string, UTF8String];
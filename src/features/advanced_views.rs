@@ -10,6 +10,19 @@
 use crate::core::error::Result;
 use crate::core::traits::Drawable;
 use objc::runtime::Object;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[cfg(not(feature = "test-mock"))]
+use objc::declare::ClassDecl;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Class, Sel};
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+#[cfg(not(feature = "test-mock"))]
+use std::ffi::c_void;
+#[cfg(not(feature = "test-mock"))]
+use std::sync::Once;
 
 /// Table view data source
 pub trait TableViewDataSource: Send + Sync {
@@ -247,48 +260,133 @@ impl Default for TabView {
     }
 }
 
+type NavigationHandler = Rc<dyn Fn(&str)>;
+
+/// Navigation state shared between a `WebView` and its `WKNavigationDelegate`
+struct NavigationState {
+    url: RefCell<String>,
+    on_navigation: RefCell<Option<NavigationHandler>>,
+}
+
+impl NavigationState {
+    /// Record the URL of a finished navigation and notify the handler
+    ///
+    /// The handler is cloned out first so it may navigate again or replace
+    /// itself.
+    fn navigated(&self, url: &str) {
+        *self.url.borrow_mut() = url.to_string();
+        let handler = self.on_navigation.borrow().clone();
+        if let Some(handler) = handler {
+            handler(url);
+        }
+    }
+}
+
 /// Web view component for WKWebView
+///
+/// In test-mock mode no web view is created; loads are recorded and
+/// navigations complete immediately.
 pub struct WebView {
+    wk_web_view: *mut Object,
+    delegate: *mut Object,
     url: String,
     html: String,
+    navigation: Rc<NavigationState>,
 }
 
 impl WebView {
     /// Create a new web view
     pub fn new() -> Self {
+        let navigation = Rc::new(NavigationState {
+            url: RefCell::new(String::new()),
+            on_navigation: RefCell::new(None),
+        });
+
+        #[cfg(not(feature = "test-mock"))]
+        let (wk_web_view, delegate) = unsafe { create_wk_web_view(&navigation) };
+        #[cfg(feature = "test-mock")]
+        let (wk_web_view, delegate) = (std::ptr::null_mut(), std::ptr::null_mut());
+
         Self {
+            wk_web_view,
+            delegate,
             url: String::new(),
             html: String::new(),
+            navigation,
         }
     }
 
     /// Load URL
+    ///
+    /// # Returns
+    ///
+    /// Returns an error if the URL is empty or malformed
     pub fn load_url(&mut self, url: &str) -> Result<()> {
-        if url.is_empty() {
-            return Err(crate::core::error::CocoanutError::InvalidParameter(
-                "URL cannot be empty".into(),
-            ));
+        validate_url(url)?;
+
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_url = ns_url(url)?;
+            if !self.wk_web_view.is_null() {
+                let request: *mut Object = msg_send![class!(NSURLRequest), requestWithURL: ns_url];
+                let _: *mut Object = msg_send![self.wk_web_view, loadRequest: request];
+            }
+            *self.navigation.url.borrow_mut() = url.to_string();
         }
         self.url = url.to_string();
+        #[cfg(feature = "test-mock")]
+        self.navigation.navigated(url);
         Ok(())
     }
 
     /// Load HTML
-    pub fn load_html(&mut self, html: &str) -> Result<()> {
+    ///
+    /// # Arguments
+    ///
+    /// * `html` - The HTML document to display
+    /// * `base_url` - URL that relative links and resources in the document resolve against
+    pub fn load_html(&mut self, html: &str, base_url: Option<&str>) -> Result<()> {
         if html.is_empty() {
             return Err(crate::core::error::CocoanutError::InvalidParameter(
                 "HTML cannot be empty".into(),
             ));
         }
+        if let Some(base_url) = base_url {
+            validate_url(base_url)?;
+        }
+
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_base_url = match base_url {
+                Some(base_url) => ns_url(base_url)?,
+                None => std::ptr::null_mut(),
+            };
+            if !self.wk_web_view.is_null() {
+                let ns_html = crate::core::utils::string_to_ns_string(html)?;
+                let _: *mut Object = msg_send![self.wk_web_view, loadHTMLString: ns_html baseURL: ns_base_url];
+            }
+        }
         self.html = html.to_string();
+        #[cfg(feature = "test-mock")]
+        self.navigation.navigated(base_url.unwrap_or("about:blank"));
         Ok(())
     }
 
-    /// Get current URL
+    /// Get current URL, as last loaded with [`WebView::load_url`]
+    ///
+    /// Use [`WebView::navigated_url`] to follow links the user clicked.
     pub fn current_url(&self) -> &str {
         &self.url
     }
 
+    /// Get the URL the web view shows
+    ///
+    /// This is the URL of the last finished navigation, or the last
+    /// requested URL while it is loading.
+    pub fn navigated_url(&self) -> String {
+        self.navigation.url.borrow().clone()
+    }
+
     /// Get current HTML
     pub fn current_html(&self) -> &str {
         &self.html
@@ -296,21 +394,203 @@ impl WebView {
 
     /// Go back
     pub fn go_back(&self) -> Result<()> {
-        // WKWebView goBack implementation
+        #[cfg(not(feature = "test-mock"))]
+        if !self.wk_web_view.is_null() {
+            unsafe {
+                let _: *mut Object = msg_send![self.wk_web_view, goBack];
+            }
+        }
         Ok(())
     }
 
     /// Go forward
     pub fn go_forward(&self) -> Result<()> {
-        // WKWebView goForward implementation
+        #[cfg(not(feature = "test-mock"))]
+        if !self.wk_web_view.is_null() {
+            unsafe {
+                let _: *mut Object = msg_send![self.wk_web_view, goForward];
+            }
+        }
         Ok(())
     }
 
     /// Reload
     pub fn reload(&self) -> Result<()> {
-        // WKWebView reload implementation
+        #[cfg(not(feature = "test-mock"))]
+        if !self.wk_web_view.is_null() {
+            unsafe {
+                let _: *mut Object = msg_send![self.wk_web_view, reload];
+            }
+        }
         Ok(())
     }
+
+    /// Set a handler called with the page URL whenever a navigation finishes
+    pub fn on_navigation<F>(&mut self, handler: F)
+    where
+        F: Fn(&str) + 'static,
+    {
+        *self.navigation.on_navigation.borrow_mut() = Some(Rc::new(handler));
+    }
+}
+
+impl Drawable for WebView {
+    fn as_view(&self) -> *mut Object {
+        self.wk_web_view
+    }
+
+    fn set_visible(&self, visible: bool) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.wk_web_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.wk_web_view, setHidden: !visible];
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = visible;
+        Ok(())
+    }
+
+    fn is_visible(&self) -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.wk_web_view.is_null() {
+            unsafe {
+                let hidden: bool = msg_send![self.wk_web_view, isHidden];
+                return !hidden;
+            }
+        }
+        true
+    }
+}
+
+impl Drop for WebView {
+    fn drop(&mut self) {
+        if !self.delegate.is_null() {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                let _: () = msg_send![self.wk_web_view, setNavigationDelegate: std::ptr::null_mut::<Object>()];
+                let slot = *(*self.delegate).get_ivar::<*mut c_void>("rustNavigation");
+                (*self.delegate).set_ivar::<*mut c_void>("rustNavigation", std::ptr::null_mut());
+                if !slot.is_null() {
+                    drop(Box::from_raw(slot as *mut Rc<NavigationState>));
+                }
+                let _: () = msg_send![self.delegate, release];
+            }
+        }
+        #[cfg(not(feature = "test-mock"))]
+        if !self.wk_web_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.wk_web_view, release];
+            }
+        }
+    }
+}
+
+/// Check that a URL has a scheme and no whitespace
+pub(crate) fn validate_url(url: &str) -> Result<()> {
+    let malformed = || crate::core::error::CocoanutError::InvalidParameter(
+        format!("Malformed URL: '{}'", url)
+    );
+    if url.is_empty() {
+        return Err(crate::core::error::CocoanutError::InvalidParameter(
+            "URL cannot be empty".into(),
+        ));
+    }
+    let (scheme, rest) = url.split_once(':').ok_or_else(malformed)?;
+    let valid_scheme = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    if !valid_scheme || rest.is_empty() || url.chars().any(char::is_whitespace) {
+        return Err(malformed());
+    }
+    Ok(())
+}
+
+/// Create an `NSURL`, failing if Foundation rejects the string
+#[cfg(not(feature = "test-mock"))]
+unsafe fn ns_url(url: &str) -> Result<*mut Object> {
+    unsafe {
+        let ns_string = crate::core::utils::string_to_ns_string(url)?;
+        let ns_url: *mut Object = msg_send![class!(NSURL), URLWithString: ns_string];
+        if ns_url.is_null() {
+            return Err(crate::core::error::CocoanutError::InvalidParameter(
+                format!("Malformed URL: '{}'", url)
+            ));
+        }
+        Ok(ns_url)
+    }
+}
+
+/// Create a `WKWebView` with a navigation delegate reporting to `navigation`
+///
+/// Returns null pointers if WebKit is not available.
+#[cfg(not(feature = "test-mock"))]
+unsafe fn create_wk_web_view(navigation: &Rc<NavigationState>) -> (*mut Object, *mut Object) {
+    use cocoa::foundation::{NSPoint, NSRect, NSSize};
+
+    let (Some(web_view_class), Some(configuration_class)) =
+        (Class::get("WKWebView"), Class::get("WKWebViewConfiguration"))
+    else {
+        return (std::ptr::null_mut(), std::ptr::null_mut());
+    };
+
+    unsafe {
+        let frame = NSRect {
+            origin: NSPoint { x: 0.0, y: 0.0 },
+            size: NSSize { width: 800.0, height: 600.0 },
+        };
+        let configuration: *mut Object = msg_send![configuration_class, new];
+        let web_view: *mut Object = msg_send![web_view_class, alloc];
+        let web_view: *mut Object = msg_send![web_view, initWithFrame: frame configuration: configuration];
+        let _: () = msg_send![configuration, release];
+        if web_view.is_null() {
+            return (std::ptr::null_mut(), std::ptr::null_mut());
+        }
+
+        let slot = Box::into_raw(Box::new(Rc::clone(navigation))) as *mut c_void;
+        let delegate: *mut Object = msg_send![web_view_delegate_class(), new];
+        (*delegate).set_ivar::<*mut c_void>("rustNavigation", slot);
+        let _: () = msg_send![web_view, setNavigationDelegate: delegate];
+        (web_view, delegate)
+    }
+}
+
+/// Lazily register the `WKNavigationDelegate` class reporting finished navigations
+#[cfg(not(feature = "test-mock"))]
+fn web_view_delegate_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutWebViewDelegate", class!(NSObject))
+            .expect("CocoanutWebViewDelegate already registered");
+        decl.add_ivar::<*mut c_void>("rustNavigation");
+
+        extern "C" fn did_finish(this: &Object, _cmd: Sel, web_view: *mut Object, _navigation: *mut Object) {
+            unsafe {
+                let slot = *this.get_ivar::<*mut c_void>("rustNavigation") as *const Rc<NavigationState>;
+                let Some(navigation) = slot.as_ref() else {
+                    return;
+                };
+                let url: *mut Object = msg_send![web_view, URL];
+                if url.is_null() {
+                    return;
+                }
+                let url: *mut Object = msg_send![url, absoluteString];
+                if let Ok(url) = crate::core::utils::ns_string_to_string(url) {
+                    navigation.navigated(&url);
+                }
+            }
+        }
+
+        unsafe {
+            decl.add_method(
+                sel!(webView:didFinishNavigation:),
+                did_finish as extern "C" fn(&Object, Sel, *mut Object, *mut Object),
+            );
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutWebViewDelegate").expect("CocoanutWebViewDelegate not registered")
 }
 
 impl Default for WebView {
@@ -385,7 +665,63 @@ mod tests {
     fn test_web_view_html() {
         let mut web = WebView::new();
         let html = "<html><body>Hello</body></html>";
-        assert!(web.load_html(html).is_ok());
+        assert!(web.load_html(html, None).is_ok());
         assert_eq!(web.current_html(), html);
+        assert!(web.load_html(html, Some("file:///docs/")).is_ok());
+        assert!(web.load_html(html, Some("not a url")).is_err());
+    }
+
+    #[test]
+    fn test_web_view_malformed_urls() {
+        let mut web = WebView::new();
+        assert!(web.load_url("").is_err());
+        assert!(web.load_url("example.com").is_err());
+        assert!(web.load_url("https://exa mple.com").is_err());
+        assert!(web.load_url("1http://example.com").is_err());
+        assert!(web.load_url("about:blank").is_ok());
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_web_view_navigation_handler() {
+        let visited = Rc::new(RefCell::new(Vec::new()));
+        let mut web = WebView::new();
+        {
+            let visited = Rc::clone(&visited);
+            web.on_navigation(move |url| visited.borrow_mut().push(url.to_string()));
+        }
+
+        web.load_url("https://example.com/docs").unwrap();
+        web.load_html("<p>Offline</p>", Some("file:///docs/")).unwrap();
+        assert!(web.go_back().is_ok());
+        assert_eq!(web.current_url(), "https://example.com/docs");
+        assert_eq!(web.navigated_url(), "file:///docs/");
+        assert_eq!(*visited.borrow(), vec!["https://example.com/docs", "file:///docs/"]);
+    }
+
+    #[test]
+    fn test_navigation_handler_can_navigate_again() {
+        let navigation = Rc::new(NavigationState {
+            url: RefCell::new(String::new()),
+            on_navigation: RefCell::new(None),
+        });
+        let visited = Rc::new(RefCell::new(Vec::new()));
+        {
+            let visited = Rc::clone(&visited);
+            let state = Rc::downgrade(&navigation);
+            *navigation.on_navigation.borrow_mut() = Some(Rc::new(move |url: &str| {
+                visited.borrow_mut().push(url.to_string());
+                if url == "https://example.com/old" {
+                    let state = state.upgrade().unwrap();
+                    state.navigated("https://example.com/new");
+                    *state.on_navigation.borrow_mut() = None;
+                }
+            }));
+        }
+
+        navigation.navigated("https://example.com/old");
+        assert_eq!(*navigation.url.borrow(), "https://example.com/new");
+        assert_eq!(*visited.borrow(), vec!["https://example.com/old", "https://example.com/new"]);
+        assert!(navigation.on_navigation.borrow().is_none());
     }
 }
@@ -1,6 +1,7 @@
 //! Drawing utilities for macOS GUI applications
 
 use crate::core::error::{CocoanutError, Result};
+use crate::features::zero_cost::{ZeroCostPoint, ZeroCostRect, ZeroCostSize};
 use objc::runtime::Object;
 use objc::{msg_send, sel, sel_impl};
 
@@ -50,7 +51,7 @@ impl Color {
     pub fn new(red: f64, green: f64, blue: f64, alpha: f64) -> Result<Self> {
         if !(0.0..=1.0).contains(&red) || !(0.0..=1.0).contains(&green) || 
            !(0.0..=1.0).contains(&blue) || !(0.0..=1.0).contains(&alpha) {
-            return Err(CocoanutError::InvalidParameter(
+            return Err(CocoanutError::InvalidColor(
                 "Color components must be between 0.0 and 1.0".to_string()
             ));
         }
@@ -190,6 +191,374 @@ impl Rect {
     }
 }
 
+impl From<Point> for ZeroCostPoint {
+    fn from(point: Point) -> Self {
+        ZeroCostPoint::new(point.x, point.y)
+    }
+}
+
+impl From<ZeroCostPoint> for Point {
+    fn from(point: ZeroCostPoint) -> Self {
+        Point::new(point.x, point.y)
+    }
+}
+
+impl From<Size> for ZeroCostSize {
+    fn from(size: Size) -> Self {
+        ZeroCostSize::new(size.width, size.height)
+    }
+}
+
+impl From<ZeroCostSize> for Size {
+    fn from(size: ZeroCostSize) -> Self {
+        Size::new(size.width, size.height)
+    }
+}
+
+impl From<Rect> for ZeroCostRect {
+    fn from(rect: Rect) -> Self {
+        ZeroCostRect::new(rect.origin.into(), rect.size.into())
+    }
+}
+
+impl From<ZeroCostRect> for Rect {
+    fn from(rect: ZeroCostRect) -> Self {
+        Rect::new(rect.origin.into(), rect.size.into())
+    }
+}
+
+/// Weight of a system font
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum FontWeight {
+    /// Light strokes
+    Light,
+    /// The standard weight
+    #[default]
+    Regular,
+    /// Slightly heavier than regular
+    Medium,
+    /// Between medium and bold
+    Semibold,
+    /// Bold strokes
+    Bold,
+}
+
+impl FontWeight {
+    /// The corresponding `NSFontWeight` value
+    pub fn ns_value(&self) -> f64 {
+        match self {
+            FontWeight::Light => -0.4,
+            FontWeight::Regular => 0.0,
+            FontWeight::Medium => 0.23,
+            FontWeight::Semibold => 0.3,
+            FontWeight::Bold => 0.4,
+        }
+    }
+}
+
+/// A font used to display text
+#[derive(Debug, Clone, PartialEq)]
+pub struct Font {
+    name: Option<String>,
+    size: f64,
+    weight: FontWeight,
+    monospaced: bool,
+}
+
+impl Font {
+    /// The system font at a point size
+    pub fn system(size: f64) -> Self {
+        Font { name: None, size, weight: FontWeight::Regular, monospaced: false }
+    }
+
+    /// The bold system font at a point size
+    pub fn bold_system(size: f64) -> Self {
+        Font { name: None, size, weight: FontWeight::Bold, monospaced: false }
+    }
+
+    /// The system font at a point size and weight
+    pub fn system_weighted(size: f64, weight: FontWeight) -> Self {
+        Font { name: None, size, weight, monospaced: false }
+    }
+
+    /// The user's fixed-pitch font at a point size
+    pub fn monospaced(size: f64) -> Self {
+        Font { name: None, size, weight: FontWeight::Regular, monospaced: true }
+    }
+
+    /// A font by PostScript or family name, e.g. "Menlo" or "Helvetica-Bold"
+    ///
+    /// Falls back to the system font if no font with the name is installed.
+    pub fn named(name: &str, size: f64) -> Self {
+        Font { name: Some(name.to_string()), size, weight: FontWeight::Regular, monospaced: false }
+    }
+
+    /// Get the font name, if this is not a system font
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Get the point size
+    pub fn size(&self) -> f64 {
+        self.size
+    }
+
+    /// Get the weight
+    pub fn weight(&self) -> FontWeight {
+        self.weight
+    }
+
+    /// Check if this is a bold font
+    pub fn is_bold(&self) -> bool {
+        self.weight == FontWeight::Bold
+    }
+
+    /// Check if this is a fixed-pitch font
+    pub fn is_monospaced(&self) -> bool {
+        self.monospaced
+    }
+
+    /// Convert to NSFont
+    #[cfg(not(feature = "test-mock"))]
+    pub(crate) fn to_ns_font(&self) -> Result<*mut Object> {
+        unsafe {
+            let font_class = objc::class!(NSFont);
+            if let Some(name) = &self.name {
+                let ns_name = crate::core::utils::string_to_ns_string(name)?;
+                let ns_font: *mut Object = msg_send![font_class, fontWithName: ns_name size: self.size];
+                if !ns_font.is_null() {
+                    return Ok(ns_font);
+                }
+            }
+            let ns_font: *mut Object = match self.weight {
+                _ if self.monospaced => msg_send![font_class, userFixedPitchFontOfSize: self.size],
+                FontWeight::Regular => msg_send![font_class, systemFontOfSize: self.size],
+                FontWeight::Bold => msg_send![font_class, boldSystemFontOfSize: self.size],
+                weight => msg_send![font_class, systemFontOfSize: self.size weight: weight.ns_value()],
+            };
+            Ok(ns_font)
+        }
+    }
+}
+
+/// How a gradient's colors spread
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GradientKind {
+    /// Colors change along a line, in the direction of an angle
+    Linear,
+    /// Colors change outward from the center
+    Radial,
+}
+
+/// A color at a position along a gradient
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientStop {
+    /// Color at the stop
+    pub color: Color,
+    /// Position from 0.0 (start, or center for radial) to 1.0 (end, or edge)
+    pub location: f64,
+}
+
+/// Name given to the gradient layers added by [`Gradient::apply_as_background`]
+#[cfg(not(feature = "test-mock"))]
+const GRADIENT_LAYER_NAME: &str = "CocoanutGradient";
+
+/// A linear or radial color gradient
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::drawing::{Color, Gradient};
+///
+/// # fn header(bar: *mut objc::runtime::Object) -> cocoanut::Result<()> {
+/// let gradient = Gradient::linear(Color::rgb(0.1, 0.3, 0.8)?, Color::rgb(0.4, 0.1, 0.7)?);
+/// unsafe { gradient.apply_as_background(bar, 0.0)? };
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    kind: GradientKind,
+    stops: Vec<GradientStop>,
+}
+
+impl Gradient {
+    /// A linear gradient from `start` to `end`
+    pub fn linear(start: Color, end: Color) -> Self {
+        Self::two_stop(GradientKind::Linear, start, end)
+    }
+
+    /// A radial gradient from `center` out to `edge`
+    pub fn radial(center: Color, edge: Color) -> Self {
+        Self::two_stop(GradientKind::Radial, center, edge)
+    }
+
+    /// A gradient through the given stops
+    ///
+    /// Locations are clamped to 0.0..=1.0 and the stops sorted by location.
+    /// Returns an error with fewer than two stops.
+    pub fn from_stops(kind: GradientKind, stops: Vec<GradientStop>) -> Result<Self> {
+        if stops.len() < 2 {
+            return Err(CocoanutError::InvalidParameter(
+                format!("A gradient needs at least two stops, got {}", stops.len())
+            ));
+        }
+        let mut gradient = Gradient { kind, stops: Vec::with_capacity(stops.len()) };
+        for stop in stops {
+            gradient = gradient.with_stop(stop.color, stop.location);
+        }
+        Ok(gradient)
+    }
+
+    fn two_stop(kind: GradientKind, start: Color, end: Color) -> Self {
+        Gradient {
+            kind,
+            stops: vec![
+                GradientStop { color: start, location: 0.0 },
+                GradientStop { color: end, location: 1.0 },
+            ],
+        }
+    }
+
+    /// Add a stop, clamping `location` to 0.0..=1.0
+    pub fn with_stop(mut self, color: Color, location: f64) -> Self {
+        let location = if location.is_nan() { 0.0 } else { location.clamp(0.0, 1.0) };
+        let index = self.stops.partition_point(|stop| stop.location <= location);
+        self.stops.insert(index, GradientStop { color, location });
+        self
+    }
+
+    /// Get the kind of gradient
+    pub fn kind(&self) -> GradientKind {
+        self.kind
+    }
+
+    /// Get the stops, ordered by location
+    pub fn stops(&self) -> &[GradientStop] {
+        &self.stops
+    }
+
+    /// Start and end points of a linear gradient at `angle` degrees, in the
+    /// unit square
+    ///
+    /// 0° runs left to right and 90° bottom to top, as with `NSGradient`.
+    pub fn unit_points(angle: f64) -> (Point, Point) {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        (
+            Point::new(0.5 - cos / 2.0, 0.5 - sin / 2.0),
+            Point::new(0.5 + cos / 2.0, 0.5 + sin / 2.0),
+        )
+    }
+
+    /// Fill `view`'s background with the gradient
+    ///
+    /// Adds a `CAGradientLayer` behind the view's content that resizes with
+    /// the view, replacing one added by an earlier call. `angle` is in
+    /// degrees and ignored for radial gradients. Null views are ignored.
+    ///
+    /// # Safety
+    ///
+    /// `view` must be null or a valid `NSView`.
+    pub unsafe fn apply_as_background(&self, view: *mut Object, angle: f64) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        if !view.is_null() {
+            unsafe {
+                let _: () = msg_send![view, setWantsLayer: true];
+                let host: *mut Object = msg_send![view, layer];
+                if host.is_null() {
+                    return Err(CocoanutError::DrawingError("View has no layer for a gradient".to_string()));
+                }
+                remove_gradient_layers(host)?;
+
+                let layer: *mut Object = msg_send![objc::class!(CAGradientLayer), layer];
+                let colors: *mut Object = msg_send![objc::class!(NSMutableArray), arrayWithCapacity: self.stops.len()];
+                let locations: *mut Object = msg_send![objc::class!(NSMutableArray), arrayWithCapacity: self.stops.len()];
+                for stop in &self.stops {
+                    let cg_color: *mut Object = msg_send![stop.color.to_ns_color(), CGColor];
+                    let location: *mut Object = msg_send![objc::class!(NSNumber), numberWithDouble: stop.location];
+                    let _: () = msg_send![colors, addObject: cg_color];
+                    let _: () = msg_send![locations, addObject: location];
+                }
+                let _: () = msg_send![layer, setColors: colors];
+                let _: () = msg_send![layer, setLocations: locations];
+
+                let (start, end) = match self.kind {
+                    GradientKind::Linear => Self::unit_points(angle),
+                    GradientKind::Radial => {
+                        let radial = crate::core::utils::string_to_ns_string("radial")?;
+                        let _: () = msg_send![layer, setType: radial];
+                        (Point::new(0.5, 0.5), Point::new(1.0, 1.0))
+                    }
+                };
+                let start: cocoa::foundation::NSPoint = ZeroCostPoint::from(start).into();
+                let end: cocoa::foundation::NSPoint = ZeroCostPoint::from(end).into();
+                let _: () = msg_send![layer, setStartPoint: start];
+                let _: () = msg_send![layer, setEndPoint: end];
+
+                let name = crate::core::utils::string_to_ns_string(GRADIENT_LAYER_NAME)?;
+                let bounds: cocoa::foundation::NSRect = msg_send![view, bounds];
+                let _: () = msg_send![layer, setName: name];
+                let _: () = msg_send![layer, setFrame: bounds];
+                // kCALayerWidthSizable | kCALayerHeightSizable
+                let _: () = msg_send![layer, setAutoresizingMask: 2u32 | 16u32];
+                let _: () = msg_send![host, insertSublayer: layer atIndex: 0u32];
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = (view, angle);
+        Ok(())
+    }
+
+    /// Create an `NSGradient` with the stops, autoreleased
+    #[cfg(not(feature = "test-mock"))]
+    fn to_ns_gradient(&self) -> Result<*mut Object> {
+        unsafe {
+            let colors: *mut Object = msg_send![objc::class!(NSMutableArray), arrayWithCapacity: self.stops.len()];
+            for stop in &self.stops {
+                let _: () = msg_send![colors, addObject: stop.color.to_ns_color()];
+            }
+            let locations: Vec<f64> = self.stops.iter().map(|stop| stop.location).collect();
+            let color_space: *mut Object = msg_send![objc::class!(NSColorSpace), deviceRGBColorSpace];
+            let gradient: *mut Object = msg_send![objc::class!(NSGradient), alloc];
+            let gradient: *mut Object = msg_send![
+                gradient,
+                initWithColors: colors
+                atLocations: locations.as_ptr()
+                colorSpace: color_space
+            ];
+            if gradient.is_null() {
+                return Err(CocoanutError::DrawingError("Failed to create NSGradient".to_string()));
+            }
+            Ok(msg_send![gradient, autorelease])
+        }
+    }
+}
+
+/// Remove gradient layers added to `host` by an earlier call
+#[cfg(not(feature = "test-mock"))]
+unsafe fn remove_gradient_layers(host: *mut Object) -> Result<()> {
+    unsafe {
+        let name = crate::core::utils::string_to_ns_string(GRADIENT_LAYER_NAME)?;
+        let sublayers: *mut Object = msg_send![host, sublayers];
+        if sublayers.is_null() {
+            return Ok(());
+        }
+        // Copy first; removing a layer mutates `sublayers`
+        let sublayers: *mut Object = msg_send![sublayers, copy];
+        let count: usize = msg_send![sublayers, count];
+        for index in 0..count {
+            let layer: *mut Object = msg_send![sublayers, objectAtIndex: index];
+            let layer_name: *mut Object = msg_send![layer, name];
+            let is_gradient: bool = !layer_name.is_null() && msg_send![layer_name, isEqualToString: name];
+            if is_gradient {
+                let _: () = msg_send![layer, removeFromSuperlayer];
+            }
+        }
+        let _: () = msg_send![sublayers, release];
+        Ok(())
+    }
+}
+
 /// Drawing context for custom drawing operations
 pub struct DrawingContext {
     ns_graphics_context: *mut Object,
@@ -252,6 +621,30 @@ impl DrawingContext {
             Ok(())
         }
     }
+
+    /// Fill a rectangle with a gradient
+    ///
+    /// `angle` is in degrees and ignored for radial gradients, which spread
+    /// from the center of `rect`.
+    pub fn fill_gradient(&self, gradient: &Gradient, rect: Rect, angle: f64) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_gradient = gradient.to_ns_gradient()?;
+            let ns_rect: cocoa::foundation::NSRect = ZeroCostRect::from(rect).into();
+            match gradient.kind() {
+                GradientKind::Linear => {
+                    let _: () = msg_send![ns_gradient, drawInRect: ns_rect angle: angle];
+                }
+                GradientKind::Radial => {
+                    let center = cocoa::foundation::NSPoint::new(0.0, 0.0);
+                    let _: () = msg_send![ns_gradient, drawInRect: ns_rect relativeCenterPosition: center];
+                }
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = (gradient, rect, angle);
+        Ok(())
+    }
 }
 
 impl Default for DrawingContext {
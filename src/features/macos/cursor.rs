@@ -0,0 +1,286 @@
+//! Mouse cursors shown over views
+//!
+//! [`Cursor`] names one of the standard `NSCursor`s, or a custom cursor drawn
+//! from an image file. [`Hoverable::set_cursor`] shows it whenever the
+//! pointer is over a view, the way links show a pointing hand.
+//!
+//! The view gets an `NSTrackingArea` covering its visible rect whose owner
+//! sets the cursor on `-cursorUpdate:`, so the view's class is left alone.
+
+use crate::core::error::{CocoanutError, Result};
+use crate::core::traits::Drawable;
+use crate::features::drawing::Point;
+use objc::runtime::Object;
+use std::path::{Path, PathBuf};
+
+#[cfg(not(feature = "test-mock"))]
+use objc::declare::ClassDecl;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Class, Sel};
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+#[cfg(not(feature = "test-mock"))]
+use std::ffi::c_void;
+#[cfg(not(feature = "test-mock"))]
+use std::sync::Once;
+
+/// `OBJC_ASSOCIATION_RETAIN_NONATOMIC`
+#[cfg(not(feature = "test-mock"))]
+const ASSOCIATION_RETAIN_NONATOMIC: usize = 1;
+
+/// `NSTrackingCursorUpdate | NSTrackingActiveInActiveApp | NSTrackingInVisibleRect`
+#[cfg(not(feature = "test-mock"))]
+const TRACKING_OPTIONS: u64 = 0x04 | 0x40 | 0x200;
+
+/// Address used as the associated-object key for a tracker's cursor
+#[cfg(not(feature = "test-mock"))]
+static CURSOR_KEY: u8 = 0;
+
+/// Address used as the associated-object key for a view's tracking area
+#[cfg(not(feature = "test-mock"))]
+static TRACKING_AREA_KEY: u8 = 0;
+
+#[cfg(not(feature = "test-mock"))]
+unsafe extern "C" {
+    fn objc_setAssociatedObject(object: *mut Object, key: *const c_void, value: *mut Object, policy: usize);
+    fn objc_getAssociatedObject(object: *const Object, key: *const c_void) -> *mut Object;
+}
+
+/// A mouse cursor
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Cursor {
+    /// The standard arrow
+    #[default]
+    Arrow,
+    /// The text insertion beam
+    IBeam,
+    /// A hand with a pointing finger, for links and clickable labels
+    PointingHand,
+    /// An open hand, for content that can be dragged
+    OpenHand,
+    /// A closed hand, while dragging content
+    ClosedHand,
+    /// A horizontal resize arrow
+    ResizeLeftRight,
+    /// A vertical resize arrow
+    ResizeUpDown,
+    /// Crosshairs, for precise selection
+    Crosshair,
+    /// The "not allowed" sign
+    OperationNotAllowed,
+    /// A cursor drawn from an image file
+    Image {
+        /// Path of the image
+        path: PathBuf,
+        /// Point of the image, from its top-left, that does the pointing
+        hot_spot: Point,
+    },
+}
+
+impl Cursor {
+    /// A custom cursor drawn from the image at `path`
+    ///
+    /// `hot_spot` is the point, measured from the top-left of the image,
+    /// that the cursor points with. The image is loaded when the cursor is
+    /// first set on a view.
+    pub fn from_image(path: impl AsRef<Path>, hot_spot: Point) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.is_file() {
+            return Err(CocoanutError::InvalidParameter(
+                format!("Cursor image not found: {}", path.display())
+            ));
+        }
+        Ok(Cursor::Image { path: path.to_path_buf(), hot_spot })
+    }
+
+    /// The `NSCursor` class method returning this cursor
+    ///
+    /// `None` for image cursors.
+    pub fn class_method(&self) -> Option<&'static str> {
+        match self {
+            Cursor::Arrow => Some("arrowCursor"),
+            Cursor::IBeam => Some("IBeamCursor"),
+            Cursor::PointingHand => Some("pointingHandCursor"),
+            Cursor::OpenHand => Some("openHandCursor"),
+            Cursor::ClosedHand => Some("closedHandCursor"),
+            Cursor::ResizeLeftRight => Some("resizeLeftRightCursor"),
+            Cursor::ResizeUpDown => Some("resizeUpDownCursor"),
+            Cursor::Crosshair => Some("crosshairCursor"),
+            Cursor::OperationNotAllowed => Some("operationNotAllowedCursor"),
+            Cursor::Image { .. } => None,
+        }
+    }
+
+    /// Create the native cursor, retained
+    #[cfg(not(feature = "test-mock"))]
+    unsafe fn to_ns_cursor(&self) -> Result<*mut Object> {
+        unsafe {
+            let ns_cursor: *mut Object = match self {
+                Cursor::Image { path, hot_spot } => {
+                    let path = crate::core::utils::string_to_ns_string(&path.to_string_lossy())?;
+                    let image: *mut Object = msg_send![class!(NSImage), alloc];
+                    let image: *mut Object = msg_send![image, initWithContentsOfFile: path];
+                    if image.is_null() {
+                        return Err(CocoanutError::ControlCreationFailed(
+                            "Failed to load cursor image".to_string()
+                        ));
+                    }
+                    let hot_spot = cocoa::foundation::NSPoint::new(hot_spot.x, hot_spot.y);
+                    let ns_cursor: *mut Object = msg_send![class!(NSCursor), alloc];
+                    let ns_cursor: *mut Object = msg_send![ns_cursor, initWithImage: image hotSpot: hot_spot];
+                    let _: () = msg_send![image, release];
+                    ns_cursor
+                }
+                cursor => {
+                    let name = cursor.class_method().unwrap_or("arrowCursor");
+                    let ns_cursor: *mut Object = msg_send![class!(NSCursor), performSelector: Sel::register(name)];
+                    if ns_cursor.is_null() {
+                        ns_cursor
+                    } else {
+                        msg_send![ns_cursor, retain]
+                    }
+                }
+            };
+            if ns_cursor.is_null() {
+                return Err(CocoanutError::ControlCreationFailed("Failed to create NSCursor".to_string()));
+            }
+            Ok(ns_cursor)
+        }
+    }
+}
+
+/// Views that can show their own mouse cursor
+///
+/// Implemented for every [`Drawable`].
+pub trait Hoverable: Drawable {
+    /// Show `cursor` while the pointer is over the view
+    ///
+    /// Replaces the cursor set by an earlier call.
+    fn set_cursor(&self, cursor: Cursor) -> Result<()> {
+        set_view_cursor(self.as_view(), &cursor)
+    }
+}
+
+impl<T: Drawable + ?Sized> Hoverable for T {}
+
+/// Show `cursor` while the pointer is over `view`
+///
+/// Null views are ignored.
+fn set_view_cursor(view: *mut Object, cursor: &Cursor) -> Result<()> {
+    #[cfg(not(feature = "test-mock"))]
+    if !view.is_null() {
+        unsafe {
+            use cocoa::foundation::{NSPoint, NSRect, NSSize};
+
+            let ns_cursor = cursor.to_ns_cursor()?;
+            let tracker: *mut Object = msg_send![cursor_tracker_class(), new];
+            objc_setAssociatedObject(tracker, key(&CURSOR_KEY), ns_cursor, ASSOCIATION_RETAIN_NONATOMIC);
+            let _: () = msg_send![ns_cursor, release];
+
+            // The rect is ignored with NSTrackingInVisibleRect
+            let nil: *mut Object = std::ptr::null_mut();
+            let area: *mut Object = msg_send![class!(NSTrackingArea), alloc];
+            let area: *mut Object = msg_send![area, initWithRect: NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(0.0, 0.0))
+                                                         options: TRACKING_OPTIONS
+                                                           owner: tracker
+                                                        userInfo: nil];
+            // Tracking areas do not retain their owner, so the area keeps it
+            objc_setAssociatedObject(area, key(&CURSOR_KEY), tracker, ASSOCIATION_RETAIN_NONATOMIC);
+            let _: () = msg_send![tracker, release];
+
+            let previous = objc_getAssociatedObject(view, key(&TRACKING_AREA_KEY));
+            if !previous.is_null() {
+                let _: () = msg_send![view, removeTrackingArea: previous];
+            }
+            let _: () = msg_send![view, addTrackingArea: area];
+            objc_setAssociatedObject(view, key(&TRACKING_AREA_KEY), area, ASSOCIATION_RETAIN_NONATOMIC);
+            let _: () = msg_send![area, release];
+        }
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = (view, cursor);
+    Ok(())
+}
+
+#[cfg(not(feature = "test-mock"))]
+fn key(address: &'static u8) -> *const c_void {
+    address as *const u8 as *const c_void
+}
+
+/// Lazily register the tracking area owner that sets its cursor
+#[cfg(not(feature = "test-mock"))]
+fn cursor_tracker_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        // Another copy of the library may have registered it already
+        let Some(mut decl) = ClassDecl::new("CocoanutCursorTracker", class!(NSObject)) else {
+            return;
+        };
+
+        extern "C" fn cursor_update(this: &Object, _cmd: Sel, _event: *mut Object) {
+            unsafe {
+                let ns_cursor = objc_getAssociatedObject(this, key(&CURSOR_KEY));
+                if !ns_cursor.is_null() {
+                    let _: () = msg_send![ns_cursor, set];
+                }
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(cursorUpdate:), cursor_update as extern "C" fn(&Object, Sel, *mut Object));
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutCursorTracker").expect("CocoanutCursorTracker not registered")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_class_methods() {
+        assert_eq!(Cursor::Arrow.class_method(), Some("arrowCursor"));
+        assert_eq!(Cursor::IBeam.class_method(), Some("IBeamCursor"));
+        assert_eq!(Cursor::PointingHand.class_method(), Some("pointingHandCursor"));
+        assert_eq!(Cursor::ResizeLeftRight.class_method(), Some("resizeLeftRightCursor"));
+        assert_eq!(Cursor::ResizeUpDown.class_method(), Some("resizeUpDownCursor"));
+        assert_eq!(Cursor::Crosshair.class_method(), Some("crosshairCursor"));
+        assert_eq!(Cursor::OperationNotAllowed.class_method(), Some("operationNotAllowedCursor"));
+        assert_eq!(Cursor::default(), Cursor::Arrow);
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_set_cursor_replaces_tracking_area() {
+        use crate::components::basic::controls_v2::Label;
+
+        let label = Label::new("Link").unwrap();
+        let view = label.as_view();
+        let tracking_areas = || unsafe {
+            let areas: *mut Object = msg_send![view, trackingAreas];
+            let count: usize = msg_send![areas, count];
+            count
+        };
+        let class_before = unsafe { (*view).class().name().to_string() };
+
+        label.set_cursor(Cursor::PointingHand).unwrap();
+        assert_eq!(tracking_areas(), 1);
+        label.set_cursor(Cursor::IBeam).unwrap();
+        assert_eq!(tracking_areas(), 1);
+        // The view keeps its own class
+        assert_eq!(unsafe { (*view).class().name() }, class_before);
+    }
+
+    #[test]
+    fn test_image_cursor() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+        let cursor = Cursor::from_image(&path, Point::new(4.0, 2.0)).unwrap();
+        assert_eq!(cursor.class_method(), None);
+        assert_eq!(cursor, Cursor::Image { path, hot_spot: Point::new(4.0, 2.0) });
+
+        assert!(Cursor::from_image("/no/such/cursor.png", Point::new(0.0, 0.0)).is_err());
+    }
+}
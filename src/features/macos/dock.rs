@@ -0,0 +1,98 @@
+//! Dock tile integration: badge label, attention requests and icon
+//!
+//! All functions talk to the shared `NSApplication`. In test-mock mode they
+//! are no-ops returning `Ok`.
+
+use crate::core::error::Result;
+use std::path::Path;
+
+#[cfg(not(feature = "test-mock"))]
+use crate::core::error::CocoanutError;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::Object;
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+
+/// Access to the application's Dock tile
+pub struct Dock;
+
+impl Dock {
+    /// Set the badge label shown on the Dock icon
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The badge text, or `None` to clear the badge
+    pub fn set_badge_label(label: Option<&str>) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let app: *mut Object = msg_send![class!(NSApplication), sharedApplication];
+            let dock_tile: *mut Object = msg_send![app, dockTile];
+            let badge: *mut Object = match label {
+                Some(label) => crate::core::utils::string_to_ns_string(label)?,
+                None => std::ptr::null_mut(),
+            };
+            let _: () = msg_send![dock_tile, setBadgeLabel: badge];
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = label;
+        Ok(())
+    }
+
+    /// Bounce the Dock icon to request the user's attention
+    ///
+    /// # Arguments
+    ///
+    /// * `critical` - Bounce until the app is activated instead of once
+    pub fn request_attention(critical: bool) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            // NSCriticalRequest = 0, NSInformationalRequest = 10
+            let request_type: u64 = if critical { 0 } else { 10 };
+            let app: *mut Object = msg_send![class!(NSApplication), sharedApplication];
+            let _: i64 = msg_send![app, requestUserAttention: request_type];
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = critical;
+        Ok(())
+    }
+
+    /// Replace the Dock icon with an image file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to an image file readable by `NSImage`
+    pub fn set_icon(path: impl AsRef<Path>) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let path = path.as_ref();
+            let ns_path = crate::core::utils::string_to_ns_string(&path.to_string_lossy())?;
+            let image: *mut Object = msg_send![class!(NSImage), alloc];
+            let image: *mut Object = msg_send![image, initWithContentsOfFile: ns_path];
+            if image.is_null() {
+                return Err(CocoanutError::InvalidParameter(
+                    format!("Could not load Dock icon from '{}'", path.display())
+                ));
+            }
+            let app: *mut Object = msg_send![class!(NSApplication), sharedApplication];
+            let _: () = msg_send![app, setApplicationIconImage: image];
+            let _: () = msg_send![image, release];
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = path;
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "test-mock"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dock_mock_operations() {
+        assert!(Dock::set_badge_label(Some("3")).is_ok());
+        assert!(Dock::set_badge_label(None).is_ok());
+        assert!(Dock::request_attention(true).is_ok());
+        assert!(Dock::request_attention(false).is_ok());
+        assert!(Dock::set_icon("icon.png").is_ok());
+    }
+}
@@ -0,0 +1,76 @@
+//! Force Touch trackpad feedback through `NSHapticFeedbackManager`
+//!
+//! Macs without a Force Touch trackpad, and users who turned haptics off,
+//! simply feel nothing. In test-mock mode feedback is a no-op.
+
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::Object;
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+
+/// A kind of haptic feedback, mapping to `NSHapticFeedbackPattern`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HapticPattern {
+    /// General feedback with no specific meaning
+    #[default]
+    Generic,
+    /// Something snapped into alignment, like a guide in a canvas
+    Alignment,
+    /// A value crossed a step, like a slider tick mark
+    LevelChange,
+}
+
+impl HapticPattern {
+    /// The `NSHapticFeedbackPattern` value
+    pub fn to_ns_pattern(self) -> i64 {
+        match self {
+            HapticPattern::Generic => 0,
+            HapticPattern::Alignment => 1,
+            HapticPattern::LevelChange => 2,
+        }
+    }
+}
+
+/// Access to the trackpad's haptic feedback
+pub struct Haptics;
+
+impl Haptics {
+    /// Play `pattern` on the trackpad
+    ///
+    /// The feedback is timed to the next screen refresh so it lines up with
+    /// what the user sees. Does nothing on hardware without haptics.
+    pub fn perform(pattern: HapticPattern) {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            // NSHapticFeedbackPerformanceTimeDrawCompleted
+            const DRAW_COMPLETED: u64 = 2;
+
+            let performer: *mut Object = msg_send![class!(NSHapticFeedbackManager), defaultPerformer];
+            if !performer.is_null() {
+                let _: () = msg_send![performer, performFeedbackPattern: pattern.to_ns_pattern()
+                                                         performanceTime: DRAW_COMPLETED];
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = pattern;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_haptic_pattern_constants() {
+        assert_eq!(HapticPattern::Generic.to_ns_pattern(), 0);
+        assert_eq!(HapticPattern::Alignment.to_ns_pattern(), 1);
+        assert_eq!(HapticPattern::LevelChange.to_ns_pattern(), 2);
+        assert_eq!(HapticPattern::default(), HapticPattern::Generic);
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_haptics_perform_is_silent() {
+        Haptics::perform(HapticPattern::Alignment);
+    }
+}
@@ -33,6 +33,16 @@ pub enum Appearance {
     Dark,
 }
 
+impl Appearance {
+    /// The `NSAppearanceName` for this appearance
+    pub fn ns_appearance_name(self) -> &'static str {
+        match self {
+            Appearance::Light => "NSAppearanceNameAqua",
+            Appearance::Dark => "NSAppearanceNameDarkAqua",
+        }
+    }
+}
+
 /// Native feel manager for design language compliance
 pub struct NativeFeel {
     style: DesignStyle,
@@ -151,6 +161,12 @@ impl Default for AccessibilityOptions {
     }
 }
 
+#[cfg(feature = "test-mock")]
+thread_local! {
+    /// System appearance reported in mock builds, per test thread
+    static MOCK_APPEARANCE: std::cell::Cell<Appearance> = const { std::cell::Cell::new(Appearance::Light) };
+}
+
 /// Dark mode manager for automatic theme switching
 pub struct DarkModeManager {
     enabled: bool,
@@ -188,7 +204,7 @@ impl DarkModeManager {
             
             // Check if dark
             let dark_name_class = objc::class!(NSString);
-            let dark_name: *mut Object = msg_send![dark_name_class, stringWithUTF8String: b"NSAppearanceNameDarkContent\0".as_ptr() as *const i8];
+            let dark_name: *mut Object = msg_send![dark_name_class, stringWithUTF8String: c"NSAppearanceNameDarkAqua".as_ptr()];
             let is_dark: bool = msg_send![name, isEqualToString: dark_name];
             
             if is_dark {
@@ -198,7 +214,24 @@ impl DarkModeManager {
             }
         }
         #[cfg(feature = "test-mock")]
-        Appearance::Light
+        MOCK_APPEARANCE.with(|appearance| appearance.get())
+    }
+
+    /// Make `current_appearance` report `appearance` on this thread
+    #[cfg(all(test, feature = "test-mock"))]
+    pub(crate) fn simulate_appearance(appearance: Appearance) {
+        MOCK_APPEARANCE.with(|current| current.set(appearance));
+    }
+
+    /// Get the appearance components should render with
+    ///
+    /// Returns the system appearance while enabled, and `Light` otherwise.
+    pub fn active_appearance(&self) -> Appearance {
+        if self.enabled {
+            Self::current_appearance()
+        } else {
+            Appearance::Light
+        }
     }
 
     /// Apply dark mode to a view
@@ -215,12 +248,12 @@ impl DarkModeManager {
             let appearance_obj: *mut Object = match appearance {
                 Appearance::Dark => {
                     let dark_name_class = objc::class!(NSString);
-                    let dark_name: *mut Object = msg_send![dark_name_class, stringWithUTF8String: b"NSAppearanceNameDarkContent\0".as_ptr() as *const i8];
+                    let dark_name: *mut Object = msg_send![dark_name_class, stringWithUTF8String: c"NSAppearanceNameDarkContent".as_ptr()];
                     msg_send![appearance_class, appearanceNamed: dark_name]
                 }
                 Appearance::Light => {
                     let light_name_class = objc::class!(NSString);
-                    let light_name: *mut Object = msg_send![light_name_class, stringWithUTF8String: b"NSAppearanceNameLightContent\0".as_ptr() as *const i8];
+                    let light_name: *mut Object = msg_send![light_name_class, stringWithUTF8String: c"NSAppearanceNameLightContent".as_ptr()];
                     msg_send![appearance_class, appearanceNamed: light_name]
                 }
             };
@@ -376,10 +409,8 @@ impl ContinuityManager {
         unsafe {
             let pasteboard_class = objc::class!(NSPasteboard);
             let general: *mut Object = msg_send![pasteboard_class, generalPasteboard];
-            let string_type_class = objc::class!(NSString);
-            let string_type: *mut Object = msg_send![string_type_class, stringWithUTF8String: b"public.utf8-plain-text\0".as_ptr() as *const i8];
             
-            let content: *mut Object = msg_send![general, stringForType: string_type];
+            let content: *mut Object = msg_send![general, stringForType: NSPasteboardTypeString];
             
             if content.is_null() {
                 return Ok(String::new());
@@ -407,12 +438,25 @@ impl ContinuityManager {
             let ns_string_class = objc::class!(NSString);
             let content_ns: *mut Object = msg_send![ns_string_class, stringWithUTF8String: content_cstr.as_ptr()];
             
-            let _: () = msg_send![general, setString:content_ns forType: "public.utf8-plain-text"];
+            // The pasteboard must be cleared before it accepts new data
+            let _: i64 = msg_send![general, clearContents];
+            let written: bool = msg_send![general, setString:content_ns forType: NSPasteboardTypeString];
+            if !written {
+                return Err(crate::core::error::CocoanutError::SystemError(
+                    "Failed to write string to clipboard".to_string()
+                ));
+            }
         }
         Ok(())
     }
 }
 
+#[cfg(not(feature = "test-mock"))]
+unsafe extern "C" {
+    /// AppKit's `NSPasteboardTypeString` (`public.utf8-plain-text`)
+    static NSPasteboardTypeString: *mut Object;
+}
+
 impl Default for ContinuityManager {
     fn default() -> Self {
         Self::new()
@@ -477,4 +521,12 @@ mod tests {
         manager.disable_handoff();
         assert!(!manager.is_handoff_enabled());
     }
+
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_clipboard_round_trip() {
+        let manager = ContinuityManager::new();
+        manager.set_clipboard("cocoanut clipboard test").unwrap();
+        assert_eq!(manager.get_clipboard().unwrap(), "cocoanut clipboard test");
+    }
 }
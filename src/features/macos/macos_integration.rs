@@ -5,8 +5,18 @@
 
 use crate::core::error::{CocoanutError, Result};
 use objc::runtime::Object;
+#[cfg(not(feature = "test-mock"))]
+use objc::declare::ClassDecl;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Class, Sel};
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
 use std::ffi::CString;
-use std::sync::Arc;
+#[cfg(not(feature = "test-mock"))]
+use std::ffi::c_void;
+#[cfg(not(feature = "test-mock"))]
+use std::sync::Once;
+use std::sync::{Arc, Mutex, MutexGuard};
 
 /// macOS design language compliance manager
 /// 
@@ -166,67 +176,269 @@ pub trait AccessibleComponent {
     
     /// Set the accessibility hint
     fn set_accessibility_hint(&mut self, hint: String) -> Result<()>;
+
+    /// Get the accessibility role
+    ///
+    /// Defaults to `None`, leaving the role AppKit infers.
+    fn accessibility_role(&self) -> Option<AccessibilityRole> {
+        None
+    }
+
+    /// Set the accessibility role reported to assistive technologies
+    ///
+    /// Does nothing by default.
+    fn set_accessibility_role(&mut self, role: AccessibilityRole) -> Result<()> {
+        let _ = role;
+        Ok(())
+    }
+
+    /// Set the accessibility value (e.g. the current position of a slider)
+    ///
+    /// Does nothing by default.
+    fn set_accessibility_value(&mut self, value: &str) -> Result<()> {
+        let _ = value;
+        Ok(())
+    }
+}
+
+/// Accessibility roles reported to VoiceOver and other assistive technologies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessibilityRole {
+    /// Push button
+    Button,
+    /// Non-editable text
+    StaticText,
+    /// Editable text field
+    TextField,
+    /// Image
+    Image,
+    /// Checkbox
+    Checkbox,
+    /// Radio button
+    RadioButton,
+    /// Slider
+    Slider,
+    /// Pop-up button or dropdown
+    PopUpButton,
+    /// Progress indicator
+    ProgressIndicator,
+    /// Link
+    Link,
+    /// Generic group of elements
+    Group,
+    /// Window
+    Window,
+}
+
+impl AccessibilityRole {
+    /// Get the `NSAccessibilityRole` string for this role
+    pub fn ns_role(&self) -> &'static str {
+        match self {
+            Self::Button => "AXButton",
+            Self::StaticText => "AXStaticText",
+            Self::TextField => "AXTextField",
+            Self::Image => "AXImage",
+            Self::Checkbox => "AXCheckBox",
+            Self::RadioButton => "AXRadioButton",
+            Self::Slider => "AXSlider",
+            Self::PopUpButton => "AXPopUpButton",
+            Self::ProgressIndicator => "AXProgressIndicator",
+            Self::Link => "AXLink",
+            Self::Group => "AXGroup",
+            Self::Window => "AXWindow",
+        }
+    }
+}
+
+/// Send `setAccessibilityRole:` to a view; a null view is ignored
+pub(crate) fn set_view_accessibility_role(view: *mut Object, role: AccessibilityRole) -> Result<()> {
+    if view.is_null() {
+        return Ok(());
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    unsafe {
+        let ns_role = crate::core::utils::string_to_ns_string(role.ns_role())?;
+        let _: () = msg_send![view, setAccessibilityRole: ns_role];
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = role;
+    Ok(())
+}
+
+/// Send `setAccessibilityValue:` to a view; a null view is ignored
+pub(crate) fn set_view_accessibility_value(view: *mut Object, value: &str) -> Result<()> {
+    if view.is_null() {
+        return Ok(());
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    unsafe {
+        let ns_value = crate::core::utils::string_to_ns_string(value)?;
+        let _: () = msg_send![view, setAccessibilityValue: ns_value];
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = value;
+    Ok(())
 }
 
 /// Dark mode manager for automatic theme switching
+///
+/// The system appearance is read from `NSApplication.effectiveAppearance`.
+/// Once the first observer is added, a key-value observer is registered on
+/// `NSApp` so observers fire whenever the user toggles system dark mode.
 pub struct DarkModeManager {
+    state: Arc<Mutex<AppearanceState>>,
+    system_observer: SystemObserver,
+}
+
+/// The key-value observer registered on `NSApp`, or null before the first
+/// observer is added
+///
+/// The observer is only created and removed by its `DarkModeManager`, and
+/// key-value observing may be registered and removed from any thread, so
+/// the manager stays `Send` and `Sync` like the rest of its state.
+struct SystemObserver(*mut Object);
+
+unsafe impl Send for SystemObserver {}
+unsafe impl Sync for SystemObserver {}
+
+/// An appearance observer; shared so it can be called without the state locked
+type AppearanceObserver = Arc<dyn Fn(Appearance) + Send + Sync>;
+
+/// Appearance state shared with the system appearance observer
+struct AppearanceState {
     current_appearance: Appearance,
     system_appearance: Appearance,
-    observers: Vec<Box<dyn Fn(Appearance) + Send + Sync>>,
+    observers: Vec<AppearanceObserver>,
+}
+
+impl AppearanceState {
+    /// The appearance actually in effect, resolving `Automatic` to the system value
+    fn effective_appearance(&self) -> Appearance {
+        match self.current_appearance {
+            Appearance::Automatic => self.system_appearance,
+            appearance => appearance,
+        }
+    }
+
+    /// Record a new system appearance
+    ///
+    /// Returns `true` if it changed what is shown, so observers need notifying.
+    fn system_changed(&mut self, appearance: Appearance) -> bool {
+        if self.system_appearance == appearance {
+            return false;
+        }
+        self.system_appearance = appearance;
+        self.current_appearance == Appearance::Automatic
+    }
+}
+
+/// Call every observer with the effective appearance
+///
+/// The observers are copied out first so they run without the lock held and
+/// may use the manager themselves.
+fn notify_observers(state: &Mutex<AppearanceState>) {
+    let (appearance, observers) = {
+        let state = state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        (state.effective_appearance(), state.observers.clone())
+    };
+    for observer in observers {
+        observer(appearance);
+    }
 }
 
 impl DarkModeManager {
     /// Create a new dark mode manager
     pub fn new() -> Self {
         Self {
-            current_appearance: Appearance::Automatic,
-            system_appearance: Appearance::Light,
-            observers: Vec::new(),
+            state: Arc::new(Mutex::new(AppearanceState {
+                current_appearance: Appearance::Automatic,
+                system_appearance: Appearance::Light,
+                observers: Vec::new(),
+            })),
+            system_observer: SystemObserver(std::ptr::null_mut()),
         }
     }
+
+    fn lock_state(&self) -> MutexGuard<'_, AppearanceState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
     
     /// Get the current appearance
     pub fn current_appearance(&self) -> Appearance {
-        self.current_appearance
+        self.lock_state().current_appearance
     }
     
     /// Set the appearance mode
     pub fn set_appearance(&mut self, appearance: Appearance) -> Result<()> {
-        self.current_appearance = appearance;
-        self.notify_observers();
+        self.lock_state().current_appearance = appearance;
+        notify_observers(&self.state);
         Ok(())
     }
     
     /// Get the system appearance
     pub fn system_appearance(&self) -> Appearance {
-        self.system_appearance
+        self.lock_state().system_appearance
+    }
+
+    /// Get the appearance in effect, resolving `Automatic` to the system appearance
+    pub fn effective_appearance(&self) -> Appearance {
+        self.lock_state().effective_appearance()
     }
     
     /// Update from system appearance
+    ///
+    /// Reads `NSApplication.effectiveAppearance`. In test-mock mode the
+    /// system appearance is simulated as `Light`.
     pub fn update_from_system(&mut self) -> Result<()> {
-        // This would query the system for current appearance
-        // For now, we'll simulate it
-        self.system_appearance = Appearance::Light;
-        
-        if self.current_appearance == Appearance::Automatic {
-            self.notify_observers();
+        let appearance = query_system_appearance();
+        if self.lock_state().system_changed(appearance) {
+            notify_observers(&self.state);
         }
-        
         Ok(())
     }
     
     /// Add an observer for appearance changes
+    ///
+    /// Observers receive the effective appearance whenever it is set
+    /// explicitly or, in `Automatic` mode, when the system appearance changes.
     pub fn add_observer<F>(&mut self, observer: F)
     where
         F: Fn(Appearance) + Send + Sync + 'static,
     {
-        self.observers.push(Box::new(observer));
+        self.lock_state().observers.push(Arc::new(observer));
+        self.observe_system();
     }
-    
-    /// Notify all observers of appearance changes
-    fn notify_observers(&self) {
-        for observer in &self.observers {
-            observer(self.current_appearance);
+
+    /// Check if the system appearance observer is registered
+    pub fn is_observing_system(&self) -> bool {
+        !self.system_observer.0.is_null()
+    }
+
+    /// Register the KVO observer on `NSApp.effectiveAppearance` if not already done
+    fn observe_system(&mut self) {
+        #[cfg(not(feature = "test-mock"))]
+        if self.system_observer.0.is_null() {
+            unsafe {
+                let observer: *mut Object = msg_send![appearance_observer_class(), new];
+                if observer.is_null() {
+                    return;
+                }
+                let state = Arc::into_raw(Arc::clone(&self.state)) as *mut c_void;
+                (*observer).set_ivar::<*mut c_void>("rustState", state);
+
+                let app: *mut Object = msg_send![class!(NSApplication), sharedApplication];
+                let key_path = CString::new("effectiveAppearance").unwrap();
+                let key_path: *mut Object = msg_send![class!(NSString), stringWithUTF8String: key_path.as_ptr()];
+                // NSKeyValueObservingOptionNew
+                let options: u64 = 0x01;
+                let _: () = msg_send![app, addObserver: observer
+                                            forKeyPath: key_path
+                                               options: options
+                                               context: std::ptr::null_mut::<c_void>()];
+                self.system_observer.0 = observer;
+            }
         }
     }
 }
@@ -237,12 +449,102 @@ impl Default for DarkModeManager {
     }
 }
 
+impl Drop for DarkModeManager {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.system_observer.0.is_null() {
+            unsafe {
+                let observer = self.system_observer.0;
+                let app: *mut Object = msg_send![class!(NSApplication), sharedApplication];
+                let key_path = CString::new("effectiveAppearance").unwrap();
+                let key_path: *mut Object = msg_send![class!(NSString), stringWithUTF8String: key_path.as_ptr()];
+                let _: () = msg_send![app, removeObserver: observer forKeyPath: key_path];
+
+                let state = *(*observer).get_ivar::<*mut c_void>("rustState");
+                let _: () = msg_send![observer, release];
+                drop(Arc::from_raw(state as *const Mutex<AppearanceState>));
+            }
+        }
+    }
+}
+
+/// Read the real system appearance, mapped onto this module's `Appearance`
+fn query_system_appearance() -> Appearance {
+    match super::macos_features::DarkModeManager::current_appearance() {
+        super::macos_features::Appearance::Dark => Appearance::Dark,
+        super::macos_features::Appearance::Light => Appearance::Light,
+    }
+}
+
+/// Lazily register the ObjC class that receives `effectiveAppearance` KVO callbacks
+#[cfg(not(feature = "test-mock"))]
+fn appearance_observer_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutAppearanceObserver", class!(NSObject))
+            .expect("CocoanutAppearanceObserver already registered");
+        decl.add_ivar::<*mut c_void>("rustState");
+
+        extern "C" fn observe_value(
+            this: &Object,
+            _cmd: Sel,
+            _key_path: *mut Object,
+            _object: *mut Object,
+            _change: *mut Object,
+            _context: *mut c_void,
+        ) {
+            unsafe {
+                let state = *this.get_ivar::<*mut c_void>("rustState") as *const Mutex<AppearanceState>;
+                if state.is_null() {
+                    return;
+                }
+                let appearance = query_system_appearance();
+                let changed = (*state).lock().unwrap_or_else(|poisoned| poisoned.into_inner()).system_changed(appearance);
+                if changed {
+                    notify_observers(&*state);
+                }
+            }
+        }
+
+        unsafe {
+            decl.add_method(
+                sel!(observeValueForKeyPath:ofObject:change:context:),
+                observe_value as extern "C" fn(&Object, Sel, *mut Object, *mut Object, *mut Object, *mut c_void),
+            );
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutAppearanceObserver").expect("CocoanutAppearanceObserver not registered")
+}
+
 /// Touch Bar manager for MacBook Pro Touch Bar support
+///
+/// Items are held in Rust until [`TouchBarManager::apply`] builds a real
+/// `NSTouchBar` for a responder. Button items are backed by an `NSButton`
+/// whose target dispatches to the item's `action` closure.
 pub struct TouchBarManager {
     touch_bar_available: bool,
     current_items: Vec<TouchBarItem>,
+    targets: Vec<ButtonTarget>,
+}
+
+/// A Touch Bar button built by `apply` and the target dispatching its clicks
+///
+/// The button is retained so its target can be cleared before the target
+/// is freed, even while an installed `NSTouchBar` still shows the button.
+///
+/// Only its `TouchBarManager` creates and releases the pair, so the manager
+/// stays `Send` and `Sync` as it was before it held native objects.
+struct ButtonTarget {
+    button: *mut Object,
+    target: *mut Object,
 }
 
+unsafe impl Send for ButtonTarget {}
+unsafe impl Sync for ButtonTarget {}
+
 /// Touch Bar item types
 pub enum TouchBarItem {
     /// Button item
@@ -274,6 +576,7 @@ impl TouchBarManager {
         Self {
             touch_bar_available: false,
             current_items: Vec::new(),
+            targets: Vec::new(),
         }
     }
     
@@ -289,14 +592,12 @@ impl TouchBarManager {
     }
     
     /// Remove a Touch Bar item by identifier
+    ///
+    /// Any Touch Bar previously built by `apply` stops dispatching actions;
+    /// call `apply` again to rebuild it.
     pub fn remove_item(&mut self, identifier: &str) -> Result<()> {
-        self.current_items.retain(|item| {
-            match item {
-                TouchBarItem::Button { identifier: id, .. } => id != identifier,
-                TouchBarItem::Slider { identifier: id, .. } => id != identifier,
-                TouchBarItem::SegmentedControl { identifier: id, .. } => id != identifier,
-            }
-        });
+        self.release_targets();
+        self.current_items.retain(|item| item.identifier() != identifier);
         Ok(())
     }
     
@@ -307,8 +608,166 @@ impl TouchBarManager {
     
     /// Clear all Touch Bar items
     pub fn clear(&mut self) {
+        self.release_targets();
         self.current_items.clear();
     }
+
+    /// Run the action of the button item with the given identifier
+    pub fn perform_action(&self, identifier: &str) -> Result<()> {
+        for item in &self.current_items {
+            match item {
+                TouchBarItem::Button { identifier: id, action, .. } if id == identifier => {
+                    action();
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+        Err(CocoanutError::InvalidParameter(
+            format!("No Touch Bar button with identifier '{}'", identifier)
+        ))
+    }
+
+    /// Build an `NSTouchBar` from the current items and install it on a responder
+    ///
+    /// Button items become `NSCustomTouchBarItem`s hosting an `NSButton`;
+    /// pressing the button invokes the item's `action`. Slider and segmented
+    /// control items are not rendered yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `responder` - The `NSWindow` or `NSView` that should own the Touch Bar
+    ///
+    /// # Safety
+    ///
+    /// `responder` must be null or a valid `NSResponder`.
+    pub unsafe fn apply(&mut self, responder: *mut Object) -> Result<()> {
+        if responder.is_null() {
+            return Err(CocoanutError::InvalidParameter(
+                "Cannot attach a Touch Bar to a null responder".to_string()
+            ));
+        }
+
+        self.release_targets();
+
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            if Class::get("NSTouchBar").is_none() {
+                self.touch_bar_available = false;
+                return Ok(());
+            }
+            self.touch_bar_available = true;
+
+            let template_items: *mut Object = msg_send![class!(NSMutableSet), set];
+            let identifiers: *mut Object = msg_send![class!(NSMutableArray), array];
+
+            for item in &self.current_items {
+                let TouchBarItem::Button { identifier, title, action } = item else {
+                    continue;
+                };
+
+                let ns_identifier = crate::core::utils::string_to_ns_string(identifier)?;
+                let ns_title = crate::core::utils::string_to_ns_string(title)?;
+
+                // The closure lives in `current_items` for as long as the target
+                // is registered; `release_targets` clears the pointer first.
+                let callback: *const (dyn Fn() + Send + Sync) = &**action;
+                let slot = Box::into_raw(Box::new(callback)) as *mut c_void;
+                let target: *mut Object = msg_send![touch_bar_target_class(), new];
+                (*target).set_ivar::<*mut c_void>("rustAction", slot);
+
+                let button: *mut Object = msg_send![class!(NSButton), buttonWithTitle: ns_title
+                                                                               target: target
+                                                                               action: sel!(invoke:)];
+                let button: *mut Object = msg_send![button, retain];
+                self.targets.push(ButtonTarget { button, target });
+                let bar_item: *mut Object = msg_send![class!(NSCustomTouchBarItem), alloc];
+                let bar_item: *mut Object = msg_send![bar_item, initWithIdentifier: ns_identifier];
+                let _: () = msg_send![bar_item, setView: button];
+                let _: () = msg_send![template_items, addObject: bar_item];
+                let _: () = msg_send![bar_item, release];
+                let _: () = msg_send![identifiers, addObject: ns_identifier];
+            }
+
+            let touch_bar: *mut Object = msg_send![class!(NSTouchBar), new];
+            let _: () = msg_send![touch_bar, setTemplateItems: template_items];
+            let _: () = msg_send![touch_bar, setDefaultItemIdentifiers: identifiers];
+            let _: () = msg_send![responder, setTouchBar: touch_bar];
+            let _: () = msg_send![touch_bar, release];
+        }
+
+        Ok(())
+    }
+
+    /// Detach and release the button targets created by `apply`
+    ///
+    /// Each button's target is cleared first, so a Touch Bar that is still
+    /// installed keeps showing the buttons but no longer calls into freed
+    /// targets.
+    fn release_targets(&mut self) {
+        for ButtonTarget { button, target } in self.targets.drain(..) {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                let nil: *mut Object = std::ptr::null_mut();
+                let _: () = msg_send![button, setTarget: nil];
+                let _: () = msg_send![button, release];
+
+                let slot = *(*target).get_ivar::<*mut c_void>("rustAction");
+                (*target).set_ivar::<*mut c_void>("rustAction", std::ptr::null_mut());
+                if !slot.is_null() {
+                    drop(Box::from_raw(slot as *mut *const (dyn Fn() + Send + Sync)));
+                }
+                let _: () = msg_send![target, release];
+            }
+            #[cfg(feature = "test-mock")]
+            let _ = (button, target);
+        }
+    }
+}
+
+impl TouchBarItem {
+    /// Get the identifier of this item
+    pub fn identifier(&self) -> &str {
+        match self {
+            TouchBarItem::Button { identifier, .. } => identifier,
+            TouchBarItem::Slider { identifier, .. } => identifier,
+            TouchBarItem::SegmentedControl { identifier, .. } => identifier,
+        }
+    }
+}
+
+impl Drop for TouchBarManager {
+    fn drop(&mut self) {
+        self.release_targets();
+    }
+}
+
+/// Lazily register the ObjC class used as the target of Touch Bar buttons
+#[cfg(not(feature = "test-mock"))]
+fn touch_bar_target_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutTouchBarTarget", class!(NSObject))
+            .expect("CocoanutTouchBarTarget already registered");
+        decl.add_ivar::<*mut c_void>("rustAction");
+
+        extern "C" fn invoke(this: &Object, _cmd: Sel, _sender: *mut Object) {
+            unsafe {
+                let slot = *this.get_ivar::<*mut c_void>("rustAction") as *const *const (dyn Fn() + Send + Sync);
+                if !slot.is_null() {
+                    (**slot)();
+                }
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(invoke:), invoke as extern "C" fn(&Object, Sel, *mut Object));
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutTouchBarTarget").expect("CocoanutTouchBarTarget not registered")
 }
 
 impl Default for TouchBarManager {
@@ -394,6 +853,7 @@ impl Default for MacOSIntegrationManager {
 pub struct NativeWindow {
     ns_window: *mut Object,
     integration: Arc<MacOSIntegrationManager>,
+    role: AccessibilityRole,
 }
 
 impl NativeWindow {
@@ -404,15 +864,18 @@ impl NativeWindow {
         Ok(Self {
             ns_window: std::ptr::null_mut(),
             integration,
+            role: AccessibilityRole::Window,
         })
     }
     
     /// Apply all macOS integrations to this window
+    ///
+    /// Applies the design language and accessibility settings of the
+    /// integration manager it was created with.
     pub fn apply_integrations(&mut self) -> Result<()> {
-        // Apply design language
-        // Apply accessibility
-        // Apply dark mode
-        // Apply Touch Bar
+        let integration = Arc::clone(&self.integration);
+        self.apply_design_language(integration.design_language())?;
+        self.apply_accessibility(integration.accessibility())?;
         Ok(())
     }
 }
@@ -467,12 +930,27 @@ impl AccessibleComponent for NativeWindow {
         // Set the accessibility hint
         Ok(())
     }
+
+    fn accessibility_role(&self) -> Option<AccessibilityRole> {
+        Some(self.role)
+    }
+
+    fn set_accessibility_role(&mut self, role: AccessibilityRole) -> Result<()> {
+        set_view_accessibility_role(self.ns_window, role)?;
+        self.role = role;
+        Ok(())
+    }
+
+    fn set_accessibility_value(&mut self, value: &str) -> Result<()> {
+        set_view_accessibility_value(self.ns_window, value)
+    }
 }
 
 /// Native macOS button with full integration
 pub struct NativeButton {
     ns_button: *mut Object,
     integration: Arc<MacOSIntegrationManager>,
+    role: AccessibilityRole,
 }
 
 impl NativeButton {
@@ -483,15 +961,18 @@ impl NativeButton {
         Ok(Self {
             ns_button: std::ptr::null_mut(),
             integration,
+            role: AccessibilityRole::Button,
         })
     }
     
     /// Apply all macOS integrations to this button
+    ///
+    /// Uses the design language and accessibility settings of the shared
+    /// integration manager.
     pub fn apply_integrations(&mut self) -> Result<()> {
-        // Apply design language
-        // Apply accessibility
-        // Apply dark mode
-        // Apply Touch Bar
+        let integration = Arc::clone(&self.integration);
+        self.apply_design_language(integration.design_language())?;
+        self.apply_accessibility(integration.accessibility())?;
         Ok(())
     }
 }
@@ -540,6 +1021,20 @@ impl AccessibleComponent for NativeButton {
         // Set the accessibility hint
         Ok(())
     }
+
+    fn accessibility_role(&self) -> Option<AccessibilityRole> {
+        Some(self.role)
+    }
+
+    fn set_accessibility_role(&mut self, role: AccessibilityRole) -> Result<()> {
+        set_view_accessibility_role(self.ns_button, role)?;
+        self.role = role;
+        Ok(())
+    }
+
+    fn set_accessibility_value(&mut self, value: &str) -> Result<()> {
+        set_view_accessibility_value(self.ns_button, value)
+    }
 }
 
 #[cfg(test)]
@@ -567,6 +1062,26 @@ mod tests {
         assert!(!manager.is_large_text_enabled());
     }
     
+    #[test]
+    fn test_accessibility_roles() {
+        assert_eq!(AccessibilityRole::Button.ns_role(), "AXButton");
+        assert_eq!(AccessibilityRole::StaticText.ns_role(), "AXStaticText");
+        assert_eq!(AccessibilityRole::Checkbox.ns_role(), "AXCheckBox");
+
+        let mut button = NativeButton::new(Arc::new(MacOSIntegrationManager::new())).unwrap();
+        assert_eq!(button.accessibility_role(), Some(AccessibilityRole::Button));
+        assert!(button.set_accessibility_role(AccessibilityRole::Link).is_ok());
+        assert_eq!(button.accessibility_role(), Some(AccessibilityRole::Link));
+        assert!(button.set_accessibility_value("on").is_ok());
+        assert!(button.apply_integrations().is_ok());
+
+        let mut window = NativeWindow::new(Arc::new(MacOSIntegrationManager::new())).unwrap();
+        assert_eq!(window.accessibility_role(), Some(AccessibilityRole::Window));
+        window.set_accessibility_role(AccessibilityRole::Group).unwrap();
+        assert_eq!(window.accessibility_role(), Some(AccessibilityRole::Group));
+        assert!(window.apply_integrations().is_ok());
+    }
+
     #[test]
     fn test_dark_mode_manager() {
         let mut manager = DarkModeManager::new();
@@ -574,6 +1089,77 @@ mod tests {
         
         assert_eq!(manager.current_appearance(), Appearance::Dark);
     }
+
+    #[test]
+    fn test_dark_mode_effective_appearance() {
+        let mut manager = DarkModeManager::new();
+        assert_eq!(manager.effective_appearance(), manager.system_appearance());
+
+        manager.set_appearance(Appearance::Dark).unwrap();
+        assert_eq!(manager.effective_appearance(), Appearance::Dark);
+    }
+
+    #[test]
+    fn test_dark_mode_observers_receive_effective_appearance() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut manager = DarkModeManager::new();
+        {
+            let calls = Arc::clone(&calls);
+            let seen = Arc::clone(&seen);
+            manager.add_observer(move |appearance| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                seen.lock().unwrap().push(appearance);
+            });
+        }
+
+        manager.set_appearance(Appearance::Dark).unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(seen.lock().unwrap()[0], Appearance::Dark);
+    }
+
+    #[test]
+    fn test_dark_mode_observer_can_use_manager() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<DarkModeManager>();
+
+        let mut manager = DarkModeManager::new();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        {
+            let seen = Arc::clone(&seen);
+            let state = Arc::downgrade(&manager.state);
+            manager.add_observer(move |_| {
+                // Would deadlock if observers ran with the state locked
+                let state = state.upgrade().unwrap();
+                let current = state.lock().unwrap().current_appearance;
+                seen.lock().unwrap().push(current);
+            });
+        }
+
+        manager.set_appearance(Appearance::Dark).unwrap();
+        assert_eq!(*seen.lock().unwrap(), vec![Appearance::Dark]);
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_dark_mode_system_change_notifies_only_in_automatic() {
+        let manager = DarkModeManager::new();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        {
+            let seen = Arc::clone(&seen);
+            manager.lock_state().observers.push(Arc::new(move |appearance| seen.lock().unwrap().push(appearance)));
+        }
+
+        assert!(manager.lock_state().system_changed(Appearance::Dark));
+        assert!(!manager.lock_state().system_changed(Appearance::Dark));
+
+        manager.lock_state().current_appearance = Appearance::Light;
+        assert!(!manager.lock_state().system_changed(Appearance::Light));
+        assert!(seen.lock().unwrap().is_empty());
+        assert!(!manager.is_observing_system());
+    }
     
     #[test]
     fn test_touch_bar_manager() {
@@ -591,6 +1177,35 @@ mod tests {
         manager.remove_item("test_button").unwrap();
         assert_eq!(manager.items().len(), 0);
     }
+
+    #[test]
+    fn test_touch_bar_perform_action() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let presses = Arc::new(AtomicUsize::new(0));
+        let mut manager = TouchBarManager::new();
+        {
+            let presses = Arc::clone(&presses);
+            manager.add_item(TouchBarItem::Button {
+                identifier: "play".to_string(),
+                title: "Play".to_string(),
+                action: Box::new(move || {
+                    presses.fetch_add(1, Ordering::SeqCst);
+                }),
+            }).unwrap();
+        }
+
+        manager.perform_action("play").unwrap();
+        manager.perform_action("play").unwrap();
+        assert_eq!(presses.load(Ordering::SeqCst), 2);
+        assert!(manager.perform_action("missing").is_err());
+    }
+
+    #[test]
+    fn test_touch_bar_apply_rejects_null_responder() {
+        let mut manager = TouchBarManager::new();
+        assert!(unsafe { manager.apply(std::ptr::null_mut()) }.is_err());
+    }
     
     #[test]
     fn test_macos_integration_manager() {
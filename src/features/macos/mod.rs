@@ -1,6 +1,28 @@
 //! macOS-specific features
+pub mod cursor;
+pub mod dock;
+pub mod haptics;
 pub mod macos_features;
 pub mod macos_integration;
+pub mod notification;
+pub mod pasteboard;
+pub mod preferences;
+pub mod screen;
+pub mod share;
+pub mod sound;
+pub mod speech;
+pub mod status_item;
 
 pub use macos_features::{NativeFeel, DesignStyle, DarkModeManager, Appearance, TouchBarManager, TouchBarItem, ContinuityManager};
-pub use macos_integration::{MacOSIntegrationManager, DesignLanguageManager, AccessibilityManager, DesignLanguageComponent, AccessibleComponent, NativeWindow, NativeButton};
+pub use macos_integration::{MacOSIntegrationManager, DesignLanguageManager, AccessibilityManager, DesignLanguageComponent, AccessibleComponent, AccessibilityRole, NativeWindow, NativeButton};
+pub use cursor::{Cursor, Hoverable};
+pub use dock::Dock;
+pub use haptics::{HapticPattern, Haptics};
+pub use notification::Notification;
+pub use pasteboard::Pasteboard;
+pub use preferences::Preferences;
+pub use screen::Screen;
+pub use share::{ShareItem, ShareMenu};
+pub use sound::Sound;
+pub use speech::Speech;
+pub use status_item::StatusItem;
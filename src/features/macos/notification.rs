@@ -0,0 +1,337 @@
+//! User notifications posted to Notification Center
+//!
+//! Notifications are delivered through `NSUserNotificationCenter`. Click
+//! handlers are kept in a process-wide registry keyed by the notification
+//! identifier and run when the user activates the banner. Handlers of
+//! notifications that left Notification Center unclicked are dropped the
+//! next time a notification is delivered.
+
+use crate::core::error::{CocoanutError, Result};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+#[cfg(not(feature = "test-mock"))]
+use objc::declare::ClassDecl;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Class, Object, Sel, BOOL, YES};
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+#[cfg(not(feature = "test-mock"))]
+use std::sync::Once;
+
+type ClickHandler = Arc<dyn Fn() + Send + Sync>;
+
+/// A user notification shown as a banner in Notification Center
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::features::macos::Notification;
+///
+/// Notification::new("Export finished", "report.pdf was saved")
+///     .subtitle("Documents")
+///     .sound(true)
+///     .on_click(|| println!("clicked"))
+///     .deliver()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct Notification {
+    identifier: String,
+    title: String,
+    body: String,
+    subtitle: Option<String>,
+    sound: bool,
+    on_click: Option<ClickHandler>,
+}
+
+impl Notification {
+    /// Create a new notification with a title and body text
+    pub fn new(title: impl Into<String>, body: impl Into<String>) -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+        Notification {
+            identifier: format!(
+                "cocoanut-{}-{}",
+                std::process::id(),
+                NEXT_ID.fetch_add(1, Ordering::Relaxed)
+            ),
+            title: title.into(),
+            body: body.into(),
+            subtitle: None,
+            sound: false,
+            on_click: None,
+        }
+    }
+
+    /// Set the subtitle shown between the title and body
+    pub fn subtitle(mut self, subtitle: &str) -> Self {
+        self.subtitle = Some(subtitle.to_string());
+        self
+    }
+
+    /// Play the default notification sound on delivery
+    pub fn sound(mut self, enabled: bool) -> Self {
+        self.sound = enabled;
+        self
+    }
+
+    /// Set a handler that runs when the user clicks the banner
+    pub fn on_click<F>(mut self, handler: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.on_click = Some(Arc::new(handler));
+        self
+    }
+
+    /// Get the unique identifier of this notification
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// Get the title
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+
+    /// Get the body text
+    pub fn get_body(&self) -> &str {
+        &self.body
+    }
+
+    /// Get the subtitle
+    pub fn get_subtitle(&self) -> Option<&str> {
+        self.subtitle.as_deref()
+    }
+
+    /// Check if the notification plays a sound
+    pub fn has_sound(&self) -> bool {
+        self.sound
+    }
+
+    /// Post the notification to Notification Center
+    ///
+    /// # Returns
+    ///
+    /// Returns an error if the title is empty or Notification Center is
+    /// unavailable to this process.
+    pub fn deliver(&self) -> Result<()> {
+        if self.title.trim().is_empty() {
+            return Err(CocoanutError::InvalidParameter(
+                "Notification title cannot be empty".to_string()
+            ));
+        }
+
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            use crate::core::utils::string_to_ns_string;
+
+            if Class::get("NSUserNotificationCenter").is_none() {
+                return Err(CocoanutError::SystemError(
+                    "Notification Center is not available".to_string()
+                ));
+            }
+            let center: *mut Object = msg_send![class!(NSUserNotificationCenter), defaultUserNotificationCenter];
+            if center.is_null() {
+                return Err(CocoanutError::SystemError(
+                    "Notification Center is not available to this process".to_string()
+                ));
+            }
+            install_delegate(center);
+            retain_delivered(&mut click_handlers(), &delivered_identifiers(center));
+
+            let notification: *mut Object = msg_send![class!(NSUserNotification), new];
+            if notification.is_null() {
+                return Err(CocoanutError::SystemError(
+                    "Failed to create NSUserNotification".to_string()
+                ));
+            }
+            let _: () = msg_send![notification, setIdentifier: string_to_ns_string(&self.identifier)?];
+            let _: () = msg_send![notification, setTitle: string_to_ns_string(&self.title)?];
+            let _: () = msg_send![notification, setInformativeText: string_to_ns_string(&self.body)?];
+            if let Some(subtitle) = &self.subtitle {
+                let _: () = msg_send![notification, setSubtitle: string_to_ns_string(subtitle)?];
+            }
+            if self.sound {
+                // NSUserNotificationDefaultSoundName
+                let _: () = msg_send![notification, setSoundName: string_to_ns_string("DefaultSoundName")?];
+            }
+
+            let _: () = msg_send![center, deliverNotification: notification];
+            let _: () = msg_send![notification, release];
+        }
+
+        if let Some(handler) = &self.on_click {
+            click_handlers().insert(self.identifier.clone(), Arc::clone(handler));
+        }
+        Ok(())
+    }
+}
+
+/// Click handlers for delivered notifications, keyed by identifier
+fn click_handlers() -> std::sync::MutexGuard<'static, HashMap<String, ClickHandler>> {
+    static HANDLERS: OnceLock<Mutex<HashMap<String, ClickHandler>>> = OnceLock::new();
+    HANDLERS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Drop the click handlers of notifications that are no longer in
+/// Notification Center
+///
+/// `NSUserNotificationCenter` does not report banners dismissed without a
+/// click, so the registry is checked against the delivered notifications
+/// instead.
+#[cfg(any(test, not(feature = "test-mock")))]
+fn retain_delivered(handlers: &mut HashMap<String, ClickHandler>, delivered: &[String]) {
+    handlers.retain(|identifier, _| delivered.contains(identifier));
+}
+
+/// Get the identifiers of the notifications shown in Notification Center
+#[cfg(not(feature = "test-mock"))]
+unsafe fn delivered_identifiers(center: *mut Object) -> Vec<String> {
+    unsafe {
+        let delivered: *mut Object = msg_send![center, deliveredNotifications];
+        if delivered.is_null() {
+            return Vec::new();
+        }
+        let count: usize = msg_send![delivered, count];
+        (0..count)
+            .filter_map(|index| {
+                let notification: *mut Object = msg_send![delivered, objectAtIndex: index];
+                let identifier: *mut Object = msg_send![notification, identifier];
+                crate::core::utils::ns_string_to_string(identifier).ok()
+            })
+            .collect()
+    }
+}
+
+/// Run and forget the click handler for a notification
+///
+/// Returns `false` if no handler was registered for the identifier.
+#[cfg(any(test, not(feature = "test-mock")))]
+fn dispatch_click(identifier: &str) -> bool {
+    // Release the registry lock before running user code
+    let handler = click_handlers().remove(identifier);
+    match handler {
+        Some(handler) => {
+            handler();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Install the Notification Center delegate once per process
+#[cfg(not(feature = "test-mock"))]
+unsafe fn install_delegate(center: *mut Object) {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| unsafe {
+        // The center does not retain its delegate; it lives for the whole process
+        let delegate: *mut Object = msg_send![notification_delegate_class(), new];
+        let _: () = msg_send![center, setDelegate: delegate];
+    });
+}
+
+/// Lazily register the `NSUserNotificationCenterDelegate` class
+#[cfg(not(feature = "test-mock"))]
+fn notification_delegate_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutNotificationDelegate", class!(NSObject))
+            .expect("CocoanutNotificationDelegate already registered");
+
+        extern "C" fn did_activate(_this: &Object, _cmd: Sel, _center: *mut Object, notification: *mut Object) {
+            unsafe {
+                let identifier: *mut Object = msg_send![notification, identifier];
+                if let Ok(identifier) = crate::core::utils::ns_string_to_string(identifier) {
+                    dispatch_click(&identifier);
+                }
+            }
+        }
+
+        extern "C" fn should_present(_this: &Object, _cmd: Sel, _center: *mut Object, _notification: *mut Object) -> BOOL {
+            // Show banners even while the app is frontmost
+            YES
+        }
+
+        unsafe {
+            decl.add_method(
+                sel!(userNotificationCenter:didActivateNotification:),
+                did_activate as extern "C" fn(&Object, Sel, *mut Object, *mut Object),
+            );
+            decl.add_method(
+                sel!(userNotificationCenter:shouldPresentNotification:),
+                should_present as extern "C" fn(&Object, Sel, *mut Object, *mut Object) -> BOOL,
+            );
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutNotificationDelegate").expect("CocoanutNotificationDelegate not registered")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notification_builder() {
+        let notification = Notification::new("Done", "Export finished")
+            .subtitle("report.pdf")
+            .sound(true);
+
+        assert_eq!(notification.get_title(), "Done");
+        assert_eq!(notification.get_body(), "Export finished");
+        assert_eq!(notification.get_subtitle(), Some("report.pdf"));
+        assert!(notification.has_sound());
+    }
+
+    #[test]
+    fn test_notification_identifiers_are_unique() {
+        let first = Notification::new("A", "");
+        let second = Notification::new("B", "");
+        assert_ne!(first.identifier(), second.identifier());
+    }
+
+    #[test]
+    fn test_notification_empty_title_fails() {
+        assert!(Notification::new("  ", "body").deliver().is_err());
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_notification_click_handler() {
+        use std::sync::atomic::AtomicUsize;
+
+        let clicks = Arc::new(AtomicUsize::new(0));
+        let notification = {
+            let clicks = Arc::clone(&clicks);
+            Notification::new("Done", "Click me").on_click(move || {
+                clicks.fetch_add(1, Ordering::SeqCst);
+            })
+        };
+        notification.deliver().unwrap();
+
+        assert!(dispatch_click(notification.identifier()));
+        assert!(!dispatch_click(notification.identifier()));
+        assert_eq!(clicks.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_dismissed_notification_handlers_are_dropped() {
+        let shown = Notification::new("Shown", "");
+        let dismissed = Notification::new("Dismissed", "");
+        let mut handlers: HashMap<String, ClickHandler> = HashMap::new();
+        handlers.insert(shown.identifier().to_string(), Arc::new(|| {}));
+        handlers.insert(dismissed.identifier().to_string(), Arc::new(|| {}));
+
+        retain_delivered(&mut handlers, &[shown.identifier().to_string()]);
+        assert!(handlers.contains_key(shown.identifier()));
+        assert!(!handlers.contains_key(dismissed.identifier()));
+    }
+}
@@ -0,0 +1,222 @@
+//! Clipboard access through `NSPasteboard`
+//!
+//! In test-mock mode the general pasteboard is simulated in memory, one per
+//! thread, so that writes can be read back and the change count still
+//! advances without tests running in parallel seeing each other's contents.
+
+use crate::core::error::Result;
+use std::path::PathBuf;
+
+#[cfg(not(feature = "test-mock"))]
+use crate::core::error::CocoanutError;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Object, BOOL, NO, YES};
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+
+/// Pasteboard type for plain text (`NSPasteboardTypeString`)
+pub const STRING_TYPE: &str = "public.utf8-plain-text";
+
+/// The system pasteboard
+///
+/// Every write clears the pasteboard first, which advances its change count
+/// so other applications see the new contents.
+pub struct Pasteboard {
+    #[cfg(not(feature = "test-mock"))]
+    ns_pasteboard: *mut Object,
+}
+
+impl Pasteboard {
+    /// Get the general pasteboard used for copy and paste
+    pub fn general() -> Self {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_pasteboard: *mut Object = msg_send![class!(NSPasteboard), generalPasteboard];
+            Pasteboard { ns_pasteboard }
+        }
+        #[cfg(feature = "test-mock")]
+        Pasteboard {}
+    }
+
+    /// Get the number of times the pasteboard contents have changed
+    pub fn change_count(&self) -> i64 {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            msg_send![self.ns_pasteboard, changeCount]
+        }
+        #[cfg(feature = "test-mock")]
+        mock::with_contents(|contents| contents.change_count)
+    }
+
+    /// Remove all contents from the pasteboard
+    pub fn clear(&self) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: i64 = msg_send![self.ns_pasteboard, clearContents];
+        }
+        #[cfg(feature = "test-mock")]
+        mock::with_contents(mock::Contents::clear);
+        Ok(())
+    }
+
+    /// Replace the pasteboard contents with a string
+    pub fn write_string(&self, text: &str) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_text = crate::core::utils::string_to_ns_string(text)?;
+            let ns_type = crate::core::utils::string_to_ns_string(STRING_TYPE)?;
+            let _: i64 = msg_send![self.ns_pasteboard, clearContents];
+            let written: BOOL = msg_send![self.ns_pasteboard, setString: ns_text forType: ns_type];
+            if written == NO {
+                return Err(CocoanutError::SystemError(
+                    "Failed to write string to pasteboard".to_string()
+                ));
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        mock::with_contents(|contents| {
+            contents.clear();
+            contents.string = Some(text.to_string());
+        });
+        Ok(())
+    }
+
+    /// Read the string on the pasteboard, if any
+    pub fn read_string(&self) -> Option<String> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_type = crate::core::utils::string_to_ns_string(STRING_TYPE).ok()?;
+            let ns_text: *mut Object = msg_send![self.ns_pasteboard, stringForType: ns_type];
+            if ns_text.is_null() {
+                return None;
+            }
+            crate::core::utils::ns_string_to_string(ns_text).ok()
+        }
+        #[cfg(feature = "test-mock")]
+        mock::with_contents(|contents| contents.string.clone())
+    }
+
+    /// Replace the pasteboard contents with file URLs
+    pub fn write_file_urls(&self, paths: &[PathBuf]) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let urls: *mut Object = msg_send![class!(NSMutableArray), arrayWithCapacity: paths.len()];
+            for path in paths {
+                let ns_path = crate::core::utils::string_to_ns_string(&path.to_string_lossy())?;
+                let url: *mut Object = msg_send![class!(NSURL), fileURLWithPath: ns_path];
+                let _: () = msg_send![urls, addObject: url];
+            }
+            let _: i64 = msg_send![self.ns_pasteboard, clearContents];
+            let written: BOOL = msg_send![self.ns_pasteboard, writeObjects: urls];
+            if written == NO {
+                return Err(CocoanutError::SystemError(
+                    "Failed to write file URLs to pasteboard".to_string()
+                ));
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        mock::with_contents(|contents| {
+            contents.clear();
+            contents.files = paths.to_vec();
+        });
+        Ok(())
+    }
+
+    /// Read the file URLs on the pasteboard as paths
+    pub fn read_file_urls(&self) -> Vec<PathBuf> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let mut paths = Vec::new();
+            let classes: *mut Object = msg_send![class!(NSArray), arrayWithObject: class!(NSURL)];
+            let yes: *mut Object = msg_send![class!(NSNumber), numberWithBool: YES];
+            let Ok(key) = crate::core::utils::string_to_ns_string("NSPasteboardURLReadingFileURLsOnlyKey") else {
+                return paths;
+            };
+            let options: *mut Object = msg_send![class!(NSDictionary), dictionaryWithObject: yes forKey: key];
+            let urls: *mut Object = msg_send![self.ns_pasteboard, readObjectsForClasses: classes options: options];
+            if urls.is_null() {
+                return paths;
+            }
+            let count: usize = msg_send![urls, count];
+            for index in 0..count {
+                let url: *mut Object = msg_send![urls, objectAtIndex: index];
+                let ns_path: *mut Object = msg_send![url, path];
+                if let Ok(path) = crate::core::utils::ns_string_to_string(ns_path) {
+                    paths.push(PathBuf::from(path));
+                }
+            }
+            paths
+        }
+        #[cfg(feature = "test-mock")]
+        mock::with_contents(|contents| contents.files.clone())
+    }
+}
+
+/// In-memory stand-in for the general pasteboard
+#[cfg(feature = "test-mock")]
+mod mock {
+    use std::cell::RefCell;
+    use std::path::PathBuf;
+
+    pub(super) struct Contents {
+        pub(super) change_count: i64,
+        pub(super) string: Option<String>,
+        pub(super) files: Vec<PathBuf>,
+    }
+
+    impl Contents {
+        pub(super) fn clear(&mut self) {
+            self.change_count += 1;
+            self.string = None;
+            self.files.clear();
+        }
+    }
+
+    thread_local! {
+        /// The simulated general pasteboard, per test thread
+        static CONTENTS: RefCell<Contents> = const { RefCell::new(Contents {
+            change_count: 0,
+            string: None,
+            files: Vec::new(),
+        }) };
+    }
+
+    pub(super) fn with_contents<R>(f: impl FnOnce(&mut Contents) -> R) -> R {
+        CONTENTS.with(|contents| f(&mut contents.borrow_mut()))
+    }
+}
+
+#[cfg(all(test, feature = "test-mock"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pasteboard_mock_round_trip() {
+        let pasteboard = Pasteboard::general();
+        let start = pasteboard.change_count();
+
+        pasteboard.write_string("hello").unwrap();
+        assert_eq!(pasteboard.read_string(), Some("hello".to_string()));
+        assert!(pasteboard.read_file_urls().is_empty());
+        assert_eq!(pasteboard.change_count(), start + 1);
+
+        let files = vec![PathBuf::from("/tmp/a.txt"), PathBuf::from("/tmp/b.txt")];
+        pasteboard.write_file_urls(&files).unwrap();
+        assert_eq!(pasteboard.read_file_urls(), files);
+        assert_eq!(pasteboard.read_string(), None);
+        assert_eq!(pasteboard.change_count(), start + 2);
+
+        pasteboard.clear().unwrap();
+        assert!(pasteboard.read_file_urls().is_empty());
+        assert_eq!(pasteboard.change_count(), start + 3);
+    }
+
+    #[test]
+    fn test_pasteboard_mock_shared_within_thread() {
+        Pasteboard::general().write_string("copied").unwrap();
+        assert_eq!(Pasteboard::general().read_string(), Some("copied".to_string()));
+
+        let other = std::thread::spawn(|| Pasteboard::general().read_string()).join().unwrap();
+        assert_eq!(other, None);
+    }
+}
@@ -0,0 +1,289 @@
+//! App settings stored with `NSUserDefaults`
+//!
+//! Values written through [`Preferences`] are saved in the application's
+//! defaults domain and survive relaunches. In test-mock mode the defaults
+//! are kept in an in-memory map shared by all instances.
+
+use crate::core::error::Result;
+
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::Object;
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+
+/// The application's user defaults
+///
+/// Getters for missing keys return the defaults `NSUserDefaults` uses:
+/// `None` for strings, `false` for flags and zero for numbers. Numbers and
+/// flags convert into each other, so a value stored with `set_i64(key, 1)`
+/// reads back as `true` from `get_bool`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::macos_features::Preferences;
+///
+/// # fn main() -> cocoanut::Result<()> {
+/// let preferences = Preferences::standard();
+/// preferences.set_bool("ShowSidebar", true)?;
+/// if preferences.get_bool("ShowSidebar") {
+///     // ...
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Preferences {
+    #[cfg(not(feature = "test-mock"))]
+    ns_defaults: *mut Object,
+}
+
+impl Preferences {
+    /// Get the standard user defaults of the application
+    pub fn standard() -> Self {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_defaults: *mut Object = msg_send![class!(NSUserDefaults), standardUserDefaults];
+            Preferences { ns_defaults }
+        }
+        #[cfg(feature = "test-mock")]
+        Preferences {}
+    }
+
+    /// Store a string
+    pub fn set_string(&self, key: &str, value: &str) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_value = crate::core::utils::string_to_ns_string(value)?;
+            let _: () = msg_send![self.ns_defaults, setObject: ns_value forKey: self.ns_key(key)?];
+        }
+        #[cfg(feature = "test-mock")]
+        mock::set(key, mock::Value::String(value.to_string()));
+        Ok(())
+    }
+
+    /// Read a string, or `None` if the key is missing
+    ///
+    /// Numbers and flags read back as their text, so a value stored with
+    /// `set_i64(key, 42)` reads as `"42"` and `set_bool(key, true)` as
+    /// `"1"`. Other kinds of values, such as arrays, read as `None`.
+    pub fn get_string(&self, key: &str) -> Option<String> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_value: *mut Object = msg_send![self.ns_defaults, stringForKey: self.ns_key(key).ok()?];
+            if ns_value.is_null() {
+                return None;
+            }
+            crate::core::utils::ns_string_to_string(ns_value).ok()
+        }
+        #[cfg(feature = "test-mock")]
+        match mock::get(key)? {
+            mock::Value::String(value) => Some(value),
+            mock::Value::Bool(value) => Some((value as i64).to_string()),
+            mock::Value::Int(value) => Some(value.to_string()),
+            mock::Value::Float(value) => Some(value.to_string()),
+        }
+    }
+
+    /// Store a flag
+    pub fn set_bool(&self, key: &str, value: bool) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_defaults, setBool: value forKey: self.ns_key(key)?];
+        }
+        #[cfg(feature = "test-mock")]
+        mock::set(key, mock::Value::Bool(value));
+        Ok(())
+    }
+
+    /// Read a flag, or `false` if the key is missing
+    pub fn get_bool(&self, key: &str) -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let Ok(ns_key) = self.ns_key(key) else {
+                return false;
+            };
+            msg_send![self.ns_defaults, boolForKey: ns_key]
+        }
+        #[cfg(feature = "test-mock")]
+        match mock::get(key) {
+            Some(mock::Value::Bool(value)) => value,
+            Some(mock::Value::Int(value)) => value != 0,
+            Some(mock::Value::Float(value)) => value != 0.0,
+            _ => false,
+        }
+    }
+
+    /// Store an integer
+    pub fn set_i64(&self, key: &str, value: i64) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_defaults, setInteger: value forKey: self.ns_key(key)?];
+        }
+        #[cfg(feature = "test-mock")]
+        mock::set(key, mock::Value::Int(value));
+        Ok(())
+    }
+
+    /// Read an integer, or 0 if the key is missing
+    ///
+    /// Floating-point values are truncated.
+    pub fn get_i64(&self, key: &str) -> i64 {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let Ok(ns_key) = self.ns_key(key) else {
+                return 0;
+            };
+            msg_send![self.ns_defaults, integerForKey: ns_key]
+        }
+        #[cfg(feature = "test-mock")]
+        match mock::get(key) {
+            Some(mock::Value::Int(value)) => value,
+            Some(mock::Value::Float(value)) => value as i64,
+            Some(mock::Value::Bool(value)) => value as i64,
+            _ => 0,
+        }
+    }
+
+    /// Store a floating-point number
+    pub fn set_f64(&self, key: &str, value: f64) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_defaults, setDouble: value forKey: self.ns_key(key)?];
+        }
+        #[cfg(feature = "test-mock")]
+        mock::set(key, mock::Value::Float(value));
+        Ok(())
+    }
+
+    /// Read a floating-point number, or 0.0 if the key is missing
+    pub fn get_f64(&self, key: &str) -> f64 {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let Ok(ns_key) = self.ns_key(key) else {
+                return 0.0;
+            };
+            msg_send![self.ns_defaults, doubleForKey: ns_key]
+        }
+        #[cfg(feature = "test-mock")]
+        match mock::get(key) {
+            Some(mock::Value::Float(value)) => value,
+            Some(mock::Value::Int(value)) => value as f64,
+            Some(mock::Value::Bool(value)) => value as i64 as f64,
+            _ => 0.0,
+        }
+    }
+
+    /// Check if a value is stored under `key`
+    pub fn contains(&self, key: &str) -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let Ok(ns_key) = self.ns_key(key) else {
+                return false;
+            };
+            let ns_value: *mut Object = msg_send![self.ns_defaults, objectForKey: ns_key];
+            !ns_value.is_null()
+        }
+        #[cfg(feature = "test-mock")]
+        mock::get(key).is_some()
+    }
+
+    /// Remove the value stored under `key`, so getters return their defaults
+    pub fn remove(&self, key: &str) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_defaults, removeObjectForKey: self.ns_key(key)?];
+        }
+        #[cfg(feature = "test-mock")]
+        mock::remove(key);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    fn ns_key(&self, key: &str) -> Result<*mut Object> {
+        crate::core::utils::string_to_ns_string(key)
+    }
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+unsafe impl Send for Preferences {}
+unsafe impl Sync for Preferences {}
+
+/// In-memory stand-in for the user defaults
+#[cfg(feature = "test-mock")]
+mod mock {
+    use std::collections::HashMap;
+    use std::sync::{Mutex, MutexGuard, OnceLock};
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub(super) enum Value {
+        String(String),
+        Bool(bool),
+        Int(i64),
+        Float(f64),
+    }
+
+    fn values() -> MutexGuard<'static, HashMap<String, Value>> {
+        static VALUES: OnceLock<Mutex<HashMap<String, Value>>> = OnceLock::new();
+        VALUES
+            .get_or_init(|| Mutex::new(HashMap::new()))
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(super) fn set(key: &str, value: Value) {
+        values().insert(key.to_string(), value);
+    }
+
+    pub(super) fn get(key: &str) -> Option<Value> {
+        values().get(key).cloned()
+    }
+
+    pub(super) fn remove(key: &str) {
+        values().remove(key);
+    }
+}
+
+#[cfg(all(test, feature = "test-mock"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_each_type() {
+        let preferences = Preferences::standard();
+        preferences.set_string("test.name", "Cocoanut").unwrap();
+        preferences.set_bool("test.enabled", true).unwrap();
+        preferences.set_i64("test.count", -42).unwrap();
+        preferences.set_f64("test.zoom", 1.25).unwrap();
+
+        assert_eq!(preferences.get_string("test.name"), Some("Cocoanut".to_string()));
+        assert!(preferences.get_bool("test.enabled"));
+        assert_eq!(preferences.get_i64("test.count"), -42);
+        assert_eq!(preferences.get_f64("test.zoom"), 1.25);
+
+        // Numbers read back as other numeric kinds and as their text
+        assert_eq!(preferences.get_f64("test.count"), -42.0);
+        assert_eq!(preferences.get_i64("test.zoom"), 1);
+        assert_eq!(preferences.get_string("test.count"), Some("-42".to_string()));
+        assert_eq!(preferences.get_string("test.zoom"), Some("1.25".to_string()));
+        assert_eq!(preferences.get_string("test.enabled"), Some("1".to_string()));
+    }
+
+    #[test]
+    fn test_missing_keys_use_defaults() {
+        let preferences = Preferences::standard();
+        preferences.set_i64("test.removed", 7).unwrap();
+        assert!(preferences.contains("test.removed"));
+        preferences.remove("test.removed").unwrap();
+
+        assert!(!preferences.contains("test.removed"));
+        assert_eq!(preferences.get_string("test.removed"), None);
+        assert!(!preferences.get_bool("test.removed"));
+        assert_eq!(preferences.get_i64("test.removed"), 0);
+        assert_eq!(preferences.get_f64("test.removed"), 0.0);
+    }
+}
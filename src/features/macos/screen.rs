@@ -0,0 +1,173 @@
+//! Display enumeration for placing windows on a specific screen
+//!
+//! A [`Screen`] is a snapshot of an `NSScreen`'s geometry taken when it was
+//! looked up. In test-mock mode there is a single 1440×900 screen.
+
+use crate::features::drawing::Rect;
+#[cfg(any(test, not(feature = "test-mock")))]
+use crate::features::drawing::{Point, Size};
+
+#[cfg(not(feature = "test-mock"))]
+use crate::features::zero_cost::ZeroCostRect;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::Object;
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+
+/// A display attached to the Mac
+///
+/// Frames are in global screen coordinates, with the origin at the bottom
+/// left of the primary display.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Screen {
+    frame: Rect,
+    visible_frame: Rect,
+}
+
+impl Screen {
+    /// All connected screens, the primary display first
+    pub fn all() -> Vec<Screen> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let screens: *mut Object = msg_send![class!(NSScreen), screens];
+            if screens.is_null() {
+                return Vec::new();
+            }
+            let count: usize = msg_send![screens, count];
+            (0..count)
+                .map(|index| {
+                    let ns_screen: *mut Object = msg_send![screens, objectAtIndex: index];
+                    Screen::from_ns_screen(ns_screen)
+                })
+                .collect()
+        }
+
+        #[cfg(feature = "test-mock")]
+        vec![Screen::mock()]
+    }
+
+    /// The screen containing the key window, or the primary display
+    ///
+    /// Returns `None` when no display is attached.
+    pub fn main() -> Option<Screen> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_screen: *mut Object = msg_send![class!(NSScreen), mainScreen];
+            if ns_screen.is_null() {
+                return None;
+            }
+            Some(Screen::from_ns_screen(ns_screen))
+        }
+
+        #[cfg(feature = "test-mock")]
+        Some(Screen::mock())
+    }
+
+    /// The full bounds of the screen
+    pub fn frame(&self) -> Rect {
+        self.frame
+    }
+
+    /// The bounds available to windows, excluding the menu bar and Dock
+    pub fn visible_frame(&self) -> Rect {
+        self.visible_frame
+    }
+
+    /// Where a window frame ends up when moved from `from` to this screen
+    ///
+    /// The window keeps its offset from the top-left of the visible frame,
+    /// then is pulled back inside the visible frame if it would overhang.
+    #[cfg(any(test, not(feature = "test-mock")))]
+    pub(crate) fn place(&self, window: Rect, from: Option<Rect>) -> Rect {
+        let to = self.visible_frame;
+        let (dx, dy) = match from {
+            Some(from) => (window.min_x() - from.min_x(), from.max_y() - window.max_y()),
+            None => (0.0, 0.0),
+        };
+        let top = to.max_y() - window.size.height;
+        let x = (to.min_x() + dx).min(to.max_x() - window.size.width).max(to.min_x());
+        let y = (top - dy).max(to.min_y()).min(top);
+        Rect::new(Point::new(x, y), window.size)
+    }
+
+    /// The frame of a window of `size` centered in the visible frame
+    #[cfg(any(test, not(feature = "test-mock")))]
+    pub(crate) fn centered(&self, size: Size) -> Rect {
+        let to = self.visible_frame;
+        Rect::new(
+            Point::new(
+                to.min_x() + (to.size.width - size.width) / 2.0,
+                to.min_y() + (to.size.height - size.height) / 2.0,
+            ),
+            size,
+        )
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    unsafe fn from_ns_screen(ns_screen: *mut Object) -> Screen {
+        use cocoa::foundation::NSRect;
+
+        unsafe {
+            let frame: NSRect = msg_send![ns_screen, frame];
+            let visible_frame: NSRect = msg_send![ns_screen, visibleFrame];
+            Screen {
+                frame: ZeroCostRect::from(frame).into(),
+                visible_frame: ZeroCostRect::from(visible_frame).into(),
+            }
+        }
+    }
+
+    #[cfg(feature = "test-mock")]
+    fn mock() -> Screen {
+        Screen {
+            frame: Rect::from_xywh(0.0, 0.0, 1440.0, 900.0),
+            // Menu bar at the top, Dock at the bottom
+            visible_frame: Rect::from_xywh(0.0, 70.0, 1440.0, 805.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen(visible_frame: Rect) -> Screen {
+        Screen { frame: visible_frame, visible_frame }
+    }
+
+    #[test]
+    fn test_screen_all_returns_a_screen() {
+        let screens = Screen::all();
+        assert!(!screens.is_empty());
+        for screen in screens {
+            assert!(screen.frame().size.width > 0.0);
+            assert!(screen.visible_frame().size.height <= screen.frame().size.height);
+        }
+    }
+
+    #[test]
+    fn test_place_keeps_offset_from_top_left() {
+        let primary = screen(Rect::from_xywh(0.0, 0.0, 1000.0, 800.0));
+        let secondary = screen(Rect::from_xywh(1000.0, 100.0, 1200.0, 900.0));
+        let window = Rect::from_xywh(50.0, 500.0, 300.0, 200.0);
+
+        let placed = secondary.place(window, Some(primary.visible_frame()));
+        assert_eq!(placed, Rect::from_xywh(1050.0, 700.0, 300.0, 200.0));
+    }
+
+    #[test]
+    fn test_place_keeps_window_on_screen() {
+        let small = screen(Rect::from_xywh(0.0, 0.0, 400.0, 300.0));
+        let window = Rect::from_xywh(2000.0, -500.0, 300.0, 200.0);
+
+        let placed = small.place(window, Some(Rect::from_xywh(0.0, 0.0, 3000.0, 2000.0)));
+        assert_eq!(placed, Rect::from_xywh(100.0, 0.0, 300.0, 200.0));
+    }
+
+    #[test]
+    fn test_centered() {
+        let display = screen(Rect::from_xywh(100.0, 50.0, 1000.0, 800.0));
+        let centered = display.centered(Size::new(400.0, 200.0));
+        assert_eq!(centered, Rect::from_xywh(400.0, 350.0, 400.0, 200.0));
+    }
+}
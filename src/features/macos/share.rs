@@ -0,0 +1,220 @@
+//! The system share sheet through `NSSharingServicePicker`
+//!
+//! A [`ShareMenu`] holds the items to share and shows the picker of sharing
+//! services (Mail, Messages, AirDrop, ...) next to a view, usually a "Share"
+//! button.
+
+use crate::components::basic::Button;
+use crate::core::error::{CocoanutError, Result};
+use crate::core::traits::Drawable;
+use objc::runtime::Object;
+use std::path::PathBuf;
+
+#[cfg(not(feature = "test-mock"))]
+use objc::declare::ClassDecl;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Class, Sel};
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+#[cfg(not(feature = "test-mock"))]
+use std::sync::Once;
+
+/// Something to share
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShareItem {
+    /// Plain text
+    Text(String),
+    /// A web address, such as `https://example.com`
+    Url(String),
+    /// A file on disk
+    File(PathBuf),
+}
+
+impl ShareItem {
+    /// Create the `NSString` or `NSURL` the picker shares
+    #[cfg(not(feature = "test-mock"))]
+    unsafe fn to_ns_object(&self) -> Result<*mut Object> {
+        unsafe {
+            match self {
+                ShareItem::Text(text) => crate::core::utils::string_to_ns_string(text),
+                ShareItem::Url(url) => {
+                    let ns_url: *mut Object = msg_send![class!(NSURL), URLWithString: crate::core::utils::string_to_ns_string(url)?];
+                    if ns_url.is_null() {
+                        return Err(CocoanutError::InvalidParameter(format!("'{}' is not a valid URL", url)));
+                    }
+                    Ok(ns_url)
+                }
+                ShareItem::File(path) => {
+                    let ns_path = crate::core::utils::string_to_ns_string(&path.to_string_lossy())?;
+                    Ok(msg_send![class!(NSURL), fileURLWithPath: ns_path])
+                }
+            }
+        }
+    }
+}
+
+/// A menu of the services that can share a set of items
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::prelude::*;
+/// use cocoanut::macos_features::{ShareItem, ShareMenu};
+///
+/// # fn main() -> cocoanut::Result<()> {
+/// let mut share = Button::new("Share")?;
+/// ShareMenu::for_items(&[
+///     ShareItem::Text("Check this out".to_string()),
+///     ShareItem::Url("https://example.com".to_string()),
+/// ])?
+/// .attach_to(&mut share);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ShareMenu {
+    items: Vec<ShareItem>,
+}
+
+impl ShareMenu {
+    /// Create a share menu for `items`, which must not be empty
+    ///
+    /// Fails if a [`ShareItem::Url`] has no scheme or contains whitespace.
+    pub fn for_items(items: &[ShareItem]) -> Result<Self> {
+        if items.is_empty() {
+            return Err(CocoanutError::InvalidParameter("A share menu needs at least one item".to_string()));
+        }
+        for item in items {
+            if let ShareItem::Url(url) = item {
+                crate::features::advanced_views::validate_url(url)?;
+            }
+        }
+        Ok(ShareMenu { items: items.to_vec() })
+    }
+
+    /// Get the items to share
+    pub fn items(&self) -> &[ShareItem] {
+        &self.items
+    }
+
+    /// Show the share menu below `anchor`
+    pub fn show(&self, anchor: &dyn Drawable) -> Result<()> {
+        show_picker(&self.items, anchor.as_view())
+    }
+
+    /// Show the share menu below `button` whenever it is clicked
+    ///
+    /// Replaces the button's click handler. Errors showing the menu are
+    /// logged, since there is no caller to return them to.
+    pub fn attach_to(self, button: &mut Button) {
+        // Pointers are not `Send`; the handler only runs on the main thread
+        let anchor = button.as_view() as usize;
+        button.on_click(move || {
+            if let Err(error) = show_picker(&self.items, anchor as *mut Object) {
+                crate::core::utils::log_error(&format!("Failed to show the share menu: {}", error));
+            }
+        });
+    }
+}
+
+/// Show an `NSSharingServicePicker` for `items` below `anchor`
+fn show_picker(items: &[ShareItem], anchor: *mut Object) -> Result<()> {
+    #[cfg(not(feature = "test-mock"))]
+    unsafe {
+        use cocoa::foundation::NSRect;
+
+        if anchor.is_null() {
+            return Err(CocoanutError::ObjcNull("share menu anchor"));
+        }
+        let ns_items: *mut Object = msg_send![class!(NSMutableArray), arrayWithCapacity: items.len()];
+        for item in items {
+            let _: () = msg_send![ns_items, addObject: item.to_ns_object()?];
+        }
+        let picker: *mut Object = msg_send![class!(NSSharingServicePicker), alloc];
+        let picker: *mut Object = msg_send![picker, initWithItems: ns_items];
+        if picker.is_null() {
+            return Err(CocoanutError::ControlCreationFailed("Failed to create NSSharingServicePicker".to_string()));
+        }
+        // The delegate owns the picker, and itself, until a service is
+        // chosen or the menu is dismissed
+        let delegate: *mut Object = msg_send![share_picker_delegate_class(), new];
+        (*delegate).set_ivar::<*mut Object>("picker", picker);
+        let _: () = msg_send![picker, setDelegate: delegate];
+        let bounds: NSRect = msg_send![anchor, bounds];
+        // NSMinYEdge
+        let _: () = msg_send![picker, showRelativeToRect: bounds ofView: anchor preferredEdge: 1u64];
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = (items, anchor);
+    Ok(())
+}
+
+/// Lazily register the `NSSharingServicePickerDelegate` class that
+/// releases a picker once it is done
+#[cfg(not(feature = "test-mock"))]
+fn share_picker_delegate_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutSharePickerDelegate", class!(NSObject))
+            .expect("CocoanutSharePickerDelegate already registered");
+        decl.add_ivar::<*mut Object>("picker");
+
+        // AppKit passes a nil service when the menu is dismissed
+        extern "C" fn did_choose_service(this: &mut Object, _cmd: Sel, _picker: *mut Object, _service: *mut Object) {
+            unsafe {
+                let picker = *this.get_ivar::<*mut Object>("picker");
+                if picker.is_null() {
+                    return;
+                }
+                this.set_ivar::<*mut Object>("picker", std::ptr::null_mut());
+                let nil: *mut Object = std::ptr::null_mut();
+                let _: () = msg_send![picker, setDelegate: nil];
+                // The picker is still calling us, so release it and the
+                // delegate once control returns to the run loop
+                let _: () = msg_send![picker, autorelease];
+                let _: () = msg_send![this, autorelease];
+            }
+        }
+
+        unsafe {
+            decl.add_method(
+                sel!(sharingServicePicker:didChooseSharingService:),
+                did_choose_service as extern "C" fn(&mut Object, Sel, *mut Object, *mut Object),
+            );
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutSharePickerDelegate").expect("CocoanutSharePickerDelegate not registered")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_share_menu_for_text_and_url() {
+        let menu = ShareMenu::for_items(&[
+            ShareItem::Text("Release notes".to_string()),
+            ShareItem::Url("https://example.com/notes".to_string()),
+        ])
+        .unwrap();
+        assert_eq!(menu.items().len(), 2);
+        assert_eq!(menu.items()[0], ShareItem::Text("Release notes".to_string()));
+        assert!(matches!(&menu.items()[1], ShareItem::Url(url) if url == "https://example.com/notes"));
+
+        assert!(ShareMenu::for_items(&[]).is_err());
+        assert!(ShareMenu::for_items(&[ShareItem::Url("example dot com".to_string())]).is_err());
+        assert!(ShareMenu::for_items(&[ShareItem::Url(String::new())]).is_err());
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_share_menu_attaches_to_button() {
+        let menu = ShareMenu::for_items(&[ShareItem::File(PathBuf::from("/tmp/report.pdf"))]).unwrap();
+        let mut button = Button::new("Share").unwrap();
+        menu.attach_to(&mut button);
+        button.perform_click();
+    }
+}
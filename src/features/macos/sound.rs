@@ -0,0 +1,239 @@
+//! Short sounds for UI feedback through `NSSound`
+//!
+//! In test-mock mode nothing plays; named sounds are checked against
+//! [`SYSTEM_SOUNDS`] and files against the file system.
+
+use crate::core::error::{CocoanutError, Result};
+use std::path::Path;
+
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::Object;
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+
+/// Names of the sounds macOS ships in `/System/Library/Sounds`
+pub const SYSTEM_SOUNDS: [&str; 14] = [
+    "Basso", "Blow", "Bottle", "Frog", "Funk", "Glass", "Hero",
+    "Morse", "Ping", "Pop", "Purr", "Sosumi", "Submarine", "Tink",
+];
+
+/// A sound that can be played, such as a system alert sound
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::macos_features::Sound;
+///
+/// # fn main() -> cocoanut::Result<()> {
+/// let mut error = Sound::named("Funk")?;
+/// error.set_volume(0.5)?;
+/// error.play()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Sound {
+    #[cfg(not(feature = "test-mock"))]
+    ns_sound: *mut Object,
+    volume: f32,
+    loops: bool,
+    /// Mock builds have no sound to ask
+    #[cfg(feature = "test-mock")]
+    playing: bool,
+}
+
+impl Sound {
+    /// Load a sound by name, such as one of [`SYSTEM_SOUNDS`] or a sound in
+    /// the app bundle
+    ///
+    /// Returns `CocoanutError::InvalidParameter` if there is no such sound.
+    pub fn named(name: &str) -> Result<Self> {
+        let missing = || CocoanutError::InvalidParameter(format!("No sound named '{}'", name));
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_name = crate::core::utils::string_to_ns_string(name)?;
+            let shared: *mut Object = msg_send![class!(NSSound), soundNamed: ns_name];
+            if shared.is_null() {
+                return Err(missing());
+            }
+            // Copy the shared instance so volume and looping stay our own
+            let ns_sound: *mut Object = msg_send![shared, copy];
+            Ok(Sound::from_ns_sound(ns_sound))
+        }
+        #[cfg(feature = "test-mock")]
+        {
+            if !SYSTEM_SOUNDS.contains(&name) {
+                return Err(missing());
+            }
+            Ok(Sound::mock())
+        }
+    }
+
+    /// Load a sound file, such as an AIFF, WAV or MP3
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.is_file() {
+            return Err(CocoanutError::FileNotFound(path.to_path_buf()));
+        }
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_path = crate::core::utils::string_to_ns_string(&path.to_string_lossy())?;
+            let ns_sound: *mut Object = msg_send![class!(NSSound), alloc];
+            let ns_sound: *mut Object = msg_send![ns_sound, initWithContentsOfFile: ns_path byReference: true];
+            if ns_sound.is_null() {
+                return Err(CocoanutError::InvalidParameter(
+                    format!("'{}' is not a sound file", path.display())
+                ));
+            }
+            Ok(Sound::from_ns_sound(ns_sound))
+        }
+        #[cfg(feature = "test-mock")]
+        Ok(Sound::mock())
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    fn from_ns_sound(ns_sound: *mut Object) -> Self {
+        Sound { ns_sound, volume: 1.0, loops: false }
+    }
+
+    #[cfg(feature = "test-mock")]
+    fn mock() -> Self {
+        Sound { volume: 1.0, loops: false, playing: false }
+    }
+
+    /// Start playing from the current position
+    ///
+    /// Playing a sound that is already playing does nothing.
+    pub fn play(&mut self) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let started: bool = msg_send![self.ns_sound, play];
+            if !started && !self.is_playing() {
+                return Err(CocoanutError::SystemError("Sound could not be played".to_string()));
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        {
+            self.playing = true;
+        }
+        Ok(())
+    }
+
+    /// Stop playing and rewind to the start
+    pub fn stop(&mut self) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: bool = msg_send![self.ns_sound, stop];
+        }
+        #[cfg(feature = "test-mock")]
+        {
+            self.playing = false;
+        }
+        Ok(())
+    }
+
+    /// Check if the sound is playing
+    pub fn is_playing(&self) -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            msg_send![self.ns_sound, isPlaying]
+        }
+        #[cfg(feature = "test-mock")]
+        self.playing
+    }
+
+    /// Get the volume
+    pub fn volume(&self) -> f32 {
+        self.volume
+    }
+
+    /// Set the volume, from 0.0 (silent) to 1.0 (full)
+    pub fn set_volume(&mut self, volume: f32) -> Result<()> {
+        if !(0.0..=1.0).contains(&volume) {
+            return Err(CocoanutError::InvalidParameter(
+                format!("Volume {} must be between 0.0 and 1.0", volume)
+            ));
+        }
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_sound, setVolume: volume];
+        }
+        self.volume = volume;
+        Ok(())
+    }
+
+    /// Check if the sound starts over when it ends
+    pub fn loops(&self) -> bool {
+        self.loops
+    }
+
+    /// Set whether the sound starts over when it ends, until stopped
+    pub fn set_loops(&mut self, loops: bool) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_sound, setLoops: loops];
+        }
+        self.loops = loops;
+        Ok(())
+    }
+
+    /// Play the user's alert sound, as for an invalid action
+    pub fn beep() {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![class!(NSSound), beep];
+        }
+    }
+}
+
+impl Drop for Sound {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: bool = msg_send![self.ns_sound, stop];
+            let _: () = msg_send![self.ns_sound, release];
+        }
+    }
+}
+
+unsafe impl Send for Sound {}
+unsafe impl Sync for Sound {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_system_sound() {
+        let sound = Sound::named("Glass");
+        assert!(sound.is_ok());
+        assert!(matches!(Sound::named("NoSuchSound"), Err(CocoanutError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn test_sound_from_missing_file() {
+        let result = Sound::from_file("/nonexistent/alert.aiff");
+        assert!(matches!(result, Err(CocoanutError::FileNotFound(_))));
+    }
+
+    #[test]
+    fn test_sound_volume_and_loops() {
+        let mut sound = Sound::named("Funk").unwrap();
+        assert_eq!(sound.volume(), 1.0);
+        sound.set_volume(0.25).unwrap();
+        assert!(sound.set_volume(1.5).is_err());
+        assert_eq!(sound.volume(), 0.25);
+
+        sound.set_loops(true).unwrap();
+        assert!(sound.loops());
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_sound_play_and_stop() {
+        let mut sound = Sound::named("Tink").unwrap();
+        sound.play().unwrap();
+        assert!(sound.is_playing());
+        sound.stop().unwrap();
+        assert!(!sound.is_playing());
+    }
+}
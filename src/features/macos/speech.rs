@@ -0,0 +1,280 @@
+//! Reading text aloud through `NSSpeechSynthesizer`
+//!
+//! In test-mock mode nothing is spoken: [`Speech::speak`] finishes at once,
+//! calling the `on_finish` handler, and there are no voices.
+
+use crate::core::error::{CocoanutError, Result};
+use std::sync::{Arc, Mutex};
+
+#[cfg(not(feature = "test-mock"))]
+use objc::declare::ClassDecl;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Class, Object, Sel, BOOL, YES};
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+#[cfg(not(feature = "test-mock"))]
+use std::ffi::c_void;
+#[cfg(not(feature = "test-mock"))]
+use std::sync::Once;
+
+/// Handler run when speech ends, with `true` if it was not stopped early
+pub type SpeechFinishHandler = Arc<dyn Fn(bool) + Send + Sync>;
+
+/// The finish handler, shared between a `Speech` and its delegate
+type FinishSlot = Arc<Mutex<Option<SpeechFinishHandler>>>;
+
+/// A speech synthesizer
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::macos_features::Speech;
+///
+/// # fn main() -> cocoanut::Result<()> {
+/// let mut speech = Speech::new()?.rate(200.0)?;
+/// speech.on_finish(|completed| println!("done speaking: {}", completed));
+/// speech.speak("Your export is ready")?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Speech {
+    #[cfg(not(feature = "test-mock"))]
+    synthesizer: *mut Object,
+    #[cfg(not(feature = "test-mock"))]
+    delegate: *mut Object,
+    voice: Option<String>,
+    rate: Option<f32>,
+    on_finish: FinishSlot,
+}
+
+impl Speech {
+    /// Create a synthesizer using the user's default voice
+    pub fn new() -> Result<Self> {
+        let on_finish: FinishSlot = Arc::new(Mutex::new(None));
+
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let synthesizer: *mut Object = msg_send![class!(NSSpeechSynthesizer), alloc];
+            let nil: *mut Object = std::ptr::null_mut();
+            let synthesizer: *mut Object = msg_send![synthesizer, initWithVoice: nil];
+            if synthesizer.is_null() {
+                return Err(CocoanutError::SystemError("Failed to create NSSpeechSynthesizer".to_string()));
+            }
+            let slot = Box::into_raw(Box::new(Arc::clone(&on_finish))) as *mut c_void;
+            let delegate: *mut Object = msg_send![speech_delegate_class(), new];
+            (*delegate).set_ivar::<*mut c_void>("rustHandler", slot);
+            let _: () = msg_send![synthesizer, setDelegate: delegate];
+            Ok(Speech { synthesizer, delegate, voice: None, rate: None, on_finish })
+        }
+        #[cfg(feature = "test-mock")]
+        Ok(Speech { voice: None, rate: None, on_finish })
+    }
+
+    /// Speak with the voice identified by `voice`, one of
+    /// [`Speech::available_voices`]
+    pub fn voice(mut self, voice: &str) -> Result<Self> {
+        if voice.is_empty() {
+            return Err(CocoanutError::InvalidParameter("Voice identifier is empty".to_string()));
+        }
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_voice = crate::core::utils::string_to_ns_string(voice)?;
+            let accepted: BOOL = msg_send![self.synthesizer, setVoice: ns_voice];
+            if accepted != YES {
+                return Err(CocoanutError::InvalidParameter(format!("Unknown voice '{}'", voice)));
+            }
+        }
+        self.voice = Some(voice.to_string());
+        Ok(self)
+    }
+
+    /// Speak at `words_per_minute`; typical speech is 180 to 220
+    pub fn rate(mut self, words_per_minute: f32) -> Result<Self> {
+        if !words_per_minute.is_finite() || words_per_minute <= 0.0 {
+            return Err(CocoanutError::InvalidParameter(
+                format!("Speech rate {} must be a positive number of words per minute", words_per_minute)
+            ));
+        }
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.synthesizer, setRate: words_per_minute];
+        }
+        self.rate = Some(words_per_minute);
+        Ok(self)
+    }
+
+    /// Get the voice set with [`Speech::voice`], if any
+    pub fn voice_identifier(&self) -> Option<&str> {
+        self.voice.as_deref()
+    }
+
+    /// Get the rate set with [`Speech::rate`], if any
+    pub fn words_per_minute(&self) -> Option<f32> {
+        self.rate
+    }
+
+    /// Start reading `text` aloud, interrupting anything being spoken
+    pub fn speak(&self, text: &str) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_text = crate::core::utils::string_to_ns_string(text)?;
+            let started: BOOL = msg_send![self.synthesizer, startSpeakingString: ns_text];
+            if started != YES {
+                return Err(CocoanutError::SystemError("Speech synthesis could not start".to_string()));
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        {
+            let _ = text;
+            notify_finished(&self.on_finish, true);
+        }
+        Ok(())
+    }
+
+    /// Stop speaking at once
+    pub fn stop(&self) {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.synthesizer, stopSpeaking];
+        }
+    }
+
+    /// Check if the synthesizer is speaking
+    pub fn is_speaking(&self) -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let speaking: BOOL = msg_send![self.synthesizer, isSpeaking];
+            speaking == YES
+        }
+        #[cfg(feature = "test-mock")]
+        false
+    }
+
+    /// Run `handler` whenever speech ends, with `true` if it finished
+    /// rather than being stopped
+    pub fn on_finish<F>(&mut self, handler: F)
+    where
+        F: Fn(bool) + Send + Sync + 'static,
+    {
+        *self.on_finish.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Arc::new(handler));
+    }
+
+    /// Get the identifiers of the voices installed on the system
+    pub fn available_voices() -> Vec<String> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let voices: *mut Object = msg_send![class!(NSSpeechSynthesizer), availableVoices];
+            if voices.is_null() {
+                return Vec::new();
+            }
+            let count: usize = msg_send![voices, count];
+            (0..count)
+                .filter_map(|index| {
+                    let voice: *mut Object = msg_send![voices, objectAtIndex: index];
+                    crate::core::utils::ns_string_to_string(voice).ok()
+                })
+                .collect()
+        }
+        #[cfg(feature = "test-mock")]
+        Vec::new()
+    }
+}
+
+impl Drop for Speech {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let nil: *mut Object = std::ptr::null_mut();
+            let _: () = msg_send![self.synthesizer, setDelegate: nil];
+            let _: () = msg_send![self.synthesizer, stopSpeaking];
+            let slot = *(*self.delegate).get_ivar::<*mut c_void>("rustHandler");
+            (*self.delegate).set_ivar::<*mut c_void>("rustHandler", std::ptr::null_mut());
+            if !slot.is_null() {
+                drop(Box::from_raw(slot as *mut FinishSlot));
+            }
+            let _: () = msg_send![self.delegate, release];
+            let _: () = msg_send![self.synthesizer, release];
+        }
+    }
+}
+
+unsafe impl Send for Speech {}
+unsafe impl Sync for Speech {}
+
+/// Run the finish handler in `slot`, if any, without holding its lock
+fn notify_finished(slot: &FinishSlot, completed: bool) {
+    let handler = slot.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone();
+    if let Some(handler) = handler {
+        handler(completed);
+    }
+}
+
+/// Lazily register the `NSSpeechSynthesizerDelegate` class reporting the
+/// end of speech
+#[cfg(not(feature = "test-mock"))]
+fn speech_delegate_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutSpeechDelegate", class!(NSObject))
+            .expect("CocoanutSpeechDelegate already registered");
+        decl.add_ivar::<*mut c_void>("rustHandler");
+
+        extern "C" fn did_finish_speaking(this: &Object, _cmd: Sel, _sender: *mut Object, finished: BOOL) {
+            unsafe {
+                let slot = *this.get_ivar::<*mut c_void>("rustHandler") as *const FinishSlot;
+                if let Some(slot) = slot.as_ref() {
+                    notify_finished(slot, finished == YES);
+                }
+            }
+        }
+
+        unsafe {
+            decl.add_method(
+                sel!(speechSynthesizer:didFinishSpeaking:),
+                did_finish_speaking as extern "C" fn(&Object, Sel, *mut Object, BOOL),
+            );
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutSpeechDelegate").expect("CocoanutSpeechDelegate not registered")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_speech_rate_validation() {
+        let speech = Speech::new().unwrap().rate(180.0).unwrap();
+        assert_eq!(speech.words_per_minute(), Some(180.0));
+        assert!(Speech::new().unwrap().rate(0.0).is_err());
+        assert!(Speech::new().unwrap().rate(f32::NAN).is_err());
+        assert!(Speech::new().unwrap().voice("").is_err());
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_available_voices() {
+        let voices = Speech::available_voices();
+        assert!(!voices.is_empty());
+
+        let speech = Speech::new().unwrap().voice(&voices[0]).unwrap();
+        assert_eq!(speech.voice_identifier(), Some(voices[0].as_str()));
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_speak_reports_finish() {
+        let finished = Arc::new(Mutex::new(Vec::new()));
+        let mut speech = Speech::new().unwrap();
+        {
+            let finished = Arc::clone(&finished);
+            speech.on_finish(move |completed| finished.lock().unwrap().push(completed));
+        }
+        speech.speak("Hello").unwrap();
+        assert!(!speech.is_speaking());
+        assert_eq!(*finished.lock().unwrap(), vec![true]);
+    }
+}
@@ -0,0 +1,217 @@
+//! Menu bar status items (`NSStatusItem`)
+//!
+//! A `StatusItem` is configured with fluent setters and placed in the menu
+//! bar by [`StatusItem::show`]. The item stays in the menu bar for as long
+//! as the returned handle lives and is removed when it is dropped.
+
+use crate::core::error::Result;
+use crate::menu::Menu;
+use crate::systems::target_action::TargetAction;
+use objc::runtime::Object;
+use std::path::{Path, PathBuf};
+
+#[cfg(not(feature = "test-mock"))]
+use crate::core::error::CocoanutError;
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+
+/// A status item in the system menu bar
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::features::macos::StatusItem;
+///
+/// let _item = StatusItem::new()
+///     .title("Sync")
+///     .on_click(|| println!("toggled"))
+///     .show()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct StatusItem {
+    ns_status_item: *mut Object,
+    title: Option<String>,
+    image: Option<PathBuf>,
+    menu: Option<Menu>,
+    on_click: Option<TargetAction>,
+}
+
+impl StatusItem {
+    /// Create a new status item configuration
+    pub fn new() -> Self {
+        StatusItem {
+            ns_status_item: std::ptr::null_mut(),
+            title: None,
+            image: None,
+            menu: None,
+            on_click: None,
+        }
+    }
+
+    /// Set the title shown in the menu bar
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Set an image file shown in the menu bar
+    pub fn image(mut self, path: impl AsRef<Path>) -> Self {
+        self.image = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Set the menu shown when the item is clicked
+    ///
+    /// A status item with a menu opens the menu on click instead of running
+    /// the `on_click` handler.
+    pub fn menu(mut self, menu: Menu) -> Self {
+        self.menu = Some(menu);
+        self
+    }
+
+    /// Set a handler that runs when the item is clicked
+    pub fn on_click<F>(mut self, handler: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.on_click = Some(TargetAction::new(handler));
+        self
+    }
+
+    /// Get the title
+    pub fn get_title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Get the image path
+    pub fn get_image(&self) -> Option<&Path> {
+        self.image.as_deref()
+    }
+
+    /// Check if a menu is attached
+    pub fn has_menu(&self) -> bool {
+        self.menu.is_some()
+    }
+
+    /// Check if a click handler is set
+    pub fn has_click_handler(&self) -> bool {
+        self.on_click.is_some()
+    }
+
+    /// Check if the item has been placed in the menu bar
+    pub fn is_visible(&self) -> bool {
+        !self.ns_status_item.is_null()
+    }
+
+    /// Run the click handler, if any
+    pub fn perform_click(&self) {
+        if let Some(action) = &self.on_click {
+            action.invoke();
+        }
+    }
+
+    /// Place the item in the menu bar
+    ///
+    /// # Returns
+    ///
+    /// Returns the live status item; dropping it removes it from the menu bar
+    pub fn show(mut self) -> Result<Self> {
+        if !self.is_visible() {
+            self.place_in_menu_bar()?;
+        }
+        Ok(self)
+    }
+
+    /// Create the `NSStatusItem` and apply the configured title, image, menu and handler
+    fn place_in_menu_bar(&mut self) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let status_bar: *mut Object = msg_send![class!(NSStatusBar), systemStatusBar];
+            // NSVariableStatusItemLength
+            let item: *mut Object = msg_send![status_bar, statusItemWithLength: -1.0f64];
+            if item.is_null() {
+                return Err(CocoanutError::ControlCreationFailed(
+                    "Failed to create NSStatusItem".to_string()
+                ));
+            }
+            let _: () = msg_send![item, retain];
+            self.ns_status_item = item;
+
+            let button: *mut Object = msg_send![item, button];
+            if let Some(title) = &self.title {
+                let _: () = msg_send![button, setTitle: crate::core::utils::string_to_ns_string(title)?];
+            }
+            if let Some(path) = &self.image {
+                let ns_path = crate::core::utils::string_to_ns_string(&path.to_string_lossy())?;
+                let image: *mut Object = msg_send![class!(NSImage), alloc];
+                let image: *mut Object = msg_send![image, initWithContentsOfFile: ns_path];
+                if image.is_null() {
+                    return Err(CocoanutError::InvalidParameter(
+                        format!("Could not load status item image from '{}'", path.display())
+                    ));
+                }
+                let _: () = msg_send![image, setTemplate: true];
+                let _: () = msg_send![button, setImage: image];
+                let _: () = msg_send![image, release];
+            }
+            if let Some(menu) = &self.menu {
+                let _: () = msg_send![item, setMenu: menu.ns_menu()];
+            }
+            if let Some(action) = &self.on_click {
+                action.attach_to(button);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for StatusItem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for StatusItem {
+    fn drop(&mut self) {
+        if !self.ns_status_item.is_null() {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                let status_bar: *mut Object = msg_send![class!(NSStatusBar), systemStatusBar];
+                let _: () = msg_send![status_bar, removeStatusItem: self.ns_status_item];
+                let _: () = msg_send![self.ns_status_item, release];
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_status_item_configuration() {
+        let item = StatusItem::new().title("Sync").image("icon.png");
+        assert_eq!(item.get_title(), Some("Sync"));
+        assert_eq!(item.get_image(), Some(Path::new("icon.png")));
+        assert!(!item.has_menu());
+        assert!(!item.is_visible());
+    }
+
+    #[test]
+    fn test_status_item_click_handler() {
+        let clicks = Arc::new(AtomicUsize::new(0));
+        let item = {
+            let clicks = Arc::clone(&clicks);
+            StatusItem::new().on_click(move || {
+                clicks.fetch_add(1, Ordering::SeqCst);
+            })
+        };
+        assert!(item.has_click_handler());
+
+        item.perform_click();
+        assert_eq!(clicks.load(Ordering::SeqCst), 1);
+    }
+}
@@ -3,6 +3,19 @@
 //! Includes GridView, Touch Bar, Accessibility, Dark Mode, Drag & Drop, and Advanced Styling.
 
 use crate::core::error::Result;
+use objc::runtime::Object;
+#[cfg(not(feature = "test-mock"))]
+use objc::declare::ClassDecl;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Class, Sel, BOOL, NO, YES};
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+#[cfg(not(feature = "test-mock"))]
+use std::ffi::c_void;
+use std::path::PathBuf;
+#[cfg(not(feature = "test-mock"))]
+use std::sync::Once;
+use std::sync::{Arc, Mutex, MutexGuard};
 
 // ============================================================================
 // GRID VIEW
@@ -339,10 +352,110 @@ impl DarkModeManager {
 // DRAG & DROP
 // ============================================================================
 
+/// Content dropped onto a drag and drop target
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DroppedItem {
+    files: Vec<PathBuf>,
+    text: Option<String>,
+    urls: Vec<String>,
+    image_data: Option<Vec<u8>>,
+}
+
+impl DroppedItem {
+    /// Create a dropped item from file paths and optional plain text
+    pub fn new(files: Vec<PathBuf>, text: Option<String>) -> Self {
+        DroppedItem { files, text, ..Default::default() }
+    }
+
+    /// Add dropped web addresses
+    pub fn with_urls(mut self, urls: Vec<String>) -> Self {
+        self.urls = urls;
+        self
+    }
+
+    /// Add a dropped image as TIFF data
+    pub fn with_image_data(mut self, data: Vec<u8>) -> Self {
+        self.image_data = Some(data);
+        self
+    }
+
+    /// Get the dropped file paths
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    /// Get the dropped plain text
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Get the dropped web addresses, such as `https://example.com`
+    pub fn urls(&self) -> &[String] {
+        &self.urls
+    }
+
+    /// Get the dropped image as TIFF data
+    pub fn image_data(&self) -> Option<&[u8]> {
+        self.image_data.as_deref()
+    }
+
+    /// Check if nothing usable was dropped
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty() && self.text.is_none() && self.urls.is_empty() && self.image_data.is_none()
+    }
+}
+
+/// Pasteboard type for file URLs
+const FILE_URL_TYPE: &str = "public.file-url";
+/// Pasteboard type for plain text
+const PLAIN_TEXT_TYPE: &str = "public.utf8-plain-text";
+/// Pasteboard type for images
+const TIFF_TYPE: &str = "public.tiff";
+
+/// Map an allowed type name to the pasteboard type it accepts
+///
+/// `"file"`, `"text"`, `"image"` and `"url"` are shorthands; anything else is
+/// treated as a uniform type identifier.
+fn pasteboard_type(type_name: &str) -> &str {
+    match type_name {
+        "file" | "files" => FILE_URL_TYPE,
+        "text" => PLAIN_TEXT_TYPE,
+        "image" => TIFF_TYPE,
+        "url" => "public.url",
+        other => other,
+    }
+}
+
+/// Drop state shared with the registered drop view
+struct DropState {
+    enabled: bool,
+    pasteboard_types: Vec<String>,
+    on_drop: Option<Arc<dyn Fn(DroppedItem) + Send + Sync>>,
+}
+
+impl DropState {
+    /// Pasteboard types the view registers for and accepts
+    fn accepted_types(&self) -> Vec<String> {
+        if self.pasteboard_types.is_empty() {
+            vec![FILE_URL_TYPE.to_string(), PLAIN_TEXT_TYPE.to_string()]
+        } else {
+            self.pasteboard_types.clone()
+        }
+    }
+}
+
 /// Drag and drop support
+///
+/// [`DragDropManager::attach`] wraps a window's content view in a view that
+/// is registered for the allowed pasteboard types. Drags offering none of
+/// those types are refused in `draggingEntered:`; accepted drops are read
+/// from the dragging pasteboard, as files, web addresses, a TIFF image or
+/// plain text, and passed to the `on_drop` handler.
 pub struct DragDropManager {
     enabled: bool,
     allowed_types: Vec<String>,
+    state: Arc<Mutex<DropState>>,
+    drop_view: *mut Object,
 }
 
 impl DragDropManager {
@@ -351,18 +464,30 @@ impl DragDropManager {
         Ok(DragDropManager {
             enabled: false,
             allowed_types: Vec::new(),
+            state: Arc::new(Mutex::new(DropState {
+                enabled: false,
+                pasteboard_types: Vec::new(),
+                on_drop: None,
+            })),
+            drop_view: std::ptr::null_mut(),
         })
     }
 
+    fn lock_state(&self) -> MutexGuard<'_, DropState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
     /// Enable drag and drop
     pub fn enable(&mut self) -> Result<()> {
         self.enabled = true;
+        self.lock_state().enabled = true;
         Ok(())
     }
 
     /// Disable drag and drop
     pub fn disable(&mut self) -> Result<()> {
         self.enabled = false;
+        self.lock_state().enabled = false;
         Ok(())
     }
 
@@ -372,14 +497,273 @@ impl DragDropManager {
     }
 
     /// Add an allowed type
+    ///
+    /// Accepts `"file"`, `"text"`, `"image"`, `"url"` or a uniform type
+    /// identifier such as `"public.png"`. With no allowed types, file URLs
+    /// and plain text are accepted.
     pub fn add_allowed_type(&mut self, type_name: impl Into<String>) {
-        self.allowed_types.push(type_name.into());
+        let type_name = type_name.into();
+        self.lock_state().pasteboard_types.push(pasteboard_type(&type_name).to_string());
+        self.allowed_types.push(type_name);
+        self.register_dragged_types();
     }
 
     /// Get allowed types
     pub fn allowed_types(&self) -> &[String] {
         &self.allowed_types
     }
+
+    /// Set the handler called with the content of each accepted drop
+    pub fn on_drop<F>(&mut self, handler: F)
+    where
+        F: Fn(DroppedItem) + Send + Sync + 'static,
+    {
+        self.lock_state().on_drop = Some(Arc::new(handler));
+    }
+
+    /// Check if a drag offering the given pasteboard types would be accepted
+    pub fn accepts(&self, offered_types: &[&str]) -> bool {
+        let state = self.lock_state();
+        state.enabled && state.accepted_types().iter().any(|t| offered_types.contains(&t.as_str()))
+    }
+
+    /// Deliver a dropped item to the `on_drop` handler
+    ///
+    /// Returns `false` if drag and drop is disabled, no handler is set, or
+    /// the item is empty.
+    pub fn handle_drop(&self, item: DroppedItem) -> bool {
+        deliver_drop(&self.state, item)
+    }
+
+    /// Accept drops anywhere in `window`
+    ///
+    /// The window's content view is placed inside a drop target view that
+    /// fills the window, so existing subviews keep working.
+    pub fn attach(&mut self, window: &crate::window::Window) -> Result<()> {
+        self.attach_to_ns_window(window.ns_window())
+    }
+
+    /// Wrap the content view of `window`, which must be null or an `NSWindow`
+    fn attach_to_ns_window(&mut self, window: *mut Object) -> Result<()> {
+        if window.is_null() {
+            return Err(crate::core::error::CocoanutError::InvalidParameter(
+                "Cannot attach drag and drop to a null window".to_string()
+            ));
+        }
+        if !self.drop_view.is_null() {
+            return Err(crate::core::error::CocoanutError::InvalidParameter(
+                "Drag and drop is already attached to a window".to_string()
+            ));
+        }
+
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            use cocoa::foundation::NSRect;
+
+            let content_view: *mut Object = msg_send![window, contentView];
+            let frame: NSRect = msg_send![content_view, frame];
+
+            let drop_view: *mut Object = msg_send![drop_view_class(), alloc];
+            let drop_view: *mut Object = msg_send![drop_view, initWithFrame: frame];
+            if drop_view.is_null() {
+                return Err(crate::core::error::CocoanutError::ControlCreationFailed(
+                    "Failed to create drop target view".to_string()
+                ));
+            }
+            let state = Arc::into_raw(Arc::clone(&self.state)) as *mut c_void;
+            (*drop_view).set_ivar::<*mut c_void>("rustDropState", state);
+
+            // NSViewWidthSizable | NSViewHeightSizable
+            let resizing: u64 = 2 | 16;
+            let _: () = msg_send![content_view, retain];
+            let _: () = msg_send![window, setContentView: drop_view];
+            let _: () = msg_send![drop_view, addSubview: content_view];
+            let _: () = msg_send![content_view, setAutoresizingMask: resizing];
+            let _: () = msg_send![content_view, release];
+
+            self.drop_view = drop_view;
+        }
+
+        self.register_dragged_types();
+        Ok(())
+    }
+
+    /// Register the drop view for the currently accepted pasteboard types
+    fn register_dragged_types(&self) {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.drop_view.is_null() {
+            unsafe {
+                let types: *mut Object = msg_send![class!(NSMutableArray), array];
+                for type_name in self.lock_state().accepted_types() {
+                    if let Ok(ns_type) = crate::core::utils::string_to_ns_string(&type_name) {
+                        let _: () = msg_send![types, addObject: ns_type];
+                    }
+                }
+                let _: () = msg_send![self.drop_view, unregisterDraggedTypes];
+                let _: () = msg_send![self.drop_view, registerForDraggedTypes: types];
+            }
+        }
+    }
+}
+
+impl Drop for DragDropManager {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.drop_view.is_null() {
+            unsafe {
+                let state = *(*self.drop_view).get_ivar::<*mut c_void>("rustDropState");
+                (*self.drop_view).set_ivar::<*mut c_void>("rustDropState", std::ptr::null_mut());
+                let _: () = msg_send![self.drop_view, unregisterDraggedTypes];
+                let _: () = msg_send![self.drop_view, release];
+                if !state.is_null() {
+                    drop(Arc::from_raw(state as *const Mutex<DropState>));
+                }
+            }
+        }
+    }
+}
+
+/// Pass a dropped item to the handler if the drop should be accepted
+///
+/// The handler runs without the state locked, so it may use the manager.
+fn deliver_drop(state: &Mutex<DropState>, item: DroppedItem) -> bool {
+    let handler = {
+        let state = state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if !state.enabled || item.is_empty() {
+            return false;
+        }
+        state.on_drop.clone()
+    };
+    match handler {
+        Some(handler) => {
+            handler(item);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Lazily register the NSView subclass that acts as the dragging destination
+#[cfg(not(feature = "test-mock"))]
+fn drop_view_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutDropView", class!(NSView))
+            .expect("CocoanutDropView already registered");
+        decl.add_ivar::<*mut c_void>("rustDropState");
+
+        extern "C" fn dragging_entered(this: &Object, _cmd: Sel, sender: *mut Object) -> u64 {
+            unsafe {
+                let Some(state) = drop_state(this) else {
+                    return 0;
+                };
+                let state = state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                if !state.enabled {
+                    return 0;
+                }
+
+                let pasteboard: *mut Object = msg_send![sender, draggingPasteboard];
+                let types: *mut Object = msg_send![class!(NSMutableArray), array];
+                for type_name in state.accepted_types() {
+                    if let Ok(ns_type) = crate::core::utils::string_to_ns_string(&type_name) {
+                        let _: () = msg_send![types, addObject: ns_type];
+                    }
+                }
+                let available: *mut Object = msg_send![pasteboard, availableTypeFromArray: types];
+                // NSDragOperationCopy or NSDragOperationNone
+                if available.is_null() { 0 } else { 1 }
+            }
+        }
+
+        extern "C" fn perform_drag_operation(this: &Object, _cmd: Sel, sender: *mut Object) -> BOOL {
+            unsafe {
+                let Some(state) = drop_state(this) else {
+                    return NO;
+                };
+                let pasteboard: *mut Object = msg_send![sender, draggingPasteboard];
+                let item = read_dropped_item(pasteboard);
+                if deliver_drop(state, item) { YES } else { NO }
+            }
+        }
+
+        unsafe {
+            decl.add_method(
+                sel!(draggingEntered:),
+                dragging_entered as extern "C" fn(&Object, Sel, *mut Object) -> u64,
+            );
+            decl.add_method(
+                sel!(draggingUpdated:),
+                dragging_entered as extern "C" fn(&Object, Sel, *mut Object) -> u64,
+            );
+            decl.add_method(
+                sel!(performDragOperation:),
+                perform_drag_operation as extern "C" fn(&Object, Sel, *mut Object) -> BOOL,
+            );
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutDropView").expect("CocoanutDropView not registered")
+}
+
+/// Get the drop state stored on a drop view
+#[cfg(not(feature = "test-mock"))]
+unsafe fn drop_state(view: &Object) -> Option<&Mutex<DropState>> {
+    let state = unsafe { *view.get_ivar::<*mut c_void>("rustDropState") } as *const Mutex<DropState>;
+    unsafe { state.as_ref() }
+}
+
+/// Read file URLs, web addresses, TIFF images and plain text from a
+/// dragging pasteboard
+#[cfg(not(feature = "test-mock"))]
+unsafe fn read_dropped_item(pasteboard: *mut Object) -> DroppedItem {
+    let mut files = Vec::new();
+    let mut urls = Vec::new();
+    unsafe {
+        let classes: *mut Object = msg_send![class!(NSArray), arrayWithObject: class!(NSURL)];
+        let options: *mut Object = msg_send![class!(NSDictionary), dictionary];
+        let ns_urls: *mut Object = msg_send![pasteboard, readObjectsForClasses: classes options: options];
+        if !ns_urls.is_null() {
+            let count: usize = msg_send![ns_urls, count];
+            for index in 0..count {
+                let url: *mut Object = msg_send![ns_urls, objectAtIndex: index];
+                let is_file: BOOL = msg_send![url, isFileURL];
+                if is_file == NO {
+                    let address: *mut Object = msg_send![url, absoluteString];
+                    if let Ok(address) = crate::core::utils::ns_string_to_string(address) {
+                        urls.push(address);
+                    }
+                    continue;
+                }
+                let path: *mut Object = msg_send![url, path];
+                if let Ok(path) = crate::core::utils::ns_string_to_string(path) {
+                    files.push(PathBuf::from(path));
+                }
+            }
+        }
+
+        let text = crate::core::utils::string_to_ns_string(PLAIN_TEXT_TYPE)
+            .ok()
+            .map(|ns_type| -> *mut Object { msg_send![pasteboard, stringForType: ns_type] })
+            .filter(|ns_text| !ns_text.is_null())
+            .and_then(|ns_text| crate::core::utils::ns_string_to_string(ns_text).ok());
+
+        let image_data = crate::core::utils::string_to_ns_string(TIFF_TYPE)
+            .ok()
+            .map(|ns_type| -> *mut Object { msg_send![pasteboard, dataForType: ns_type] })
+            .filter(|data| !data.is_null())
+            .map(|data| {
+                let bytes: *const u8 = msg_send![data, bytes];
+                let length: usize = msg_send![data, length];
+                if bytes.is_null() { Vec::new() } else { std::slice::from_raw_parts(bytes, length).to_vec() }
+            });
+
+        // Dropped files and links also carry their address as text; only
+        // report text for text drops
+        let text = if files.is_empty() && urls.is_empty() { text } else { None };
+        DroppedItem { files, text, urls, image_data }
+    }
 }
 
 // ============================================================================
@@ -623,6 +1007,72 @@ mod tests {
         assert_eq!(dd.allowed_types().len(), 2);
     }
 
+    #[test]
+    fn test_drag_drop_filters_types() {
+        let mut dd = DragDropManager::new().unwrap();
+        assert!(!dd.accepts(&["public.file-url"]));
+
+        dd.enable().unwrap();
+        assert!(dd.accepts(&["public.file-url"]));
+        assert!(dd.accepts(&["public.utf8-plain-text"]));
+
+        dd.add_allowed_type("file");
+        assert!(dd.accepts(&["public.file-url", "public.url"]));
+        assert!(!dd.accepts(&["public.utf8-plain-text"]));
+    }
+
+    #[test]
+    fn test_drag_drop_on_drop() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let mut dd = DragDropManager::new().unwrap();
+        {
+            let received = Arc::clone(&received);
+            dd.on_drop(move |item| received.lock().unwrap().push(item));
+        }
+
+        let item = DroppedItem::new(vec![PathBuf::from("/tmp/report.pdf")], None);
+        assert!(!dd.handle_drop(item.clone()));
+
+        dd.enable().unwrap();
+        assert!(dd.handle_drop(item));
+        assert!(!dd.handle_drop(DroppedItem::default()));
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].files(), &[PathBuf::from("/tmp/report.pdf")]);
+        assert_eq!(received[0].text(), None);
+    }
+
+    #[test]
+    fn test_drag_drop_attach_rejects_null_window() {
+        let mut dd = DragDropManager::new().unwrap();
+        assert!(dd.attach_to_ns_window(std::ptr::null_mut()).is_err());
+    }
+
+    #[test]
+    fn test_drag_drop_handler_runs_unlocked() {
+        let mut dd = DragDropManager::new().unwrap();
+        dd.enable().unwrap();
+        // The handler locks the drop state again, as `accepts` does
+        let state = Arc::downgrade(&dd.state);
+        dd.on_drop(move |_| {
+            let state = state.upgrade().unwrap();
+            assert!(state.lock().unwrap().enabled);
+        });
+        assert!(dd.handle_drop(DroppedItem::new(Vec::new(), Some("Hello".to_string()))));
+    }
+
+    #[test]
+    fn test_dropped_urls_and_images_are_not_empty() {
+        let link = DroppedItem::default().with_urls(vec!["https://example.com".to_string()]);
+        assert!(!link.is_empty());
+        assert_eq!(link.urls(), ["https://example.com"]);
+
+        let image = DroppedItem::default().with_image_data(vec![0x4d, 0x4d]);
+        assert!(!image.is_empty());
+        assert_eq!(image.image_data(), Some(&[0x4d, 0x4d][..]));
+    }
+
     // AdvancedStyling Tests
     #[test]
     fn test_advanced_styling_creation() {
@@ -3,7 +3,24 @@
 //! Provides consistent styling and theming for UI components following
 //! IBM's Carbon Design System guidelines.
 
-use crate::drawing::Color;
+use crate::core::error::Result;
+use crate::core::traits::Drawable;
+use crate::drawing::{Color, Font, FontWeight};
+use crate::features::macos::{Appearance, DarkModeManager};
+use crate::features::phase3_features::AdvancedStyling;
+use crate::systems::layout::EdgeInsets;
+use objc::runtime::Object;
+
+#[cfg(not(feature = "test-mock"))]
+use objc::{msg_send, sel, sel_impl};
+
+/// Blur radius of component shadows, in points
+#[cfg(not(feature = "test-mock"))]
+const SHADOW_RADIUS: f64 = 4.0;
+
+/// Downward offset of component shadows, in points
+#[cfg(not(feature = "test-mock"))]
+const SHADOW_OFFSET: f64 = 2.0;
 
 /// Carbon Design System color palette
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -65,6 +82,49 @@ impl CarbonColor {
             Self::SupportInfo => (0.0, 113.0 / 255.0, 197.0 / 255.0),           // #0071C5
         }
     }
+
+    /// Get the RGB values for this color under the given appearance
+    ///
+    /// Light appearance uses the same values as [`CarbonColor::rgb`].
+    pub fn rgb_for(&self, appearance: Appearance) -> (f64, f64, f64) {
+        match appearance {
+            Appearance::Light => self.rgb(),
+            Appearance::Dark => self.dark_rgb(),
+        }
+    }
+
+    /// Get this color as a `Color` under the given appearance
+    pub fn color_for(&self, appearance: Appearance) -> Color {
+        let (red, green, blue) = self.rgb_for(appearance);
+        Color { red, green, blue, alpha: 1.0 }
+    }
+
+    /// Dark theme RGB values
+    fn dark_rgb(&self) -> (f64, f64, f64) {
+        match self {
+            // Interactive colors
+            Self::Interactive => (69.0 / 255.0, 137.0 / 255.0, 1.0),           // #4589FF
+            Self::InteractiveHover => (110.0 / 255.0, 166.0 / 255.0, 1.0),     // #6EA6FF
+            Self::InteractiveActive => (0.0, 67.0 / 255.0, 206.0 / 255.0),     // #0043CE
+
+            // UI colors
+            Self::UIBackground => (22.0 / 255.0, 22.0 / 255.0, 22.0 / 255.0),   // #161616
+            Self::UILightBackground => (38.0 / 255.0, 38.0 / 255.0, 38.0 / 255.0), // #262626
+            Self::UIDarkBackground => (11.0 / 255.0, 11.0 / 255.0, 11.0 / 255.0), // #0B0B0B
+
+            // Text colors
+            Self::TextPrimary => (244.0 / 255.0, 244.0 / 255.0, 244.0 / 255.0), // #F4F4F4
+            Self::TextSecondary => (198.0 / 255.0, 198.0 / 255.0, 198.0 / 255.0), // #C6C6C6
+            Self::TextTertiary => (141.0 / 255.0, 141.0 / 255.0, 141.0 / 255.0), // #8D8D8D
+            Self::TextDisabled => (82.0 / 255.0, 82.0 / 255.0, 82.0 / 255.0),   // #525252
+
+            // Support colors
+            Self::SupportSuccess => (66.0 / 255.0, 190.0 / 255.0, 101.0 / 255.0), // #42BE65
+            Self::SupportWarning => (241.0 / 255.0, 194.0 / 255.0, 27.0 / 255.0), // #F1C21B
+            Self::SupportError => (250.0 / 255.0, 77.0 / 255.0, 86.0 / 255.0),  // #FA4D56
+            Self::SupportInfo => (69.0 / 255.0, 137.0 / 255.0, 1.0),            // #4589FF
+        }
+    }
 }
 
 /// Typography scale following Carbon Design System
@@ -125,6 +185,27 @@ impl TypographyScale {
             Self::Caption => 1.4,
         }
     }
+
+    /// Get the height of a line of text in points, for layout
+    pub fn line_height(&self) -> f64 {
+        self.font_size() * self.line_height_multiplier()
+    }
+
+    /// Get the system font weight closest to [`font_weight`](Self::font_weight)
+    pub fn system_weight(&self) -> FontWeight {
+        match self.font_weight() {
+            w if w >= 0.9 => FontWeight::Bold,
+            w if w >= 0.7 => FontWeight::Semibold,
+            w if w >= 0.6 => FontWeight::Medium,
+            w if w >= 0.4 => FontWeight::Regular,
+            _ => FontWeight::Light,
+        }
+    }
+
+    /// Get the system font for this step of the scale
+    pub fn to_font(&self) -> Font {
+        Font::system_weighted(self.font_size(), self.system_weight())
+    }
 }
 
 /// Spacing scale following Carbon Design System
@@ -184,10 +265,22 @@ impl CornerRadiusScale {
             Self::Pronounced => 8.0,
         }
     }
+
+    /// Get the step of the scale closest to `radius` points
+    pub fn nearest(radius: f64) -> Self {
+        [Self::Sharp, Self::Subtle, Self::Standard, Self::Pronounced]
+            .into_iter()
+            .min_by(|a, b| (a.value() - radius).abs().total_cmp(&(b.value() - radius).abs()))
+            .unwrap_or(Self::Sharp)
+    }
 }
 
 /// Component style configuration
+///
+/// Start from a preset such as [`button`](Self::button) and adjust it with
+/// the `with_*` methods; new fields may be added in minor releases.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct ComponentStyle {
     /// Background color
     pub background: CarbonColor,
@@ -201,6 +294,10 @@ pub struct ComponentStyle {
     pub corner_radius: CornerRadiusScale,
     /// Padding
     pub padding: SpacingScale,
+    /// Border width in points; 0 draws no border
+    pub border_width: f64,
+    /// Opacity of the drop shadow from 0.0 to 1.0; 0 draws no shadow
+    pub shadow_opacity: f64,
 }
 
 impl ComponentStyle {
@@ -213,6 +310,8 @@ impl ComponentStyle {
             typography: TypographyScale::Label,
             corner_radius: CornerRadiusScale::Standard,
             padding: SpacingScale::Standard,
+            border_width: 0.0,
+            shadow_opacity: 0.0,
         }
     }
 
@@ -225,6 +324,8 @@ impl ComponentStyle {
             typography: TypographyScale::Body,
             corner_radius: CornerRadiusScale::Sharp,
             padding: SpacingScale::Tight,
+            border_width: 0.0,
+            shadow_opacity: 0.0,
         }
     }
 
@@ -237,6 +338,8 @@ impl ComponentStyle {
             typography: TypographyScale::Body,
             corner_radius: CornerRadiusScale::Subtle,
             padding: SpacingScale::Standard,
+            border_width: 1.0,
+            shadow_opacity: 0.0,
         }
     }
 
@@ -252,13 +355,157 @@ impl ComponentStyle {
         self
     }
 
+    /// Set the border color
+    pub fn with_border(mut self, border: CarbonColor) -> Self {
+        self.border = border;
+        self
+    }
+
     /// Set the typography scale
     pub fn with_typography(mut self, typography: TypographyScale) -> Self {
         self.typography = typography;
         self
     }
+
+    /// Set the corner radius
+    pub fn with_corner_radius(mut self, corner_radius: CornerRadiusScale) -> Self {
+        self.corner_radius = corner_radius;
+        self
+    }
+
+    /// Set the padding
+    pub fn with_padding(mut self, padding: SpacingScale) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Set the border width in points
+    pub fn with_border_width(mut self, width: f64) -> Self {
+        self.border_width = width.max(0.0);
+        self
+    }
+
+    /// Set the drop shadow opacity, clamped to 0.0..=1.0
+    pub fn with_shadow(mut self, opacity: f64) -> Self {
+        self.shadow_opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Take the border, shadow and corner radius of `advanced`
+    ///
+    /// The corner radius is rounded to the nearest step of the
+    /// [`CornerRadiusScale`].
+    pub fn with_advanced(self, advanced: &AdvancedStyling) -> Self {
+        let shadow = if advanced.shadow_enabled() { advanced.shadow_opacity() } else { 0.0 };
+        self.with_corner_radius(CornerRadiusScale::nearest(advanced.corner_radius()))
+            .with_border_width(advanced.border_width())
+            .with_shadow(shadow)
+    }
+
+    /// Get the font of the typography scale
+    pub fn font(&self) -> Font {
+        self.typography.to_font()
+    }
+
+    /// Get the padding as insets for [`Padded`](crate::layout::Padded) layout items
+    ///
+    /// Views cannot pad their own content, so padding is applied by layout
+    /// containers rather than by [`Styleable::apply_style`].
+    pub fn padding_insets(&self) -> EdgeInsets {
+        EdgeInsets::uniform(self.padding.value())
+    }
+
+    /// Style `view`'s layer with the background, border, corner radius and
+    /// shadow, in colors for the system's current appearance
+    ///
+    /// Views showing text, such as labels and buttons, also get the font of
+    /// the typography scale and the text color. The view is made
+    /// layer-backed. Null views are ignored; `view` must otherwise be an
+    /// `NSView`, which [`Styleable`] guarantees.
+    pub(crate) fn apply(&self, view: *mut Object) -> Result<()> {
+        self.apply_for(view, DarkModeManager::current_appearance())
+    }
+
+    /// Like [`apply`](Self::apply), with colors for `appearance`
+    pub(crate) fn apply_for(&self, view: *mut Object, appearance: Appearance) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        if !view.is_null() {
+            use cocoa::foundation::NSSize;
+
+            unsafe {
+                let _: () = msg_send![view, setWantsLayer: true];
+                let layer: *mut Object = msg_send![view, layer];
+                if layer.is_null() {
+                    return Err(crate::core::error::CocoanutError::DrawingError(
+                        "View has no layer to style".to_string()
+                    ));
+                }
+                let background: *mut Object = msg_send![self.background.color_for(appearance).to_ns_color(), CGColor];
+                let border: *mut Object = msg_send![self.border.color_for(appearance).to_ns_color(), CGColor];
+                let _: () = msg_send![layer, setBackgroundColor: background];
+                let _: () = msg_send![layer, setCornerRadius: self.corner_radius.value()];
+                let _: () = msg_send![layer, setBorderWidth: self.border_width];
+                let _: () = msg_send![layer, setBorderColor: border];
+
+                // Shadows are drawn outside the bounds, so the layer must not clip
+                let _: () = msg_send![layer, setMasksToBounds: false];
+                let _: () = msg_send![layer, setShadowOpacity: self.shadow_opacity as f32];
+                if self.shadow_opacity > 0.0 {
+                    let shadow: *mut Object = msg_send![Color::black().to_ns_color(), CGColor];
+                    let _: () = msg_send![layer, setShadowColor: shadow];
+                    let _: () = msg_send![layer, setShadowRadius: SHADOW_RADIUS];
+                    let _: () = msg_send![layer, setShadowOffset: NSSize::new(0.0, -SHADOW_OFFSET)];
+                }
+
+                let has_font: bool = msg_send![view, respondsToSelector: sel!(setFont:)];
+                if has_font {
+                    let _: () = msg_send![view, setFont: self.typography.to_font().to_ns_font()?];
+                }
+                let has_text_color: bool = msg_send![view, respondsToSelector: sel!(setTextColor:)];
+                if has_text_color {
+                    let _: () = msg_send![view, setTextColor: self.text.color_for(appearance).to_ns_color()];
+                }
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = (view, appearance);
+        Ok(())
+    }
+
+    /// Resolve the background color for the appearance reported by `dark_mode`
+    pub fn background_color(&self, dark_mode: &DarkModeManager) -> Color {
+        self.background.color_for(dark_mode.active_appearance())
+    }
+
+    /// Resolve the text color for the appearance reported by `dark_mode`
+    pub fn text_color(&self, dark_mode: &DarkModeManager) -> Color {
+        self.text.color_for(dark_mode.active_appearance())
+    }
+
+    /// Resolve the border color for the appearance reported by `dark_mode`
+    pub fn border_color(&self, dark_mode: &DarkModeManager) -> Color {
+        self.border.color_for(dark_mode.active_appearance())
+    }
 }
 
+/// Views that can take a [`ComponentStyle`]
+///
+/// Implemented for every [`Drawable`].
+pub trait Styleable: Drawable {
+    /// Apply `style`'s colors, border, shadow and typography to the view
+    fn apply_style(&self, style: &ComponentStyle) -> Result<()> {
+        style.apply(self.as_view())
+    }
+
+    /// Like [`apply_style`](Self::apply_style), with colors for `appearance`
+    /// rather than the system's current one
+    fn apply_style_for(&self, style: &ComponentStyle, appearance: Appearance) -> Result<()> {
+        style.apply_for(self.as_view(), appearance)
+    }
+}
+
+impl<T: Drawable + ?Sized> Styleable for T {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -278,6 +525,25 @@ mod tests {
         assert_eq!(TypographyScale::Caption.font_size(), 12.0);
     }
 
+    #[test]
+    fn test_typography_fonts() {
+        let display = TypographyScale::Display.to_font();
+        let caption = TypographyScale::Caption.to_font();
+        assert!(display.size() > caption.size());
+        assert!(display.is_bold());
+        assert_eq!(TypographyScale::Heading1.to_font().weight(), FontWeight::Semibold);
+        assert_eq!(caption.weight(), FontWeight::Regular);
+        assert_eq!(ComponentStyle::label().font(), TypographyScale::Body.to_font());
+    }
+
+    #[test]
+    fn test_typography_line_height() {
+        for scale in [TypographyScale::Display, TypographyScale::Body, TypographyScale::Caption] {
+            assert_eq!(scale.line_height(), scale.font_size() * scale.line_height_multiplier());
+        }
+        assert_eq!(TypographyScale::Body.line_height(), 24.0);
+    }
+
     #[test]
     fn test_spacing_scale() {
         assert_eq!(SpacingScale::Compact.value(), 2.0);
@@ -291,6 +557,77 @@ mod tests {
         assert_eq!(CornerRadiusScale::Standard.value(), 4.0);
     }
 
+    #[test]
+    fn test_corner_radius_nearest() {
+        assert_eq!(CornerRadiusScale::nearest(0.4), CornerRadiusScale::Sharp);
+        assert_eq!(CornerRadiusScale::nearest(3.5), CornerRadiusScale::Standard);
+        assert_eq!(CornerRadiusScale::nearest(20.0), CornerRadiusScale::Pronounced);
+    }
+
+    #[test]
+    fn test_component_style_with_advanced() {
+        let advanced = crate::features::phase3_features::AdvancedStylingBuilder::new()
+            .corner_radius(7.0)
+            .border_width(2.0)
+            .shadow(true)
+            .shadow_opacity(0.3)
+            .build()
+            .unwrap();
+        let style = ComponentStyle::label().with_advanced(&advanced);
+        assert_eq!(style.corner_radius, CornerRadiusScale::Pronounced);
+        assert_eq!(style.border_width, 2.0);
+        assert_eq!(style.shadow_opacity, 0.3);
+
+        let flat = crate::features::phase3_features::AdvancedStyling::new().unwrap();
+        assert_eq!(style.with_advanced(&flat).shadow_opacity, 0.0);
+        assert_eq!(ComponentStyle::button().with_shadow(3.0).shadow_opacity, 1.0);
+        assert_eq!(ComponentStyle::label().padding_insets(), EdgeInsets::uniform(4.0));
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_apply_in_mock() {
+        let style = ComponentStyle::button().with_border_width(1.0).with_shadow(0.5);
+        assert!(style.apply(std::ptr::null_mut()).is_ok());
+        assert!(style.apply_for(std::ptr::null_mut(), Appearance::Dark).is_ok());
+
+        let label = crate::components::Label::new("Styled").unwrap();
+        assert!(label.apply_style(&style).is_ok());
+        assert!(label.apply_style_for(&style, Appearance::Dark).is_ok());
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_apply_makes_view_layer_backed() {
+        use objc::{class, msg_send, sel, sel_impl};
+
+        unsafe {
+            let view: *mut Object = msg_send![class!(NSView), new];
+            ComponentStyle::button().with_shadow(0.5).apply(view).unwrap();
+            let wants_layer: bool = msg_send![view, wantsLayer];
+            let layer: *mut Object = msg_send![view, layer];
+            let radius: f64 = msg_send![layer, cornerRadius];
+            let _: () = msg_send![view, release];
+            assert!(wants_layer);
+            assert_eq!(radius, CornerRadiusScale::Standard.value());
+        }
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_apply_sets_typography_font() {
+        use objc::{class, msg_send, sel, sel_impl};
+
+        unsafe {
+            let label: *mut Object = msg_send![class!(NSTextField), new];
+            ComponentStyle::label().with_typography(TypographyScale::Heading2).apply(label).unwrap();
+            let font: *mut Object = msg_send![label, font];
+            let size: f64 = msg_send![font, pointSize];
+            let _: () = msg_send![label, release];
+            assert_eq!(size, TypographyScale::Heading2.font_size());
+        }
+    }
+
     #[test]
     fn test_component_styles() {
         let button_style = ComponentStyle::button();
@@ -301,5 +638,56 @@ mod tests {
 
         let text_field_style = ComponentStyle::text_field();
         assert_eq!(text_field_style.background, CarbonColor::UILightBackground);
+
+        let custom = ComponentStyle::label()
+            .with_border(CarbonColor::Interactive)
+            .with_padding(SpacingScale::Loose);
+        assert_eq!(custom.border, CarbonColor::Interactive);
+        assert_eq!(custom.padding, SpacingScale::Loose);
+    }
+
+    #[test]
+    fn test_carbon_colors_for_appearance() {
+        assert_eq!(CarbonColor::Interactive.rgb_for(Appearance::Light), CarbonColor::Interactive.rgb());
+        assert_ne!(
+            CarbonColor::UIBackground.rgb_for(Appearance::Light),
+            CarbonColor::UIBackground.rgb_for(Appearance::Dark)
+        );
+        assert_ne!(
+            CarbonColor::TextPrimary.rgb_for(Appearance::Light),
+            CarbonColor::TextPrimary.rgb_for(Appearance::Dark)
+        );
+
+        // Primary text flips to near-white in dark mode
+        let (r, g, b) = CarbonColor::TextPrimary.rgb_for(Appearance::Dark);
+        assert!(r > 0.9 && g > 0.9 && b > 0.9);
+    }
+
+    #[test]
+    fn test_component_style_follows_dark_mode_manager() {
+        let mut dark_mode = DarkModeManager::new();
+        dark_mode.disable();
+
+        let style = ComponentStyle::label();
+        assert_eq!(style.text_color(&dark_mode), CarbonColor::TextPrimary.color_for(Appearance::Light));
+        assert_eq!(style.background_color(&dark_mode), CarbonColor::UIBackground.color_for(Appearance::Light));
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_component_style_resolves_dark_colors() {
+        let mut dark_mode = DarkModeManager::new();
+        DarkModeManager::simulate_appearance(Appearance::Dark);
+
+        let style = ComponentStyle::label();
+        assert_eq!(style.text_color(&dark_mode), CarbonColor::TextPrimary.color_for(Appearance::Dark));
+        assert_eq!(style.background_color(&dark_mode), CarbonColor::UIBackground.color_for(Appearance::Dark));
+        assert_eq!(style.border_color(&dark_mode), style.border.color_for(Appearance::Dark));
+
+        // Disabling dark mode support falls back to the light palette
+        dark_mode.disable();
+        assert_eq!(style.text_color(&dark_mode), CarbonColor::TextPrimary.color_for(Appearance::Light));
+
+        DarkModeManager::simulate_appearance(Appearance::Light);
     }
 }
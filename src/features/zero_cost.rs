@@ -4,6 +4,7 @@
 //! with minimal runtime overhead, following Rust's zero-cost abstraction principle.
 
 use crate::core::error::{CocoanutError, Result};
+use cocoa::foundation::{NSPoint, NSRect, NSSize};
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 
@@ -164,6 +165,11 @@ impl<T> ZeroCostArray<T> {
             None
         }
     }
+
+    /// Iterate over the elements
+    pub fn iter(&self) -> ZeroCostIter<'_, T> {
+        ZeroCostIter::from_slice(self.as_slice())
+    }
 }
 
 impl<T> Clone for ZeroCostArray<T> {
@@ -313,6 +319,42 @@ impl ZeroCostRect {
     }
 }
 
+impl From<ZeroCostPoint> for NSPoint {
+    fn from(point: ZeroCostPoint) -> Self {
+        NSPoint { x: point.x, y: point.y }
+    }
+}
+
+impl From<NSPoint> for ZeroCostPoint {
+    fn from(point: NSPoint) -> Self {
+        ZeroCostPoint { x: point.x, y: point.y }
+    }
+}
+
+impl From<ZeroCostSize> for NSSize {
+    fn from(size: ZeroCostSize) -> Self {
+        NSSize { width: size.width, height: size.height }
+    }
+}
+
+impl From<NSSize> for ZeroCostSize {
+    fn from(size: NSSize) -> Self {
+        ZeroCostSize { width: size.width, height: size.height }
+    }
+}
+
+impl From<ZeroCostRect> for NSRect {
+    fn from(rect: ZeroCostRect) -> Self {
+        NSRect { origin: rect.origin.into(), size: rect.size.into() }
+    }
+}
+
+impl From<NSRect> for ZeroCostRect {
+    fn from(rect: NSRect) -> Self {
+        ZeroCostRect { origin: rect.origin.into(), size: rect.size.into() }
+    }
+}
+
 /// Zero-cost color structure
 /// 
 /// This structure represents a color with zero-cost operations
@@ -366,20 +408,22 @@ impl ZeroCostColor {
 /// Zero-cost iterator over an array
 /// 
 /// This iterator provides zero-cost iteration over arrays
-/// without unnecessary allocations or copies.
-pub struct ZeroCostIter<T> {
+/// without unnecessary allocations or copies. The items borrow from the
+/// source for `'a`.
+pub struct ZeroCostIter<'a, T> {
     ptr: *const T,
     len: usize,
     index: usize,
-    _phantom: PhantomData<T>,
+    _phantom: PhantomData<&'a T>,
 }
 
-impl<T> ZeroCostIter<T> {
+impl<'a, T> ZeroCostIter<'a, T> {
     /// Create a new zero-cost iterator
     /// 
     /// # Safety
     /// 
     /// The pointer must be valid and point to an array of length `len`
+    /// that stays alive and unmodified for `'a`
     pub unsafe fn new(ptr: *const T, len: usize) -> Self {
         Self {
             ptr,
@@ -388,10 +432,15 @@ impl<T> ZeroCostIter<T> {
             _phantom: PhantomData,
         }
     }
+
+    /// Iterate over a borrowed slice
+    pub fn from_slice(slice: &'a [T]) -> Self {
+        unsafe { Self::new(slice.as_ptr(), slice.len()) }
+    }
 }
 
-impl<T: 'static> Iterator for ZeroCostIter<T> {
-    type Item = &'static T;
+impl<'a, T> Iterator for ZeroCostIter<'a, T> {
+    type Item = &'a T;
     
     fn next(&mut self) -> Option<Self::Item> {
         if self.index < self.len {
@@ -409,12 +458,13 @@ impl<T: 'static> Iterator for ZeroCostIter<T> {
     }
 }
 
-impl<T: 'static> ExactSizeIterator for ZeroCostIter<T> {}
+impl<T> ExactSizeIterator for ZeroCostIter<'_, T> {}
 
 /// Zero-cost string iterator
 /// 
-/// This iterator provides zero-cost iteration over strings
-/// without unnecessary allocations or copies.
+/// This iterator decodes the characters of a UTF-8 C string without
+/// unnecessary allocations or copies. Invalid byte sequences come back as
+/// `char::REPLACEMENT_CHARACTER`.
 pub struct ZeroCostStringIter {
     ptr: *const i8,
     len: usize,
@@ -437,13 +487,45 @@ impl Iterator for ZeroCostStringIter {
     type Item = char;
     
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index < self.len {
-            let byte = unsafe { *self.ptr.add(self.index) } as u8;
-            self.index += 1;
-            Some(byte as char)
-        } else {
-            None
+        if self.index >= self.len {
+            return None;
         }
+        let rest = unsafe {
+            std::slice::from_raw_parts(self.ptr.add(self.index) as *const u8, self.len - self.index)
+        };
+        let width = utf8_width(rest[0]);
+        let decoded = rest
+            .get(..width)
+            .and_then(|bytes| std::str::from_utf8(bytes).ok())
+            .and_then(|text| text.chars().next());
+        match decoded {
+            Some(ch) => {
+                self.index += width;
+                Some(ch)
+            }
+            None => {
+                // Skip the whole invalid sequence, as `String::from_utf8_lossy` does
+                let invalid = rest.utf8_chunks().next().map_or(1, |chunk| chunk.invalid().len());
+                self.index += invalid.max(1);
+                Some(char::REPLACEMENT_CHARACTER)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining.div_ceil(4), Some(remaining))
+    }
+}
+
+/// Length of the UTF-8 sequence starting with `byte`, or 0 if it cannot start one
+fn utf8_width(byte: u8) -> usize {
+    match byte {
+        0x00..=0x7F => 1,
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF4 => 4,
+        _ => 0,
     }
 }
 
@@ -507,6 +589,22 @@ mod tests {
         assert_eq!(rect.height(), 200.0);
     }
     
+    #[test]
+    fn test_zero_cost_rect_ns_rect_round_trip() {
+        let rect = ZeroCostRect::from_xywh(10.0, 20.0, 100.0, 200.0);
+        let ns_rect: NSRect = rect.into();
+        assert_eq!(ns_rect.origin.x, 10.0);
+        assert_eq!(ns_rect.origin.y, 20.0);
+        assert_eq!(ns_rect.size.width, 100.0);
+        assert_eq!(ns_rect.size.height, 200.0);
+        assert_eq!(ZeroCostRect::from(ns_rect), rect);
+
+        let point: NSPoint = ZeroCostPoint::new(-1.5, 2.5).into();
+        assert_eq!(ZeroCostPoint::from(point), ZeroCostPoint::new(-1.5, 2.5));
+        let size: NSSize = ZeroCostSize::new(3.0, 4.0).into();
+        assert_eq!(ZeroCostSize::from(size), ZeroCostSize::new(3.0, 4.0));
+    }
+    
     #[test]
     fn test_zero_cost_color() {
         let color = ZeroCostColor::rgb(1.0, 0.5, 0.0);
@@ -535,4 +633,31 @@ mod tests {
         let collected: Vec<_> = iter.collect();
         assert_eq!(collected, vec![&1, &2, &3, &4, &5]);
     }
+
+    #[test]
+    fn test_zero_cost_string_iter_decodes_utf8() {
+        let text = std::ffi::CString::new("café 🦀").unwrap();
+        let chars: Vec<char> = unsafe { ZeroCostStringIter::new(text.as_ptr()) }.collect();
+        assert_eq!(chars, "café 🦀".chars().collect::<Vec<_>>());
+
+        let invalid = std::ffi::CString::new(b"a\xffb\xe2\x82".to_vec()).unwrap();
+        let chars: Vec<char> = unsafe { ZeroCostStringIter::new(invalid.as_ptr()) }.collect();
+        assert_eq!(chars, ['a', char::REPLACEMENT_CHARACTER, 'b', char::REPLACEMENT_CHARACTER]);
+    }
+
+    #[test]
+    fn test_zero_cost_iter_borrows_slice() {
+        let data = vec![String::from("a"), String::from("b")];
+        let iter = ZeroCostIter::from_slice(&data);
+        assert_eq!(iter.len(), 2);
+        let joined: Vec<&str> = iter.map(String::as_str).collect();
+        assert_eq!(joined, ["a", "b"]);
+
+        let array = unsafe { ZeroCostArray::new(data.as_ptr(), data.len()) };
+        assert_eq!(array.iter().count(), 2);
+        assert_eq!(array.iter().last(), Some(&data[1]));
+
+        let empty = unsafe { ZeroCostArray::<u8>::new(std::ptr::null(), 0) };
+        assert_eq!(empty.iter().next(), None);
+    }
 }
@@ -43,6 +43,7 @@ pub mod utils;
 pub mod window;
 pub mod window_v2;
 pub mod menu;
+pub mod toolbar;
 pub mod simple_app {
     pub mod component;
     pub mod layout;
@@ -124,35 +125,40 @@ pub mod core_fixes {
 
 /// Re-exports for convenient usage
 pub mod prelude {
-    pub use crate::application::Application;
-    pub use crate::window::Window;
+    pub use crate::application::{Application, ActivationPolicy};
+    pub use crate::window::{TabbingMode, Window, WindowLevel, WindowOrder};
     pub use crate::menu::{Menu, MenuItem};
-    pub use crate::components::basic::{Button, Label, TextField};
-    pub use crate::systems::events::{Event, EventHandler};
-    pub use crate::features::drawing::{Color, Point, Size, Rect};
-    pub use crate::core::error::{CocoanutError, Result};
+    pub use crate::toolbar::{Toolbar, ToolbarItem, ToolbarDisplayMode};
+    pub use crate::components::basic::{Button, Label, NumberFormat, TextField};
+    pub use crate::components::basic::{ImageView, ImageViewBuilder, ImageScaling};
+    pub use crate::components::basic::{TextView, TextViewBuilder};
+    pub use crate::systems::events::{Event, EventHandler, KeyEvent, KeyHandler, Modifiers, MouseEvent, MouseButton};
+    pub use crate::systems::shortcuts::{ShortcutRegistry, KeyCombo};
+    pub use crate::features::drawing::{Color, Font, FontWeight, Gradient, Point, Size, Rect};
+    pub use crate::core::error::{CocoanutError, Result, ResultExt};
     
     // Modern Rust patterns
-    pub use crate::async_ui::{AsyncUI, AsyncUIExecutor, AsyncUIContext, AsyncWindow, AsyncButton};
+    pub use crate::async_ui::{AsyncUI, AsyncUIExecutor, AsyncUIContext, AsyncWindow, AsyncButton, Debouncer};
     pub use crate::streaming::{ReactiveUI, UIEvent, UIEventStream, ReactiveButton, ReactiveTextField, ReactiveWindow, ReactiveUIManager};
     pub use crate::features::zero_cost::{ZeroCostObject, ZeroCostString, ZeroCostArray, ZeroCostPoint, ZeroCostSize, ZeroCostRect, ZeroCostColor};
     
     // macOS integration
     pub use crate::features::macos::{
         MacOSIntegrationManager, DesignLanguageManager, AccessibilityManager, DarkModeManager, TouchBarManager,
-        DesignStyle, Appearance, TouchBarItem, DesignLanguageComponent, AccessibleComponent,
+        DesignStyle, Appearance, TouchBarItem, DesignLanguageComponent, AccessibleComponent, AccessibilityRole,
         NativeWindow, NativeButton
     };
+    pub use crate::features::macos::{Cursor, Hoverable, Dock, Notification, Pasteboard, Preferences, Screen, StatusItem};
     
     // Builders for simplified API
     pub use crate::builder::{ButtonBuilder, LabelBuilder, TextFieldBuilder, WindowBuilder};
     
     // Layout system
-    pub use crate::layout::{VStack, HStack, Spacer, Spacing, Alignment};
+    pub use crate::layout::{VStack, HStack, ZStack, Grid, Spacer, Spacing, Alignment, Alignment2D, LayoutItem};
     
     // Styling system
     pub use crate::styling::{
-        CarbonColor, TypographyScale, SpacingScale, CornerRadiusScale, ComponentStyle,
+        CarbonColor, TypographyScale, SpacingScale, CornerRadiusScale, ComponentStyle, Styleable,
     };
     
     // Phase 2: Basic Controls
@@ -163,6 +169,10 @@ pub mod prelude {
         SegmentedControl, SegmentedControlBuilder,
         Stepper, StepperBuilder,
         Switch, SwitchBuilder,
+        NumberField,
+        PopUpButton,
+        LevelIndicator, LevelIndicatorBuilder, LevelIndicatorStyle,
+        Badge,
     };
     
     // Phase 2: Container Views
@@ -171,11 +181,12 @@ pub mod prelude {
         TabView, TabViewBuilder,
         SplitView, SplitViewBuilder, SplitOrientation,
         GroupBox, GroupBoxBuilder,
+        Popover, PopoverBehavior, Edge as PopoverEdge,
     };
     
     // Phase 3: Data Display
     pub use crate::data_display::{
-        TableView, TableViewBuilder,
+        TableView, TableViewBuilder, DataTable, CellContent,
         OutlineView, OutlineViewBuilder, OutlineItem,
         CollectionView, CollectionViewBuilder,
     };
@@ -186,7 +197,7 @@ pub mod prelude {
         TouchBar as TouchBarFeature, TouchBarBuilder as TouchBarFeatureBuilder, TouchBarItem as TouchBarFeatureItem,
         AccessibilityOptions, AccessibilityBuilder,
         DarkModeManager as DarkModeFeature, Appearance as AppearanceMode,
-        DragDropManager,
+        DragDropManager, DroppedItem,
         AdvancedStyling, AdvancedStylingBuilder,
     };
     
@@ -227,4 +238,4 @@ pub mod prelude {
     pub use crate::simple_app::{SimpleApp, app, Comp, Kind};
 }
 
-pub use core::error::{CocoanutError, Result};
+pub use core::error::{CocoanutError, Result, ResultExt};
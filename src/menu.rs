@@ -1,14 +1,19 @@
 //! Menu system for macOS GUI applications
 
 use crate::core::error::{CocoanutError, Result};
-use objc::runtime::Object;
+use crate::systems::target_action::TargetAction;
+use objc::runtime::{Object, Sel};
 use objc::{msg_send, sel, sel_impl};
-use std::ffi::CString;
+use std::sync::Mutex;
 
 /// A macOS menu wrapper
+///
+/// The menu keeps the action handlers of the items added to it alive, since
+/// `NSMenuItem` does not retain its target.
 pub struct Menu {
     ns_menu: *mut Object,
     title: String,
+    handlers: Mutex<Vec<TargetAction>>,
 }
 
 /// A macOS menu item wrapper
@@ -16,6 +21,8 @@ pub struct MenuItem {
     ns_menu_item: *mut Object,
     title: String,
     action: Option<String>,
+    key_equivalent: String,
+    handler: Option<TargetAction>,
 }
 
 impl Menu {
@@ -33,12 +40,11 @@ impl Menu {
             let menu_class = objc::class!(NSMenu);
             let ns_menu: *mut Object = msg_send![menu_class, alloc];
             
-            let title_cstr = CString::new(title)
-                .map_err(|e| CocoanutError::InvalidParameter(e.to_string()))?;
+            let ns_title = crate::core::utils::string_to_ns_string(title)?;
             
             let ns_menu: *mut Object = msg_send![
                 ns_menu,
-                initWithTitle: title_cstr.as_ptr()
+                initWithTitle: ns_title
             ];
             
             if ns_menu.is_null() {
@@ -50,6 +56,7 @@ impl Menu {
             Ok(Menu {
                 ns_menu,
                 title: title.to_string(),
+                handlers: Mutex::new(Vec::new()),
             })
         }
     }
@@ -69,6 +76,12 @@ impl Menu {
     /// 
     /// Returns a `Result<()>` indicating success or failure
     pub fn add_item(&self, item: MenuItem) -> Result<()> {
+        if let Some(handler) = &item.handler {
+            self.handlers
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .push(handler.clone());
+        }
         unsafe {
             let _: () = msg_send![self.ns_menu, addItem: item.ns_menu_item];
             Ok(())
@@ -87,7 +100,8 @@ impl MenuItem {
     /// # Arguments
     /// 
     /// * `title` - The menu item title
-    /// * `action` - Optional action selector name
+    /// * `action` - Optional action selector name, such as `"copy:"`. With
+    ///   no target the action goes to the first responder that handles it.
     /// 
     /// # Returns
     /// 
@@ -97,17 +111,15 @@ impl MenuItem {
             let menu_item_class = objc::class!(NSMenuItem);
             let ns_menu_item: *mut Object = msg_send![menu_item_class, alloc];
             
-            let title_cstr = CString::new(title)
-                .map_err(|e| CocoanutError::InvalidParameter(e.to_string()))?;
+            let ns_title = crate::core::utils::string_to_ns_string(title)?;
             
-            let action_sel = if action.is_some() {
-                sel!(action)
-            } else {
-                sel!(null)
+            let action_sel = match action {
+                Some(action) => Sel::register(action),
+                None => sel!(null),
             };
             
-            let empty_str = CString::new("").unwrap();
-            let ns_menu_item: *mut Object = msg_send![ns_menu_item, initWithTitle:title_cstr.as_ptr() action:action_sel keyEquivalent:empty_str.as_ptr()];
+            let empty_str = crate::core::utils::string_to_ns_string("")?;
+            let ns_menu_item: *mut Object = msg_send![ns_menu_item, initWithTitle:ns_title action:action_sel keyEquivalent:empty_str];
             
             if ns_menu_item.is_null() {
                 return Err(CocoanutError::MenuCreationFailed(
@@ -119,6 +131,8 @@ impl MenuItem {
                 ns_menu_item,
                 title: title.to_string(),
                 action: action.map(|s| s.to_string()),
+                key_equivalent: String::new(),
+                handler: None,
             })
         }
     }
@@ -142,6 +156,8 @@ impl MenuItem {
                 ns_menu_item,
                 title: "".to_string(),
                 action: None,
+                key_equivalent: String::new(),
+                handler: None,
             })
         }
     }
@@ -156,23 +172,89 @@ impl MenuItem {
         self.action.as_deref()
     }
     
+    /// Get the key that chooses the item together with Command, empty if none
+    pub fn key_equivalent(&self) -> &str {
+        &self.key_equivalent
+    }
+
+    /// Choose the item with Command and `key`, e.g. `"c"` for Command-C
+    pub fn with_key_equivalent(mut self, key: &str) -> Result<Self> {
+        unsafe {
+            let ns_key = crate::core::utils::string_to_ns_string(key)?;
+            let _: () = msg_send![self.ns_menu_item, setKeyEquivalent: ns_key];
+        }
+        self.key_equivalent = key.to_string();
+        Ok(self)
+    }
+    
     /// Set the menu item title
     pub fn set_title(&mut self, title: &str) -> Result<()> {
         unsafe {
-            let title_cstr = CString::new(title)
-                .map_err(|e| CocoanutError::InvalidParameter(e.to_string()))?;
-            let _: () = msg_send![self.ns_menu_item, setTitle: title_cstr.as_ptr()];
+            let ns_title = crate::core::utils::string_to_ns_string(title)?;
+            let _: () = msg_send![self.ns_menu_item, setTitle: ns_title];
             self.title = title.to_string();
             Ok(())
         }
     }
     
+    /// Run `handler` whenever the item is chosen
+    ///
+    /// Replaces the selector given to [`MenuItem::new`] and any previously set handler.
+    pub fn on_action<F>(&mut self, handler: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let target_action = TargetAction::new(handler);
+        target_action.attach_to(self.ns_menu_item);
+        self.handler = Some(target_action);
+    }
+
+    /// Run the handler set with [`MenuItem::on_action`], if any
+    pub fn perform_action(&self) {
+        if let Some(handler) = &self.handler {
+            handler.invoke();
+        }
+    }
+    
     /// Get the underlying NSMenuItem pointer
     pub(crate) fn ns_menu_item(&self) -> *mut Object {
         self.ns_menu_item
     }
 }
 
+/// Title, responder action and Command key of each standard Edit menu item
+const EDIT_MENU_ITEMS: [(&str, &str, &str); 4] = [
+    ("Cut", "cut:", "x"),
+    ("Copy", "copy:", "c"),
+    ("Paste", "paste:", "v"),
+    ("Select All", "selectAll:", "a"),
+];
+
+/// Create the items of the standard Edit menu: Cut, Copy, Paste and Select
+/// All, with their usual Command keys
+///
+/// The items have no target, so AppKit sends their actions to the focused
+/// text control.
+pub fn edit_menu_items() -> Result<Vec<MenuItem>> {
+    EDIT_MENU_ITEMS
+        .iter()
+        .map(|(title, action, key)| MenuItem::new(title, Some(action))?.with_key_equivalent(key))
+        .collect()
+}
+
+/// Create the standard Edit menu, so Command-C and friends work in text
+/// fields
+///
+/// Install it in the menu bar with
+/// [`Application::add_menu`](crate::application::Application::add_menu).
+pub fn edit_menu() -> Result<Menu> {
+    let menu = Menu::new("Edit")?;
+    for item in edit_menu_items()? {
+        menu.add_item(item)?;
+    }
+    Ok(menu)
+}
+
 impl Drop for Menu {
     fn drop(&mut self) {
         unsafe {
@@ -193,3 +275,45 @@ unsafe impl Send for Menu {}
 unsafe impl Sync for Menu {}
 unsafe impl Send for MenuItem {}
 unsafe impl Sync for MenuItem {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_menu_items() {
+        let items = edit_menu_items().unwrap();
+        let entries: Vec<(&str, Option<&str>, &str)> = items
+            .iter()
+            .map(|item| (item.title(), item.action(), item.key_equivalent()))
+            .collect();
+        assert_eq!(entries, vec![
+            ("Cut", Some("cut:"), "x"),
+            ("Copy", Some("copy:"), "c"),
+            ("Paste", Some("paste:"), "v"),
+            ("Select All", Some("selectAll:"), "a"),
+        ]);
+
+        let menu = edit_menu().unwrap();
+        assert_eq!(menu.title(), "Edit");
+        unsafe {
+            let count: isize = msg_send![menu.ns_menu(), numberOfItems];
+            let mut native = Vec::new();
+            for index in 0..count {
+                let item: *mut Object = msg_send![menu.ns_menu(), itemAtIndex: index];
+                let title: *mut Object = msg_send![item, title];
+                let action: Sel = msg_send![item, action];
+                let key: *mut Object = msg_send![item, keyEquivalent];
+                native.push((
+                    crate::core::utils::ns_string_to_string(title).unwrap(),
+                    action.name().to_string(),
+                    crate::core::utils::ns_string_to_string(key).unwrap(),
+                ));
+            }
+            assert_eq!(native, entries
+                .iter()
+                .map(|(title, action, key)| (title.to_string(), action.unwrap().to_string(), key.to_string()))
+                .collect::<Vec<_>>());
+        }
+    }
+}
@@ -34,8 +34,13 @@ pub struct SimpleApp {
     pub layout: Layout,
     /// Components to display
     pub components: Vec<Comp>,
+    /// Whether to scroll the content when components overflow the window
+    pub scrollable: bool,
 }
 
+/// Space kept free below the last component, in points
+const BOTTOM_PADDING: f64 = 20.0;
+
 impl SimpleApp {
     /// Create a new application
     pub fn new(name: &str) -> Self {
@@ -48,6 +53,7 @@ impl SimpleApp {
             window: None,
             layout: Layout::default(),
             components: Vec::new(),
+            scrollable: true,
         }
     }
 
@@ -94,6 +100,30 @@ impl SimpleApp {
         self
     }
 
+    /// Scroll the content when the components do not fit in the window
+    ///
+    /// Enabled by default. When disabled, components that would overflow
+    /// the window are skipped.
+    pub fn scrollable(mut self, scrollable: bool) -> Self {
+        self.scrollable = scrollable;
+        self
+    }
+
+    /// Get the height needed to show every component, including padding
+    pub fn content_height(&self) -> f64 {
+        self.layout.content_height(&self.component_sizes(), self.width) + BOTTOM_PADDING
+    }
+
+    /// The preferred `(width, height)` of each component
+    fn component_sizes(&self) -> Vec<(f64, f64)> {
+        self.components.iter().map(|comp| (comp.width, comp.height)).collect()
+    }
+
+    /// Check if the components will be placed in a scroll view
+    pub fn needs_scrolling(&self) -> bool {
+        self.scrollable && self.content_height() > self.height
+    }
+
     /// Clear all components
     pub fn clear_components(mut self) -> Self {
         self.components.clear();
@@ -197,8 +227,10 @@ impl SimpleApp {
                 // Step 6: Configure window to stop app when closed
                 let _: () = msg_send![ns_window, setReleasedWhenClosed:true];
                 
-                // Make close button terminate the app
-                let _: () = msg_send![app, setDelegate:ns_window];
+                // Quit once the window is closed. The delegate must be an
+                // NSApplicationDelegate; an NSWindow never answers
+                // applicationShouldTerminateAfterLastWindowClosed:
+                let _: () = msg_send![app, setDelegate:crate::application::quit_after_last_window_delegate()];
 
                 // Step 7: Run event loop
                 println!("🚀 Running event loop (close window or press Cmd+Q to quit)...\n");
@@ -222,15 +254,16 @@ impl SimpleApp {
         unsafe {
             println!("Adding {} component(s)...", self.components.len());
             
-            let available_width = self.width - (self.layout.horizontal_margin * 2.0);
-            let bottom_padding = 20.0;
-            let mut y_position = self.height - self.layout.top_padding;
+            let (container, container_height) = if self.needs_scrolling() {
+                (self.add_scroll_container(content_view)?, self.content_height())
+            } else {
+                (content_view, self.height)
+            };
+            let frames = self.layout.frames(&self.component_sizes(), self.width, container_height);
             let mut components_added = 0;
             
-            for comp in &self.components {
-                let comp_y = y_position - comp.height;
-                
-                if comp_y < bottom_padding {
+            for (comp, &(comp_x, comp_y, comp_width, comp_height)) in self.components.iter().zip(&frames) {
+                if comp_y < BOTTOM_PADDING {
                     println!("  ⚠️  Component \"{}\" would overflow - skipping", comp.text);
                     continue;
                 }
@@ -251,15 +284,6 @@ impl SimpleApp {
                     .ok_or(format!("{} class not found", class_name))?;
                 let view: *mut Object = msg_send![view_class, alloc];
                 
-                let comp_width = if comp.width > available_width {
-                    available_width
-                } else {
-                    comp.width
-                };
-                
-                let comp_x = self.layout.horizontal_margin;
-                let comp_height = comp.height;
-                
                 let frame = NSRect {
                     origin: NSPoint { x: comp_x, y: comp_y },
                     size: NSSize { width: comp_width, height: comp_height },
@@ -267,11 +291,11 @@ impl SimpleApp {
                 let view: *mut Object = msg_send![view, initWithFrame:frame];
                 
                 self.configure_component(view, comp)?;
+                attach_handlers(view, comp);
                 
-                let _: () = msg_send![content_view, addSubview:view];
+                let _: () = msg_send![container, addSubview:view];
                 println!("  ✓ {:?} added: \"{}\" ({}x{})", comp.kind, comp.text, comp_width as i32, comp_height as i32);
                 components_added += 1;
-                y_position -= (comp_height + self.layout.gap);
             }
             println!("  ℹ️  {} of {} components displayed (window height: {}px)", 
                 components_added, self.components.len(), self.height as i32);
@@ -280,6 +304,53 @@ impl SimpleApp {
         Ok(())
     }
 
+    /// Fill the content view with a scroll view and return its document view
+    ///
+    /// The document view is tall enough for every component and starts
+    /// scrolled to the top.
+    #[cfg(not(feature = "test-mock"))]
+    fn add_scroll_container(&self, content_view: *mut objc::runtime::Object) -> Result<*mut objc::runtime::Object> {
+        use objc::runtime::Object;
+        use objc::{class, msg_send, sel, sel_impl};
+        use cocoa::foundation::{NSRect, NSPoint, NSSize};
+
+        unsafe {
+            let bounds: NSRect = msg_send![content_view, bounds];
+            let scroll_view: *mut Object = msg_send![class!(NSScrollView), alloc];
+            let scroll_view: *mut Object = msg_send![scroll_view, initWithFrame:bounds];
+            if scroll_view.is_null() {
+                return Err(crate::core::error::CocoanutError::ControlCreationFailed(
+                    "Failed to create NSScrollView".to_string()
+                ));
+            }
+            let _: () = msg_send![scroll_view, setHasVerticalScroller:true];
+            let _: () = msg_send![scroll_view, setAutohidesScrollers:true];
+            let _: () = msg_send![scroll_view, setDrawsBackground:false];
+            let _: () = msg_send![scroll_view, setAutoresizingMask:18u64]; // NSViewWidthSizable | NSViewHeightSizable
+
+            let content_height = self.content_height();
+            let document_frame = NSRect {
+                origin: NSPoint { x: 0.0, y: 0.0 },
+                size: NSSize { width: bounds.size.width, height: content_height },
+            };
+            let document_view: *mut Object = msg_send![class!(NSView), alloc];
+            let document_view: *mut Object = msg_send![document_view, initWithFrame:document_frame];
+            let _: () = msg_send![scroll_view, setDocumentView:document_view];
+            let _: () = msg_send![document_view, release];
+            let _: () = msg_send![content_view, addSubview:scroll_view];
+            let _: () = msg_send![scroll_view, release];
+
+            // Document views are not flipped, so the top is at the largest y
+            let clip_view: *mut Object = msg_send![scroll_view, contentView];
+            let top = NSPoint { x: 0.0, y: content_height - bounds.size.height };
+            let _: () = msg_send![clip_view, scrollToPoint:top];
+            let _: () = msg_send![scroll_view, reflectScrolledClipView:clip_view];
+
+            println!("  ↕️  Content is {}px tall - scrolling enabled", content_height as i32);
+            Ok(document_view)
+        }
+    }
+
     #[cfg(not(feature = "test-mock"))]
     fn configure_component(&self, view: *mut objc::runtime::Object, comp: &Comp) -> Result<()> {
         use objc::{msg_send, sel, sel_impl};
@@ -321,28 +392,18 @@ impl SimpleApp {
                     let _: () = msg_send![view, setEditable:true];
                 }
                 super::component::Kind::Slider => {
-                    let _: () = msg_send![view, setMinValue:0.0];
-                    let _: () = msg_send![view, setMaxValue:100.0];
-                    let _: () = msg_send![view, setDoubleValue:50.0];
+                    let (min, max) = comp.get_range();
+                    let _: () = msg_send![view, setMinValue:min];
+                    let _: () = msg_send![view, setMaxValue:max];
+                    let _: () = msg_send![view, setDoubleValue:comp.get_value()];
                 }
                 super::component::Kind::Dropdown => {
                     let text = std::ffi::CString::new(comp.text.as_str()).unwrap();
                     let ns_string: *mut objc::runtime::Object = msg_send![objc::class!(NSString), stringWithUTF8String:text.as_ptr()];
                     let _: () = msg_send![view, addItemWithTitle:ns_string];
                     
-                    let choices: Vec<&str> = if comp.text.contains("theme") {
-                        vec!["Light", "Dark", "Auto"]
-                    } else if comp.text.contains("language") {
-                        vec!["English", "Spanish", "French", "German"]
-                    } else if comp.text.contains("size") || comp.text.contains("Font") {
-                        vec!["Small", "Medium", "Large", "Extra Large"]
-                    } else {
-                        vec!["Option 1", "Option 2", "Option 3"]
-                    };
-                    
-                    for choice in choices {
-                        let choice_cstr = std::ffi::CString::new(choice).unwrap();
-                        let choice_ns: *mut objc::runtime::Object = msg_send![objc::class!(NSString), stringWithUTF8String:choice_cstr.as_ptr()];
+                    for choice in comp.get_options() {
+                        let choice_ns = crate::core::utils::string_to_ns_string(choice)?;
                         let _: () = msg_send![view, addItemWithTitle:choice_ns];
                     }
                 }
@@ -402,9 +463,56 @@ impl SimpleApp {
     }
 }
 
+/// Make a component's control call its click or change handler
+///
+/// The control keeps its target alive, so the handlers live as long as the
+/// window does.
+#[cfg(not(feature = "test-mock"))]
+unsafe fn attach_handlers(view: *mut objc::runtime::Object, comp: &Comp) {
+    use super::component::Kind;
+    use crate::systems::target_action::TargetAction;
+    use objc::runtime::{Object, BOOL, YES};
+    use objc::{class, msg_send, sel, sel_impl};
+
+    let action = match comp.kind {
+        Kind::Button | Kind::Checkbox | Kind::Radio => comp.get_on_click().cloned().map(|handler| {
+            TargetAction::new(move || handler())
+        }),
+        Kind::TextField | Kind::Slider | Kind::Dropdown => comp.get_on_change().cloned().map(|handler| {
+            // Pointers are not `Send`; actions only arrive on the main thread
+            let sender = view as usize;
+            TargetAction::new(move || unsafe {
+                let sender = sender as *mut Object;
+                let is_popup: BOOL = msg_send![sender, isKindOfClass: class!(NSPopUpButton)];
+                let value: *mut Object = if is_popup == YES {
+                    msg_send![sender, titleOfSelectedItem]
+                } else {
+                    msg_send![sender, stringValue]
+                };
+                if let Ok(value) = crate::core::utils::ns_string_to_string(value) {
+                    handler(&value);
+                }
+            })
+        }),
+        _ => None,
+    };
+    let Some(action) = action else {
+        return;
+    };
+
+    action.attach_to(view);
+    if comp.kind == Kind::TextField {
+        // Report every edit rather than only Return
+        unsafe {
+            let _: () = msg_send![view, setContinuous: true];
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::simple_app::Kind;
 
     #[test]
     fn test_simple_app_creation() {
@@ -418,4 +526,46 @@ mod tests {
         let app = crate::simple_app::app("Builder App");
         assert_eq!(app.name, "Builder App");
     }
+
+    #[test]
+    fn test_simple_app_scrolls_overflowing_content() {
+        let labels: Vec<Comp> = (0..20).map(|_| Comp::new(Kind::Label)).collect();
+        let app = SimpleApp::new("Overflow").size(400.0, 300.0).add_all(labels);
+        assert!(app.scrollable);
+
+        // 40 top + 20 * 30 labels + 19 * 12 gaps + 20 bottom
+        assert_eq!(app.content_height(), 888.0);
+        assert!(app.needs_scrolling());
+        assert!(!app.scrollable(false).needs_scrolling());
+    }
+
+    #[test]
+    fn test_simple_app_fitting_content_does_not_scroll() {
+        let app = SimpleApp::new("Fits")
+            .add(Comp::new(Kind::Button))
+            .add(Comp::new(Kind::Label));
+        assert!(!app.needs_scrolling());
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_simple_app_run_mock() {
+        let app = SimpleApp::new("Mock App").size(320.0, 240.0);
+        assert!(app.run().is_ok());
+    }
+
+    // Regression: the app delegate used to be the NSWindow, which does not
+    // implement applicationShouldTerminateAfterLastWindowClosed:, so closing
+    // the window left the process running
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_app_delegate_terminates_after_last_window_closed() {
+        use objc::runtime::{Object, BOOL, YES};
+        use objc::{msg_send, sel, sel_impl};
+
+        let delegate = crate::application::quit_after_last_window_delegate();
+        let nil: *mut Object = std::ptr::null_mut();
+        let terminates: BOOL = unsafe { msg_send![delegate, applicationShouldTerminateAfterLastWindowClosed: nil] };
+        assert_eq!(terminates, YES);
+    }
 }
@@ -1,6 +1,14 @@
 //! Component types and configuration
 
+use crate::core::error::{CocoanutError, Result};
 use std::fmt;
+use std::sync::Arc;
+
+/// Handler run when a Button, Checkbox or Radio component is clicked
+pub type ClickHandler = Arc<dyn Fn() + Send + Sync>;
+
+/// Handler run with the new value when a TextField, Slider or Dropdown component changes
+pub type ChangeHandler = Arc<dyn Fn(&str) + Send + Sync>;
 
 /// Component types that can be added to a window
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -51,7 +59,7 @@ impl fmt::Display for Kind {
 }
 
 /// Configurable component with customizable properties
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Comp {
     /// Component type
     pub kind: Kind,
@@ -61,6 +69,35 @@ pub struct Comp {
     pub width: f64,
     /// Component height
     pub height: f64,
+    /// Click handler for Button, Checkbox and Radio components
+    on_click: Option<ClickHandler>,
+    /// Change handler for TextField, Slider and Dropdown components
+    on_change: Option<ChangeHandler>,
+    /// Slider minimum value
+    min: f64,
+    /// Slider maximum value
+    max: f64,
+    /// Slider initial value, always within `min..=max`
+    value: f64,
+    /// Dropdown choices, listed after the title item
+    options: Vec<String>,
+}
+
+impl fmt::Debug for Comp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Comp")
+            .field("kind", &self.kind)
+            .field("text", &self.text)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .field("value", &self.value)
+            .field("options", &self.options)
+            .field("on_click", &self.on_click.is_some())
+            .field("on_change", &self.on_change.is_some())
+            .finish()
+    }
 }
 
 impl Comp {
@@ -80,7 +117,22 @@ impl Comp {
             Kind::SplitView => ("SplitView".to_string(), 350.0, 200.0),
             Kind::GroupBox => ("GroupBox".to_string(), 350.0, 200.0),
         };
-        Self { kind, text, width, height }
+        let options = match kind {
+            Kind::Dropdown => vec!["Option 1".to_string(), "Option 2".to_string(), "Option 3".to_string()],
+            _ => Vec::new(),
+        };
+        Self {
+            kind,
+            text,
+            width,
+            height,
+            on_click: None,
+            on_change: None,
+            min: 0.0,
+            max: 100.0,
+            value: 50.0,
+            options,
+        }
     }
 
     /// Set component text
@@ -95,4 +147,160 @@ impl Comp {
         self.height = height;
         self
     }
+
+    /// Set the slider range
+    ///
+    /// The bounds are swapped if given in the wrong order, and the current
+    /// value is clamped into the new range.
+    ///
+    /// Returns `CocoanutError::InvalidParameter` if a bound is not finite.
+    pub fn range(mut self, min: f64, max: f64) -> Result<Self> {
+        if !min.is_finite() || !max.is_finite() {
+            return Err(CocoanutError::InvalidParameter(
+                format!("Slider range {}..{} must be finite", min, max)
+            ));
+        }
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+        self.min = min;
+        self.max = max;
+        self.value = self.value.clamp(min, max);
+        Ok(self)
+    }
+
+    /// Set the slider's initial value, clamped into its range
+    ///
+    /// Returns `CocoanutError::InvalidParameter` if the value is not finite.
+    pub fn value(mut self, value: f64) -> Result<Self> {
+        if !value.is_finite() {
+            return Err(CocoanutError::InvalidParameter(
+                format!("Slider value {} must be finite", value)
+            ));
+        }
+        self.value = value.clamp(self.min, self.max);
+        Ok(self)
+    }
+
+    /// Set the dropdown choices
+    pub fn options(mut self, options: &[&str]) -> Self {
+        self.options = options.iter().map(|option| option.to_string()).collect();
+        self
+    }
+
+    /// Run a handler when the component is clicked
+    ///
+    /// Only Button, Checkbox and Radio components send clicks.
+    pub fn on_click<F>(mut self, handler: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.on_click = Some(Arc::new(handler));
+        self
+    }
+
+    /// Run a handler with the new value when the component changes
+    ///
+    /// Only TextField, Slider and Dropdown components send changes. The value
+    /// is the field text, the slider value, or the selected item title.
+    pub fn on_change<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(handler));
+        self
+    }
+
+    /// Get the slider range as `(min, max)`
+    pub fn get_range(&self) -> (f64, f64) {
+        (self.min, self.max)
+    }
+
+    /// Get the slider's initial value
+    pub fn get_value(&self) -> f64 {
+        self.value
+    }
+
+    /// Get the dropdown choices
+    pub fn get_options(&self) -> &[String] {
+        &self.options
+    }
+
+    /// Get the click handler, if any
+    pub fn get_on_click(&self) -> Option<&ClickHandler> {
+        self.on_click.as_ref()
+    }
+
+    /// Get the change handler, if any
+    pub fn get_on_change(&self) -> Option<&ChangeHandler> {
+        self.on_change.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_comp_slider_range() {
+        let slider = Comp::new(Kind::Slider).range(10.0, 20.0).unwrap().value(15.0).unwrap();
+        assert_eq!((slider.get_range(), slider.get_value()), ((10.0, 20.0), 15.0));
+
+        // The default value of 50 is clamped into a narrower range
+        let slider = Comp::new(Kind::Slider).range(0.0, 10.0).unwrap();
+        assert_eq!(slider.get_value(), 10.0);
+
+        let slider = Comp::new(Kind::Slider).range(1.0, -1.0).unwrap().value(-5.0).unwrap();
+        assert_eq!((slider.get_range(), slider.get_value()), ((-1.0, 1.0), -1.0));
+    }
+
+    #[test]
+    fn test_comp_slider_rejects_nan() {
+        assert!(matches!(Comp::new(Kind::Slider).range(f64::NAN, 1.0), Err(CocoanutError::InvalidParameter(_))));
+        assert!(Comp::new(Kind::Slider).range(0.0, f64::INFINITY).is_err());
+        assert!(Comp::new(Kind::Slider).value(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_comp_dropdown_options() {
+        assert_eq!(Comp::new(Kind::Dropdown).get_options().len(), 3);
+        assert!(Comp::new(Kind::Button).get_options().is_empty());
+
+        let dropdown = Comp::new(Kind::Dropdown).options(&["Light", "Dark", "Auto"]);
+        assert_eq!(dropdown.get_options(), ["Light", "Dark", "Auto"]);
+    }
+
+    #[test]
+    fn test_comp_stores_click_handler() {
+        let clicks = Arc::new(AtomicUsize::new(0));
+        let comp = {
+            let clicks = Arc::clone(&clicks);
+            Comp::new(Kind::Button).on_click(move || {
+                clicks.fetch_add(1, Ordering::SeqCst);
+            })
+        };
+        assert!(comp.get_on_change().is_none());
+
+        // Clones share the handler
+        let copy = comp.clone();
+        (comp.get_on_click().unwrap())();
+        (copy.get_on_click().unwrap())();
+        assert_eq!(clicks.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_comp_stores_change_handler() {
+        let values = Arc::new(Mutex::new(Vec::new()));
+        let comp = {
+            let values = Arc::clone(&values);
+            Comp::new(Kind::Slider).on_change(move |value| {
+                values.lock().unwrap().push(value.to_string());
+            })
+        };
+        assert!(comp.get_on_click().is_none());
+        assert!(format!("{:?}", comp).contains("on_change: true"));
+
+        (comp.get_on_change().unwrap())("75");
+        assert_eq!(*values.lock().unwrap(), vec!["75"]);
+    }
 }
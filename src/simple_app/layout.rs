@@ -1,5 +1,15 @@
 //! Layout configuration and management
 
+/// Direction in which components are placed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// Top to bottom, filling each row of `columns` before starting the next
+    Vertical,
+    /// Left to right at each component's own width, wrapping onto a new row
+    /// when the next component does not fit
+    Horizontal,
+}
+
 /// Layout configuration for component positioning
 #[derive(Debug, Clone)]
 pub struct Layout {
@@ -9,6 +19,10 @@ pub struct Layout {
     pub horizontal_margin: f64,
     /// Gap between components
     pub gap: f64,
+    /// Number of equal-width columns used by the vertical orientation
+    pub columns: usize,
+    /// Direction in which components are placed
+    pub orientation: Orientation,
 }
 
 impl Layout {
@@ -18,6 +32,8 @@ impl Layout {
             top_padding: 40.0,
             horizontal_margin: 20.0,
             gap: 12.0,
+            columns: 1,
+            orientation: Orientation::Vertical,
         }
     }
 
@@ -27,6 +43,8 @@ impl Layout {
             top_padding: 20.0,
             horizontal_margin: 10.0,
             gap: 8.0,
+            columns: 1,
+            orientation: Orientation::Vertical,
         }
     }
 
@@ -36,6 +54,8 @@ impl Layout {
             top_padding: 60.0,
             horizontal_margin: 40.0,
             gap: 20.0,
+            columns: 1,
+            orientation: Orientation::Vertical,
         }
     }
 
@@ -56,6 +76,102 @@ impl Layout {
         self.gap = gap;
         self
     }
+
+    /// Set the number of columns components flow into (at least 1)
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Set the direction in which components are placed
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Get the width of one column in a container of the given width
+    pub fn column_width(&self, container_width: f64) -> f64 {
+        let columns = self.columns.max(1) as f64;
+        let available = container_width - self.horizontal_margin * 2.0;
+        ((available - self.gap * (columns - 1.0)) / columns).max(0.0)
+    }
+
+    /// Compute the frame of each component in a container
+    ///
+    /// # Arguments
+    ///
+    /// * `sizes` - The preferred `(width, height)` of each component, in order
+    /// * `container_width` - Width of the view the components are added to
+    /// * `container_height` - Height of that view
+    ///
+    /// # Returns
+    ///
+    /// Returns one `(x, y, width, height)` frame per component, with the
+    /// origin at the bottom-left of the container as in AppKit. Widths are
+    /// clamped to the column (or row) width, and components in a row are
+    /// aligned to the top of the row.
+    pub fn frames(&self, sizes: &[(f64, f64)], container_width: f64, container_height: f64) -> Vec<(f64, f64, f64, f64)> {
+        let (placed, row_heights) = self.place(sizes, container_width);
+
+        let mut row_tops = Vec::with_capacity(row_heights.len());
+        let mut top = self.top_padding;
+        for height in &row_heights {
+            row_tops.push(top);
+            top += height + self.gap;
+        }
+
+        placed
+            .iter()
+            .zip(sizes)
+            .map(|(&(x, width, row), &(_, height))| (x, container_height - row_tops[row] - height, width, height))
+            .collect()
+    }
+
+    /// Get the height from the top of the container to the bottom of the last row
+    pub fn content_height(&self, sizes: &[(f64, f64)], container_width: f64) -> f64 {
+        let (_, row_heights) = self.place(sizes, container_width);
+        let gaps = self.gap * row_heights.len().saturating_sub(1) as f64;
+        self.top_padding + row_heights.iter().sum::<f64>() + gaps
+    }
+
+    /// Assign each component an x position, width and row, and measure the rows
+    fn place(&self, sizes: &[(f64, f64)], container_width: f64) -> (Vec<(f64, f64, usize)>, Vec<f64>) {
+        let margin = self.horizontal_margin;
+        let mut placed = Vec::with_capacity(sizes.len());
+
+        match self.orientation {
+            Orientation::Vertical => {
+                let columns = self.columns.max(1);
+                let column_width = self.column_width(container_width);
+                for (index, &(width, _)) in sizes.iter().enumerate() {
+                    let column = (index % columns) as f64;
+                    let x = margin + column * (column_width + self.gap);
+                    placed.push((x, width.min(column_width), index / columns));
+                }
+            }
+            Orientation::Horizontal => {
+                let available = (container_width - margin * 2.0).max(0.0);
+                let mut x = margin;
+                let mut row = 0;
+                for &(width, _) in sizes {
+                    let width = width.min(available);
+                    if x > margin && x + width > margin + available {
+                        row += 1;
+                        x = margin;
+                    }
+                    placed.push((x, width, row));
+                    x += width + self.gap;
+                }
+            }
+        }
+
+        let rows = placed.last().map_or(0, |&(_, _, row)| row + 1);
+        let mut row_heights = vec![0.0_f64; rows];
+        for (&(_, _, row), &(_, height)) in placed.iter().zip(sizes) {
+            row_heights[row] = row_heights[row].max(height);
+        }
+        (placed, row_heights)
+    }
 }
 
 impl Default for Layout {
@@ -63,3 +179,54 @@ impl Default for Layout {
         Self::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_column_frames() {
+        let layout = Layout::default();
+        let frames = layout.frames(&[(100.0, 40.0), (800.0, 30.0)], 600.0, 400.0);
+        assert_eq!(frames[0], (20.0, 320.0, 100.0, 40.0));
+        // Clamped to the window width less margins, placed below the first with a gap
+        assert_eq!(frames[1], (20.0, 278.0, 560.0, 30.0));
+    }
+
+    #[test]
+    fn test_two_column_frames() {
+        let layout = Layout::default().columns(2);
+        // (600 - 2 * 20 margin - 12 gap) / 2
+        assert_eq!(layout.column_width(600.0), 274.0);
+
+        let sizes = [(100.0, 30.0), (300.0, 30.0), (100.0, 30.0), (300.0, 40.0)];
+        let frames = layout.frames(&sizes, 600.0, 400.0);
+        let xs: Vec<f64> = frames.iter().map(|frame| frame.0).collect();
+        assert_eq!(xs, vec![20.0, 306.0, 20.0, 306.0]);
+
+        // Field widths are clamped to the column
+        assert_eq!(frames[1].2, 274.0);
+
+        // Both rows are aligned to their tops; the second row starts below the first
+        assert_eq!(frames[0].1, 330.0);
+        assert_eq!(frames[2].1, 288.0);
+        assert_eq!(frames[3].1, 278.0);
+        assert_eq!(layout.content_height(&sizes, 600.0), 40.0 + 30.0 + 12.0 + 40.0);
+    }
+
+    #[test]
+    fn test_horizontal_frames_wrap() {
+        let layout = Layout::default().orientation(Orientation::Horizontal);
+        let sizes = [(200.0, 30.0), (200.0, 30.0), (200.0, 30.0)];
+        let frames = layout.frames(&sizes, 600.0, 400.0);
+        assert_eq!((frames[0].0, frames[0].1), (20.0, 330.0));
+        assert_eq!((frames[1].0, frames[1].1), (232.0, 330.0));
+        // 444 + 200 overflows the 580 right edge, so the third wraps
+        assert_eq!((frames[2].0, frames[2].1), (20.0, 288.0));
+    }
+
+    #[test]
+    fn test_columns_at_least_one() {
+        assert_eq!(Layout::default().columns(0).columns, 1);
+    }
+}
@@ -13,8 +13,8 @@ pub mod component;
 pub mod layout;
 pub mod app;
 
-pub use component::{Kind, Comp};
-pub use layout::Layout;
+pub use component::{Kind, Comp, ClickHandler, ChangeHandler};
+pub use layout::{Layout, Orientation};
 pub use app::SimpleApp;
 
 /// Quick builder for creating a minimal app in one line
@@ -3,7 +3,7 @@
 //! This module provides fluent builder APIs for creating UI components,
 //! reducing boilerplate and improving code readability compared to raw objc calls.
 
-use crate::controls::{Button, Label, TextField};
+use crate::controls::{Button, Label, NumberFormat, TextField};
 use crate::window::Window;
 use crate::core::error::Result;
 use std::sync::Arc;
@@ -20,6 +20,7 @@ pub struct ButtonBuilder {
     width: Option<f64>,
     height: Option<f64>,
     enabled: bool,
+    tooltip: Option<String>,
     on_click: Option<OnClickCallback>,
 }
 
@@ -31,6 +32,7 @@ impl ButtonBuilder {
             width: None,
             height: None,
             enabled: true,
+            tooltip: None,
             on_click: None,
         }
     }
@@ -66,6 +68,17 @@ impl ButtonBuilder {
         self
     }
 
+    /// Set the tooltip shown when the pointer rests on the button
+    pub fn with_tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Get the tooltip if set
+    pub fn get_tooltip(&self) -> Option<&str> {
+        self.tooltip.as_deref()
+    }
+
     /// Set the on_click callback
     pub fn on_click<F>(mut self, callback: F) -> Self
     where
@@ -81,8 +94,17 @@ impl ButtonBuilder {
     }
 
     /// Build the button
+    ///
+    /// The `on_click` callback, if set, runs whenever the button is clicked.
     pub fn build(self) -> Result<Button> {
-        Button::new(&self.title)
+        let mut button = Button::new(&self.title)?;
+        if let Some(callback) = self.on_click {
+            button.on_click(move || callback());
+        }
+        if let Some(tooltip) = &self.tooltip {
+            button.set_tooltip(tooltip)?;
+        }
+        Ok(button)
     }
 }
 
@@ -97,6 +119,7 @@ pub struct LabelBuilder {
     text: String,
     width: Option<f64>,
     height: Option<f64>,
+    tooltip: Option<String>,
 }
 
 impl LabelBuilder {
@@ -106,6 +129,7 @@ impl LabelBuilder {
             text: String::new(),
             width: None,
             height: None,
+            tooltip: None,
         }
     }
 
@@ -134,9 +158,24 @@ impl LabelBuilder {
         self
     }
 
+    /// Set the tooltip shown when the pointer rests on the label
+    pub fn with_tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Get the tooltip if set
+    pub fn get_tooltip(&self) -> Option<&str> {
+        self.tooltip.as_deref()
+    }
+
     /// Build the label
     pub fn build(self) -> Result<Label> {
-        Label::new(&self.text)
+        let label = Label::new(&self.text)?;
+        if let Some(tooltip) = &self.tooltip {
+            label.set_tooltip(tooltip)?;
+        }
+        Ok(label)
     }
 }
 
@@ -153,7 +192,9 @@ pub struct TextFieldBuilder {
     width: Option<f64>,
     height: Option<f64>,
     editable: bool,
+    tooltip: Option<String>,
     on_change: Option<OnChangeCallback>,
+    number_format: Option<NumberFormat>,
 }
 
 impl TextFieldBuilder {
@@ -165,7 +206,9 @@ impl TextFieldBuilder {
             width: None,
             height: None,
             editable: true,
+            tooltip: None,
             on_change: None,
+            number_format: None,
         }
     }
 
@@ -206,6 +249,17 @@ impl TextFieldBuilder {
         self
     }
 
+    /// Set the tooltip shown when the pointer rests on the text field
+    pub fn with_tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Get the tooltip if set
+    pub fn get_tooltip(&self) -> Option<&str> {
+        self.tooltip.as_deref()
+    }
+
     /// Set the on_change callback
     pub fn on_change<F>(mut self, callback: F) -> Self
     where
@@ -220,9 +274,27 @@ impl TextFieldBuilder {
         self.on_change.as_ref()
     }
 
+    /// Only accept numbers in `format`
+    pub fn number_formatter(mut self, format: NumberFormat) -> Self {
+        self.number_format = Some(format);
+        self
+    }
+
     /// Build the text field
+    ///
+    /// The `on_change` callback, if set, receives the new text on every edit.
     pub fn build(self) -> Result<TextField> {
-        TextField::new(&self.text)
+        let mut text_field = TextField::new(&self.text)?;
+        if let Some(callback) = self.on_change {
+            text_field.on_change(move |text| callback(text));
+        }
+        if let Some(tooltip) = &self.tooltip {
+            text_field.set_tooltip(tooltip)?;
+        }
+        if let Some(format) = self.number_format {
+            text_field.set_number_format(format)?;
+        }
+        Ok(text_field)
     }
 }
 
@@ -241,6 +313,7 @@ pub struct WindowBuilder {
     resizable: bool,
     minimizable: bool,
     closable: bool,
+    frame_autosave_name: Option<String>,
 }
 
 impl WindowBuilder {
@@ -254,6 +327,7 @@ impl WindowBuilder {
             resizable: true,
             minimizable: true,
             closable: true,
+            frame_autosave_name: None,
         }
     }
 
@@ -306,6 +380,14 @@ impl WindowBuilder {
         self
     }
 
+    /// Save the window's frame under `name` and restore it on later launches
+    ///
+    /// A restored frame takes precedence over the size and centering.
+    pub fn frame_autosave_name(mut self, name: impl Into<String>) -> Self {
+        self.frame_autosave_name = Some(name.into());
+        self
+    }
+
     /// Build the window
     pub fn build(self) -> Result<Window> {
         let mut window = Window::new(&self.title, self.width, self.height)?;
@@ -313,6 +395,10 @@ impl WindowBuilder {
         if self.center {
             window.center()?;
         }
+
+        if let Some(name) = &self.frame_autosave_name {
+            window.set_frame_autosave_name(name)?;
+        }
         
         Ok(window)
     }
@@ -531,6 +617,39 @@ mod tests {
         assert!(builder.get_on_click().is_some());
     }
 
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_button_builder_build_wires_on_click() {
+        let click_count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let click_count_clone = click_count.clone();
+
+        let button = ButtonBuilder::new()
+            .title("Click Me")
+            .on_click(move || {
+                click_count_clone.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            })
+            .build()
+            .unwrap();
+
+        button.perform_click();
+        button.perform_click();
+        assert_eq!(click_count.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_textfield_builder_build_wires_on_change() {
+        let text_field = TextFieldBuilder::new()
+            .placeholder("Search")
+            .on_change(|_| {})
+            .build()
+            .unwrap();
+        assert!(text_field.has_change_handler());
+
+        let text_field = TextFieldBuilder::new().build().unwrap();
+        assert!(!text_field.has_change_handler());
+    }
+
     #[test]
     fn test_textfield_builder_fluent_with_on_change() {
         let builder = TextFieldBuilder::new()
@@ -544,6 +663,27 @@ mod tests {
         assert!(builder.get_on_change().is_some());
     }
 
+    #[test]
+    fn test_builders_store_tooltip() {
+        let button = ButtonBuilder::new().title("Save").with_tooltip("Save the document");
+        assert_eq!(button.get_tooltip(), Some("Save the document"));
+
+        let label = LabelBuilder::new().text("Status").with_tooltip(String::from("Sync status"));
+        assert_eq!(label.tooltip.as_deref(), Some("Sync status"));
+
+        let text_field = TextFieldBuilder::new().with_tooltip("");
+        assert_eq!(text_field.get_tooltip(), Some(""));
+        assert_eq!(TextFieldBuilder::new().get_tooltip(), None);
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_builders_apply_tooltip() {
+        assert!(ButtonBuilder::new().with_tooltip("Save").build().is_ok());
+        assert!(LabelBuilder::new().with_tooltip("Status").build().is_ok());
+        assert!(TextFieldBuilder::new().with_tooltip("").build().is_ok());
+    }
+
     #[test]
     fn test_button_on_click_with_closure_capture() {
         let message = Arc::new(std::sync::Mutex::new(String::new()));
@@ -137,11 +137,137 @@ macro_rules! simple_new {
     };
 }
 
+/// Macro for a whole component builder
+///
+/// Generates the builder struct, a `Default` impl from the given defaults,
+/// one fluent setter per listed field (taking anything `Into` the field's
+/// type, documented by the field's doc comment), an inherent `build`, and
+/// the [`Builder`](crate::core::traits::Builder) trait impl. Fields in the
+/// optional `internal` block get no setter, for state set through
+/// hand-written methods in a separate `impl` block.
+///
+/// Usage:
+///
+/// ```rust,ignore
+/// define_builder! {
+///     /// Label builder
+///     pub struct LabelBuilder -> Label {
+///         /// Set label text
+///         text: String = String::new(),
+///     }
+///     internal {
+///         width: Option<f64> = None,
+///     }
+///     build(self) {
+///         Label::new(&self.text)
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! define_builder {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident -> $output:ty {
+            $( $(#[$field_meta:meta])* $field:ident : $field_ty:ty = $default:expr ),* $(,)?
+        }
+        $( internal { $( $internal:ident : $internal_ty:ty = $internal_default:expr ),* $(,)? } )?
+        build($this:ident) $build:block
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $( $field: $field_ty, )*
+            $( $( $internal: $internal_ty, )* )?
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name {
+                    $( $field: $default, )*
+                    $( $( $internal: $internal_default, )* )?
+                }
+            }
+        }
+
+        impl $name {
+            $(
+                $(#[$field_meta])*
+                pub fn $field(mut self, value: impl Into<$field_ty>) -> Self {
+                    self.$field = value.into();
+                    self
+                }
+            )*
+
+            #[doc = concat!("Build the ", stringify!($output))]
+            pub fn build($this) -> $crate::core::error::Result<$output> $build
+        }
+
+        impl $crate::core::traits::Builder for $name {
+            type Output = $output;
+
+            fn build(self) -> $crate::core::error::Result<$output> {
+                $name::build(self)
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::core::error::{CocoanutError, Result};
+    use crate::core::traits::Builder;
+
+    #[derive(Debug, PartialEq)]
+    struct Gauge {
+        title: String,
+        value: f64,
+        range: (f64, f64),
+        animated: bool,
+    }
+
+    define_builder! {
+        /// Gauge builder
+        struct GaugeBuilder -> Gauge {
+            /// Set the title
+            title: String = "Gauge".to_string(),
+            /// Set the value
+            value: f64 = 0.0,
+            /// Animate changes
+            animated: bool = false,
+        }
+        internal {
+            range: (f64, f64) = (0.0, 1.0),
+        }
+        build(self) {
+            if !(self.range.0..=self.range.1).contains(&self.value) {
+                return Err(CocoanutError::InvalidParameter("Value out of range".to_string()));
+            }
+            Ok(Gauge { title: self.title, value: self.value, range: self.range, animated: self.animated })
+        }
+    }
+
+    impl GaugeBuilder {
+        fn range(mut self, min: f64, max: f64) -> Self {
+            self.range = (min, max);
+            self
+        }
+    }
+
     #[test]
     fn test_macros_compile() {
         // This test just ensures the macros compile correctly
         assert!(true);
     }
+
+    #[test]
+    fn test_define_builder_chains_and_builds() {
+        let gauge = GaugeBuilder::default().title("CPU").range(0.0, 100.0).value(42.0).animated(true).build().unwrap();
+        assert_eq!(
+            gauge,
+            Gauge { title: "CPU".to_string(), value: 42.0, range: (0.0, 100.0), animated: true }
+        );
+
+        let gauge: Result<Gauge> = Builder::build(GaugeBuilder::default());
+        assert_eq!(gauge.unwrap().title, "Gauge");
+        assert!(GaugeBuilder::default().value(2.0).build().is_err());
+    }
 }
@@ -1,10 +1,30 @@
 //! Phase 5: Custom Components
 //! 
 //! Implements custom component framework for extensibility.
-
-use crate::core::error::Result;
+//!
+//! A [`ComponentRegistry`] describes a UI declaratively and
+//! [`ComponentRegistry::render`] turns it into native views. Components of
+//! type `"Button"`, `"Label"`, `"TextField"` (or `"Input"`) and
+//! `"Container"` can be rendered. They read these properties:
+//!
+//! - `title`: button title, or label and text field text
+//! - `color`: `#RRGGBB`, `#RRGGBBAA` or one of `black`, `white`, `red`,
+//!   `green` and `blue`
+//! - `frame`: `x,y,width,height` in the parent's coordinates
+//!
+//! [`ComponentRegistry::from_json`] loads such a description from JSON.
+
+use crate::core::error::{CocoanutError, Result};
+use crate::features::drawing::{Color, Rect};
+use objc::runtime::Object;
+use serde::Deserialize;
 use std::collections::HashMap;
 
+#[cfg(not(feature = "test-mock"))]
+use crate::features::zero_cost::ZeroCostRect;
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+
 /// Component property - key-value pair for component configuration
 pub struct ComponentProperty {
     key: String,
@@ -94,6 +114,77 @@ impl CustomComponent {
     pub fn child_count(&self) -> usize {
         self.children.len()
     }
+
+    /// Render this component as a native view inside `into`
+    ///
+    /// Children are only names, so they are not rendered here; use
+    /// [`ComponentRegistry::render`] to render a whole tree.
+    pub fn render(&self, into: *mut Object) -> Result<()> {
+        let plan = self.plan_tree(None, &mut Vec::new())?;
+        plan.create(into).map(|_| ())
+    }
+
+    /// Check this component and, given a registry, its descendants, and
+    /// resolve the views to create
+    ///
+    /// Nothing is created here, so a bad component anywhere in the tree
+    /// fails the render before any view has been added.
+    fn plan_tree<'a>(
+        &'a self,
+        registry: Option<&'a ComponentRegistry>,
+        ancestors: &mut Vec<&'a str>,
+    ) -> Result<ViewPlan<'a>> {
+        if ancestors.contains(&self.name.as_str()) {
+            return Err(CocoanutError::InvalidParameter(
+                format!("Component '{}' contains itself", self.name)
+            ));
+        }
+
+        let kind = ViewKind::from_type(&self.component_type)?;
+        let frame = match self.get_property("frame") {
+            Some(frame) => parse_frame(frame)?,
+            None => kind.default_frame(),
+        };
+        let color = self.get_property("color").map(parse_color).transpose()?;
+
+        let mut children = Vec::new();
+        if let Some(registry) = registry {
+            ancestors.push(&self.name);
+            for child_name in &self.children {
+                let child = registry.get(child_name).ok_or_else(|| {
+                    CocoanutError::InvalidParameter(
+                        format!("Component '{}' has unknown child '{}'", self.name, child_name)
+                    )
+                })?;
+                children.push(child.plan_tree(Some(registry), ancestors)?);
+            }
+            ancestors.pop();
+        }
+        Ok(ViewPlan { kind, title: self.get_property("title"), color, frame, children })
+    }
+}
+
+/// A checked component, ready to be created as a native view
+struct ViewPlan<'a> {
+    kind: ViewKind,
+    title: Option<&'a str>,
+    color: Option<Color>,
+    frame: Rect,
+    children: Vec<ViewPlan<'a>>,
+}
+
+impl ViewPlan<'_> {
+    /// Create this view and its descendants inside `into`
+    ///
+    /// Returns the number of views created.
+    fn create(&self, into: *mut Object) -> Result<usize> {
+        let view = unsafe { create_view(self.kind, self.title, self.color, self.frame, into)? };
+        let mut count = 1;
+        for child in &self.children {
+            count += child.create(view)?;
+        }
+        Ok(count)
+    }
 }
 
 /// Component registry - registry for custom components
@@ -109,6 +200,78 @@ impl ComponentRegistry {
         }
     }
 
+    /// Create a registry from a JSON description of components
+    ///
+    /// The JSON is a component or an array of components. Each component has
+    /// a `name`, a `type`, optional `properties` and optional `children`.
+    /// Children are either the names of components described elsewhere or
+    /// nested components, which are registered too. Property values that are
+    /// not strings are stored in their JSON form.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cocoanut::systems::custom_components::ComponentRegistry;
+    ///
+    /// let registry = ComponentRegistry::from_json(r#"{
+    ///     "name": "toolbar",
+    ///     "type": "Container",
+    ///     "properties": { "frame": "0,0,300,50" },
+    ///     "children": [
+    ///         { "name": "ok", "type": "Button", "properties": { "title": "OK" } }
+    ///     ]
+    /// }"#).unwrap();
+    /// assert_eq!(registry.count(), 2);
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self> {
+        let root: JsonRoot = serde_json::from_str(json)
+            .map_err(|e| CocoanutError::InvalidParameter(format!("Invalid component JSON: {}", e)))?;
+        let specs = match root {
+            JsonRoot::Many(specs) => specs,
+            JsonRoot::One(spec) => vec![spec],
+        };
+
+        let mut registry = ComponentRegistry::new();
+        for spec in specs {
+            registry.register_spec(spec)?;
+        }
+        Ok(registry)
+    }
+
+    /// Register a component parsed from JSON and its nested children
+    fn register_spec(&mut self, spec: ComponentSpec) -> Result<()> {
+        if self.exists(&spec.name) {
+            return Err(CocoanutError::InvalidParameter(
+                format!("Component '{}' is defined more than once", spec.name)
+            ));
+        }
+
+        let mut component = CustomComponent::new(spec.name, spec.component_type);
+        for (key, value) in spec.properties {
+            let value = match value {
+                serde_json::Value::String(value) => value,
+                other => other.to_string(),
+            };
+            component.add_property(key, value);
+        }
+        let mut nested = Vec::new();
+        for child in spec.children {
+            match child {
+                ChildSpec::Name(name) => component.add_child(name),
+                ChildSpec::Component(child) => {
+                    component.add_child(child.name.clone());
+                    nested.push(child);
+                }
+            }
+        }
+
+        self.register(component)?;
+        for child in nested {
+            self.register_spec(child)?;
+        }
+        Ok(())
+    }
+
     /// Register a component
     pub fn register(&mut self, component: CustomComponent) -> Result<()> {
         let name = component.get_name().to_string();
@@ -150,6 +313,22 @@ impl ComponentRegistry {
     pub fn exists(&self, name: &str) -> bool {
         self.components.contains_key(name)
     }
+
+    /// Render the component called `name` and all of its descendants inside `into`
+    ///
+    /// Children are looked up in this registry by name. Unknown children
+    /// and components that contain themselves are errors.
+    pub fn render(&self, name: &str, into: *mut Object) -> Result<()> {
+        self.render_count(name, into).map(|_| ())
+    }
+
+    fn render_count(&self, name: &str, into: *mut Object) -> Result<usize> {
+        let component = self.get(name).ok_or_else(|| {
+            CocoanutError::InvalidParameter(format!("Unknown component '{}'", name))
+        })?;
+        let plan = component.plan_tree(Some(self), &mut Vec::new())?;
+        plan.create(into)
+    }
 }
 
 impl Default for ComponentRegistry {
@@ -207,6 +386,200 @@ impl ComponentTemplate {
     }
 }
 
+/// Top level of a JSON component description
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonRoot {
+    Many(Vec<ComponentSpec>),
+    One(ComponentSpec),
+}
+
+/// A component in a JSON description
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ComponentSpec {
+    name: String,
+    #[serde(rename = "type")]
+    component_type: String,
+    #[serde(default)]
+    properties: HashMap<String, serde_json::Value>,
+    #[serde(default)]
+    children: Vec<ChildSpec>,
+}
+
+/// A child in a JSON description: a component name or a nested component
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ChildSpec {
+    Name(String),
+    Component(ComponentSpec),
+}
+
+/// Native view created for a component type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ViewKind {
+    Button,
+    Label,
+    TextField,
+    Container,
+}
+
+impl ViewKind {
+    fn from_type(component_type: &str) -> Result<Self> {
+        match component_type {
+            "Button" => Ok(ViewKind::Button),
+            "Label" => Ok(ViewKind::Label),
+            "TextField" | "Input" => Ok(ViewKind::TextField),
+            "Container" => Ok(ViewKind::Container),
+            other => Err(CocoanutError::InvalidParameter(
+                format!("Cannot render component type '{}'", other)
+            )),
+        }
+    }
+
+    fn default_frame(self) -> Rect {
+        match self {
+            ViewKind::Button => Rect::from_xywh(0.0, 0.0, 100.0, 32.0),
+            ViewKind::Label | ViewKind::TextField => Rect::from_xywh(0.0, 0.0, 200.0, 24.0),
+            ViewKind::Container => Rect::from_xywh(0.0, 0.0, 400.0, 300.0),
+        }
+    }
+}
+
+/// Parse a `frame` property of the form `x,y,width,height`
+fn parse_frame(value: &str) -> Result<Rect> {
+    let parts = value
+        .split(',')
+        .map(|part| part.trim().parse::<f64>())
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .ok()
+        .filter(|parts| parts.len() == 4)
+        .ok_or_else(|| {
+            CocoanutError::InvalidParameter(
+                format!("Invalid frame '{}', expected x,y,width,height", value)
+            )
+        })?;
+    Ok(Rect::from_xywh(parts[0], parts[1], parts[2], parts[3]))
+}
+
+/// Parse a `color` property: `#RRGGBB`, `#RRGGBBAA` or a basic color name
+fn parse_color(value: &str) -> Result<Color> {
+    let invalid = || CocoanutError::InvalidColor(format!("Invalid color '{}'", value));
+    match value {
+        "black" => return Ok(Color::black()),
+        "white" => return Ok(Color::white()),
+        "red" => return Ok(Color::red()),
+        "green" => return Ok(Color::green()),
+        "blue" => return Ok(Color::blue()),
+        _ => {}
+    }
+
+    let hex = value.strip_prefix('#').ok_or_else(invalid)?;
+    if !(hex.len() == 6 || hex.len() == 8) || !hex.is_ascii() {
+        return Err(invalid());
+    }
+    let channel = |index: usize| {
+        u8::from_str_radix(&hex[index..index + 2], 16)
+            .map(|byte| byte as f64 / 255.0)
+            .map_err(|_| invalid())
+    };
+    let alpha = if hex.len() == 8 { channel(6)? } else { 1.0 };
+    Color::new(channel(0)?, channel(2)?, channel(4)?, alpha)
+}
+
+/// Create the view for a component, add it to `into` and return it
+///
+/// The superview owns the new view. Mock builds create nothing and return
+/// null.
+#[cfg(not(feature = "test-mock"))]
+unsafe fn create_view(
+    kind: ViewKind,
+    title: Option<&str>,
+    color: Option<Color>,
+    frame: Rect,
+    into: *mut Object,
+) -> Result<*mut Object> {
+    if into.is_null() {
+        return Err(CocoanutError::InvalidParameter(
+            "Cannot render a component into a null view".to_string()
+        ));
+    }
+
+    unsafe {
+        let frame: cocoa::foundation::NSRect = ZeroCostRect::from(frame).into();
+        let title = title.map(crate::core::utils::string_to_ns_string).transpose()?;
+        let view: *mut Object = match kind {
+            ViewKind::Button => {
+                let ns_button: *mut Object = msg_send![class!(NSButton), alloc];
+                let ns_button: *mut Object = msg_send![ns_button, initWithFrame: frame];
+                if !ns_button.is_null() {
+                    let _: () = msg_send![ns_button, setBezelStyle: 1u64]; // NSBezelStyleRounded
+                    if let Some(title) = title {
+                        let _: () = msg_send![ns_button, setTitle: title];
+                    }
+                    if let Some(color) = color {
+                        let _: () = msg_send![ns_button, setBezelColor: color.to_ns_color()];
+                    }
+                }
+                ns_button
+            }
+            ViewKind::Label | ViewKind::TextField => {
+                let ns_text_field: *mut Object = msg_send![class!(NSTextField), alloc];
+                let ns_text_field: *mut Object = msg_send![ns_text_field, initWithFrame: frame];
+                if !ns_text_field.is_null() {
+                    if kind == ViewKind::Label {
+                        let _: () = msg_send![ns_text_field, setBezeled: false];
+                        let _: () = msg_send![ns_text_field, setDrawsBackground: false];
+                        let _: () = msg_send![ns_text_field, setEditable: false];
+                        let _: () = msg_send![ns_text_field, setSelectable: false];
+                    }
+                    if let Some(title) = title {
+                        let _: () = msg_send![ns_text_field, setStringValue: title];
+                    }
+                    if let Some(color) = color {
+                        let _: () = msg_send![ns_text_field, setTextColor: color.to_ns_color()];
+                    }
+                }
+                ns_text_field
+            }
+            ViewKind::Container => {
+                let ns_view: *mut Object = msg_send![class!(NSView), alloc];
+                let ns_view: *mut Object = msg_send![ns_view, initWithFrame: frame];
+                if !ns_view.is_null()
+                    && let Some(color) = color
+                {
+                    let _: () = msg_send![ns_view, setWantsLayer: true];
+                    let layer: *mut Object = msg_send![ns_view, layer];
+                    let cg_color: *mut Object = msg_send![color.to_ns_color(), CGColor];
+                    let _: () = msg_send![layer, setBackgroundColor: cg_color];
+                }
+                ns_view
+            }
+        };
+
+        if view.is_null() {
+            return Err(CocoanutError::ControlCreationFailed(
+                format!("Failed to create view for {:?} component", kind)
+            ));
+        }
+        let _: () = msg_send![into, addSubview: view];
+        let _: () = msg_send![view, release];
+        Ok(view)
+    }
+}
+
+#[cfg(feature = "test-mock")]
+unsafe fn create_view(
+    kind: ViewKind,
+    title: Option<&str>,
+    color: Option<Color>,
+    frame: Rect,
+    into: *mut Object,
+) -> Result<*mut Object> {
+    let _ = (kind, title, color, frame, into);
+    Ok(std::ptr::null_mut())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -441,4 +814,129 @@ mod tests {
             assert_eq!(instance.get_property(&key), Some(expected.as_str()));
         }
     }
+
+    #[test]
+    fn test_registry_from_json() {
+        let registry = ComponentRegistry::from_json(r##"[
+            {
+                "name": "toolbar",
+                "type": "Container",
+                "properties": { "frame": "0,0,300,50", "color": "#eeeeee" },
+                "children": [
+                    "status",
+                    { "name": "ok", "type": "Button", "properties": { "title": "OK", "enabled": true } },
+                    { "name": "cancel", "type": "Button", "properties": { "title": "Cancel" } }
+                ]
+            },
+            { "name": "status", "type": "Label", "properties": { "title": "Ready", "width": 120 } }
+        ]"##).unwrap();
+
+        assert_eq!(registry.count(), 4);
+        let toolbar = registry.get("toolbar").unwrap();
+        assert_eq!(toolbar.get_type(), "Container");
+        assert_eq!(toolbar.get_property("frame"), Some("0,0,300,50"));
+        assert_eq!(toolbar.get_children(), ["status", "ok", "cancel"]);
+        assert_eq!(registry.get("ok").unwrap().get_property("title"), Some("OK"));
+        assert_eq!(registry.get("ok").unwrap().get_property("enabled"), Some("true"));
+        assert_eq!(registry.get("status").unwrap().get_property("width"), Some("120"));
+    }
+
+    #[test]
+    fn test_registry_from_invalid_json() {
+        assert!(ComponentRegistry::from_json("{ not json").is_err());
+        assert!(ComponentRegistry::from_json(r#"{ "name": "x" }"#).is_err());
+        assert!(ComponentRegistry::from_json(r#"{ "name": "x", "type": "Label", "colour": "red" }"#).is_err());
+        assert!(ComponentRegistry::from_json(
+            r#"[{ "name": "x", "type": "Label" }, { "name": "x", "type": "Button" }]"#
+        ).is_err());
+    }
+
+    #[test]
+    fn test_parse_frame_and_color() {
+        assert_eq!(parse_frame("10, 20,100,40").unwrap(), Rect::from_xywh(10.0, 20.0, 100.0, 40.0));
+        assert!(parse_frame("10,20,100").is_err());
+        assert!(parse_frame("a,b,c,d").is_err());
+
+        assert_eq!(parse_color("red").unwrap(), Color::red());
+        assert_eq!(parse_color("#ff0000").unwrap(), Color::red());
+        assert_eq!(parse_color("#00000000").unwrap().alpha, 0.0);
+        assert!(parse_color("#ff00").is_err());
+        assert!(parse_color("#gg0000").is_err());
+        assert!(parse_color("purple").is_err());
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_render_container_with_button_children() {
+        let mut registry = ComponentRegistry::new();
+        let mut container = CustomComponent::new("toolbar", "Container");
+        container.add_property("frame", "0,0,300,50");
+        container.add_property("color", "#eeeeee");
+        container.add_child("ok");
+        container.add_child("cancel");
+        registry.register(container).unwrap();
+        for (name, title) in [("ok", "OK"), ("cancel", "Cancel")] {
+            let mut button = CustomComponent::new(name, "Button");
+            button.add_property("title", title);
+            registry.register(button).unwrap();
+        }
+
+        let into = std::ptr::null_mut();
+        assert_eq!(registry.render_count("toolbar", into).unwrap(), 3);
+        assert!(registry.render("toolbar", into).is_ok());
+        assert!(registry.get("toolbar").unwrap().render(into).is_ok());
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_render_rejects_bad_trees() {
+        let into = std::ptr::null_mut();
+        let mut registry = ComponentRegistry::new();
+        assert!(registry.render("missing", into).is_err());
+
+        let mut panel = CustomComponent::new("panel", "Container");
+        panel.add_child("ghost");
+        registry.register(panel).unwrap();
+        assert!(registry.render("panel", into).is_err());
+
+        let mut looped = CustomComponent::new("loop", "Container");
+        looped.add_child("loop");
+        registry.register(looped).unwrap();
+        assert!(registry.render("loop", into).is_err());
+
+        registry.register(CustomComponent::new("form", "Form")).unwrap();
+        assert!(registry.render("form", into).is_err());
+
+        let mut button = CustomComponent::new("bad", "Button");
+        button.add_property("frame", "wide");
+        assert!(button.render(into).is_err());
+    }
+
+    // Regression: a bad component deep in the tree used to fail only after
+    // its ancestors and earlier siblings had been added to the view
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_render_creates_nothing_for_bad_tree() {
+        use cocoa::foundation::{NSPoint, NSRect, NSSize};
+
+        let mut registry = ComponentRegistry::new();
+        let mut container = CustomComponent::new("toolbar", "Container");
+        container.add_child("ok");
+        container.add_child("bad");
+        registry.register(container).unwrap();
+        registry.register(CustomComponent::new("ok", "Button")).unwrap();
+        let mut bad = CustomComponent::new("bad", "Label");
+        bad.add_property("color", "purple");
+        registry.register(bad).unwrap();
+
+        unsafe {
+            let into: *mut Object = msg_send![class!(NSView), alloc];
+            let into: *mut Object = msg_send![into, initWithFrame: NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(300.0, 50.0))];
+            assert!(registry.render("toolbar", into).is_err());
+            let subviews: *mut Object = msg_send![into, subviews];
+            let count: usize = msg_send![subviews, count];
+            assert_eq!(count, 0);
+            let _: () = msg_send![into, release];
+        }
+    }
 }
@@ -7,8 +7,37 @@
 //! - Custom view support
 //! - Reactive data binding
 
+use crate::components::advanced::checkbox::Checkbox;
+use crate::components::basic::TextField;
 use crate::core::error::Result;
+use crate::core::traits::Drawable;
+use crate::features::drawing::Rect;
+use objc::runtime::Object;
 use std::sync::Arc;
+use std::time::Duration;
+
+#[cfg(not(feature = "test-mock"))]
+use crate::core::error::CocoanutError;
+#[cfg(not(feature = "test-mock"))]
+use crate::features::zero_cost::ZeroCostRect;
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+#[cfg(not(feature = "test-mock"))]
+use std::ffi::c_void;
+
+/// Address used as the associated-object key for a view's fade generation
+#[cfg(not(feature = "test-mock"))]
+static FADE_GENERATION_KEY: u8 = 0;
+
+/// `OBJC_ASSOCIATION_RETAIN_NONATOMIC`
+#[cfg(not(feature = "test-mock"))]
+const ASSOCIATION_RETAIN_NONATOMIC: usize = 1;
+
+#[cfg(not(feature = "test-mock"))]
+unsafe extern "C" {
+    fn objc_setAssociatedObject(object: *mut Object, key: *const c_void, value: *mut Object, policy: usize);
+    fn objc_getAssociatedObject(object: *const Object, key: *const c_void) -> *mut Object;
+}
 
 /// Event callback type
 pub type EventCallback = Arc<dyn Fn() + Send + Sync>;
@@ -79,6 +108,10 @@ impl Default for EventSystem {
 }
 
 /// Auto Layout constraint builder
+///
+/// Constraints made by [`AutoLayout::constrain`] are backed by a real
+/// `NSLayoutConstraint`; ones made with [`LayoutConstraint::new`] only
+/// describe a constraint.
 pub struct LayoutConstraint {
     /// Constraint identifier
     pub identifier: String,
@@ -86,6 +119,8 @@ pub struct LayoutConstraint {
     pub priority: f64,
     /// Constraint constant
     pub constant: f64,
+    /// The retained `NSLayoutConstraint`, or null
+    ns_constraint: *mut Object,
 }
 
 impl LayoutConstraint {
@@ -95,7 +130,39 @@ impl LayoutConstraint {
             identifier: identifier.to_string(),
             priority: 750.0, // Default priority
             constant: 0.0,
+            ns_constraint: std::ptr::null_mut(),
+        }
+    }
+
+    /// Turn the native constraint on
+    ///
+    /// Fails for constraints that are not backed by an `NSLayoutConstraint`.
+    pub fn activate(&self) -> Result<()> {
+        self.set_active(true)
+    }
+
+    /// Turn the native constraint off
+    pub fn deactivate(&self) -> Result<()> {
+        self.set_active(false)
+    }
+
+    fn set_active(&self, active: bool) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        {
+            if self.ns_constraint.is_null() {
+                return Err(CocoanutError::InvalidParameter(
+                    format!("Constraint '{}' is not attached to any views", self.identifier)
+                ));
+            }
+            unsafe {
+                let _: () = msg_send![self.ns_constraint, setPriority: self.priority as f32];
+                let _: () = msg_send![self.ns_constraint, setConstant: self.constant];
+                let _: () = msg_send![self.ns_constraint, setActive: active];
+            }
         }
+        #[cfg(feature = "test-mock")]
+        let _ = active;
+        Ok(())
     }
 
     /// Set constraint priority
@@ -111,6 +178,226 @@ impl LayoutConstraint {
     }
 }
 
+impl Drop for LayoutConstraint {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_constraint.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_constraint, release];
+            }
+        }
+    }
+}
+
+unsafe impl Send for LayoutConstraint {}
+unsafe impl Sync for LayoutConstraint {}
+
+/// Layout attribute a [`ConstraintBuilder`] pins
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Anchor {
+    Leading,
+    Trailing,
+    Top,
+    Bottom,
+    CenterX,
+    CenterY,
+    Width,
+    Height,
+}
+
+impl Anchor {
+    fn name(self) -> &'static str {
+        match self {
+            Anchor::Leading => "leading",
+            Anchor::Trailing => "trailing",
+            Anchor::Top => "top",
+            Anchor::Bottom => "bottom",
+            Anchor::CenterX => "centerX",
+            Anchor::CenterY => "centerY",
+            Anchor::Width => "width",
+            Anchor::Height => "height",
+        }
+    }
+
+    /// The view's `NSLayoutAnchor` for this attribute
+    #[cfg(not(feature = "test-mock"))]
+    unsafe fn of(self, view: *mut Object) -> *mut Object {
+        unsafe {
+            match self {
+                Anchor::Leading => msg_send![view, leadingAnchor],
+                Anchor::Trailing => msg_send![view, trailingAnchor],
+                Anchor::Top => msg_send![view, topAnchor],
+                Anchor::Bottom => msg_send![view, bottomAnchor],
+                Anchor::CenterX => msg_send![view, centerXAnchor],
+                Anchor::CenterY => msg_send![view, centerYAnchor],
+                Anchor::Width => msg_send![view, widthAnchor],
+                Anchor::Height => msg_send![view, heightAnchor],
+            }
+        }
+    }
+}
+
+/// One constraint a [`ConstraintBuilder`] will create
+struct PendingConstraint {
+    anchor: Anchor,
+    /// The view to pin to, or null for a fixed size
+    other: *mut Object,
+    constraint: LayoutConstraint,
+}
+
+/// Chains constraints on one view, created by [`AutoLayout::constrain`]
+///
+/// Nothing reaches AppKit until [`ConstraintBuilder::activate`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::essential_features::AutoLayout;
+/// use cocoanut::prelude::*;
+///
+/// # fn main() -> cocoanut::Result<()> {
+/// let sidebar = Label::new("Sidebar")?;
+/// let content = Label::new("Content")?;
+/// let layout = AutoLayout::new();
+/// layout.constrain(&sidebar)
+///     .leading_to(&content, 0.0)
+///     .width(200.0)
+///     .activate()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ConstraintBuilder<'a> {
+    layout: &'a AutoLayout,
+    view: *mut Object,
+    name: String,
+    pending: Vec<PendingConstraint>,
+}
+
+impl<'a> ConstraintBuilder<'a> {
+    /// Prefix the identifiers of the constraints, e.g. `"sidebar.width"`
+    pub fn named(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        for pending in &mut self.pending {
+            pending.constraint.identifier = format!("{}.{}", self.name, pending.anchor.name());
+        }
+        self
+    }
+
+    /// Pin the leading edge to `other`'s leading edge plus `constant`
+    pub fn leading_to(self, other: &dyn Drawable, constant: f64) -> Self {
+        self.pin(Anchor::Leading, other.as_view(), constant)
+    }
+
+    /// Pin the trailing edge to `other`'s trailing edge plus `constant`
+    pub fn trailing_to(self, other: &dyn Drawable, constant: f64) -> Self {
+        self.pin(Anchor::Trailing, other.as_view(), constant)
+    }
+
+    /// Pin the top edge to `other`'s top edge plus `constant`
+    pub fn top_to(self, other: &dyn Drawable, constant: f64) -> Self {
+        self.pin(Anchor::Top, other.as_view(), constant)
+    }
+
+    /// Pin the bottom edge to `other`'s bottom edge plus `constant`
+    pub fn bottom_to(self, other: &dyn Drawable, constant: f64) -> Self {
+        self.pin(Anchor::Bottom, other.as_view(), constant)
+    }
+
+    /// Center horizontally on `other`, offset by `constant`
+    pub fn center_x_to(self, other: &dyn Drawable, constant: f64) -> Self {
+        self.pin(Anchor::CenterX, other.as_view(), constant)
+    }
+
+    /// Center vertically on `other`, offset by `constant`
+    pub fn center_y_to(self, other: &dyn Drawable, constant: f64) -> Self {
+        self.pin(Anchor::CenterY, other.as_view(), constant)
+    }
+
+    /// Fix the width
+    pub fn width(self, width: f64) -> Self {
+        self.pin(Anchor::Width, std::ptr::null_mut(), width)
+    }
+
+    /// Fix the height
+    pub fn height(self, height: f64) -> Self {
+        self.pin(Anchor::Height, std::ptr::null_mut(), height)
+    }
+
+    /// The constraints chained so far
+    pub fn constraints(&self) -> Vec<&LayoutConstraint> {
+        self.pending.iter().map(|pending| &pending.constraint).collect()
+    }
+
+    /// Create and activate the constraints and add them to the layout
+    ///
+    /// Also turns off the view's autoresizing-mask translation, which would
+    /// otherwise conflict with the constraints. If any constraint fails, the
+    /// ones already activated are turned off again and none are added.
+    pub fn activate(self) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            if self.view.is_null() {
+                return Err(CocoanutError::InvalidParameter("Cannot constrain a null view".to_string()));
+            }
+            let _: () = msg_send![self.view, setTranslatesAutoresizingMaskIntoConstraints: false];
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = self.view;
+
+        let mut created = Vec::with_capacity(self.pending.len());
+        for pending in self.pending {
+            match Self::create(self.view, pending) {
+                Ok(constraint) => created.push(constraint),
+                Err(error) => {
+                    for constraint in &created {
+                        let _ = constraint.deactivate();
+                    }
+                    return Err(error);
+                }
+            }
+        }
+
+        let mut constraints = self.layout.constraints.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        constraints.extend(created);
+        Ok(())
+    }
+
+    /// Create the native constraint for `pending` on `view` and activate it
+    fn create(view: *mut Object, pending: PendingConstraint) -> Result<LayoutConstraint> {
+        #[allow(unused_mut)]
+        let mut constraint = pending.constraint;
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let anchor = pending.anchor.of(view);
+            let ns_constraint: *mut Object = if pending.other.is_null() {
+                msg_send![anchor, constraintEqualToConstant: constraint.constant]
+            } else {
+                let other = pending.anchor.of(pending.other);
+                msg_send![anchor, constraintEqualToAnchor: other constant: constraint.constant]
+            };
+            if ns_constraint.is_null() {
+                return Err(CocoanutError::SystemError(
+                    format!("Failed to create constraint '{}'", constraint.identifier)
+                ));
+            }
+            let identifier = crate::core::utils::string_to_ns_string(&constraint.identifier)?;
+            let _: () = msg_send![ns_constraint, setIdentifier: identifier];
+            constraint.ns_constraint = msg_send![ns_constraint, retain];
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = (view, pending.other);
+        constraint.activate()?;
+        Ok(constraint)
+    }
+
+    fn pin(mut self, anchor: Anchor, other: *mut Object, constant: f64) -> Self {
+        let identifier = format!("{}.{}", self.name, anchor.name());
+        let constraint = LayoutConstraint::new(&identifier).priority(1000.0).constant(constant);
+        self.pending.push(PendingConstraint { anchor, other, constraint });
+        self
+    }
+}
+
 /// Auto Layout system
 pub struct AutoLayout {
     constraints: std::sync::Mutex<Vec<LayoutConstraint>>,
@@ -124,6 +411,16 @@ impl AutoLayout {
         }
     }
 
+    /// Start chaining constraints on `view`
+    pub fn constrain(&self, view: &dyn Drawable) -> ConstraintBuilder<'_> {
+        ConstraintBuilder {
+            layout: self,
+            view: view.as_view(),
+            name: "constraint".to_string(),
+            pending: Vec::new(),
+        }
+    }
+
     /// Add a constraint
     pub fn add_constraint(&self, constraint: LayoutConstraint) -> Result<()> {
         let mut constraints = self.constraints.lock().map_err(|_| {
@@ -149,6 +446,11 @@ impl AutoLayout {
             crate::core::error::CocoanutError::ThreadingError("Failed to acquire lock".into())
         })?;
 
+        for constraint in constraints.iter().filter(|c| c.identifier == identifier) {
+            if !constraint.ns_constraint.is_null() {
+                constraint.deactivate()?;
+            }
+        }
         constraints.retain(|c| c.identifier != identifier);
         Ok(())
     }
@@ -204,6 +506,248 @@ impl Animation {
         self.timing = timing;
         self
     }
+
+    /// Animate `view` to `to_frame`, then run `completion`
+    ///
+    /// Runs in an `NSAnimationContext` through the view's animator proxy.
+    /// The view is made layer-backed so Core Animation drives the change.
+    /// When the user has turned on Reduce Motion, the view jumps straight
+    /// to `to_frame`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use cocoanut::essential_features::{Animation, TimingFunction};
+    /// use cocoanut::drawing::Rect;
+    /// use cocoanut::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> cocoanut::Result<()> {
+    /// let sidebar = Label::new("Sidebar")?;
+    /// Animation::animate(
+    ///     &sidebar,
+    ///     Rect::from_xywh(0.0, 0.0, 240.0, 600.0),
+    ///     Duration::from_millis(250),
+    ///     TimingFunction::EaseOut,
+    ///     || println!("sidebar is in"),
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn animate<F>(
+        view: &dyn Drawable,
+        to_frame: Rect,
+        duration: Duration,
+        timing: TimingFunction,
+        completion: F,
+    ) -> Result<()>
+    where
+        F: FnOnce() + 'static,
+    {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let frame: cocoa::foundation::NSRect = ZeroCostRect::from(to_frame).into();
+            run_animation(view.as_view(), duration, timing, completion, move |target| {
+                let _: () = msg_send![target, setFrame: frame];
+            })
+        }
+
+        #[cfg(feature = "test-mock")]
+        {
+            let _ = (view, to_frame, duration, timing);
+            completion();
+            Ok(())
+        }
+    }
+
+    /// Fade `view` to `to_alpha` (0.0 to 1.0), then run `completion`
+    ///
+    /// Like [`Animation::animate`], this jumps to the end when Reduce
+    /// Motion is on.
+    pub fn animate_alpha<F>(
+        view: &dyn Drawable,
+        to_alpha: f64,
+        duration: Duration,
+        timing: TimingFunction,
+        completion: F,
+    ) -> Result<()>
+    where
+        F: FnOnce() + 'static,
+    {
+        let to_alpha = to_alpha.clamp(0.0, 1.0);
+
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            run_animation(view.as_view(), duration, timing, completion, move |target| {
+                let _: () = msg_send![target, setAlphaValue: to_alpha];
+            })
+        }
+
+        #[cfg(feature = "test-mock")]
+        {
+            let _ = (view, to_alpha, duration, timing);
+            completion();
+            Ok(())
+        }
+    }
+
+    /// Fade a raw `NSView` to `to_alpha`, then run `completion`
+    #[cfg(not(feature = "test-mock"))]
+    pub(crate) fn animate_view_alpha<F>(ns_view: *mut Object, to_alpha: f64, duration: Duration, completion: F) -> Result<()>
+    where
+        F: FnOnce() + 'static,
+    {
+        unsafe {
+            run_animation(ns_view, duration, TimingFunction::EaseInOut, completion, move |target| {
+                let _: () = msg_send![target, setAlphaValue: to_alpha];
+            })
+        }
+    }
+
+    /// Check if the user has asked for reduced motion in Accessibility settings
+    pub fn reduced_motion() -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let workspace: *mut Object = msg_send![class!(NSWorkspace), sharedWorkspace];
+            msg_send![workspace, accessibilityDisplayShouldReduceMotion]
+        }
+
+        #[cfg(feature = "test-mock")]
+        false
+    }
+}
+
+impl TimingFunction {
+    /// The `CAMediaTimingFunctionName` for this timing function
+    pub fn media_timing_function_name(self) -> &'static str {
+        match self {
+            TimingFunction::Linear => "linear",
+            TimingFunction::EaseIn => "easeIn",
+            TimingFunction::EaseOut => "easeOut",
+            TimingFunction::EaseInOut => "easeInEaseOut",
+        }
+    }
+}
+
+/// Fade `ns_view` in or out over `duration`
+///
+/// Returns `false` without animating when the view is null or Reduce Motion
+/// is on, so the caller can show or hide it at once. Every call starts a new
+/// fade generation, and a fade out only hides the view if no other fade
+/// started before it finished. The view is retained until the fade ends.
+#[cfg(not(feature = "test-mock"))]
+pub(crate) fn fade_view(ns_view: *mut Object, visible: bool, duration: Duration) -> Result<bool> {
+    if ns_view.is_null() {
+        return Ok(false);
+    }
+    unsafe {
+        let generation = next_fade_generation(ns_view);
+        if Animation::reduced_motion() {
+            return Ok(false);
+        }
+        if visible {
+            let _: () = msg_send![ns_view, setAlphaValue: 0.0f64];
+            let _: () = msg_send![ns_view, setHidden: false];
+        }
+        let to_alpha = if visible { 1.0 } else { 0.0 };
+
+        let _: *mut Object = msg_send![ns_view, retain];
+        // Pointers are not `Send`; the completion runs on the main thread
+        let view = ns_view as usize;
+        let result = Animation::animate_view_alpha(ns_view, to_alpha, duration, move || {
+            let view = view as *mut Object;
+            if !visible && fade_generation(view) == generation {
+                let _ = crate::core::utils::set_view_alpha(view, 1.0);
+                let _: () = msg_send![view, setHidden: true];
+            }
+            let _: () = msg_send![view, release];
+        });
+        if result.is_err() {
+            let _: () = msg_send![ns_view, release];
+        }
+        result.map(|()| true)
+    }
+}
+
+/// Get the generation of the latest fade started on `ns_view`
+#[cfg(not(feature = "test-mock"))]
+unsafe fn fade_generation(ns_view: *mut Object) -> u64 {
+    unsafe {
+        let key = &FADE_GENERATION_KEY as *const u8 as *const c_void;
+        let number = objc_getAssociatedObject(ns_view, key);
+        if number.is_null() {
+            return 0;
+        }
+        msg_send![number, unsignedLongLongValue]
+    }
+}
+
+/// Start a new fade generation on `ns_view` and return it
+#[cfg(not(feature = "test-mock"))]
+unsafe fn next_fade_generation(ns_view: *mut Object) -> u64 {
+    unsafe {
+        let generation = fade_generation(ns_view).wrapping_add(1);
+        let number: *mut Object = msg_send![class!(NSNumber), numberWithUnsignedLongLong: generation];
+        let key = &FADE_GENERATION_KEY as *const u8 as *const c_void;
+        objc_setAssociatedObject(ns_view, key, number, ASSOCIATION_RETAIN_NONATOMIC);
+        generation
+    }
+}
+
+/// Apply a change to `ns_view` inside an animation group, then run `completion`
+///
+/// `change` receives the view's animator proxy, or the view itself when
+/// Reduce Motion is on.
+#[cfg(not(feature = "test-mock"))]
+pub(crate) unsafe fn run_animation<F, C>(
+    ns_view: *mut Object,
+    duration: Duration,
+    timing: TimingFunction,
+    completion: F,
+    change: C,
+) -> Result<()>
+where
+    F: FnOnce() + 'static,
+    C: Fn(*mut Object) + 'static,
+{
+    use block::ConcreteBlock;
+    use std::cell::Cell;
+
+    if ns_view.is_null() {
+        return Err(CocoanutError::InvalidParameter("Cannot animate a null view".to_string()));
+    }
+
+    unsafe {
+        if Animation::reduced_motion() {
+            change(ns_view);
+            completion();
+            return Ok(());
+        }
+
+        let _: () = msg_send![ns_view, setWantsLayer: true];
+        let timing_name = crate::core::utils::string_to_ns_string(timing.media_timing_function_name())?;
+        let timing_function: *mut Object = msg_send![class!(CAMediaTimingFunction), functionWithName: timing_name];
+        let seconds = duration.as_secs_f64();
+
+        let animations = ConcreteBlock::new(move |context: *mut Object| {
+            let _: () = msg_send![context, setDuration: seconds];
+            let _: () = msg_send![context, setTimingFunction: timing_function];
+            let _: () = msg_send![context, setAllowsImplicitAnimation: true];
+            let animator: *mut Object = msg_send![ns_view, animator];
+            change(animator);
+        })
+        .copy();
+        let completion = Cell::new(Some(completion));
+        let completion_handler = ConcreteBlock::new(move || {
+            if let Some(completion) = completion.take() {
+                completion();
+            }
+        })
+        .copy();
+        let _: () = msg_send![class!(NSAnimationContext), runAnimationGroup: &*animations
+                                                        completionHandler: &*completion_handler];
+        Ok(())
+    }
 }
 
 /// Custom view trait for user-defined components
@@ -221,10 +765,27 @@ pub trait CustomView {
     fn update(&self) -> Result<()>;
 }
 
+/// Pushes the model value into one bound control
+type ControlRefresh = Box<dyn Fn() -> Result<()> + Send + Sync>;
+
 /// Data binding for reactive updates
+///
+/// Controls bound with [`DataBinding::bind_text`] or
+/// [`DataBinding::bind_bool`] write user edits back to the model and are
+/// updated from it by [`DataBinding::refresh`], which also runs on every
+/// [`DataBinding::set`].
 pub struct DataBinding<T: Clone + Send + Sync + 'static> {
     value: std::sync::Arc<std::sync::Mutex<T>>,
     observers: std::sync::Mutex<Vec<Arc<dyn Fn(T) + Send + Sync>>>,
+    controls: std::sync::Mutex<Vec<ControlRefresh>>,
+}
+
+impl DataBinding<()> {
+    /// Create a binding with no value of its own, for binding controls to
+    /// a model kept elsewhere
+    pub fn without_value() -> Self {
+        Self::new(())
+    }
 }
 
 impl<T: Clone + Send + Sync + 'static> DataBinding<T> {
@@ -233,6 +794,7 @@ impl<T: Clone + Send + Sync + 'static> DataBinding<T> {
         Self {
             value: std::sync::Arc::new(std::sync::Mutex::new(initial_value)),
             observers: std::sync::Mutex::new(Vec::new()),
+            controls: std::sync::Mutex::new(Vec::new()),
         }
     }
 
@@ -259,8 +821,9 @@ impl<T: Clone + Send + Sync + 'static> DataBinding<T> {
         for observer in observers.iter() {
             observer(new_value.clone());
         }
+        drop(observers);
 
-        Ok(())
+        self.refresh()
     }
 
     /// Subscribe to changes
@@ -275,6 +838,77 @@ impl<T: Clone + Send + Sync + 'static> DataBinding<T> {
         observers.push(Arc::new(observer));
         Ok(())
     }
+
+    /// Show the model's text in `field` and write the user's edits back
+    ///
+    /// `get` reads the model value and `set` stores an edited one. This
+    /// replaces the field's `on_change` handler.
+    pub fn bind_text<G, S>(&self, field: &mut TextField, get: G, set: S) -> Result<()>
+    where
+        G: Fn() -> String + Send + Sync + 'static,
+        S: Fn(String) + Send + Sync + 'static,
+    {
+        field.set_text(&get())?;
+        field.on_change(set);
+
+        let field = std::sync::Mutex::new(field.clone());
+        self.add_control(Box::new(move || {
+            let text = get();
+            let mut field = field.lock().map_err(|_| {
+                crate::core::error::CocoanutError::ThreadingError("Failed to acquire lock".into())
+            })?;
+            if field.current_text() != text {
+                field.set_text(&text)?;
+            }
+            Ok(())
+        }))
+    }
+
+    /// Show the model's flag in `checkbox` and write the user's toggles back
+    ///
+    /// `get` reads the model value and `set` stores a toggled one. This
+    /// replaces the checkbox's `on_change` handler.
+    pub fn bind_bool<G, S>(&self, checkbox: &mut Checkbox, get: G, set: S) -> Result<()>
+    where
+        G: Fn() -> bool + Send + Sync + 'static,
+        S: Fn(bool) + Send + Sync + 'static,
+    {
+        checkbox.set_checked(get())?;
+        checkbox.on_change(set);
+
+        let checkbox = std::sync::Mutex::new(checkbox.clone());
+        self.add_control(Box::new(move || {
+            let checked = get();
+            let mut checkbox = checkbox.lock().map_err(|_| {
+                crate::core::error::CocoanutError::ThreadingError("Failed to acquire lock".into())
+            })?;
+            if checkbox.is_checked() != checked {
+                checkbox.set_checked(checked)?;
+            }
+            Ok(())
+        }))
+    }
+
+    /// Update every bound control from the model
+    pub fn refresh(&self) -> Result<()> {
+        let controls = self.controls.lock().map_err(|_| {
+            crate::core::error::CocoanutError::ThreadingError("Failed to acquire lock".into())
+        })?;
+
+        for refresh in controls.iter() {
+            refresh()?;
+        }
+        Ok(())
+    }
+
+    fn add_control(&self, refresh: ControlRefresh) -> Result<()> {
+        let mut controls = self.controls.lock().map_err(|_| {
+            crate::core::error::CocoanutError::ThreadingError("Failed to acquire lock".into())
+        })?;
+
+        controls.push(refresh);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -318,6 +952,77 @@ mod tests {
         assert_eq!(constraints.len(), 1);
     }
 
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_constraint_builder_chains() {
+        use crate::components::basic::controls_v2::Label;
+
+        let sidebar = Label::new("Sidebar").unwrap();
+        let content = Label::new("Content").unwrap();
+        let layout = AutoLayout::new();
+
+        let builder = layout
+            .constrain(&sidebar)
+            .leading_to(&content, 8.0)
+            .width(100.0)
+            .named("sidebar");
+        let constraints = builder.constraints();
+        assert_eq!(constraints.len(), 2);
+        assert_eq!(constraints[0].identifier, "sidebar.leading");
+        assert_eq!(constraints[0].constant, 8.0);
+        assert_eq!(constraints[1].identifier, "sidebar.width");
+        assert_eq!(constraints[1].constant, 100.0);
+        assert_eq!(constraints[1].priority, 1000.0);
+
+        builder.activate().unwrap();
+        assert_eq!(layout.constraints().unwrap(), ["sidebar.leading", "sidebar.width"]);
+        layout.remove_constraint("sidebar.width").unwrap();
+        assert_eq!(layout.constraints().unwrap(), ["sidebar.leading"]);
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_unattached_constraint_cannot_activate() {
+        assert!(LayoutConstraint::new("free").activate().is_err());
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_fade_in_supersedes_fade_out() {
+        unsafe {
+            let view: *mut Object = msg_send![class!(NSView), new];
+            let before = fade_generation(view);
+            fade_view(view, false, Duration::from_millis(200)).unwrap();
+            fade_view(view, true, Duration::from_millis(200)).unwrap();
+            assert_eq!(fade_generation(view), before + 2);
+            let hidden: bool = msg_send![view, isHidden];
+            let _: () = msg_send![view, release];
+            assert!(!hidden);
+        }
+    }
+
+    // Regression: a failing constraint used to leave the ones before it
+    // active on the view but unknown to the layout
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_failed_activate_rolls_back() {
+        use crate::components::basic::controls_v2::Label;
+
+        let label = Label::new("Sidebar").unwrap();
+        let layout = AutoLayout::new();
+        let mut builder = layout.constrain(&label).width(100.0).height(20.0);
+        // Identifiers cannot hold a NUL, so the second constraint fails
+        builder.pending[1].constraint.identifier = "bad\0height".to_string();
+        assert!(builder.activate().is_err());
+
+        assert!(layout.constraints().unwrap().is_empty());
+        unsafe {
+            let active: *mut Object = msg_send![label.as_view(), constraints];
+            let count: usize = msg_send![active, count];
+            assert_eq!(count, 0);
+        }
+    }
+
     #[test]
     fn test_animation() {
         let anim = Animation::new(0.3)
@@ -329,6 +1034,43 @@ mod tests {
         assert_eq!(anim.timing, TimingFunction::EaseOut);
     }
 
+    #[test]
+    fn test_timing_function_names() {
+        assert_eq!(TimingFunction::Linear.media_timing_function_name(), "linear");
+        assert_eq!(TimingFunction::EaseIn.media_timing_function_name(), "easeIn");
+        assert_eq!(TimingFunction::EaseOut.media_timing_function_name(), "easeOut");
+        assert_eq!(TimingFunction::EaseInOut.media_timing_function_name(), "easeInEaseOut");
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_animate_runs_completion() {
+        use crate::components::basic::controls_v2::Label;
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let label = Label::new("Sidebar").unwrap();
+        let finished = Rc::new(Cell::new(0));
+
+        let done = Rc::clone(&finished);
+        Animation::animate(
+            &label,
+            Rect::from_xywh(0.0, 0.0, 240.0, 600.0),
+            Duration::from_millis(250),
+            TimingFunction::EaseOut,
+            move || done.set(done.get() + 1),
+        )
+        .unwrap();
+        let done = Rc::clone(&finished);
+        Animation::animate_alpha(&label, 0.0, Duration::from_millis(100), TimingFunction::Linear, move || {
+            done.set(done.get() + 1)
+        })
+        .unwrap();
+
+        assert_eq!(finished.get(), 2);
+        assert!(!Animation::reduced_motion());
+    }
+
     #[test]
     fn test_data_binding() {
         let binding = DataBinding::new(42);
@@ -353,4 +1095,55 @@ mod tests {
         binding.set(42).unwrap();
         assert_eq!(*received.lock().unwrap(), 42);
     }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_bind_text_two_way() {
+        let model = Arc::new(std::sync::Mutex::new(String::from("Ada")));
+        let binding = DataBinding::without_value();
+        let mut field = TextField::new("").unwrap();
+
+        let (get_model, set_model) = (Arc::clone(&model), Arc::clone(&model));
+        binding
+            .bind_text(
+                &mut field,
+                move || get_model.lock().unwrap().clone(),
+                move |text| *set_model.lock().unwrap() = text,
+            )
+            .unwrap();
+        assert_eq!(field.text(), "Ada");
+
+        *model.lock().unwrap() = String::from("Grace");
+        binding.refresh().unwrap();
+        assert_eq!(field.text(), "Grace");
+
+        field.simulate_change("Linus");
+        assert_eq!(*model.lock().unwrap(), "Linus");
+        assert_eq!(field.current_text(), "Linus");
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_bind_bool_two_way() {
+        let model = Arc::new(std::sync::atomic::AtomicBool::new(true));
+        let binding = DataBinding::new(0);
+        let mut checkbox = Checkbox::new("Subscribe").unwrap();
+
+        let (get_model, set_model) = (Arc::clone(&model), Arc::clone(&model));
+        binding
+            .bind_bool(
+                &mut checkbox,
+                move || get_model.load(std::sync::atomic::Ordering::SeqCst),
+                move |on| set_model.store(on, std::sync::atomic::Ordering::SeqCst),
+            )
+            .unwrap();
+        assert!(checkbox.is_checked());
+
+        checkbox.perform_click();
+        assert!(!model.load(std::sync::atomic::Ordering::SeqCst));
+
+        model.store(true, std::sync::atomic::Ordering::SeqCst);
+        binding.set(1).unwrap();
+        assert!(checkbox.is_checked());
+    }
 }
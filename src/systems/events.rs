@@ -1,6 +1,7 @@
 //! Event handling for macOS GUI applications
 
 use crate::core::error::{CocoanutError, Result};
+use crate::features::drawing::Point;
 use std::ffi::CString;
 
 /// Event types that can be handled
@@ -142,6 +143,218 @@ impl Default for TestEventHandler {
     }
 }
 
+/// Modifier keys held during an input event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Modifiers {
+    /// Shift (⇧)
+    pub shift: bool,
+    /// Control (⌃)
+    pub control: bool,
+    /// Option (⌥)
+    pub option: bool,
+    /// Command (⌘)
+    pub command: bool,
+}
+
+impl Modifiers {
+    /// No modifier keys
+    pub const NONE: Modifiers = Modifiers { shift: false, control: false, option: false, command: false };
+
+    /// Read the modifier keys from `NSEventModifierFlags`
+    pub fn from_ns_flags(flags: u64) -> Self {
+        Modifiers {
+            shift: flags & (1 << 17) != 0,
+            control: flags & (1 << 18) != 0,
+            option: flags & (1 << 19) != 0,
+            command: flags & (1 << 20) != 0,
+        }
+    }
+
+    /// The matching `NSEventModifierFlags`
+    pub fn to_ns_flags(self) -> u64 {
+        (self.shift as u64) << 17
+            | (self.control as u64) << 18
+            | (self.option as u64) << 19
+            | (self.command as u64) << 20
+    }
+
+    /// Check if no modifier key is held
+    pub fn is_empty(self) -> bool {
+        self == Modifiers::NONE
+    }
+}
+
+/// A key press delivered to a view
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyEvent {
+    key_code: u16,
+    characters: String,
+    characters_ignoring_modifiers: String,
+    modifiers: Modifiers,
+}
+
+impl KeyEvent {
+    /// Create a key event
+    ///
+    /// The characters ignoring modifiers default to `characters`.
+    pub fn new(key_code: u16, characters: impl Into<String>, modifiers: Modifiers) -> Self {
+        let characters = characters.into();
+        KeyEvent { key_code, characters_ignoring_modifiers: characters.clone(), characters, modifiers }
+    }
+
+    /// Set the text the key produces without Control or Option applied
+    pub fn with_characters_ignoring_modifiers(mut self, characters: impl Into<String>) -> Self {
+        self.characters_ignoring_modifiers = characters.into();
+        self
+    }
+
+    /// The hardware key code, independent of the keyboard layout
+    pub fn key_code(&self) -> u16 {
+        self.key_code
+    }
+
+    /// The text the key produces, after applying the modifiers
+    pub fn characters(&self) -> &str {
+        &self.characters
+    }
+
+    /// The text the key produces without Control or Option applied
+    ///
+    /// Shift still applies, so Shift-P gives `"P"`.
+    pub fn characters_ignoring_modifiers(&self) -> &str {
+        &self.characters_ignoring_modifiers
+    }
+
+    /// The modifier keys held
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// Read a key event from an `NSEvent`
+    ///
+    /// # Safety
+    ///
+    /// `ns_event` must be a valid key `NSEvent`.
+    #[cfg(not(feature = "test-mock"))]
+    pub(crate) unsafe fn from_ns_event(ns_event: *mut objc::runtime::Object) -> Self {
+        use objc::{msg_send, sel, sel_impl};
+
+        unsafe {
+            let key_code: u16 = msg_send![ns_event, keyCode];
+            let flags: u64 = msg_send![ns_event, modifierFlags];
+            let ns_characters: *mut objc::runtime::Object = msg_send![ns_event, characters];
+            let characters = crate::core::utils::ns_string_to_string(ns_characters).unwrap_or_default();
+            let ns_unmodified: *mut objc::runtime::Object = msg_send![ns_event, charactersIgnoringModifiers];
+            let unmodified = crate::core::utils::ns_string_to_string(ns_unmodified).unwrap_or_default();
+            KeyEvent::new(key_code, characters, Modifiers::from_ns_flags(flags))
+                .with_characters_ignoring_modifiers(unmodified)
+        }
+    }
+}
+
+/// Trait for components that receive raw key presses
+pub trait KeyHandler {
+    /// Set the key-down handler
+    ///
+    /// The handler returns `true` when it handled the key; unhandled keys
+    /// continue up the responder chain.
+    fn on_key_down<F>(&mut self, handler: F) -> Result<()>
+    where
+        F: Fn(KeyEvent) -> bool + Send + Sync + 'static;
+}
+
+/// The mouse button behind a mouse event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MouseButton {
+    /// The primary button
+    #[default]
+    Left,
+    /// The secondary button
+    Right,
+    /// Any other button, by its `NSEvent` button number
+    Other(i64),
+}
+
+impl MouseButton {
+    /// Map an `NSEvent` `buttonNumber`
+    pub fn from_button_number(number: i64) -> Self {
+        match number {
+            0 => MouseButton::Left,
+            1 => MouseButton::Right,
+            number => MouseButton::Other(number),
+        }
+    }
+}
+
+/// A mouse press, release or movement delivered to a view
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MouseEvent {
+    location: Point,
+    button: MouseButton,
+    click_count: u32,
+    modifiers: Modifiers,
+}
+
+impl MouseEvent {
+    /// Create a mouse event
+    pub fn new(location: Point, button: MouseButton, click_count: u32, modifiers: Modifiers) -> Self {
+        MouseEvent { location, button, click_count, modifiers }
+    }
+
+    /// Where the pointer was, in the receiving view's coordinates
+    pub fn location(&self) -> Point {
+        self.location
+    }
+
+    /// The button pressed or released, or held while dragging
+    pub fn button(&self) -> MouseButton {
+        self.button
+    }
+
+    /// 1 for a single click, 2 for a double click and so on; 0 for moves
+    pub fn click_count(&self) -> u32 {
+        self.click_count
+    }
+
+    /// The modifier keys held
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// Read a mouse event from an `NSEvent`, relative to `ns_view`
+    ///
+    /// Mouse-moved events have no click count; `clickCount` raises for them,
+    /// so pass `has_clicks: false`.
+    ///
+    /// # Safety
+    ///
+    /// `ns_event` must be a valid mouse `NSEvent` and `ns_view` a valid view.
+    #[cfg(not(feature = "test-mock"))]
+    pub(crate) unsafe fn from_ns_event(
+        ns_event: *mut objc::runtime::Object,
+        ns_view: *mut objc::runtime::Object,
+        has_clicks: bool,
+    ) -> Self {
+        use cocoa::foundation::NSPoint;
+        use objc::{msg_send, sel, sel_impl};
+
+        unsafe {
+            let in_window: NSPoint = msg_send![ns_event, locationInWindow];
+            let nil: *mut objc::runtime::Object = std::ptr::null_mut();
+            let location: NSPoint = msg_send![ns_view, convertPoint: in_window fromView: nil];
+            let click_count: i64 = if has_clicks { msg_send![ns_event, clickCount] } else { 0 };
+            let button: i64 = msg_send![ns_event, buttonNumber];
+            let flags: u64 = msg_send![ns_event, modifierFlags];
+            MouseEvent::new(
+                Point::new(location.x, location.y),
+                MouseButton::from_button_number(button),
+                click_count.max(0) as u32,
+                Modifiers::from_ns_flags(flags),
+            )
+        }
+    }
+}
+
 /// Utility functions for event handling
 pub mod utils {
     use super::*;
@@ -194,3 +407,40 @@ pub mod utils {
         CString::new(s).map_err(|e| CocoanutError::InvalidParameter(e.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_event_with_modifiers() {
+        let modifiers = Modifiers { shift: true, command: true, ..Modifiers::NONE };
+        let event = KeyEvent::new(35, "P", modifiers);
+        assert_eq!(event.key_code(), 35);
+        assert_eq!(event.characters(), "P");
+        assert!(event.modifiers().shift && event.modifiers().command);
+        assert!(!event.modifiers().option);
+        assert!(!event.modifiers().is_empty());
+    }
+
+    #[test]
+    fn test_mouse_event_click_count() {
+        let double_click = MouseEvent::new(Point::new(10.0, 20.0), MouseButton::Left, 2, Modifiers::NONE);
+        assert_eq!(double_click.location(), Point::new(10.0, 20.0));
+        assert_eq!(double_click.button(), MouseButton::Left);
+        assert_eq!(double_click.click_count(), 2);
+        assert!(double_click.modifiers().is_empty());
+
+        assert_eq!(MouseButton::from_button_number(1), MouseButton::Right);
+        assert_eq!(MouseButton::from_button_number(3), MouseButton::Other(3));
+    }
+
+    #[test]
+    fn test_modifier_flags_round_trip() {
+        let modifiers = Modifiers { control: true, option: true, ..Modifiers::default() };
+        // Caps lock and other flags are ignored
+        let flags = modifiers.to_ns_flags() | (1 << 16);
+        assert_eq!(Modifiers::from_ns_flags(flags), modifiers);
+        assert!(Modifiers::from_ns_flags(0).is_empty());
+    }
+}
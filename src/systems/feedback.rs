@@ -3,6 +3,22 @@
 //! Implements status messages, notifications, and feedback elements for user communication.
 
 use crate::core::error::Result;
+use crate::window::Window;
+use std::cell::RefCell;
+use std::time::Duration;
+
+#[cfg(not(feature = "test-mock"))]
+use crate::core::error::CocoanutError;
+#[cfg(not(feature = "test-mock"))]
+use crate::systems::essential_features::{run_animation, TimingFunction};
+#[cfg(not(feature = "test-mock"))]
+use objc::declare::ClassDecl;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Class, Object, Sel};
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+#[cfg(not(feature = "test-mock"))]
+use std::sync::Once;
 
 /// Status message types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -182,6 +198,425 @@ impl Toast {
     pub fn get_duration(&self) -> f64 {
         self.duration
     }
+
+    /// Overlay `message` near the bottom of `window` for `duration`
+    ///
+    /// The toast fades out when the duration is up. Toasts shown while
+    /// others are still on screen stack above them.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use cocoanut::prelude::*;
+    /// use cocoanut::systems::feedback::Toast;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> cocoanut::Result<()> {
+    /// let window = Window::new("Editor", 800.0, 600.0)?;
+    /// Toast::show(&window, "Saved", Duration::from_secs(2))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn show(window: &Window, message: impl Into<String>, duration: Duration) -> Result<ToastHandle> {
+        Toast::new(message, StatusType::Info)
+            .with_duration(duration.as_secs_f64())
+            .present(window)
+    }
+
+    /// Overlay this toast on `window` for its duration
+    pub fn present(&self, window: &Window) -> Result<ToastHandle> {
+        let dismiss_after = Duration::from_secs_f64(self.duration.max(0.0));
+        let window_key = window.ns_window() as usize;
+        let slot = claim_toast_slot(window_key);
+
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            if let Err(err) = show_toast_view(window.ns_window(), &self.message, slot, dismiss_after) {
+                release_toast_slot(window_key, slot);
+                return Err(err);
+            }
+        }
+        // Mock builds have no timer; tests fire the dismissal themselves
+        #[cfg(feature = "test-mock")]
+        MOCK_TOAST_TIMERS.with(|timers| timers.borrow_mut().push((window_key, slot, dismiss_after)));
+
+        Ok(ToastHandle { slot, dismiss_after })
+    }
+}
+
+/// A toast on screen, returned by [`Toast::show`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToastHandle {
+    slot: usize,
+    dismiss_after: Duration,
+}
+
+impl ToastHandle {
+    /// Position in the window's stack of toasts, 0 being the bottom
+    pub fn slot(&self) -> usize {
+        self.slot
+    }
+
+    /// Delay of the timer that dismisses the toast
+    pub fn dismiss_after(&self) -> Duration {
+        self.dismiss_after
+    }
+}
+
+/// Height of a toast
+#[cfg(not(feature = "test-mock"))]
+const TOAST_HEIGHT: f64 = 32.0;
+/// Gap between stacked toasts and below the lowest one
+#[cfg(not(feature = "test-mock"))]
+const TOAST_SPACING: f64 = 12.0;
+/// Horizontal padding around the message
+#[cfg(not(feature = "test-mock"))]
+const TOAST_PADDING: f64 = 16.0;
+
+thread_local! {
+    /// Stack slots taken by visible toasts, as (window, slot) pairs
+    ///
+    /// Toasts are shown and dismissed on the main thread only.
+    static TOAST_SLOTS: RefCell<Vec<(usize, usize)>> = const { RefCell::new(Vec::new()) };
+}
+
+#[cfg(feature = "test-mock")]
+thread_local! {
+    /// Dismissals mock builds would have scheduled, as (window, slot, delay)
+    static MOCK_TOAST_TIMERS: RefCell<Vec<(usize, usize, Duration)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Take the lowest free stack slot in the window
+fn claim_toast_slot(window_key: usize) -> usize {
+    TOAST_SLOTS.with(|slots| {
+        let mut slots = slots.borrow_mut();
+        let slot = (0..)
+            .find(|slot| !slots.contains(&(window_key, *slot)))
+            .unwrap_or_default();
+        slots.push((window_key, slot));
+        slot
+    })
+}
+
+/// Free a slot once its toast is gone
+#[cfg(any(test, not(feature = "test-mock")))]
+fn release_toast_slot(window_key: usize, slot: usize) {
+    TOAST_SLOTS.with(|slots| slots.borrow_mut().retain(|taken| *taken != (window_key, slot)));
+}
+
+/// Run the dismissals scheduled by toasts, as their timers would
+///
+/// Returns the delays the timers were scheduled with.
+#[cfg(all(test, feature = "test-mock"))]
+fn fire_mock_toast_timers() -> Vec<Duration> {
+    let timers = MOCK_TOAST_TIMERS.with(|timers| std::mem::take(&mut *timers.borrow_mut()));
+    timers
+        .into_iter()
+        .map(|(window_key, slot, delay)| {
+            release_toast_slot(window_key, slot);
+            delay
+        })
+        .collect()
+}
+
+/// Add the toast label to the window and schedule its fade-out
+#[cfg(not(feature = "test-mock"))]
+unsafe fn show_toast_view(ns_window: *mut Object, message: &str, slot: usize, dismiss_after: Duration) -> Result<()> {
+    use block::ConcreteBlock;
+    use cocoa::foundation::{NSPoint, NSRect, NSSize};
+    use std::cell::Cell;
+
+    unsafe {
+        let content_view: *mut Object = msg_send![ns_window, contentView];
+        if content_view.is_null() {
+            return Err(CocoanutError::InvalidParameter(
+                "Cannot show a toast in a window without a content view".to_string()
+            ));
+        }
+
+        let text = crate::core::utils::string_to_ns_string(message)?;
+        let label: *mut Object = msg_send![class!(NSTextField), labelWithString: text];
+        if label.is_null() {
+            return Err(CocoanutError::ControlCreationFailed("Failed to create toast label".to_string()));
+        }
+        let background: *mut Object = msg_send![class!(NSColor), colorWithWhite: 0.1f64 alpha: 0.8f64];
+        let foreground: *mut Object = msg_send![class!(NSColor), whiteColor];
+        let _: () = msg_send![label, setDrawsBackground: true];
+        let _: () = msg_send![label, setBackgroundColor: background];
+        let _: () = msg_send![label, setTextColor: foreground];
+        let _: () = msg_send![label, setAlignment: crate::core::utils::NS_TEXT_ALIGNMENT_CENTER];
+        let _: () = msg_send![label, setWantsLayer: true];
+        let layer: *mut Object = msg_send![label, layer];
+        let _: () = msg_send![layer, setCornerRadius: TOAST_HEIGHT / 2.0];
+        let _: () = msg_send![layer, setMasksToBounds: true];
+
+        let fitting: NSSize = msg_send![label, fittingSize];
+        let bounds: NSRect = msg_send![content_view, bounds];
+        let width = (fitting.width + TOAST_PADDING * 2.0).min(bounds.size.width - TOAST_SPACING * 2.0);
+        let frame = NSRect {
+            origin: NSPoint {
+                x: (bounds.size.width - width) / 2.0,
+                y: TOAST_SPACING + slot as f64 * (TOAST_HEIGHT + TOAST_SPACING),
+            },
+            size: NSSize { width, height: TOAST_HEIGHT },
+        };
+        let _: () = msg_send![label, setFrame: frame];
+        // Stay centered above the bottom edge when the window resizes
+        // (NSViewMinXMargin | NSViewMaxXMargin | NSViewMaxYMargin)
+        let _: () = msg_send![label, setAutoresizingMask: 1u64 | 4 | 32];
+        let _: () = msg_send![content_view, addSubview: label];
+
+        // The timer holds its own reference, as the window may drop the
+        // label first; it is released once the toast is gone
+        let label: *mut Object = msg_send![label, retain];
+        let label = label as usize;
+        let window_key = ns_window as usize;
+        let remove = move || {
+            let label = label as *mut Object;
+            let _: () = msg_send![label, removeFromSuperview];
+            let _: () = msg_send![label, release];
+            release_toast_slot(window_key, slot);
+        };
+        let dismiss = Cell::new(Some(move || {
+            let label = label as *mut Object;
+            let faded = run_animation(label, Duration::from_millis(250), TimingFunction::EaseOut, remove, |target| {
+                let _: () = msg_send![target, setAlphaValue: 0.0f64];
+            });
+            if faded.is_err() {
+                remove();
+            }
+        }));
+        let fire = ConcreteBlock::new(move |_timer: *mut Object| {
+            if let Some(dismiss) = dismiss.take() {
+                dismiss();
+            }
+        })
+        .copy();
+        let _: *mut Object = msg_send![class!(NSTimer), scheduledTimerWithTimeInterval: dismiss_after.as_secs_f64()
+                                                                            repeats: false
+                                                                              block: &*fire];
+        Ok(())
+    }
+}
+
+/// Size of the spinner shown over a busy view
+#[cfg(not(feature = "test-mock"))]
+const SPINNER_SIZE: f64 = 32.0;
+
+#[cfg(feature = "test-mock")]
+thread_local! {
+    /// Views mock builds would cover with a spinner
+    static MOCK_SPINNER_VIEWS: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Cover `view` with a spinning progress indicator that swallows clicks,
+/// disabling the controls inside it until the spinner is hidden
+///
+/// Does nothing if the view already shows one. Backs
+/// [`Drawable::show_spinner`](crate::core::traits::Drawable::show_spinner).
+pub(crate) fn show_spinner_overlay(view: *mut objc::runtime::Object) -> Result<()> {
+    #[cfg(not(feature = "test-mock"))]
+    unsafe {
+        if view.is_null() {
+            return Err(CocoanutError::ObjcNull("spinner view"));
+        }
+        if !find_spinner_overlay(view).is_null() {
+            return Ok(());
+        }
+        let disabled: *mut Object = msg_send![class!(NSMutableArray), new];
+        disable_controls(view, disabled);
+        match add_spinner_overlay(view) {
+            Ok(overlay) => (*overlay).set_ivar::<*mut Object>("disabledControls", disabled),
+            Err(error) => {
+                enable_controls(disabled);
+                return Err(error);
+            }
+        }
+    }
+    #[cfg(feature = "test-mock")]
+    MOCK_SPINNER_VIEWS.with(|views| {
+        let mut views = views.borrow_mut();
+        if !views.contains(&(view as usize)) {
+            views.push(view as usize);
+        }
+    });
+    Ok(())
+}
+
+/// Remove the spinner shown over `view`, if any, and enable the controls
+/// it disabled
+pub(crate) fn hide_spinner_overlay(view: *mut objc::runtime::Object) -> Result<()> {
+    #[cfg(not(feature = "test-mock"))]
+    unsafe {
+        let overlay = find_spinner_overlay(view);
+        if overlay.is_null() {
+            return Ok(());
+        }
+        let spinner: *mut Object = msg_send![overlay, viewWithTag: SPINNER_TAG];
+        let nil: *mut Object = std::ptr::null_mut();
+        let _: () = msg_send![spinner, stopAnimation: nil];
+        let disabled = *(*overlay).get_ivar::<*mut Object>("disabledControls");
+        (*overlay).set_ivar::<*mut Object>("disabledControls", std::ptr::null_mut());
+        enable_controls(disabled);
+        let _: () = msg_send![overlay, removeFromSuperview];
+    }
+    #[cfg(feature = "test-mock")]
+    MOCK_SPINNER_VIEWS.with(|views| views.borrow_mut().retain(|busy| *busy != view as usize));
+    Ok(())
+}
+
+/// Check if `view` shows a spinner
+pub(crate) fn has_spinner_overlay(view: *mut objc::runtime::Object) -> bool {
+    #[cfg(not(feature = "test-mock"))]
+    return unsafe { !find_spinner_overlay(view).is_null() };
+    #[cfg(feature = "test-mock")]
+    MOCK_SPINNER_VIEWS.with(|views| views.borrow().contains(&(view as usize)))
+}
+
+/// Tag that finds the progress indicator inside its overlay
+#[cfg(not(feature = "test-mock"))]
+const SPINNER_TAG: i64 = 0x5350494e;
+
+/// Find the spinner overlay among the subviews of `view`, or null
+#[cfg(not(feature = "test-mock"))]
+unsafe fn find_spinner_overlay(view: *mut Object) -> *mut Object {
+    if view.is_null() {
+        return std::ptr::null_mut();
+    }
+    unsafe {
+        let subviews: *mut Object = msg_send![view, subviews];
+        let count: usize = msg_send![subviews, count];
+        for index in 0..count {
+            let subview: *mut Object = msg_send![subviews, objectAtIndex: index];
+            let is_overlay: bool = msg_send![subview, isKindOfClass: spinner_overlay_class()];
+            if is_overlay {
+                return subview;
+            }
+        }
+        std::ptr::null_mut()
+    }
+}
+
+/// Disable `view` and every enabled control below it, adding each to the
+/// `disabled` array
+#[cfg(not(feature = "test-mock"))]
+unsafe fn disable_controls(view: *mut Object, disabled: *mut Object) {
+    unsafe {
+        let responds: bool = msg_send![view, respondsToSelector: sel!(isEnabled)];
+        if responds {
+            let enabled: bool = msg_send![view, isEnabled];
+            if enabled {
+                let _: () = msg_send![view, setEnabled: false];
+                let _: () = msg_send![disabled, addObject: view];
+            }
+        }
+        let subviews: *mut Object = msg_send![view, subviews];
+        let count: usize = msg_send![subviews, count];
+        for index in 0..count {
+            let subview: *mut Object = msg_send![subviews, objectAtIndex: index];
+            disable_controls(subview, disabled);
+        }
+    }
+}
+
+/// Enable the controls collected by `disable_controls` and release the
+/// array; null arrays are ignored
+#[cfg(not(feature = "test-mock"))]
+unsafe fn enable_controls(disabled: *mut Object) {
+    if disabled.is_null() {
+        return;
+    }
+    unsafe {
+        let count: usize = msg_send![disabled, count];
+        for index in 0..count {
+            let control: *mut Object = msg_send![disabled, objectAtIndex: index];
+            let _: () = msg_send![control, setEnabled: true];
+        }
+        let _: () = msg_send![disabled, release];
+    }
+}
+
+/// Add a click-swallowing overlay with a centered spinner over `view`
+///
+/// The view's subviews keep the overlay, which is returned unretained.
+#[cfg(not(feature = "test-mock"))]
+unsafe fn add_spinner_overlay(view: *mut Object) -> Result<*mut Object> {
+    use cocoa::foundation::{NSPoint, NSRect, NSSize};
+
+    unsafe {
+        let bounds: NSRect = msg_send![view, bounds];
+        let overlay: *mut Object = msg_send![spinner_overlay_class(), alloc];
+        let overlay: *mut Object = msg_send![overlay, initWithFrame: bounds];
+        let spinner: *mut Object = msg_send![class!(NSProgressIndicator), alloc];
+        let spinner_frame = NSRect::new(
+            NSPoint::new((bounds.size.width - SPINNER_SIZE) / 2.0, (bounds.size.height - SPINNER_SIZE) / 2.0),
+            NSSize::new(SPINNER_SIZE, SPINNER_SIZE),
+        );
+        let spinner: *mut Object = msg_send![spinner, initWithFrame: spinner_frame];
+        if overlay.is_null() || spinner.is_null() {
+            return Err(CocoanutError::ControlCreationFailed("Failed to create loading spinner".to_string()));
+        }
+
+        // NSProgressIndicatorStyleSpinning
+        let _: () = msg_send![spinner, setStyle: 1u64];
+        let _: () = msg_send![spinner, setIndeterminate: true];
+        let _: () = msg_send![spinner, setTag: SPINNER_TAG];
+        // Stay centered as the view resizes
+        // (NSViewMinXMargin | NSViewMaxXMargin | NSViewMinYMargin | NSViewMaxYMargin)
+        let _: () = msg_send![spinner, setAutoresizingMask: 1u64 | 4 | 8 | 32];
+        let _: () = msg_send![overlay, addSubview: spinner];
+        let _: () = msg_send![spinner, release];
+        // NSViewWidthSizable | NSViewHeightSizable
+        let _: () = msg_send![overlay, setAutoresizingMask: 2u64 | 16];
+
+        let nil: *mut Object = std::ptr::null_mut();
+        let _: () = msg_send![view, addSubview: overlay positioned: 1i64 relativeTo: nil];
+        let _: () = msg_send![overlay, release];
+        let _: () = msg_send![spinner, startAnimation: nil];
+        Ok(overlay)
+    }
+}
+
+/// Lazily register the `NSView` subclass that swallows mouse events meant
+/// for the busy view underneath and holds the controls it disabled
+#[cfg(not(feature = "test-mock"))]
+fn spinner_overlay_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutSpinnerOverlay", class!(NSView))
+            .expect("CocoanutSpinnerOverlay already registered");
+        decl.add_ivar::<*mut Object>("disabledControls");
+
+        extern "C" fn swallow_event(_this: &Object, _cmd: Sel, _event: *mut Object) {}
+
+        extern "C" fn dealloc(this: &mut Object, _cmd: Sel) {
+            unsafe {
+                let disabled = *this.get_ivar::<*mut Object>("disabledControls");
+                this.set_ivar::<*mut Object>("disabledControls", std::ptr::null_mut());
+                if !disabled.is_null() {
+                    let _: () = msg_send![disabled, release];
+                }
+                let _: () = msg_send![super(this, class!(NSView)), dealloc];
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&mut Object, Sel));
+            for selector in [
+                sel!(mouseDown:),
+                sel!(mouseUp:),
+                sel!(rightMouseDown:),
+                sel!(otherMouseDown:),
+                sel!(scrollWheel:),
+            ] {
+                decl.add_method(selector, swallow_event as extern "C" fn(&Object, Sel, *mut Object));
+            }
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutSpinnerOverlay").expect("CocoanutSpinnerOverlay not registered")
 }
 
 /// Status container with spinner
@@ -362,4 +797,100 @@ mod tests {
         let spinner = Spinner::new().with_text("Loading...");
         assert_eq!(spinner.get_text(), Some("Loading..."));
     }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_toast_schedules_dismissal_and_stacks() {
+        let window = Window::new("Toasts", 400.0, 300.0).unwrap();
+
+        let first = Toast::show(&window, "Saved", Duration::from_millis(1500)).unwrap();
+        assert_eq!(first.dismiss_after(), Duration::from_millis(1500));
+
+        let second = Toast::new("Exported", StatusType::Success)
+            .with_duration(4.0)
+            .present(&window)
+            .unwrap();
+        assert_eq!(second.dismiss_after(), Duration::from_secs(4));
+        assert!(second.slot() > first.slot());
+
+        // Each toast scheduled its dismissal, which frees its slot
+        assert_eq!(fire_mock_toast_timers(), [Duration::from_millis(1500), Duration::from_secs(4)]);
+        assert!(fire_mock_toast_timers().is_empty());
+        let third = Toast::show(&window, "Synced", Duration::from_secs(1)).unwrap();
+        assert_eq!(third.slot(), 0);
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_spinner_overlay_is_removed_on_hide() {
+        use crate::core::traits::Drawable;
+
+        /// Mock builds never dereference the view pointer
+        struct Panel(*mut objc::runtime::Object);
+
+        impl Drawable for Panel {
+            fn as_view(&self) -> *mut objc::runtime::Object {
+                self.0
+            }
+
+            fn set_visible(&self, _visible: bool) -> Result<()> {
+                Ok(())
+            }
+
+            fn is_visible(&self) -> bool {
+                true
+            }
+        }
+
+        let panel = Panel(0x5150 as *mut objc::runtime::Object);
+        let other = Panel(0x5158 as *mut objc::runtime::Object);
+        assert!(!panel.is_showing_spinner());
+
+        panel.show_spinner().unwrap();
+        panel.show_spinner().unwrap();
+        other.show_spinner().unwrap();
+        assert!(panel.is_showing_spinner());
+        assert_eq!(MOCK_SPINNER_VIEWS.with(|views| views.borrow().iter().filter(|view| **view == 0x5150).count()), 1);
+
+        panel.hide_spinner().unwrap();
+        assert!(!panel.is_showing_spinner());
+        assert!(!MOCK_SPINNER_VIEWS.with(|views| views.borrow().contains(&0x5150)));
+        assert!(other.is_showing_spinner());
+
+        other.hide_spinner().unwrap();
+        assert!(panel.hide_spinner().is_ok());
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_spinner_overlay_disables_controls_while_shown() {
+        use cocoa::foundation::{NSPoint, NSRect, NSSize};
+
+        unsafe {
+            let frame = NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(200.0, 100.0));
+            let panel: *mut Object = msg_send![class!(NSView), alloc];
+            let panel: *mut Object = msg_send![panel, initWithFrame: frame];
+            let button: *mut Object = msg_send![class!(NSButton), new];
+            let inactive: *mut Object = msg_send![class!(NSButton), new];
+            let _: () = msg_send![inactive, setEnabled: false];
+            let _: () = msg_send![panel, addSubview: button];
+            let _: () = msg_send![panel, addSubview: inactive];
+
+            show_spinner_overlay(panel).unwrap();
+            assert!(has_spinner_overlay(panel));
+            let enabled: bool = msg_send![button, isEnabled];
+            assert!(!enabled);
+
+            hide_spinner_overlay(panel).unwrap();
+            assert!(!has_spinner_overlay(panel));
+            let enabled: bool = msg_send![button, isEnabled];
+            assert!(enabled);
+            let enabled: bool = msg_send![inactive, isEnabled];
+            assert!(!enabled);
+
+            let _: () = msg_send![button, release];
+            let _: () = msg_send![inactive, release];
+            let _: () = msg_send![panel, release];
+        }
+    }
 }
@@ -1,12 +1,40 @@
 //! Layout system for simplified UI composition
 //!
 //! Provides simple layout containers (VStack, HStack) for organizing UI components
-//! without manual positioning, inspired by SwiftUI.
+//! without manual positioning, inspired by SwiftUI. A [`RootView`] hosts a
+//! layout in a window and lays it out again whenever the window resizes.
 
-use crate::core::error::Result;
+use crate::core::error::{CocoanutError, Result};
+use crate::core::traits::{Drawable, Positionable};
+use crate::features::drawing::{Point, Rect, Size};
+use crate::features::styling::SpacingScale;
+use objc::runtime::Object;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[cfg(not(feature = "test-mock"))]
+use objc::declare::ClassDecl;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Class, Sel};
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+#[cfg(not(feature = "test-mock"))]
+use std::ffi::c_void;
+#[cfg(not(feature = "test-mock"))]
+use std::sync::Once;
 
 /// Represents the spacing between items in a layout
-#[derive(Debug, Clone, Copy)]
+///
+/// Spacings add together and scale by a factor, so layouts can stay on the
+/// [`SpacingScale`] while deriving related gaps:
+///
+/// ```rust
+/// use cocoanut::layout::Spacing;
+///
+/// assert_eq!(Spacing::standard() * 2.0, Spacing::lg());
+/// assert_eq!(Spacing::md() + Spacing::sm(), Spacing::new(12.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Spacing {
     /// Space in points
     pub value: f64,
@@ -32,10 +60,71 @@ impl Spacing {
     pub fn relaxed() -> Self {
         Self { value: 16.0 }
     }
+
+    /// Extra-small spacing token (2 points)
+    pub fn xs() -> Self {
+        SpacingScale::Compact.into()
+    }
+
+    /// Small spacing token (4 points)
+    pub fn sm() -> Self {
+        SpacingScale::Tight.into()
+    }
+
+    /// Medium spacing token (8 points)
+    pub fn md() -> Self {
+        SpacingScale::Standard.into()
+    }
+
+    /// Large spacing token (16 points)
+    pub fn lg() -> Self {
+        SpacingScale::Loose.into()
+    }
+
+    /// Extra-large spacing token (24 points)
+    pub fn xl() -> Self {
+        SpacingScale::Spacious.into()
+    }
+
+    /// Double extra-large spacing token (32 points)
+    pub fn xxl() -> Self {
+        SpacingScale::ExtraSpacious.into()
+    }
+
+    /// Multiply the spacing by `factor`
+    pub fn scaled(self, factor: f64) -> Self {
+        Self { value: self.value * factor }
+    }
+}
+
+impl From<SpacingScale> for Spacing {
+    fn from(scale: SpacingScale) -> Self {
+        Self { value: scale.value() }
+    }
+}
+
+impl std::ops::Add for Spacing {
+    type Output = Spacing;
+
+    fn add(self, other: Spacing) -> Spacing {
+        Spacing { value: self.value + other.value }
+    }
+}
+
+impl std::ops::Mul<f64> for Spacing {
+    type Output = Spacing;
+
+    fn mul(self, factor: f64) -> Spacing {
+        self.scaled(factor)
+    }
 }
 
 /// Alignment for layout items
+///
+/// New alignments may be added in minor releases, so matches must include a
+/// wildcard arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Alignment {
     /// Align to the leading edge
     Leading,
@@ -43,10 +132,328 @@ pub enum Alignment {
     Center,
     /// Align to the trailing edge
     Trailing,
+    /// Align the first text baselines of an HStack's children
+    ///
+    /// Children without text align their bottom edges. Behaves like
+    /// `Leading` in a VStack.
+    FirstBaseline,
+    /// Align the last text baselines of an HStack's children
+    ///
+    /// Children without text align their bottom edges. Behaves like
+    /// `Leading` in a VStack.
+    LastBaseline,
+}
+
+/// Two-dimensional alignment used by overlapping layouts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment2D {
+    /// Top edge, leading side
+    TopLeading,
+    /// Top edge, horizontally centered
+    Top,
+    /// Top edge, trailing side
+    TopTrailing,
+    /// Vertically centered, leading side
+    Leading,
+    /// Centered on both axes
+    Center,
+    /// Vertically centered, trailing side
+    Trailing,
+    /// Bottom edge, leading side
+    BottomLeading,
+    /// Bottom edge, horizontally centered
+    Bottom,
+    /// Bottom edge, trailing side
+    BottomTrailing,
+}
+
+impl Alignment2D {
+    /// Compute the origin of an item of `size` aligned within `bounds`
+    ///
+    /// Uses AppKit's bottom-left origin, so top-aligned items sit at
+    /// `bounds.max_y() - size.height`.
+    pub fn origin_in(&self, bounds: Rect, size: Size) -> Point {
+        let left = bounds.min_x();
+        let center_x = bounds.min_x() + (bounds.size.width - size.width) / 2.0;
+        let right = bounds.max_x() - size.width;
+        let top = bounds.max_y() - size.height;
+        let center_y = bounds.min_y() + (bounds.size.height - size.height) / 2.0;
+        let bottom = bounds.min_y();
+
+        let (x, y) = match self {
+            Alignment2D::TopLeading => (left, top),
+            Alignment2D::Top => (center_x, top),
+            Alignment2D::TopTrailing => (right, top),
+            Alignment2D::Leading => (left, center_y),
+            Alignment2D::Center => (center_x, center_y),
+            Alignment2D::Trailing => (right, center_y),
+            Alignment2D::BottomLeading => (left, bottom),
+            Alignment2D::Bottom => (center_x, bottom),
+            Alignment2D::BottomTrailing => (right, bottom),
+        };
+        Point::new(x, y)
+    }
+}
+
+/// An element that layout containers can measure and position
+pub trait LayoutItem {
+    /// The size the item would like to occupy, in points
+    fn preferred_size(&self) -> Size;
+
+    /// Assign the item's frame within its container
+    fn place(&self, frame: Rect) -> Result<()>;
+
+    /// Whether the item expands to fill leftover space along a stack's axis
+    ///
+    /// Flexible items never shrink below their preferred size.
+    fn is_flexible(&self) -> bool {
+        false
+    }
+
+    /// Distance from the item's top edge to the baseline of its first line
+    /// of text, or `None` if it shows no text
+    fn first_baseline(&self) -> Option<f64> {
+        None
+    }
+
+    /// Distance from the item's top edge to the baseline of its last line
+    /// of text, or `None` if it shows no text
+    fn last_baseline(&self) -> Option<f64> {
+        None
+    }
+
+    /// The native views of the item and of everything inside it, back to
+    /// front
+    ///
+    /// A [`RootView`] adds them as its subviews. Items without a view, such
+    /// as a [`Spacer`], have none.
+    fn views(&self) -> Vec<*mut Object> {
+        Vec::new()
+    }
+}
+
+impl<T: Drawable + Positionable> LayoutItem for T {
+    fn preferred_size(&self) -> Size {
+        let (_, _, width, height) = Positionable::frame(self);
+        Size::new(width, height)
+    }
+
+    fn place(&self, frame: Rect) -> Result<()> {
+        self.set_frame(frame.origin.x, frame.origin.y, frame.size.width, frame.size.height)
+    }
+
+    fn first_baseline(&self) -> Option<f64> {
+        view_baselines(self.as_view()).map(|(first, _)| first)
+    }
+
+    fn last_baseline(&self) -> Option<f64> {
+        view_baselines(self.as_view()).map(|(_, last)| last)
+    }
+
+    fn views(&self) -> Vec<*mut Object> {
+        let view = self.as_view();
+        if view.is_null() { Vec::new() } else { vec![view] }
+    }
+}
+
+/// First and last baselines of a native view, measured from its top edge
+///
+/// AppKit derives them from the font metrics of text controls; views
+/// without text report zero offsets and have no baselines.
+fn view_baselines(view: *mut objc::runtime::Object) -> Option<(f64, f64)> {
+    #[cfg(not(feature = "test-mock"))]
+    if !view.is_null() {
+        use objc::{msg_send, sel, sel_impl};
+
+        unsafe {
+            let first: f64 = msg_send![view, firstBaselineOffsetFromTop];
+            let last_from_bottom: f64 = msg_send![view, lastBaselineOffsetFromBottom];
+            if first == 0.0 && last_from_bottom == 0.0 {
+                return None;
+            }
+            let frame: cocoa::foundation::NSRect = msg_send![view, frame];
+            return Some((first, frame.size.height - last_from_bottom));
+        }
+    }
+    #[cfg(feature = "test-mock")]
+    let _ = view;
+    None
+}
+
+/// Insets applied around the edges of a layout item
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EdgeInsets {
+    /// Top inset in points
+    pub top: f64,
+    /// Left inset in points
+    pub left: f64,
+    /// Bottom inset in points
+    pub bottom: f64,
+    /// Right inset in points
+    pub right: f64,
+}
+
+impl EdgeInsets {
+    /// Create insets with a value for each edge
+    pub fn new(top: f64, left: f64, bottom: f64, right: f64) -> Self {
+        Self { top, left, bottom, right }
+    }
+
+    /// Create the same inset on every edge
+    pub fn uniform(value: f64) -> Self {
+        Self::new(value, value, value, value)
+    }
+
+    /// Total horizontal inset (left + right)
+    pub fn horizontal(&self) -> f64 {
+        self.left + self.right
+    }
+
+    /// Total vertical inset (top + bottom)
+    pub fn vertical(&self) -> f64 {
+        self.top + self.bottom
+    }
+}
+
+/// A layout item surrounded by padding
+///
+/// The padding is added to the item's preferred size and removed again when
+/// the item is placed, so containers reserve room for it automatically.
+pub struct Padded<T> {
+    /// The wrapped item
+    item: T,
+    /// Padding around the item
+    insets: EdgeInsets,
+}
+
+impl<T: LayoutItem> Padded<T> {
+    /// Wrap an item with padding
+    pub fn new(item: T, insets: EdgeInsets) -> Self {
+        Self { item, insets }
+    }
+
+    /// Get the padding
+    pub fn get_insets(&self) -> EdgeInsets {
+        self.insets
+    }
+
+    /// Get the wrapped item
+    pub fn inner(&self) -> &T {
+        &self.item
+    }
 }
 
+impl<T: LayoutItem> LayoutItem for Padded<T> {
+    fn preferred_size(&self) -> Size {
+        let size = self.item.preferred_size();
+        Size::new(
+            size.width + self.insets.horizontal(),
+            size.height + self.insets.vertical(),
+        )
+    }
+
+    fn place(&self, frame: Rect) -> Result<()> {
+        // AppKit's origin is bottom-left, so the bottom inset moves the item up
+        self.item.place(Rect::from_xywh(
+            frame.origin.x + self.insets.left,
+            frame.origin.y + self.insets.bottom,
+            (frame.size.width - self.insets.horizontal()).max(0.0),
+            (frame.size.height - self.insets.vertical()).max(0.0),
+        ))
+    }
+
+    fn is_flexible(&self) -> bool {
+        self.item.is_flexible()
+    }
+
+    fn first_baseline(&self) -> Option<f64> {
+        self.item.first_baseline().map(|baseline| baseline + self.insets.top)
+    }
+
+    fn last_baseline(&self) -> Option<f64> {
+        self.item.last_baseline().map(|baseline| baseline + self.insets.top)
+    }
+
+    fn views(&self) -> Vec<*mut Object> {
+        self.item.views()
+    }
+}
+
+/// A layout item with a fixed size
+///
+/// The item always occupies exactly `width` x `height` points and is centered
+/// in whatever frame its container offers it.
+pub struct Framed<T> {
+    /// The wrapped item
+    item: T,
+    /// Fixed size of the item
+    size: Size,
+}
+
+impl<T: LayoutItem> Framed<T> {
+    /// Wrap an item with a fixed size
+    pub fn new(item: T, width: f64, height: f64) -> Self {
+        Self { item, size: Size::new(width, height) }
+    }
+
+    /// Get the fixed size
+    pub fn get_size(&self) -> Size {
+        self.size
+    }
+
+    /// Get the wrapped item
+    pub fn inner(&self) -> &T {
+        &self.item
+    }
+}
+
+impl<T: LayoutItem> LayoutItem for Framed<T> {
+    fn preferred_size(&self) -> Size {
+        self.size
+    }
+
+    fn place(&self, frame: Rect) -> Result<()> {
+        let origin = Alignment2D::Center.origin_in(frame, self.size);
+        self.item.place(Rect::new(origin, self.size))
+    }
+
+    fn first_baseline(&self) -> Option<f64> {
+        self.item.first_baseline()
+    }
+
+    fn last_baseline(&self) -> Option<f64> {
+        self.item.last_baseline()
+    }
+
+    fn views(&self) -> Vec<*mut Object> {
+        self.item.views()
+    }
+}
+
+/// SwiftUI-style modifiers available on every layout item
+///
+/// Not part of the prelude because `frame` would shadow
+/// `Positionable::frame` on owned controls; import it explicitly.
+pub trait LayoutModifiers: LayoutItem + Sized {
+    /// Add the same padding on every edge
+    fn padding(self, spacing: Spacing) -> Padded<Self> {
+        Padded::new(self, EdgeInsets::uniform(spacing.value))
+    }
+
+    /// Add padding with a value for each edge
+    fn padding_each(self, top: f64, left: f64, bottom: f64, right: f64) -> Padded<Self> {
+        Padded::new(self, EdgeInsets::new(top, left, bottom, right))
+    }
+
+    /// Fix the item's size inside its container
+    fn frame(self, width: f64, height: f64) -> Framed<Self> {
+        Framed::new(self, width, height)
+    }
+}
+
+impl<T: LayoutItem> LayoutModifiers for T {}
+
 /// Vertical stack layout container
-#[derive(Debug)]
 pub struct VStack {
     /// Spacing between items
     spacing: Spacing,
@@ -56,6 +463,8 @@ pub struct VStack {
     width: Option<f64>,
     /// Height of the stack
     height: Option<f64>,
+    /// Children, in stacking order
+    children: Vec<Box<dyn LayoutItem>>,
 }
 
 impl VStack {
@@ -66,9 +475,17 @@ impl VStack {
             alignment: Alignment::Center,
             width: None,
             height: None,
+            children: Vec::new(),
         }
     }
 
+    /// Add a child after the existing ones
+    #[allow(clippy::should_implement_trait, reason = "builder method, not arithmetic")]
+    pub fn add(mut self, item: impl LayoutItem + 'static) -> Self {
+        self.children.push(Box::new(item));
+        self
+    }
+
     /// Set the spacing between items
     pub fn spacing(mut self, spacing: Spacing) -> Self {
         self.spacing = spacing;
@@ -119,6 +536,71 @@ impl VStack {
     pub fn get_height(&self) -> Option<f64> {
         self.height
     }
+
+    /// Get the number of children
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Check if the stack has no children
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Compute each child's frame within `bounds`, in stacking order
+    pub fn compute_frames(&self, bounds: Rect) -> Vec<Rect> {
+        stack_frames(&self.children, Axis::Vertical, self.spacing, self.alignment, bounds)
+    }
+
+    /// Compute and apply each child's frame within `bounds`
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use cocoanut::prelude::*;
+    /// use cocoanut::core::View;
+    ///
+    /// let ok: Box<dyn View> = Box::new(cocoanut::components::basic::controls_v2::Button::new("OK")?);
+    /// let stack = VStack::new().spacing(Spacing::standard()).add(ok);
+    /// stack.layout_in(Rect::from_xywh(0.0, 0.0, 400.0, 300.0))?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn layout_in(&self, bounds: Rect) -> Result<()> {
+        for (child, frame) in self.children.iter().zip(self.compute_frames(bounds)) {
+            child.place(frame)?;
+        }
+        Ok(())
+    }
+}
+
+impl LayoutItem for VStack {
+    fn preferred_size(&self) -> Size {
+        let natural = stack_size(&self.children, Axis::Vertical, self.spacing, self.alignment);
+        Size::new(
+            self.width.unwrap_or(natural.width),
+            self.height.unwrap_or(natural.height),
+        )
+    }
+
+    fn place(&self, frame: Rect) -> Result<()> {
+        self.layout_in(frame)
+    }
+
+    fn views(&self) -> Vec<*mut Object> {
+        self.children.iter().flat_map(|child| child.views()).collect()
+    }
+}
+
+impl std::fmt::Debug for VStack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VStack")
+            .field("spacing", &self.spacing)
+            .field("alignment", &self.alignment)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("children", &self.children.len())
+            .finish()
+    }
 }
 
 impl Default for VStack {
@@ -128,7 +610,6 @@ impl Default for VStack {
 }
 
 /// Horizontal stack layout container
-#[derive(Debug)]
 pub struct HStack {
     /// Spacing between items
     spacing: Spacing,
@@ -138,6 +619,8 @@ pub struct HStack {
     width: Option<f64>,
     /// Height of the stack
     height: Option<f64>,
+    /// Children, in stacking order
+    children: Vec<Box<dyn LayoutItem>>,
 }
 
 impl HStack {
@@ -148,9 +631,17 @@ impl HStack {
             alignment: Alignment::Center,
             width: None,
             height: None,
+            children: Vec::new(),
         }
     }
 
+    /// Add a child after the existing ones
+    #[allow(clippy::should_implement_trait, reason = "builder method, not arithmetic")]
+    pub fn add(mut self, item: impl LayoutItem + 'static) -> Self {
+        self.children.push(Box::new(item));
+        self
+    }
+
     /// Set the spacing between items
     pub fn spacing(mut self, spacing: Spacing) -> Self {
         self.spacing = spacing;
@@ -201,6 +692,71 @@ impl HStack {
     pub fn get_height(&self) -> Option<f64> {
         self.height
     }
+
+    /// Get the number of children
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Check if the stack has no children
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Compute each child's frame within `bounds`, in stacking order
+    pub fn compute_frames(&self, bounds: Rect) -> Vec<Rect> {
+        stack_frames(&self.children, Axis::Horizontal, self.spacing, self.alignment, bounds)
+    }
+
+    /// Compute and apply each child's frame within `bounds`
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use cocoanut::prelude::*;
+    /// use cocoanut::core::View;
+    ///
+    /// let ok: Box<dyn View> = Box::new(cocoanut::components::basic::controls_v2::Button::new("OK")?);
+    /// let stack = HStack::new().spacing(Spacing::standard()).add(ok);
+    /// stack.layout_in(Rect::from_xywh(0.0, 0.0, 400.0, 300.0))?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn layout_in(&self, bounds: Rect) -> Result<()> {
+        for (child, frame) in self.children.iter().zip(self.compute_frames(bounds)) {
+            child.place(frame)?;
+        }
+        Ok(())
+    }
+}
+
+impl LayoutItem for HStack {
+    fn preferred_size(&self) -> Size {
+        let natural = stack_size(&self.children, Axis::Horizontal, self.spacing, self.alignment);
+        Size::new(
+            self.width.unwrap_or(natural.width),
+            self.height.unwrap_or(natural.height),
+        )
+    }
+
+    fn place(&self, frame: Rect) -> Result<()> {
+        self.layout_in(frame)
+    }
+
+    fn views(&self) -> Vec<*mut Object> {
+        self.children.iter().flat_map(|child| child.views()).collect()
+    }
+}
+
+impl std::fmt::Debug for HStack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HStack")
+            .field("spacing", &self.spacing)
+            .field("alignment", &self.alignment)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("children", &self.children.len())
+            .finish()
+    }
 }
 
 impl Default for HStack {
@@ -209,6 +765,493 @@ impl Default for HStack {
     }
 }
 
+/// Main axis of a stack
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    Vertical,
+    Horizontal,
+}
+
+/// Shared layout pass for VStack and HStack
+///
+/// Fixed children keep their preferred length along the axis. Space left after
+/// fixed children and spacing is split equally between flexible children
+/// (spacers), except that none is given less than its minimum length.
+/// VStacks fill from the top, HStacks from the leading edge. On the cross axis
+/// children keep their preferred size, clamped to the bounds, and are aligned
+/// by `alignment` (for an HStack, leading is the top edge). Baseline
+/// alignment moves an HStack's fixed children down until their baselines
+/// line up with the lowest one.
+fn stack_frames(
+    children: &[Box<dyn LayoutItem>],
+    axis: Axis,
+    spacing: Spacing,
+    alignment: Alignment,
+    bounds: Rect,
+) -> Vec<Rect> {
+    let (main_extent, cross_extent) = match axis {
+        Axis::Vertical => (bounds.size.height, bounds.size.width),
+        Axis::Horizontal => (bounds.size.width, bounds.size.height),
+    };
+    let main_of = |size: Size| match axis {
+        Axis::Vertical => size.height,
+        Axis::Horizontal => size.width,
+    };
+    let cross_of = |size: Size| match axis {
+        Axis::Vertical => size.width,
+        Axis::Horizontal => size.height,
+    };
+
+    let gaps = spacing.value * children.len().saturating_sub(1) as f64;
+    let fixed: f64 = children
+        .iter()
+        .filter(|child| !child.is_flexible())
+        .map(|child| main_of(child.preferred_size()))
+        .sum();
+    let minimums: Vec<f64> = children
+        .iter()
+        .filter(|child| child.is_flexible())
+        .map(|child| main_of(child.preferred_size()))
+        .collect();
+    let mut flexible = distribute_flexible(&minimums, main_extent - fixed - gaps).into_iter();
+    let baseline = match axis {
+        Axis::Horizontal => shared_baseline(children, alignment),
+        Axis::Vertical => None,
+    };
+
+    let mut cursor = 0.0;
+    children
+        .iter()
+        .map(|child| {
+            let preferred = child.preferred_size();
+            let (main, cross) = if child.is_flexible() {
+                (flexible.next().unwrap_or(0.0), cross_extent)
+            } else {
+                (main_of(preferred), cross_of(preferred).min(cross_extent))
+            };
+            let cross_offset = match (alignment, baseline) {
+                (Alignment::FirstBaseline | Alignment::LastBaseline, Some(shared)) if !child.is_flexible() => {
+                    shared - baseline_of(child.as_ref(), alignment)
+                }
+                (Alignment::Center, _) => (cross_extent - cross) / 2.0,
+                (Alignment::Trailing, _) => cross_extent - cross,
+                _ => 0.0,
+            };
+            let main_offset = cursor;
+            cursor += main + spacing.value;
+
+            match axis {
+                Axis::Vertical => Rect::from_xywh(
+                    bounds.min_x() + cross_offset,
+                    bounds.max_y() - main_offset - main,
+                    cross,
+                    main,
+                ),
+                Axis::Horizontal => Rect::from_xywh(
+                    bounds.min_x() + main_offset,
+                    bounds.max_y() - cross_offset - cross,
+                    main,
+                    cross,
+                ),
+            }
+        })
+        .collect()
+}
+
+/// Baseline of `item` for a baseline `alignment`, measured from its top
+///
+/// Items without text use their bottom edge.
+fn baseline_of(item: &dyn LayoutItem, alignment: Alignment) -> f64 {
+    let baseline = match alignment {
+        Alignment::LastBaseline => item.last_baseline(),
+        _ => item.first_baseline(),
+    };
+    baseline.unwrap_or_else(|| item.preferred_size().height)
+}
+
+/// The lowest baseline of an HStack's fixed children, measured from the
+/// stack's top, or `None` unless `alignment` is a baseline alignment
+fn shared_baseline(children: &[Box<dyn LayoutItem>], alignment: Alignment) -> Option<f64> {
+    if !matches!(alignment, Alignment::FirstBaseline | Alignment::LastBaseline) {
+        return None;
+    }
+    children
+        .iter()
+        .filter(|child| !child.is_flexible())
+        .map(|child| baseline_of(child.as_ref(), alignment))
+        .reduce(f64::max)
+}
+
+/// Natural size of a stack: children and spacing along the axis, the largest
+/// child across it
+///
+/// Baseline-aligned HStacks are tall enough for the children hanging below
+/// the shared baseline.
+fn stack_size(children: &[Box<dyn LayoutItem>], axis: Axis, spacing: Spacing, alignment: Alignment) -> Size {
+    let gaps = spacing.value * children.len().saturating_sub(1) as f64;
+    let (main, mut cross) = children.iter().fold((gaps, 0.0_f64), |(main, cross), child| {
+        let size = child.preferred_size();
+        match axis {
+            Axis::Vertical => (main + size.height, cross.max(size.width)),
+            Axis::Horizontal => (main + size.width, cross.max(size.height)),
+        }
+    });
+    if let (Axis::Horizontal, Some(shared)) = (axis, shared_baseline(children, alignment)) {
+        let descent = children
+            .iter()
+            .filter(|child| !child.is_flexible())
+            .map(|child| child.preferred_size().height - baseline_of(child.as_ref(), alignment))
+            .fold(0.0_f64, f64::max);
+        cross = cross.max(shared + descent);
+    }
+    match axis {
+        Axis::Vertical => Size::new(cross, main),
+        Axis::Horizontal => Size::new(main, cross),
+    }
+}
+
+/// Split `available` points equally between flexible items, giving any item
+/// whose minimum exceeds its share exactly its minimum
+fn distribute_flexible(minimums: &[f64], available: f64) -> Vec<f64> {
+    let mut sizes = vec![0.0; minimums.len()];
+    let mut pool = available.max(0.0);
+    let mut open: Vec<usize> = (0..minimums.len()).collect();
+
+    while !open.is_empty() {
+        let share = pool / open.len() as f64;
+        let (pinned, rest): (Vec<usize>, Vec<usize>) =
+            open.into_iter().partition(|&i| minimums[i] > share);
+        if pinned.is_empty() {
+            rest.iter().for_each(|&i| sizes[i] = share);
+            break;
+        }
+        for i in pinned {
+            sizes[i] = minimums[i];
+            pool -= minimums[i];
+        }
+        open = rest;
+    }
+    sizes
+}
+
+/// Overlay layout container that stacks children on top of each other
+///
+/// Children are drawn in insertion order, so later children appear above
+/// earlier ones. Each child keeps its preferred size (clamped to the stack's
+/// bounds) and is positioned according to the stack's alignment.
+pub struct ZStack {
+    /// Alignment of children within the stack
+    alignment: Alignment2D,
+    /// Children, from back to front
+    children: Vec<Box<dyn LayoutItem>>,
+}
+
+impl ZStack {
+    /// Create a new overlay stack
+    pub fn new() -> Self {
+        Self {
+            alignment: Alignment2D::Center,
+            children: Vec::new(),
+        }
+    }
+
+    /// Set the alignment of children
+    pub fn alignment(mut self, alignment: Alignment2D) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Add a child on top of the existing ones
+    #[allow(clippy::should_implement_trait, reason = "builder method, not arithmetic")]
+    pub fn add(mut self, item: impl LayoutItem + 'static) -> Self {
+        self.children.push(Box::new(item));
+        self
+    }
+
+    /// Get the alignment
+    pub fn get_alignment(&self) -> Alignment2D {
+        self.alignment
+    }
+
+    /// Get the number of children
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Check if the stack has no children
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Compute each child's frame within `bounds`, back to front
+    pub fn compute_frames(&self, bounds: Rect) -> Vec<Rect> {
+        self.children
+            .iter()
+            .map(|child| {
+                let preferred = child.preferred_size();
+                let size = Size::new(
+                    preferred.width.min(bounds.size.width),
+                    preferred.height.min(bounds.size.height),
+                );
+                Rect::new(self.alignment.origin_in(bounds, size), size)
+            })
+            .collect()
+    }
+
+    /// Compute and apply each child's frame within `bounds`
+    pub fn layout_in(&self, bounds: Rect) -> Result<()> {
+        for (child, frame) in self.children.iter().zip(self.compute_frames(bounds)) {
+            child.place(frame)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for ZStack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LayoutItem for ZStack {
+    fn preferred_size(&self) -> Size {
+        self.children.iter().fold(Size::new(0.0, 0.0), |acc, child| {
+            let size = child.preferred_size();
+            Size::new(acc.width.max(size.width), acc.height.max(size.height))
+        })
+    }
+
+    fn place(&self, frame: Rect) -> Result<()> {
+        self.layout_in(frame)
+    }
+
+    fn views(&self) -> Vec<*mut Object> {
+        self.children.iter().flat_map(|child| child.views()).collect()
+    }
+}
+
+/// A child of a [`Grid`] together with the cells it occupies
+struct GridCell {
+    item: Box<dyn LayoutItem>,
+    row: usize,
+    col: usize,
+    row_span: usize,
+    col_span: usize,
+}
+
+impl GridCell {
+    fn covers(&self, row: usize, col: usize) -> bool {
+        (self.row..self.row + self.row_span).contains(&row)
+            && (self.col..self.col + self.col_span).contains(&col)
+    }
+}
+
+/// Grid layout engine with row and column spanning
+///
+/// Column widths and row heights start from the largest preferred size of the
+/// items in each track. Space left over in the container is shared equally
+/// between tracks; if the content does not fit, tracks shrink proportionally.
+/// Items fill the full area of the cells they span. Row 0 is the top row.
+pub struct Grid {
+    /// Number of columns
+    columns: usize,
+    /// Horizontal space between columns
+    column_spacing: f64,
+    /// Vertical space between rows
+    row_spacing: f64,
+    /// Placed children
+    cells: Vec<GridCell>,
+}
+
+impl Grid {
+    /// Create a new grid with a fixed number of columns
+    pub fn new(columns: usize) -> Self {
+        Self {
+            columns,
+            column_spacing: Spacing::standard().value,
+            row_spacing: Spacing::standard().value,
+            cells: Vec::new(),
+        }
+    }
+
+    /// Set the space between columns
+    pub fn column_spacing(mut self, spacing: f64) -> Self {
+        self.column_spacing = spacing;
+        self
+    }
+
+    /// Set the space between rows
+    pub fn row_spacing(mut self, spacing: f64) -> Self {
+        self.row_spacing = spacing;
+        self
+    }
+
+    /// Add an item occupying `row_span` x `col_span` cells starting at `row`, `col`
+    ///
+    /// # Returns
+    ///
+    /// Returns `CocoanutError::InvalidParameter` if a span is zero, the item
+    /// extends past the last column, or it overlaps an item already added.
+    /// The grid is left unchanged on error.
+    pub fn add(
+        &mut self,
+        item: impl LayoutItem + 'static,
+        row: usize,
+        col: usize,
+        row_span: usize,
+        col_span: usize,
+    ) -> Result<&mut Self> {
+        if row_span == 0 || col_span == 0 {
+            return Err(CocoanutError::InvalidParameter(
+                "Grid spans must be at least 1".to_string()
+            ));
+        }
+        if col + col_span > self.columns {
+            return Err(CocoanutError::InvalidParameter(format!(
+                "Grid item at column {} spanning {} exceeds {} columns",
+                col, col_span, self.columns
+            )));
+        }
+
+        let cell = GridCell { item: Box::new(item), row, col, row_span, col_span };
+        for r in row..row + row_span {
+            for c in col..col + col_span {
+                if self.cells.iter().any(|existing| existing.covers(r, c)) {
+                    return Err(CocoanutError::InvalidParameter(format!(
+                        "Grid cell ({}, {}) is already occupied", r, c
+                    )));
+                }
+            }
+        }
+
+        self.cells.push(cell);
+        Ok(self)
+    }
+
+    /// Get the number of columns
+    pub fn get_columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Get the number of rows in use
+    pub fn get_rows(&self) -> usize {
+        self.cells.iter().map(|cell| cell.row + cell.row_span).max().unwrap_or(0)
+    }
+
+    /// Get the space between columns
+    pub fn get_column_spacing(&self) -> f64 {
+        self.column_spacing
+    }
+
+    /// Get the space between rows
+    pub fn get_row_spacing(&self) -> f64 {
+        self.row_spacing
+    }
+
+    /// Natural column widths and row heights before distributing leftover space
+    fn natural_tracks(&self) -> (Vec<f64>, Vec<f64>) {
+        let mut widths = vec![0.0; self.columns];
+        let mut heights = vec![0.0; self.get_rows()];
+
+        // Single-span items size their track directly
+        for cell in &self.cells {
+            let size = cell.item.preferred_size();
+            if cell.col_span == 1 {
+                widths[cell.col] = f64::max(widths[cell.col], size.width);
+            }
+            if cell.row_span == 1 {
+                heights[cell.row] = f64::max(heights[cell.row], size.height);
+            }
+        }
+
+        // Spanning items grow their tracks evenly if they still don't fit
+        for cell in &self.cells {
+            let size = cell.item.preferred_size();
+            grow_span(&mut widths[cell.col..cell.col + cell.col_span], self.column_spacing, size.width);
+            grow_span(&mut heights[cell.row..cell.row + cell.row_span], self.row_spacing, size.height);
+        }
+
+        (widths, heights)
+    }
+
+    /// Compute each item's frame within `bounds`, in the order items were added
+    pub fn compute_frames(&self, bounds: Rect) -> Vec<Rect> {
+        let (widths, heights) = self.natural_tracks();
+        let widths = fit_tracks(widths, self.column_spacing, bounds.size.width);
+        let heights = fit_tracks(heights, self.row_spacing, bounds.size.height);
+
+        let offset = |tracks: &[f64], spacing: f64, index: usize| -> f64 {
+            tracks[..index].iter().map(|t| t + spacing).sum()
+        };
+        let extent = |tracks: &[f64], spacing: f64, start: usize, span: usize| -> f64 {
+            tracks[start..start + span].iter().sum::<f64>() + spacing * (span - 1) as f64
+        };
+
+        self.cells
+            .iter()
+            .map(|cell| {
+                let x = bounds.min_x() + offset(&widths, self.column_spacing, cell.col);
+                let width = extent(&widths, self.column_spacing, cell.col, cell.col_span);
+                let top = bounds.max_y() - offset(&heights, self.row_spacing, cell.row);
+                let height = extent(&heights, self.row_spacing, cell.row, cell.row_span);
+                Rect::from_xywh(x, top - height, width, height)
+            })
+            .collect()
+    }
+
+    /// Compute and apply each item's frame within `bounds`
+    pub fn layout_in(&self, bounds: Rect) -> Result<()> {
+        for (cell, frame) in self.cells.iter().zip(self.compute_frames(bounds)) {
+            cell.item.place(frame)?;
+        }
+        Ok(())
+    }
+}
+
+impl LayoutItem for Grid {
+    fn preferred_size(&self) -> Size {
+        let (widths, heights) = self.natural_tracks();
+        let total = |tracks: &[f64], spacing: f64| -> f64 {
+            tracks.iter().sum::<f64>() + spacing * tracks.len().saturating_sub(1) as f64
+        };
+        Size::new(total(&widths, self.column_spacing), total(&heights, self.row_spacing))
+    }
+
+    fn place(&self, frame: Rect) -> Result<()> {
+        self.layout_in(frame)
+    }
+
+    fn views(&self) -> Vec<*mut Object> {
+        self.cells.iter().flat_map(|cell| cell.item.views()).collect()
+    }
+}
+
+/// Grow a run of tracks evenly so they span at least `needed` points
+fn grow_span(tracks: &mut [f64], spacing: f64, needed: f64) {
+    let current = tracks.iter().sum::<f64>() + spacing * (tracks.len() - 1) as f64;
+    if needed > current {
+        let extra = (needed - current) / tracks.len() as f64;
+        tracks.iter_mut().for_each(|t| *t += extra);
+    }
+}
+
+/// Fit tracks into `available` points, sharing leftover space equally or
+/// shrinking proportionally when the content is too large
+fn fit_tracks(tracks: Vec<f64>, spacing: f64, available: f64) -> Vec<f64> {
+    if tracks.is_empty() {
+        return tracks;
+    }
+    let space = (available - spacing * (tracks.len() - 1) as f64).max(0.0);
+    let content: f64 = tracks.iter().sum();
+    if content <= space {
+        let extra = (space - content) / tracks.len() as f64;
+        tracks.into_iter().map(|t| t + extra).collect()
+    } else {
+        let scale = space / content;
+        tracks.into_iter().map(|t| t * scale).collect()
+    }
+}
+
 /// Spacer for flexible spacing in layouts
 #[derive(Debug, Clone, Copy)]
 pub struct Spacer {
@@ -235,16 +1278,307 @@ impl Spacer {
     }
 }
 
+impl LayoutItem for Spacer {
+    fn preferred_size(&self) -> Size {
+        let length = self.min_length.unwrap_or(0.0);
+        Size::new(length, length)
+    }
+
+    fn place(&self, _frame: Rect) -> Result<()> {
+        // Spacers only reserve space; there is no view to position
+        Ok(())
+    }
+
+    fn is_flexible(&self) -> bool {
+        true
+    }
+}
+
 impl Default for Spacer {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// The layout a [`RootView`] hosts, shared with its native view
+struct RootLayout {
+    content: Box<dyn LayoutItem>,
+    size: Cell<Size>,
+}
+
+impl RootLayout {
+    /// Lay the content out to fill `size`
+    fn resized(&self, size: Size) -> Result<()> {
+        self.size.set(size);
+        self.content.place(Rect::new(Point::new(0.0, 0.0), size))
+    }
+}
+
+/// A view that fills its window and lays out a stack inside it
+///
+/// Install it with [`Window::set_content_view`](crate::window::Window::set_content_view).
+/// Every view in the layout becomes a subview of the root view. The view
+/// resizes with its superview and lays its content out again on every
+/// resize, so the layout reflows as the window is resized.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::prelude::*;
+/// use cocoanut::layout::{RootView, Spacer};
+///
+/// # fn main() -> cocoanut::Result<()> {
+/// let mut window = Window::new("Reflow", 400.0, 300.0)?;
+/// let root = RootView::new(VStack::new().add(Spacer::new()))?;
+/// window.set_content_view(Box::new(root))?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct RootView {
+    ns_view: *mut Object,
+    layout: Rc<RootLayout>,
+}
+
+impl RootView {
+    /// Create a root view hosting `content`
+    pub fn new(content: impl LayoutItem + 'static) -> Result<Self> {
+        let size = content.preferred_size();
+        let layout = Rc::new(RootLayout {
+            content: Box::new(content),
+            size: Cell::new(size),
+        });
+
+        #[cfg(not(feature = "test-mock"))]
+        let ns_view = unsafe { create_root_view(&layout, size)? };
+        #[cfg(feature = "test-mock")]
+        let ns_view: *mut Object = std::ptr::null_mut();
+
+        Ok(RootView { ns_view, layout })
+    }
+
+    /// Get the current size
+    pub fn size(&self) -> Size {
+        self.layout.size.get()
+    }
+
+    /// Resize the view, laying out its content to fill the new size
+    pub fn resize(&self, width: f64, height: f64) -> Result<()> {
+        if width < 0.0 || height < 0.0 {
+            return Err(CocoanutError::InvalidParameter(
+                format!("Root view size {}x{} cannot be negative", width, height)
+            ));
+        }
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            // The view lays itself out from `setFrameSize:`
+            unsafe {
+                let size = cocoa::foundation::NSSize::new(width, height);
+                let _: () = msg_send![self.ns_view, setFrameSize: size];
+            }
+            return Ok(());
+        }
+        self.layout.resized(Size::new(width, height))
+    }
+
+    /// Lay the content out again at the current size
+    pub fn relayout(&self) -> Result<()> {
+        self.layout.resized(self.size())
+    }
+}
+
+impl Drawable for RootView {
+    fn as_view(&self) -> *mut Object {
+        self.ns_view
+    }
+
+    fn set_visible(&self, visible: bool) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_view, setHidden: !visible];
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = visible;
+        Ok(())
+    }
+
+    fn is_visible(&self) -> bool {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let hidden: bool = msg_send![self.ns_view, isHidden];
+                return !hidden;
+            }
+        }
+        true
+    }
+}
+
+impl Positionable for RootView {
+    fn set_frame(&self, x: f64, y: f64, width: f64, height: f64) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let _: () = msg_send![self.ns_view, setFrameOrigin: cocoa::foundation::NSPoint::new(x, y)];
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = (x, y);
+        self.resize(width, height)
+    }
+
+    fn frame(&self) -> (f64, f64, f64, f64) {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let frame: cocoa::foundation::NSRect = msg_send![self.ns_view, frame];
+                return (frame.origin.x, frame.origin.y, frame.size.width, frame.size.height);
+            }
+        }
+        let size = self.size();
+        (0.0, 0.0, size.width, size.height)
+    }
+}
+
+impl Drop for RootView {
+    fn drop(&mut self) {
+        // The window may keep the view after this struct is gone; detach the
+        // layout so later resizes do nothing
+        #[cfg(not(feature = "test-mock"))]
+        if !self.ns_view.is_null() {
+            unsafe {
+                let view = &mut *self.ns_view;
+                let slot = *view.get_ivar::<*mut c_void>("rustLayout");
+                view.set_ivar::<*mut c_void>("rustLayout", std::ptr::null_mut());
+                if !slot.is_null() {
+                    drop(Box::from_raw(slot as *mut Rc<RootLayout>));
+                }
+                let _: () = msg_send![self.ns_view, release];
+            }
+        }
+    }
+}
+
+/// Create a `CocoanutRootView` of `size` laying out `layout`, resizing with
+/// its superview
+#[cfg(not(feature = "test-mock"))]
+unsafe fn create_root_view(layout: &Rc<RootLayout>, size: Size) -> Result<*mut Object> {
+    use cocoa::foundation::{NSPoint, NSRect, NSSize};
+
+    // NSViewWidthSizable | NSViewHeightSizable
+    const FILL_SUPERVIEW: u64 = 2 | 16;
+
+    unsafe {
+        let frame = NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(size.width, size.height));
+        let ns_view: *mut Object = msg_send![root_view_class(), alloc];
+        let ns_view: *mut Object = msg_send![ns_view, initWithFrame: frame];
+        if ns_view.is_null() {
+            return Err(CocoanutError::ControlCreationFailed("Failed to create root view".to_string()));
+        }
+        let slot = Box::into_raw(Box::new(Rc::clone(layout))) as *mut c_void;
+        (*ns_view).set_ivar::<*mut c_void>("rustLayout", slot);
+        for subview in layout.content.views() {
+            let _: () = msg_send![ns_view, addSubview: subview];
+        }
+        let _: () = msg_send![ns_view, setAutoresizingMask: FILL_SUPERVIEW];
+        let _: () = msg_send![ns_view, setAutoresizesSubviews: false];
+        Ok(ns_view)
+    }
+}
+
+/// Lazily register the `NSView` subclass that lays out its content on resize
+#[cfg(not(feature = "test-mock"))]
+fn root_view_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutRootView", class!(NSView))
+            .expect("CocoanutRootView already registered");
+        decl.add_ivar::<*mut c_void>("rustLayout");
+
+        extern "C" fn set_frame_size(this: &Object, _cmd: Sel, size: cocoa::foundation::NSSize) {
+            unsafe {
+                let _: () = msg_send![super(this, class!(NSView)), setFrameSize: size];
+                let slot = *this.get_ivar::<*mut c_void>("rustLayout") as *const Rc<RootLayout>;
+                if let Some(layout) = slot.as_ref() {
+                    let _ = layout.resized(Size::new(size.width, size.height));
+                }
+            }
+        }
+
+        unsafe {
+            decl.add_method(
+                sel!(setFrameSize:),
+                set_frame_size as extern "C" fn(&Object, Sel, cocoa::foundation::NSSize),
+            );
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutRootView").expect("CocoanutRootView not registered")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Fixed-size item that records the frame it was placed at
+    struct TestItem {
+        size: Size,
+        baselines: Option<(f64, f64)>,
+        view: *mut Object,
+        placed: Rc<Cell<Option<Rect>>>,
+    }
+
+    impl TestItem {
+        fn new(width: f64, height: f64) -> (Self, Rc<Cell<Option<Rect>>>) {
+            let placed = Rc::new(Cell::new(None));
+            let item = TestItem {
+                size: Size::new(width, height),
+                baselines: None,
+                view: std::ptr::null_mut(),
+                placed: placed.clone(),
+            };
+            (item, placed)
+        }
+
+        /// Give the item text with first and last baselines measured from its top
+        fn with_baselines(mut self, first: f64, last: f64) -> Self {
+            self.baselines = Some((first, last));
+            self
+        }
+
+        /// Give the item a native view, which is never messaged
+        fn with_view(mut self, view: usize) -> Self {
+            self.view = std::ptr::without_provenance_mut(view);
+            self
+        }
+    }
+
+    impl LayoutItem for TestItem {
+        fn preferred_size(&self) -> Size {
+            self.size
+        }
+
+        fn place(&self, frame: Rect) -> Result<()> {
+            self.placed.set(Some(frame));
+            Ok(())
+        }
+
+        fn first_baseline(&self) -> Option<f64> {
+            self.baselines.map(|(first, _)| first)
+        }
+
+        fn last_baseline(&self) -> Option<f64> {
+            self.baselines.map(|(_, last)| last)
+        }
+
+        fn views(&self) -> Vec<*mut Object> {
+            if self.view.is_null() { Vec::new() } else { vec![self.view] }
+        }
+    }
+
     #[test]
     fn test_vstack_builder() {
         let stack = VStack::new()
@@ -278,6 +1612,27 @@ mod tests {
         assert_eq!(Spacing::relaxed().value, 16.0);
     }
 
+    #[test]
+    fn test_spacing_tokens_are_distinct() {
+        let tokens = [Spacing::xs(), Spacing::sm(), Spacing::md(), Spacing::lg(), Spacing::xl(), Spacing::xxl()];
+        let values: Vec<f64> = tokens.iter().map(|spacing| spacing.value).collect();
+        assert_eq!(values, [2.0, 4.0, 8.0, 16.0, 24.0, 32.0]);
+
+        // The presets sit on the token scale
+        assert_eq!(Spacing::compact(), Spacing::sm());
+        assert_eq!(Spacing::standard(), Spacing::md());
+        assert_eq!(Spacing::relaxed(), Spacing::lg());
+        assert_eq!(Spacing::from(SpacingScale::Relaxed), Spacing::new(12.0));
+    }
+
+    #[test]
+    fn test_spacing_arithmetic() {
+        assert_eq!(Spacing::standard() * 2.0, Spacing::new(16.0));
+        assert_eq!(Spacing::standard().scaled(0.5), Spacing::sm());
+        assert_eq!(Spacing::md() + Spacing::sm(), Spacing::new(12.0));
+        assert_eq!((Spacing::xs() + Spacing::xs()) * 3.0, Spacing::new(12.0));
+    }
+
     #[test]
     fn test_spacer() {
         let spacer1 = Spacer::new();
@@ -286,4 +1641,389 @@ mod tests {
         let spacer2 = Spacer::with_min_length(20.0);
         assert_eq!(spacer2.min_length(), Some(20.0));
     }
+
+    #[test]
+    fn test_zstack_top_trailing_alignment() {
+        let (image, _) = TestItem::new(200.0, 200.0);
+        let (badge, _) = TestItem::new(20.0, 20.0);
+        let stack = ZStack::new()
+            .alignment(Alignment2D::TopTrailing)
+            .add(image)
+            .add(badge);
+
+        let frames = stack.compute_frames(Rect::from_xywh(10.0, 10.0, 200.0, 200.0));
+        assert_eq!(frames[0].origin, Point::new(10.0, 10.0));
+        assert_eq!(frames[1].origin, Point::new(190.0, 190.0));
+        assert_eq!(frames[1].size, Size::new(20.0, 20.0));
+    }
+
+    #[test]
+    fn test_zstack_center_and_bottom_leading_alignment() {
+        let bounds = Rect::from_xywh(0.0, 0.0, 100.0, 60.0);
+
+        let (item, _) = TestItem::new(40.0, 20.0);
+        let centered = ZStack::new().add(item);
+        assert_eq!(centered.get_alignment(), Alignment2D::Center);
+        assert_eq!(centered.compute_frames(bounds)[0].origin, Point::new(30.0, 20.0));
+
+        let (item, _) = TestItem::new(40.0, 20.0);
+        let bottom_leading = ZStack::new().alignment(Alignment2D::BottomLeading).add(item);
+        assert_eq!(bottom_leading.compute_frames(bounds)[0].origin, Point::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_zstack_layout_in_places_children() {
+        let (item, placed) = TestItem::new(300.0, 30.0);
+        let stack = ZStack::new().alignment(Alignment2D::Top).add(item);
+        stack.layout_in(Rect::from_xywh(0.0, 0.0, 100.0, 100.0)).unwrap();
+
+        // Oversized children are clamped to the stack's bounds
+        assert_eq!(placed.get(), Some(Rect::from_xywh(0.0, 70.0, 100.0, 30.0)));
+        assert_eq!(stack.preferred_size(), Size::new(300.0, 30.0));
+    }
+
+    #[test]
+    fn test_grid_settings_form() {
+        let (name_label, _) = TestItem::new(80.0, 20.0);
+        let (name_field, _) = TestItem::new(150.0, 24.0);
+        let (email_label, _) = TestItem::new(60.0, 20.0);
+        let (email_field, _) = TestItem::new(150.0, 24.0);
+
+        let mut grid = Grid::new(2).column_spacing(10.0).row_spacing(8.0);
+        grid.add(name_label, 0, 0, 1, 1).unwrap()
+            .add(name_field, 0, 1, 1, 1).unwrap()
+            .add(email_label, 1, 0, 1, 1).unwrap()
+            .add(email_field, 1, 1, 1, 1).unwrap();
+        assert_eq!(grid.get_rows(), 2);
+        assert_eq!(grid.preferred_size(), Size::new(240.0, 56.0));
+
+        // 260 wide leaves 20 extra points, 10 per column; 64 high leaves 8, 4 per row
+        let frames = grid.compute_frames(Rect::from_xywh(0.0, 0.0, 260.0, 64.0));
+        assert_eq!(frames[0], Rect::from_xywh(0.0, 36.0, 90.0, 28.0));
+        assert_eq!(frames[1], Rect::from_xywh(100.0, 36.0, 160.0, 28.0));
+        assert_eq!(frames[2], Rect::from_xywh(0.0, 0.0, 90.0, 28.0));
+        assert_eq!(frames[3], Rect::from_xywh(100.0, 0.0, 160.0, 28.0));
+    }
+
+    #[test]
+    fn test_grid_column_span() {
+        let (header, placed) = TestItem::new(100.0, 20.0);
+        let (left, _) = TestItem::new(50.0, 20.0);
+        let (right, _) = TestItem::new(50.0, 20.0);
+
+        let mut grid = Grid::new(2).column_spacing(0.0).row_spacing(0.0);
+        grid.add(header, 0, 0, 1, 2).unwrap()
+            .add(left, 1, 0, 1, 1).unwrap()
+            .add(right, 1, 1, 1, 1).unwrap();
+
+        grid.layout_in(Rect::from_xywh(0.0, 0.0, 100.0, 40.0)).unwrap();
+        assert_eq!(placed.get(), Some(Rect::from_xywh(0.0, 20.0, 100.0, 20.0)));
+    }
+
+    #[test]
+    fn test_grid_rejects_overlapping_cells() {
+        let (a, _) = TestItem::new(10.0, 10.0);
+        let (b, _) = TestItem::new(10.0, 10.0);
+        let mut grid = Grid::new(3);
+        grid.add(a, 0, 0, 2, 2).unwrap();
+        let result = grid.add(b, 1, 1, 1, 1);
+        assert!(matches!(result, Err(CocoanutError::InvalidParameter(_))));
+
+        // The grid survives the rejected item and still takes free cells
+        let (c, _) = TestItem::new(10.0, 10.0);
+        grid.add(c, 2, 0, 1, 1).unwrap();
+        assert_eq!(grid.get_rows(), 3);
+    }
+
+    #[test]
+    fn test_grid_rejects_out_of_range_span() {
+        let (a, _) = TestItem::new(10.0, 10.0);
+        assert!(Grid::new(2).add(a, 0, 1, 1, 2).is_err());
+        let (b, _) = TestItem::new(10.0, 10.0);
+        assert!(Grid::new(2).add(b, 0, 0, 0, 1).is_err());
+    }
+
+    #[test]
+    fn test_padding_increases_measured_size() {
+        let (item, _) = TestItem::new(100.0, 40.0);
+        let padded = item.padding(Spacing::standard());
+        assert_eq!(padded.preferred_size(), Size::new(116.0, 56.0));
+        assert_eq!(padded.get_insets(), EdgeInsets::uniform(8.0));
+    }
+
+    #[test]
+    fn test_padding_each_insets_placed_frame() {
+        let (item, placed) = TestItem::new(100.0, 40.0);
+        let padded = item.padding_each(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(padded.preferred_size(), Size::new(106.0, 44.0));
+
+        padded.place(Rect::from_xywh(10.0, 10.0, 106.0, 44.0)).unwrap();
+        assert_eq!(placed.get(), Some(Rect::from_xywh(12.0, 13.0, 100.0, 40.0)));
+    }
+
+    #[test]
+    fn test_frame_fixes_size_and_centers() {
+        let (item, placed) = TestItem::new(100.0, 40.0);
+        let framed = item.frame(50.0, 20.0);
+        assert_eq!(framed.preferred_size(), Size::new(50.0, 20.0));
+
+        framed.place(Rect::from_xywh(0.0, 0.0, 100.0, 40.0)).unwrap();
+        assert_eq!(placed.get(), Some(Rect::from_xywh(25.0, 10.0, 50.0, 20.0)));
+    }
+
+    #[test]
+    fn test_modifiers_compose() {
+        let (item, _) = TestItem::new(100.0, 40.0);
+        let modified = item.frame(50.0, 20.0).padding(Spacing::compact());
+        assert_eq!(modified.preferred_size(), Size::new(58.0, 28.0));
+    }
+
+    #[test]
+    fn test_vstack_packs_children_from_top() {
+        let (a, _) = TestItem::new(100.0, 20.0);
+        let (b, _) = TestItem::new(60.0, 30.0);
+        let stack = VStack::new()
+            .spacing(Spacing::new(10.0))
+            .alignment(Alignment::Leading)
+            .add(a)
+            .add(b);
+
+        let frames = stack.compute_frames(Rect::from_xywh(0.0, 0.0, 200.0, 100.0));
+        assert_eq!(frames[0], Rect::from_xywh(0.0, 80.0, 100.0, 20.0));
+        assert_eq!(frames[1], Rect::from_xywh(0.0, 40.0, 60.0, 30.0));
+    }
+
+    #[test]
+    fn test_stack_accounts_for_child_padding() {
+        let (a, _) = TestItem::new(100.0, 20.0);
+        let (b, _) = TestItem::new(100.0, 20.0);
+        let stack = HStack::new()
+            .spacing(Spacing::new(0.0))
+            .add(a.padding(Spacing::standard()))
+            .add(b);
+
+        let frames = stack.compute_frames(Rect::from_xywh(0.0, 0.0, 300.0, 36.0));
+        assert_eq!(frames[0], Rect::from_xywh(0.0, 0.0, 116.0, 36.0));
+        assert_eq!(frames[1], Rect::from_xywh(116.0, 8.0, 100.0, 20.0));
+    }
+
+    #[test]
+    fn test_hstack_first_baseline_alignment() {
+        // A 17pt label next to a 32pt button: their baselines must meet
+        let (label, placed_label) = TestItem::new(100.0, 17.0);
+        let (button, placed_button) = TestItem::new(80.0, 32.0);
+        let stack = HStack::new()
+            .spacing(Spacing::new(0.0))
+            .alignment(Alignment::FirstBaseline)
+            .add(label.with_baselines(13.0, 13.0))
+            .add(button.with_baselines(21.0, 21.0));
+
+        stack.layout_in(Rect::from_xywh(0.0, 0.0, 200.0, 40.0)).unwrap();
+        let label = placed_label.get().unwrap();
+        let button = placed_button.get().unwrap();
+        assert_eq!(label, Rect::from_xywh(0.0, 15.0, 100.0, 17.0));
+        assert_eq!(button, Rect::from_xywh(100.0, 8.0, 80.0, 32.0));
+        assert_eq!(label.max_y() - 13.0, button.max_y() - 21.0);
+    }
+
+    #[test]
+    fn test_hstack_last_baseline_alignment() {
+        // Two lines of text next to a single line; items without text sit
+        // on the shared baseline
+        let (paragraph, _) = TestItem::new(100.0, 34.0);
+        let (label, _) = TestItem::new(60.0, 17.0);
+        let (icon, _) = TestItem::new(16.0, 16.0);
+        let stack = HStack::new()
+            .spacing(Spacing::new(0.0))
+            .alignment(Alignment::LastBaseline)
+            .add(paragraph.with_baselines(13.0, 30.0))
+            .add(label.with_baselines(13.0, 13.0))
+            .add(icon);
+
+        let frames = stack.compute_frames(Rect::from_xywh(0.0, 0.0, 200.0, 40.0));
+        let baselines: Vec<f64> = frames.iter().zip([30.0, 13.0, 16.0]).map(|(frame, b)| frame.max_y() - b).collect();
+        assert_eq!(baselines, [10.0, 10.0, 10.0]);
+
+        // Tall enough for the paragraph above and the label's descent below
+        assert_eq!(stack.preferred_size(), Size::new(176.0, 34.0));
+    }
+
+    #[test]
+    fn test_baseline_alignment_descent_grows_stack() {
+        let (small, _) = TestItem::new(10.0, 20.0);
+        let (large, _) = TestItem::new(10.0, 30.0);
+        let stack = HStack::new()
+            .spacing(Spacing::new(0.0))
+            .alignment(Alignment::FirstBaseline)
+            .add(small.with_baselines(5.0, 5.0))
+            .add(large.with_baselines(25.0, 25.0));
+
+        // 25pt above the baseline for `large`, 15pt below it for `small`
+        assert_eq!(stack.preferred_size(), Size::new(20.0, 40.0));
+    }
+
+    #[test]
+    fn test_padding_offsets_baseline() {
+        let (item, _) = TestItem::new(10.0, 20.0);
+        let padded = item.with_baselines(14.0, 16.0).padding_each(4.0, 0.0, 0.0, 0.0);
+        assert_eq!(padded.first_baseline(), Some(18.0));
+        assert_eq!(padded.last_baseline(), Some(20.0));
+    }
+
+    #[test]
+    fn test_spacers_split_remaining_space_equally() {
+        let (a, _) = TestItem::new(50.0, 20.0);
+        let (b, _) = TestItem::new(50.0, 20.0);
+        let stack = HStack::new()
+            .spacing(Spacing::new(0.0))
+            .add(Spacer::new())
+            .add(a)
+            .add(Spacer::with_min_length(10.0))
+            .add(b);
+
+        // 300 - 100 fixed leaves 200, 100 per spacer
+        let frames = stack.compute_frames(Rect::from_xywh(0.0, 0.0, 300.0, 20.0));
+        assert_eq!(frames[0].size.width, 100.0);
+        assert_eq!(frames[1].origin.x, 100.0);
+        assert_eq!(frames[2].size.width, 100.0);
+        assert_eq!(frames[3].origin.x, 250.0);
+    }
+
+    #[test]
+    fn test_spacers_respect_min_length() {
+        let (a, _) = TestItem::new(100.0, 20.0);
+        let stack = VStack::new()
+            .spacing(Spacing::new(0.0))
+            .add(Spacer::with_min_length(50.0))
+            .add(a)
+            .add(Spacer::new());
+
+        // 60 remaining would be 30 each, but the first spacer needs 50
+        let frames = stack.compute_frames(Rect::from_xywh(0.0, 0.0, 100.0, 80.0));
+        assert_eq!(frames[0].size.height, 50.0);
+        assert_eq!(frames[1].origin.y, 10.0);
+        assert_eq!(frames[2].size.height, 10.0);
+
+        // With no room left, spacers still keep their minimums
+        let (b, _) = TestItem::new(100.0, 100.0);
+        let stack = VStack::new()
+            .spacing(Spacing::new(0.0))
+            .add(Spacer::with_min_length(20.0))
+            .add(b)
+            .add(Spacer::with_min_length(20.0));
+        let frames = stack.compute_frames(Rect::from_xywh(0.0, 0.0, 100.0, 80.0));
+        assert_eq!(frames[0].size.height, 20.0);
+        assert_eq!(frames[2].size.height, 20.0);
+    }
+
+    #[test]
+    fn test_vstack_layout_in_places_children() {
+        let (a, placed_a) = TestItem::new(100.0, 20.0);
+        let (b, placed_b) = TestItem::new(100.0, 20.0);
+        let stack = VStack::new().spacing(Spacing::new(10.0)).add(a).add(b);
+        assert_eq!(stack.preferred_size(), Size::new(100.0, 50.0));
+
+        stack.layout_in(Rect::from_xywh(0.0, 0.0, 300.0, 100.0)).unwrap();
+        assert_eq!(placed_a.get(), Some(Rect::from_xywh(100.0, 80.0, 100.0, 20.0)));
+        assert_eq!(placed_b.get(), Some(Rect::from_xywh(100.0, 50.0, 100.0, 20.0)));
+    }
+
+    #[test]
+    fn test_nested_stacks() {
+        let (a, placed_a) = TestItem::new(40.0, 20.0);
+        let (b, placed_b) = TestItem::new(40.0, 20.0);
+        let row = HStack::new().spacing(Spacing::new(0.0)).add(a).add(b);
+        let stack = VStack::new().alignment(Alignment::Leading).add(row);
+
+        stack.layout_in(Rect::from_xywh(0.0, 0.0, 200.0, 100.0)).unwrap();
+        assert_eq!(placed_a.get(), Some(Rect::from_xywh(0.0, 80.0, 40.0, 20.0)));
+        assert_eq!(placed_b.get(), Some(Rect::from_xywh(40.0, 80.0, 40.0, 20.0)));
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_vstack_three_buttons() {
+        use crate::components::basic::controls_v2::Button;
+        use crate::core::traits::View;
+
+        let buttons: Vec<Box<dyn View>> = vec![
+            Box::new(Button::new("One").unwrap()),
+            Box::new(Button::new("Two").unwrap()),
+            Box::new(Button::new("Three").unwrap()),
+        ];
+        let stack = buttons
+            .into_iter()
+            .fold(VStack::new().spacing(Spacing::standard()), |stack, button| stack.add(button));
+
+        // Mock buttons are 100x40; centered in a 300 wide column from the top
+        let frames = stack.compute_frames(Rect::from_xywh(0.0, 0.0, 300.0, 200.0));
+        let origins: Vec<Point> = frames.iter().map(|frame| frame.origin).collect();
+        assert_eq!(origins, vec![
+            Point::new(100.0, 160.0),
+            Point::new(100.0, 112.0),
+            Point::new(100.0, 64.0),
+        ]);
+        assert!(stack.layout_in(Rect::from_xywh(0.0, 0.0, 300.0, 200.0)).is_ok());
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_root_view_relayouts_on_resize() {
+        let (a, placed_a) = TestItem::new(50.0, 20.0);
+        let (b, placed_b) = TestItem::new(50.0, 20.0);
+        let root = RootView::new(VStack::new().spacing(Spacing::new(0.0)).add(a).add(b)).unwrap();
+        assert_eq!(root.size(), Size::new(50.0, 40.0));
+        assert_eq!(placed_a.get(), None);
+
+        root.resize(200.0, 100.0).unwrap();
+        assert_eq!(Positionable::frame(&root), (0.0, 0.0, 200.0, 100.0));
+        assert_eq!(placed_a.get(), Some(Rect::from_xywh(75.0, 80.0, 50.0, 20.0)));
+        assert_eq!(placed_b.get(), Some(Rect::from_xywh(75.0, 60.0, 50.0, 20.0)));
+
+        // Growing the window moves the stack to the new top edge
+        root.set_frame(0.0, 0.0, 400.0, 300.0).unwrap();
+        assert_eq!(placed_a.get(), Some(Rect::from_xywh(175.0, 280.0, 50.0, 20.0)));
+        assert_eq!(placed_b.get(), Some(Rect::from_xywh(175.0, 260.0, 50.0, 20.0)));
+
+        assert!(root.resize(-1.0, 10.0).is_err());
+        assert_eq!(root.size(), Size::new(400.0, 300.0));
+    }
+
+    #[test]
+    fn test_views_lists_every_leaf_in_order() {
+        let a = TestItem::new(10.0, 10.0).0.with_view(8);
+        let b = TestItem::new(10.0, 10.0).0.with_view(16);
+        let c = TestItem::new(10.0, 10.0).0.with_view(24);
+        let d = TestItem::new(10.0, 10.0).0.with_view(32);
+        let mut grid = Grid::new(1);
+        grid.add(d, 0, 0, 1, 1).unwrap();
+
+        let stack = VStack::new()
+            .add(a.padding(Spacing::sm()))
+            .add(Spacer::new())
+            .add(HStack::new().add(b).add(ZStack::new().add(c.frame(5.0, 5.0))))
+            .add(grid);
+        let views: Vec<usize> = stack.views().into_iter().map(|view| view as usize).collect();
+        assert_eq!(views, [8, 16, 24, 32]);
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_root_view_adds_controls_as_subviews() {
+        use crate::components::basic::controls_v2::Label;
+
+        let title = Label::new("Title").unwrap();
+        let body = Label::new("Body").unwrap();
+        let (title_view, body_view) = (title.as_view(), body.as_view());
+        let root = RootView::new(VStack::new().add(title).add(HStack::new().add(Spacer::new()).add(body))).unwrap();
+
+        unsafe {
+            let subviews: *mut Object = msg_send![root.as_view(), subviews];
+            let count: usize = msg_send![subviews, count];
+            let first: *mut Object = msg_send![subviews, objectAtIndex: 0usize];
+            let second: *mut Object = msg_send![subviews, objectAtIndex: 1usize];
+            assert_eq!(count, 2);
+            assert_eq!((first, second), (title_view, body_view));
+        }
+    }
 }
@@ -7,6 +7,7 @@ pub mod layout;
 pub mod builder;
 pub mod essential_features;
 pub mod target_action;
+pub mod shortcuts;
 
 // Phase 1: Streamlit Migration - Display Elements
 pub mod display;
@@ -38,6 +39,7 @@ pub use layout::*;
 pub use builder::*;
 pub use essential_features::*;
 pub use target_action::*;
+pub use shortcuts::*;
 pub use display::*;
 pub use data_display::*;
 pub use feedback::*;
@@ -50,3 +52,7 @@ pub use state_management::*;
 pub use callbacks::*;
 pub use multi_page::*;
 pub use custom_components::*;
+
+// `layout::Grid` and `advanced_layouts::Grid` share a name; keep the
+// original meaning of `systems::Grid`. The layout engine is `layout::Grid`.
+pub use advanced_layouts::Grid;
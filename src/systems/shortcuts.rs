@@ -0,0 +1,404 @@
+//! Application-wide keyboard shortcuts
+//!
+//! A [`ShortcutRegistry`] watches every key press sent to the application's
+//! windows and runs the closure registered for the matching [`KeyCombo`],
+//! whichever view has focus. Matched presses are swallowed.
+//!
+//! Combos are written as `+`-separated modifiers followed by one key, such
+//! as `"cmd+shift+p"`, `"ctrl+option+space"` or `"f5"`. Modifiers are
+//! `cmd` (or `command`), `shift`, `ctrl` (or `control`) and `option` (or
+//! `opt`, `alt`). The key is a single character or one of `return`,
+//! `tab`, `space`, `delete`, `escape`, `forwarddelete`, `home`, `end`,
+//! `pageup`, `pagedown`, the arrows `left`, `right`, `up` and `down`, and
+//! `f1` to `f12`.
+
+use crate::core::error::{CocoanutError, Result};
+use crate::systems::events::{KeyEvent, Modifiers};
+use objc::runtime::Object;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+
+/// Callback run when a shortcut is pressed
+pub type ShortcutCallback = Arc<dyn Fn() + Send + Sync>;
+
+/// Keys without a character, with their virtual key codes
+///
+/// Aliases follow the name used when displaying a combo.
+const NAMED_KEYS: &[(&str, u16)] = &[
+    ("return", 36),
+    ("enter", 36),
+    ("tab", 48),
+    ("space", 49),
+    ("delete", 51),
+    ("backspace", 51),
+    ("escape", 53),
+    ("esc", 53),
+    ("forwarddelete", 117),
+    ("home", 115),
+    ("end", 119),
+    ("pageup", 116),
+    ("pagedown", 121),
+    ("left", 123),
+    ("right", 124),
+    ("down", 125),
+    ("up", 126),
+    ("f1", 122),
+    ("f2", 120),
+    ("f3", 99),
+    ("f4", 118),
+    ("f5", 96),
+    ("f6", 97),
+    ("f7", 98),
+    ("f8", 100),
+    ("f9", 101),
+    ("f10", 109),
+    ("f11", 103),
+    ("f12", 111),
+];
+
+/// The non-modifier key of a combo
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Key {
+    /// A key typing this (lowercase) character
+    Character(char),
+    /// A key without a character, by name and key code
+    Named(&'static str, u16),
+}
+
+/// A key with modifiers, such as ⌘⇧P
+///
+/// Two combos are equal when they have the same key and modifiers, however
+/// they were written: `"shift+cmd+P"` equals `"cmd+shift+p"`.
+///
+/// Character keys are matched against the character the key types with
+/// Shift applied, so Command-Shift-1 is `"cmd+shift+!"` on a US keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyCombo {
+    modifiers: Modifiers,
+    key: Key,
+}
+
+impl KeyCombo {
+    /// Parse a combo such as `"cmd+shift+p"`
+    ///
+    /// Returns `CocoanutError::InvalidParameter` for empty parts, unknown
+    /// keys, repeated modifiers, a missing key or more than one key, and for
+    /// character keys without Command, Control or Option, which would take
+    /// over ordinary typing.
+    pub fn parse(combo: &str) -> Result<Self> {
+        let invalid = |reason: &str| CocoanutError::InvalidParameter(format!("Invalid shortcut {:?}: {}", combo, reason));
+
+        let mut modifiers = Modifiers::NONE;
+        let mut key = None;
+        for part in combo.split('+') {
+            let part = part.trim().to_lowercase();
+            if part.is_empty() {
+                return Err(invalid("empty key or modifier"));
+            }
+            let modifier = match part.as_str() {
+                "cmd" | "command" => Some(&mut modifiers.command),
+                "shift" => Some(&mut modifiers.shift),
+                "ctrl" | "control" => Some(&mut modifiers.control),
+                "option" | "opt" | "alt" => Some(&mut modifiers.option),
+                _ => None,
+            };
+            if let Some(held) = modifier {
+                if key.is_some() {
+                    return Err(invalid("modifiers must come before the key"));
+                }
+                if *held {
+                    return Err(invalid(&format!("{} appears twice", part)));
+                }
+                *held = true;
+                continue;
+            }
+            if key.is_some() {
+                return Err(invalid("more than one key"));
+            }
+            let mut chars = part.chars();
+            key = Some(match (chars.next(), chars.next()) {
+                (Some(character), None) => Key::Character(character),
+                _ => named_key(&part).ok_or_else(|| invalid(&format!("unknown key {:?}", part)))?,
+            });
+        }
+
+        let key = key.ok_or_else(|| invalid("no key"))?;
+        if matches!(key, Key::Character(_)) && !(modifiers.command || modifiers.control || modifiers.option) {
+            return Err(invalid("character keys need cmd, ctrl or option"));
+        }
+        Ok(KeyCombo { modifiers, key })
+    }
+
+    /// The modifier keys of the combo
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// The key of the combo: a lowercase character or a key name
+    pub fn key(&self) -> String {
+        match self.key {
+            Key::Character(character) => character.to_string(),
+            Key::Named(name, _) => name.to_string(),
+        }
+    }
+
+    /// Check if a key press is this combo
+    ///
+    /// The modifiers must match exactly.
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        if event.modifiers() != self.modifiers {
+            return false;
+        }
+        match self.key {
+            Key::Character(character) => {
+                let mut typed = event.characters_ignoring_modifiers().chars().flat_map(char::to_lowercase);
+                typed.next() == Some(character) && typed.next().is_none()
+            }
+            Key::Named(_, key_code) => event.key_code() == key_code,
+        }
+    }
+}
+
+impl FromStr for KeyCombo {
+    type Err = CocoanutError;
+
+    fn from_str(combo: &str) -> Result<Self> {
+        KeyCombo::parse(combo)
+    }
+}
+
+/// Writes the combo in the menu order of its symbols, ⌃⌥⇧⌘
+impl fmt::Display for KeyCombo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let held = [
+            (self.modifiers.control, "ctrl+"),
+            (self.modifiers.option, "option+"),
+            (self.modifiers.shift, "shift+"),
+            (self.modifiers.command, "cmd+"),
+        ];
+        for (_, name) in held.iter().filter(|(held, _)| *held) {
+            f.write_str(name)?;
+        }
+        f.write_str(&self.key())
+    }
+}
+
+/// Look up a key name, resolving aliases to the first name with its code
+fn named_key(name: &str) -> Option<Key> {
+    let (_, key_code) = NAMED_KEYS.iter().find(|(alias, _)| *alias == name)?;
+    NAMED_KEYS
+        .iter()
+        .find(|(_, code)| code == key_code)
+        .map(|(name, code)| Key::Named(name, *code))
+}
+
+/// Registered shortcuts, shared with the event monitor
+type Shortcuts = Arc<Mutex<Vec<(KeyCombo, ShortcutCallback)>>>;
+
+/// Keyboard shortcuts that work anywhere in the application
+///
+/// Shortcuts only fire while the registry is alive. Create it on the main
+/// thread, after the application has started.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::systems::shortcuts::ShortcutRegistry;
+///
+/// # fn main() -> cocoanut::Result<()> {
+/// let shortcuts = ShortcutRegistry::new()?;
+/// shortcuts.register("cmd+shift+p", || println!("open command palette"))?;
+/// shortcuts.unregister("cmd+shift+p")?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ShortcutRegistry {
+    shortcuts: Shortcuts,
+    monitor: *mut Object,
+}
+
+impl ShortcutRegistry {
+    /// Start watching the application's key presses
+    pub fn new() -> Result<Self> {
+        let shortcuts: Shortcuts = Arc::new(Mutex::new(Vec::new()));
+
+        #[cfg(not(feature = "test-mock"))]
+        let monitor = unsafe { add_key_monitor(Arc::clone(&shortcuts))? };
+        #[cfg(feature = "test-mock")]
+        let monitor: *mut Object = std::ptr::null_mut();
+
+        Ok(ShortcutRegistry { shortcuts, monitor })
+    }
+
+    /// Run `callback` when `combo` is pressed, replacing any earlier
+    /// callback for the same combo
+    pub fn register<F>(&self, combo: &str, callback: F) -> Result<()>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let combo = KeyCombo::parse(combo)?;
+        let mut shortcuts = self.lock_shortcuts();
+        shortcuts.retain(|(registered, _)| *registered != combo);
+        shortcuts.push((combo, Arc::new(callback)));
+        Ok(())
+    }
+
+    /// Remove the shortcut for `combo`, returning whether there was one
+    pub fn unregister(&self, combo: &str) -> Result<bool> {
+        let combo = KeyCombo::parse(combo)?;
+        let mut shortcuts = self.lock_shortcuts();
+        let count = shortcuts.len();
+        shortcuts.retain(|(registered, _)| *registered != combo);
+        Ok(shortcuts.len() != count)
+    }
+
+    /// Check if a shortcut is registered for `combo`
+    ///
+    /// Unparsable combos are never registered.
+    pub fn is_registered(&self, combo: &str) -> bool {
+        KeyCombo::parse(combo)
+            .map(|combo| self.lock_shortcuts().iter().any(|(registered, _)| *registered == combo))
+            .unwrap_or(false)
+    }
+
+    /// Get the registered combos, oldest first
+    pub fn combos(&self) -> Vec<KeyCombo> {
+        self.lock_shortcuts().iter().map(|(combo, _)| *combo).collect()
+    }
+
+    /// Run the shortcut matching `event`, returning whether one ran
+    ///
+    /// The event monitor calls this for every key press.
+    pub fn handle_key_event(&self, event: &KeyEvent) -> bool {
+        run_shortcut(&self.shortcuts, event)
+    }
+
+    fn lock_shortcuts(&self) -> std::sync::MutexGuard<'_, Vec<(KeyCombo, ShortcutCallback)>> {
+        self.shortcuts.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Drop for ShortcutRegistry {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![class!(NSEvent), removeMonitor: self.monitor];
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = self.monitor;
+    }
+}
+
+unsafe impl Send for ShortcutRegistry {}
+unsafe impl Sync for ShortcutRegistry {}
+
+/// Run the callback registered for `event`, if any
+fn run_shortcut(shortcuts: &Mutex<Vec<(KeyCombo, ShortcutCallback)>>, event: &KeyEvent) -> bool {
+    // Clone the callback out so it can change the registry
+    let callback = shortcuts
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .iter()
+        .find(|(combo, _)| combo.matches(event))
+        .map(|(_, callback)| Arc::clone(callback));
+    callback.map(|callback| callback()).is_some()
+}
+
+/// Install a local key-down monitor that runs matching shortcuts
+#[cfg(not(feature = "test-mock"))]
+unsafe fn add_key_monitor(shortcuts: Shortcuts) -> Result<*mut Object> {
+    use block::ConcreteBlock;
+
+    /// `NSEventMaskKeyDown`
+    const KEY_DOWN_MASK: u64 = 1 << 10;
+
+    unsafe {
+        let handler = ConcreteBlock::new(move |ns_event: *mut Object| -> *mut Object {
+            if run_shortcut(&shortcuts, &KeyEvent::from_ns_event(ns_event)) {
+                std::ptr::null_mut()
+            } else {
+                ns_event
+            }
+        })
+        .copy();
+        let monitor: *mut Object = msg_send![class!(NSEvent), addLocalMonitorForEventsMatchingMask: KEY_DOWN_MASK
+                                                                                           handler: &*handler];
+        if monitor.is_null() {
+            return Err(CocoanutError::SystemError("Failed to add a key event monitor".to_string()));
+        }
+        Ok(monitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn combo(text: &str) -> KeyCombo {
+        KeyCombo::parse(text).unwrap()
+    }
+
+    #[test]
+    fn test_parse_combos() {
+        let palette = combo("cmd+shift+p");
+        assert_eq!(palette.key(), "p");
+        assert_eq!(palette.modifiers(), Modifiers { command: true, shift: true, ..Modifiers::NONE });
+        assert_eq!(palette, combo(" Shift + Command + P "));
+        assert_eq!(palette.to_string(), "shift+cmd+p");
+
+        assert_eq!(combo("ctrl+alt+enter").to_string(), "ctrl+option+return");
+        assert_eq!(combo("f5").modifiers(), Modifiers::NONE);
+        assert_eq!("opt+esc".parse::<KeyCombo>().unwrap().key(), "escape");
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_combos() {
+        for text in ["", "cmd+", "+p", "cmd++p", "cmd+shift", "cmd+cmd+p", "cmd+p+q", "p+cmd", "cmd+pp", "shift+p", "q"] {
+            match KeyCombo::parse(text) {
+                Err(CocoanutError::InvalidParameter(message)) => assert!(message.contains("Invalid shortcut")),
+                other => panic!("{:?} parsed as {:?}", text, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_combo_matches_key_events() {
+        let command_shift = Modifiers { command: true, shift: true, ..Modifiers::NONE };
+        let palette = combo("cmd+shift+p");
+        assert!(palette.matches(&KeyEvent::new(35, "P", command_shift)));
+        assert!(!palette.matches(&KeyEvent::new(35, "p", Modifiers { command: true, ..Modifiers::NONE })));
+
+        let option = Modifiers { option: true, ..Modifiers::NONE };
+        let pi = KeyEvent::new(35, "π", option).with_characters_ignoring_modifiers("p");
+        assert!(combo("option+p").matches(&pi));
+        assert!(combo("option+up").matches(&KeyEvent::new(126, "", option)));
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_registry_register_and_unregister() {
+        let registry = ShortcutRegistry::new().unwrap();
+        let count = Arc::new(Mutex::new(0));
+        {
+            let count = Arc::clone(&count);
+            registry.register("cmd+shift+p", move || *count.lock().unwrap() += 1).unwrap();
+        }
+        assert!(registry.register("cmd+", || {}).is_err());
+        assert!(registry.is_registered("shift+cmd+p"));
+        assert_eq!(registry.combos(), vec![combo("cmd+shift+p")]);
+
+        let press = KeyEvent::new(35, "P", Modifiers { command: true, shift: true, ..Modifiers::NONE });
+        assert!(registry.handle_key_event(&press));
+        assert!(!registry.handle_key_event(&KeyEvent::new(35, "p", Modifiers::NONE)));
+        assert_eq!(*count.lock().unwrap(), 1);
+
+        assert!(registry.unregister("cmd+shift+p").unwrap());
+        assert!(!registry.unregister("cmd+shift+p").unwrap());
+        assert!(!registry.handle_key_event(&press));
+        assert_eq!(*count.lock().unwrap(), 1);
+    }
+}
@@ -4,7 +4,7 @@
 
 use crate::core::error::Result;
 use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, Weak};
 
 /// Session state - global state management
 pub struct SessionState {
@@ -309,6 +309,135 @@ impl Clone for ResourceCache {
     }
 }
 
+/// Listener called with the new state after each [`Store::update`]
+pub type StoreListener<S> = Arc<dyn Fn(&S) + Send + Sync>;
+
+/// Listeners of a store, keyed by subscription id
+struct Listeners<S> {
+    next_id: u64,
+    entries: Vec<(u64, StoreListener<S>)>,
+}
+
+/// Application state with change subscriptions
+///
+/// All changes go through [`Store::update`], which notifies every
+/// subscriber once the change is applied. Subscribers stay registered for as
+/// long as their [`Subscription`] is alive.
+///
+/// # Example
+///
+/// ```rust
+/// use cocoanut::systems::state_management::Store;
+///
+/// let mut store = Store::new(0);
+/// let _subscription = store.subscribe(|count| println!("count is now {}", count));
+/// store.update(|count| *count += 1);
+/// assert_eq!(*store.get(), 1);
+/// ```
+pub struct Store<S> {
+    state: S,
+    listeners: Arc<Mutex<Listeners<S>>>,
+}
+
+impl<S: 'static> Store<S> {
+    /// Create a store holding `initial`
+    pub fn new(initial: S) -> Self {
+        Store {
+            state: initial,
+            listeners: Arc::new(Mutex::new(Listeners { next_id: 0, entries: Vec::new() })),
+        }
+    }
+
+    /// Get the current state
+    pub fn get(&self) -> &S {
+        &self.state
+    }
+
+    /// Change the state with `reducer`, then notify the subscribers
+    pub fn update<F>(&mut self, reducer: F)
+    where
+        F: FnOnce(&mut S),
+    {
+        reducer(&mut self.state);
+
+        // Copy the listeners so they may subscribe or unsubscribe while running
+        let listeners: Vec<StoreListener<S>> = self
+            .listeners
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .entries
+            .iter()
+            .map(|(_, listener)| Arc::clone(listener))
+            .collect();
+        for listener in listeners {
+            listener(&self.state);
+        }
+    }
+
+    /// Call `listener` with the new state after every update
+    ///
+    /// The listener is removed when the returned [`Subscription`] is dropped.
+    pub fn subscribe<F>(&self, listener: F) -> Subscription
+    where
+        F: Fn(&S) + Send + Sync + 'static,
+    {
+        let id = {
+            let mut listeners = self.listeners.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            let id = listeners.next_id;
+            listeners.next_id += 1;
+            listeners.entries.push((id, Arc::new(listener)));
+            id
+        };
+
+        let listeners: Weak<Mutex<Listeners<S>>> = Arc::downgrade(&self.listeners);
+        Subscription {
+            unsubscribe: Some(Box::new(move || {
+                if let Some(listeners) = listeners.upgrade() {
+                    listeners
+                        .lock()
+                        .unwrap_or_else(|poisoned| poisoned.into_inner())
+                        .entries
+                        .retain(|(entry_id, _)| *entry_id != id);
+                }
+            })),
+        }
+    }
+
+    /// Get the number of active subscriptions
+    pub fn subscriber_count(&self) -> usize {
+        self.listeners.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).entries.len()
+    }
+}
+
+impl<S: Default + 'static> Default for Store<S> {
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+/// Keeps a [`Store`] listener registered until dropped
+#[must_use = "the listener is removed as soon as the subscription is dropped"]
+pub struct Subscription {
+    unsubscribe: Option<Box<dyn FnOnce() + Send + Sync>>,
+}
+
+impl Subscription {
+    /// Stop receiving updates
+    pub fn unsubscribe(mut self) {
+        if let Some(unsubscribe) = self.unsubscribe.take() {
+            unsubscribe();
+        }
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some(unsubscribe) = self.unsubscribe.take() {
+            unsubscribe();
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -366,4 +495,42 @@ mod tests {
         cache.set("resource1", data.clone(), None).unwrap();
         assert_eq!(cache.get("resource1").unwrap(), Some(data));
     }
+
+    #[test]
+    fn test_store_notifies_live_subscribers() {
+        let mut store = Store::new(0);
+        let first = Arc::new(Mutex::new(Vec::new()));
+        let second = Arc::new(Mutex::new(Vec::new()));
+        let dropped = Arc::new(Mutex::new(Vec::new()));
+
+        let _first = {
+            let first = Arc::clone(&first);
+            store.subscribe(move |count: &i32| first.lock().unwrap().push(*count))
+        };
+        let _second = {
+            let second = Arc::clone(&second);
+            store.subscribe(move |count: &i32| second.lock().unwrap().push(*count))
+        };
+        let unsubscribed = {
+            let dropped = Arc::clone(&dropped);
+            store.subscribe(move |count: &i32| dropped.lock().unwrap().push(*count))
+        };
+        assert_eq!(store.subscriber_count(), 3);
+        drop(unsubscribed);
+        assert_eq!(store.subscriber_count(), 2);
+
+        store.update(|count| *count += 5);
+        assert_eq!(*store.get(), 5);
+        assert_eq!(*first.lock().unwrap(), vec![5]);
+        assert_eq!(*second.lock().unwrap(), vec![5]);
+        assert!(dropped.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_subscription_outliving_store() {
+        let store = Store::new(String::new());
+        let subscription = store.subscribe(|_: &String| {});
+        drop(store);
+        subscription.unsubscribe();
+    }
 }
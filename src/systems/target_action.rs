@@ -1,9 +1,242 @@
-//! TargetActionHandler - Safe callback handling for Objective-C controls
+//! Target/action callbacks for Objective-C controls
 //!
-//! Simplified implementation for objc 0.2
+//! [`TargetAction`] turns a Rust closure into an object that AppKit controls
+//! can use as their `target`, with [`TargetAction::action`] as the selector.
+//! Controls do not retain their targets; attaching a `TargetAction` to a
+//! control ties the target's lifetime to the control's.
 
-use objc::runtime::Object;
+use objc::runtime::{Object, Sel};
+use objc::{sel, sel_impl};
 use std::fmt;
+use std::sync::Arc;
+
+#[cfg(not(feature = "test-mock"))]
+use objc::declare::ClassDecl;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::Class;
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send};
+#[cfg(not(feature = "test-mock"))]
+use std::ffi::c_void;
+#[cfg(not(feature = "test-mock"))]
+use std::sync::Once;
+
+type ActionClosure = Arc<dyn Fn() + Send + Sync>;
+
+/// `OBJC_ASSOCIATION_RETAIN_NONATOMIC`
+#[cfg(not(feature = "test-mock"))]
+const ASSOCIATION_RETAIN_NONATOMIC: usize = 1;
+
+/// Address used as the associated-object key for the closure holder
+#[cfg(not(feature = "test-mock"))]
+static CLOSURE_KEY: u8 = 0;
+
+/// Address used as the associated-object key for a control's attached target
+#[cfg(not(feature = "test-mock"))]
+static ATTACHED_TARGET_KEY: u8 = 0;
+
+#[cfg(not(feature = "test-mock"))]
+unsafe extern "C" {
+    fn objc_setAssociatedObject(object: *mut Object, key: *const c_void, value: *mut Object, policy: usize);
+    fn objc_getAssociatedObject(object: *const Object, key: *const c_void) -> *mut Object;
+}
+
+/// A Rust closure exposed as an Objective-C target/action pair
+///
+/// The target is an instance of a lazily registered class whose
+/// `-invoke:` method runs the closure. The closure is attached to the target
+/// with `objc_setAssociatedObject` and freed when the last reference to the
+/// target goes away, so clones share one closure.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::systems::target_action::TargetAction;
+///
+/// let action = TargetAction::new(|| println!("clicked"));
+/// // let _: () = msg_send![ns_button, setTarget: action.target()];
+/// // let _: () = msg_send![ns_button, setAction: action.action()];
+/// action.invoke();
+/// ```
+pub struct TargetAction {
+    target: *mut Object,
+    #[cfg(feature = "test-mock")]
+    closure: ActionClosure,
+}
+
+impl TargetAction {
+    /// Wrap `closure` in a new target object
+    pub fn new<F>(closure: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let closure: ActionClosure = Arc::new(closure);
+
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let target: *mut Object = msg_send![target_action_class(), new];
+            let holder: *mut Object = msg_send![closure_holder_class(), new];
+            let slot = Box::into_raw(Box::new(closure)) as *mut c_void;
+            (*holder).set_ivar::<*mut c_void>("rustClosure", slot);
+            objc_setAssociatedObject(target, closure_key(), holder, ASSOCIATION_RETAIN_NONATOMIC);
+            let _: () = msg_send![holder, release];
+            TargetAction { target }
+        }
+
+        #[cfg(feature = "test-mock")]
+        TargetAction { target: std::ptr::null_mut(), closure }
+    }
+
+    /// The object to install as a control's target
+    ///
+    /// Null in mock builds.
+    pub fn target(&self) -> *mut Object {
+        self.target
+    }
+
+    /// The selector to install as a control's action
+    pub fn action(&self) -> Sel {
+        sel!(invoke:)
+    }
+
+    /// Install this as the target and action of `control`
+    ///
+    /// `control` may be anything that responds to `setTarget:` and
+    /// `setAction:`, such as an `NSControl` or `NSMenuItem`. Null controls
+    /// are ignored. Controls do not retain their targets, so the target is
+    /// also associated with `control` and lives as long as it does, even
+    /// after `self` is dropped. Attaching another target replaces it.
+    pub(crate) fn attach_to(&self, control: *mut Object) {
+        #[cfg(not(feature = "test-mock"))]
+        if !control.is_null() {
+            unsafe {
+                objc_setAssociatedObject(control, attached_target_key(), self.target, ASSOCIATION_RETAIN_NONATOMIC);
+                let _: () = msg_send![control, setTarget: self.target];
+                let _: () = msg_send![control, setAction: self.action()];
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = control;
+    }
+
+    /// Send the action to the target, as a control would
+    pub fn invoke(&self) {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let nil: *mut Object = std::ptr::null_mut();
+            let _: () = msg_send![self.target, invoke: nil];
+        }
+
+        #[cfg(feature = "test-mock")]
+        (self.closure)();
+    }
+}
+
+impl Clone for TargetAction {
+    fn clone(&self) -> Self {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.target, retain];
+        }
+
+        TargetAction {
+            target: self.target,
+            #[cfg(feature = "test-mock")]
+            closure: Arc::clone(&self.closure),
+        }
+    }
+}
+
+impl Drop for TargetAction {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "test-mock"))]
+        if !self.target.is_null() {
+            unsafe {
+                let _: () = msg_send![self.target, release];
+            }
+        }
+    }
+}
+
+impl fmt::Debug for TargetAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TargetAction").field("target", &self.target).finish()
+    }
+}
+
+unsafe impl Send for TargetAction {}
+unsafe impl Sync for TargetAction {}
+
+#[cfg(not(feature = "test-mock"))]
+fn closure_key() -> *const c_void {
+    &CLOSURE_KEY as *const u8 as *const c_void
+}
+
+#[cfg(not(feature = "test-mock"))]
+fn attached_target_key() -> *const c_void {
+    &ATTACHED_TARGET_KEY as *const u8 as *const c_void
+}
+
+/// Lazily register the target class whose `-invoke:` runs the associated closure
+#[cfg(not(feature = "test-mock"))]
+fn target_action_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutTargetAction", class!(NSObject))
+            .expect("CocoanutTargetAction already registered");
+
+        extern "C" fn invoke(this: &Object, _cmd: Sel, _sender: *mut Object) {
+            unsafe {
+                let holder = objc_getAssociatedObject(this, closure_key());
+                if holder.is_null() {
+                    return;
+                }
+                let slot = *(*holder).get_ivar::<*mut c_void>("rustClosure") as *const ActionClosure;
+                if let Some(closure) = slot.as_ref() {
+                    closure();
+                }
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(invoke:), invoke as extern "C" fn(&Object, Sel, *mut Object));
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutTargetAction").expect("CocoanutTargetAction not registered")
+}
+
+/// Lazily register the class that owns a boxed closure and frees it on dealloc
+#[cfg(not(feature = "test-mock"))]
+fn closure_holder_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutActionClosure", class!(NSObject))
+            .expect("CocoanutActionClosure already registered");
+        decl.add_ivar::<*mut c_void>("rustClosure");
+
+        extern "C" fn dealloc(this: &mut Object, _cmd: Sel) {
+            unsafe {
+                let slot = *this.get_ivar::<*mut c_void>("rustClosure");
+                this.set_ivar::<*mut c_void>("rustClosure", std::ptr::null_mut());
+                if !slot.is_null() {
+                    drop(Box::from_raw(slot as *mut ActionClosure));
+                }
+                let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&mut Object, Sel));
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutActionClosure").expect("CocoanutActionClosure not registered")
+}
 
 pub static ACTION_CALLBACK_PTR: &str = "rstTargetActionPtr";
 
@@ -41,6 +274,13 @@ impl TargetActionHandler {
             action: unsafe { Box::from_raw(_ptr) },
         }
     }
+
+    /// Run the stored callback with `sender`, as the control would
+    ///
+    /// Use [`TargetAction`] to have a control call a closure itself.
+    pub fn invoke(&self, sender: *mut Object) {
+        (self.action.0)(sender);
+    }
 }
 
 impl Drop for TargetActionHandler {
@@ -60,10 +300,86 @@ mod tests {
         assert!(debug_str.contains("Action"));
     }
 
+    #[test]
+    fn test_target_action_invoke_runs_closure() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&count);
+        let action = TargetAction::new(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        assert_eq!(action.action(), sel!(invoke:));
+
+        action.invoke();
+        let copy = action.clone();
+        drop(action);
+        copy.invoke();
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+
+        drop(copy);
+        assert_eq!(Arc::strong_count(&count), 1);
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_attach_to_sets_target_and_action() {
+        let action = TargetAction::new(|| {});
+        unsafe {
+            let button: *mut Object = msg_send![class!(NSButton), new];
+            action.attach_to(button);
+            let target: *mut Object = msg_send![button, target];
+            let selector: Sel = msg_send![button, action];
+            assert_eq!(target, action.target());
+            assert_eq!(selector, action.action());
+            let _: () = msg_send![button, release];
+        }
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    #[test]
+    fn test_attached_target_outlives_action() {
+        let count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let action = {
+            let count = Arc::clone(&count);
+            TargetAction::new(move || {
+                count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            })
+        };
+        unsafe {
+            let button: *mut Object = msg_send![class!(NSButton), new];
+            action.attach_to(button);
+            drop(action);
+
+            // The button still holds a live target
+            let target: *mut Object = msg_send![button, target];
+            let _: () = msg_send![target, invoke: button];
+            assert_eq!(count.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+            let _: () = msg_send![button, release];
+        }
+        assert_eq!(Arc::strong_count(&count), 1);
+    }
+
     #[test]
     fn test_handler_creation() {
         let handler = TargetActionHandler::new(std::ptr::null_mut(), |_| {});
         let debug_str = format!("{:?}", handler);
         assert!(debug_str.contains("TargetActionHandler"));
     }
+
+    #[test]
+    fn test_handler_invoke_passes_sender() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let sender = Arc::new(AtomicUsize::new(0));
+        let handler = {
+            let sender = Arc::clone(&sender);
+            TargetActionHandler::new(std::ptr::null_mut(), move |control| {
+                sender.store(control as usize, Ordering::SeqCst);
+            })
+        };
+        handler.invoke(0x40 as *mut Object);
+        assert_eq!(sender.load(Ordering::SeqCst), 0x40);
+    }
 }
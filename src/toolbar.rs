@@ -0,0 +1,458 @@
+//! Window toolbars for macOS GUI applications
+
+use crate::core::error::{CocoanutError, Result};
+use crate::systems::target_action::TargetAction;
+use crate::window::Window;
+use objc::runtime::Object;
+use std::path::{Path, PathBuf};
+
+#[cfg(not(feature = "test-mock"))]
+use objc::declare::ClassDecl;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Class, Sel, BOOL};
+#[cfg(not(feature = "test-mock"))]
+use objc::{class, msg_send, sel, sel_impl};
+#[cfg(not(feature = "test-mock"))]
+use std::ffi::c_void;
+#[cfg(not(feature = "test-mock"))]
+use std::sync::Once;
+
+/// Identifier AppKit uses for a flexible space item
+const FLEXIBLE_SPACE_IDENTIFIER: &str = "NSToolbarFlexibleSpaceItem";
+
+/// How toolbar items are displayed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolbarDisplayMode {
+    /// Use the system default
+    Default,
+    /// Show icon and label
+    IconAndLabel,
+    /// Show icon only
+    IconOnly,
+    /// Show label only
+    LabelOnly,
+}
+
+impl ToolbarDisplayMode {
+    /// Get the `NSToolbarDisplayMode` value
+    pub fn ns_value(&self) -> u64 {
+        match self {
+            Self::Default => 0,
+            Self::IconAndLabel => 1,
+            Self::IconOnly => 2,
+            Self::LabelOnly => 3,
+        }
+    }
+}
+
+/// An item in a toolbar
+#[derive(Clone)]
+pub struct ToolbarItem {
+    identifier: String,
+    label: String,
+    image: Option<PathBuf>,
+    action: Option<TargetAction>,
+}
+
+impl ToolbarItem {
+    /// Create a button item that runs `action` when clicked
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier` - Unique identifier of the item within the toolbar
+    /// * `label` - Text shown under the item
+    /// * `action` - Closure run when the item is clicked
+    pub fn button<F>(identifier: &str, label: &str, action: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        ToolbarItem {
+            identifier: identifier.to_string(),
+            label: label.to_string(),
+            image: None,
+            action: Some(TargetAction::new(action)),
+        }
+    }
+
+    /// Create a flexible space that pushes the following items to the trailing edge
+    pub fn flexible_space() -> Self {
+        ToolbarItem {
+            identifier: FLEXIBLE_SPACE_IDENTIFIER.to_string(),
+            label: String::new(),
+            image: None,
+            action: None,
+        }
+    }
+
+    /// Set an image file shown as the item's icon
+    pub fn image(mut self, path: impl AsRef<Path>) -> Self {
+        self.image = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Get the item identifier
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// Get the item label
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Get the image path
+    pub fn get_image(&self) -> Option<&Path> {
+        self.image.as_deref()
+    }
+}
+
+/// A window toolbar backed by `NSToolbar`
+///
+/// The toolbar returned by [`Toolbar::build_and_attach`] owns the delegate
+/// that creates the items and the targets their actions are sent to, so it
+/// must be kept alive for as long as the window shows it.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::prelude::*;
+/// use cocoanut::toolbar::{Toolbar, ToolbarItem, ToolbarDisplayMode};
+///
+/// let window = Window::new("Document", 800.0, 600.0)?;
+/// let _toolbar = Toolbar::new()
+///     .add_item(ToolbarItem::button("new", "New", || println!("new")))
+///     .add_item(ToolbarItem::flexible_space())
+///     .add_item(ToolbarItem::button("share", "Share", || println!("share")))
+///     .display_mode(ToolbarDisplayMode::IconAndLabel)
+///     .build_and_attach(&window)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct Toolbar {
+    identifier: String,
+    items: Vec<ToolbarItem>,
+    display_mode: ToolbarDisplayMode,
+    allows_customization: bool,
+    ns_toolbar: *mut Object,
+    delegate: *mut Object,
+}
+
+impl Toolbar {
+    /// Create a new toolbar
+    pub fn new() -> Self {
+        Toolbar {
+            identifier: "CocoanutToolbar".to_string(),
+            items: Vec::new(),
+            display_mode: ToolbarDisplayMode::Default,
+            allows_customization: false,
+            ns_toolbar: std::ptr::null_mut(),
+            delegate: std::ptr::null_mut(),
+        }
+    }
+
+    /// Set the toolbar identifier used to save its configuration
+    pub fn identifier(mut self, identifier: &str) -> Self {
+        self.identifier = identifier.to_string();
+        self
+    }
+
+    /// Add an item to the end of the toolbar
+    pub fn add_item(mut self, item: ToolbarItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Set how items are displayed
+    pub fn display_mode(mut self, mode: ToolbarDisplayMode) -> Self {
+        self.display_mode = mode;
+        self
+    }
+
+    /// Allow the user to customize the toolbar
+    pub fn allows_customization(mut self, allow: bool) -> Self {
+        self.allows_customization = allow;
+        self
+    }
+
+    /// Get the toolbar identifier
+    pub fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// Get the toolbar items
+    pub fn items(&self) -> &[ToolbarItem] {
+        &self.items
+    }
+
+    /// Get the display mode
+    pub fn get_display_mode(&self) -> ToolbarDisplayMode {
+        self.display_mode
+    }
+
+    /// Check if the user can customize the toolbar
+    pub fn get_allows_customization(&self) -> bool {
+        self.allows_customization
+    }
+
+    /// Run the action of the item with the given identifier
+    pub fn perform_action(&self, identifier: &str) -> Result<()> {
+        match find_action(&self.items, identifier) {
+            Some(action) => {
+                action.invoke();
+                Ok(())
+            }
+            None => Err(CocoanutError::InvalidParameter(
+                format!("No toolbar button with identifier '{}'", identifier)
+            )),
+        }
+    }
+
+    /// Create the `NSToolbar` and attach it to a window
+    ///
+    /// # Returns
+    ///
+    /// Returns the attached toolbar; keep it alive while the window is shown
+    pub fn build_and_attach(mut self, window: &Window) -> Result<Self> {
+        let mut seen = std::collections::HashSet::new();
+        for item in &self.items {
+            if item.identifier != FLEXIBLE_SPACE_IDENTIFIER && !seen.insert(item.identifier.as_str()) {
+                return Err(CocoanutError::InvalidParameter(
+                    format!("Duplicate toolbar item identifier '{}'", item.identifier)
+                ));
+            }
+        }
+
+        self.attach(window.ns_window())?;
+        Ok(self)
+    }
+
+    /// Create the delegate and toolbar, then install it on the window
+    fn attach(&mut self, ns_window: *mut Object) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            if ns_window.is_null() {
+                return Err(CocoanutError::InvalidParameter(
+                    "Cannot attach a toolbar to a null window".to_string()
+                ));
+            }
+
+            let delegate: *mut Object = msg_send![toolbar_delegate_class(), new];
+            let items = Box::into_raw(Box::new(self.items.clone())) as *mut c_void;
+            (*delegate).set_ivar::<*mut c_void>("rustItems", items);
+            self.delegate = delegate;
+
+            let ns_identifier = crate::core::utils::string_to_ns_string(&self.identifier)?;
+            let ns_toolbar: *mut Object = msg_send![class!(NSToolbar), alloc];
+            let ns_toolbar: *mut Object = msg_send![ns_toolbar, initWithIdentifier: ns_identifier];
+            if ns_toolbar.is_null() {
+                return Err(CocoanutError::ControlCreationFailed(
+                    "Failed to create NSToolbar".to_string()
+                ));
+            }
+            self.ns_toolbar = ns_toolbar;
+
+            let _: () = msg_send![ns_toolbar, setDelegate: delegate];
+            let _: () = msg_send![ns_toolbar, setDisplayMode: self.display_mode.ns_value()];
+            let _: () = msg_send![ns_toolbar, setAllowsUserCustomization: self.allows_customization];
+            let _: () = msg_send![ns_window, setToolbar: ns_toolbar];
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = ns_window;
+        Ok(())
+    }
+}
+
+impl Default for Toolbar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Toolbar {
+    fn drop(&mut self) {
+        if !self.ns_toolbar.is_null() {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                let nil: *mut Object = std::ptr::null_mut();
+                let _: () = msg_send![self.ns_toolbar, setDelegate: nil];
+                let _: () = msg_send![self.ns_toolbar, release];
+            }
+        }
+        if !self.delegate.is_null() {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                let items = *(*self.delegate).get_ivar::<*mut c_void>("rustItems");
+                (*self.delegate).set_ivar::<*mut c_void>("rustItems", std::ptr::null_mut());
+                if !items.is_null() {
+                    drop(Box::from_raw(items as *mut Vec<ToolbarItem>));
+                }
+                let _: () = msg_send![self.delegate, release];
+            }
+        }
+    }
+}
+
+/// Find the action of the button item with the given identifier
+fn find_action<'a>(items: &'a [ToolbarItem], identifier: &str) -> Option<&'a TargetAction> {
+    items
+        .iter()
+        .find(|item| item.identifier == identifier)
+        .and_then(|item| item.action.as_ref())
+}
+
+/// Get the items stored on a toolbar delegate
+#[cfg(not(feature = "test-mock"))]
+unsafe fn delegate_items(delegate: &Object) -> Option<&Vec<ToolbarItem>> {
+    let items = unsafe { *delegate.get_ivar::<*mut c_void>("rustItems") } as *const Vec<ToolbarItem>;
+    unsafe { items.as_ref() }
+}
+
+/// Build an NSArray of the identifiers of the delegate's items
+#[cfg(not(feature = "test-mock"))]
+unsafe fn item_identifiers(delegate: &Object) -> *mut Object {
+    unsafe {
+        let identifiers: *mut Object = msg_send![class!(NSMutableArray), array];
+        if let Some(items) = delegate_items(delegate) {
+            for item in items {
+                if let Ok(ns_identifier) = crate::core::utils::string_to_ns_string(&item.identifier) {
+                    let _: () = msg_send![identifiers, addObject: ns_identifier];
+                }
+            }
+        }
+        identifiers
+    }
+}
+
+/// Lazily register the `NSToolbarDelegate` class that creates the items
+#[cfg(not(feature = "test-mock"))]
+fn toolbar_delegate_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutToolbarDelegate", class!(NSObject))
+            .expect("CocoanutToolbarDelegate already registered");
+        decl.add_ivar::<*mut c_void>("rustItems");
+
+        extern "C" fn default_identifiers(this: &Object, _cmd: Sel, _toolbar: *mut Object) -> *mut Object {
+            unsafe { item_identifiers(this) }
+        }
+
+        extern "C" fn item_for_identifier(
+            this: &Object,
+            _cmd: Sel,
+            _toolbar: *mut Object,
+            identifier: *mut Object,
+            _will_insert: BOOL,
+        ) -> *mut Object {
+            unsafe {
+                let Some(items) = delegate_items(this) else {
+                    return std::ptr::null_mut();
+                };
+                let Ok(id) = crate::core::utils::ns_string_to_string(identifier) else {
+                    return std::ptr::null_mut();
+                };
+                let Some(item) = items.iter().find(|item| item.identifier == id) else {
+                    return std::ptr::null_mut();
+                };
+
+                let ns_item: *mut Object = msg_send![class!(NSToolbarItem), alloc];
+                let ns_item: *mut Object = msg_send![ns_item, initWithItemIdentifier: identifier];
+                if let Ok(label) = crate::core::utils::string_to_ns_string(&item.label) {
+                    let _: () = msg_send![ns_item, setLabel: label];
+                    let _: () = msg_send![ns_item, setPaletteLabel: label];
+                }
+                if let Some(path) = &item.image
+                    && let Ok(ns_path) = crate::core::utils::string_to_ns_string(&path.to_string_lossy())
+                {
+                    let image: *mut Object = msg_send![class!(NSImage), alloc];
+                    let image: *mut Object = msg_send![image, initWithContentsOfFile: ns_path];
+                    if !image.is_null() {
+                        let _: () = msg_send![ns_item, setImage: image];
+                        let _: () = msg_send![image, release];
+                    }
+                }
+                if let Some(action) = &item.action {
+                    action.attach_to(ns_item);
+                }
+                msg_send![ns_item, autorelease]
+            }
+        }
+
+        unsafe {
+            decl.add_method(
+                sel!(toolbarDefaultItemIdentifiers:),
+                default_identifiers as extern "C" fn(&Object, Sel, *mut Object) -> *mut Object,
+            );
+            decl.add_method(
+                sel!(toolbarAllowedItemIdentifiers:),
+                default_identifiers as extern "C" fn(&Object, Sel, *mut Object) -> *mut Object,
+            );
+            decl.add_method(
+                sel!(toolbar:itemForItemIdentifier:willBeInsertedIntoToolbar:),
+                item_for_identifier as extern "C" fn(&Object, Sel, *mut Object, *mut Object, BOOL) -> *mut Object,
+            );
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutToolbarDelegate").expect("CocoanutToolbarDelegate not registered")
+}
+
+unsafe impl Send for Toolbar {}
+unsafe impl Sync for Toolbar {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn document_toolbar(new_count: Arc<AtomicUsize>, share_count: Arc<AtomicUsize>) -> Toolbar {
+        Toolbar::new()
+            .add_item(ToolbarItem::button("new", "New", move || {
+                new_count.fetch_add(1, Ordering::SeqCst);
+            }))
+            .add_item(ToolbarItem::flexible_space())
+            .add_item(ToolbarItem::button("share", "Share", move || {
+                share_count.fetch_add(1, Ordering::SeqCst);
+            }))
+            .display_mode(ToolbarDisplayMode::IconAndLabel)
+            .allows_customization(true)
+    }
+
+    #[test]
+    fn test_toolbar_configuration() {
+        let toolbar = document_toolbar(Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+        assert_eq!(toolbar.items().len(), 3);
+        assert_eq!(toolbar.items()[0].label(), "New");
+        assert_eq!(toolbar.get_display_mode(), ToolbarDisplayMode::IconAndLabel);
+        assert_eq!(toolbar.get_display_mode().ns_value(), 1);
+        assert!(toolbar.get_allows_customization());
+    }
+
+    #[test]
+    fn test_toolbar_new_and_share_actions() {
+        let new_count = Arc::new(AtomicUsize::new(0));
+        let share_count = Arc::new(AtomicUsize::new(0));
+        let toolbar = document_toolbar(Arc::clone(&new_count), Arc::clone(&share_count));
+
+        toolbar.perform_action("new").unwrap();
+        toolbar.perform_action("share").unwrap();
+        toolbar.perform_action("share").unwrap();
+        assert_eq!(new_count.load(Ordering::SeqCst), 1);
+        assert_eq!(share_count.load(Ordering::SeqCst), 2);
+        assert!(toolbar.perform_action(FLEXIBLE_SPACE_IDENTIFIER).is_err());
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_toolbar_attach_rejects_duplicate_identifiers() {
+        let window = Window::new("Document", 400.0, 300.0).unwrap();
+        let toolbar = Toolbar::new()
+            .add_item(ToolbarItem::button("new", "New", || {}))
+            .add_item(ToolbarItem::button("new", "New Again", || {}));
+        assert!(toolbar.build_and_attach(&window).is_err());
+
+        let toolbar = document_toolbar(Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+        assert!(toolbar.build_and_attach(&window).is_ok());
+    }
+}
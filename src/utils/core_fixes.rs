@@ -68,14 +68,30 @@ impl Clone for ThreadSafeView {
 }
 
 /// Memory management helper for ARC integration
+///
+/// Under `test-mock` no Objective-C objects exist, so retains and releases are
+/// counted per thread instead; `MemoryManager::mock_balance` returning to its
+/// starting value shows that every reference taken was given back.
 pub struct MemoryManager;
 
+#[cfg(feature = "test-mock")]
+thread_local! {
+    static MOCK_BALANCE: std::cell::Cell<isize> = const { std::cell::Cell::new(0) };
+}
+
 impl MemoryManager {
     /// Retain an Objective-C object (increment reference count)
     pub fn retain(obj: *mut Object) -> Result<()> {
         #[cfg(not(feature = "test-mock"))]
-        unsafe {
-            let _: () = objc::msg_send![obj, retain];
+        if !obj.is_null() {
+            unsafe {
+                let _: () = objc::msg_send![obj, retain];
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        {
+            let _ = obj;
+            MOCK_BALANCE.with(|balance| balance.set(balance.get() + 1));
         }
         Ok(())
     }
@@ -83,12 +99,25 @@ impl MemoryManager {
     /// Release an Objective-C object (decrement reference count)
     pub fn release(obj: *mut Object) -> Result<()> {
         #[cfg(not(feature = "test-mock"))]
-        unsafe {
-            let _: () = objc::msg_send![obj, release];
+        if !obj.is_null() {
+            unsafe {
+                let _: () = objc::msg_send![obj, release];
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        {
+            let _ = obj;
+            MOCK_BALANCE.with(|balance| balance.set(balance.get() - 1));
         }
         Ok(())
     }
 
+    /// Retains minus releases made on the current thread
+    #[cfg(feature = "test-mock")]
+    pub fn mock_balance() -> isize {
+        MOCK_BALANCE.with(|balance| balance.get())
+    }
+
     /// Autorelease an Objective-C object
     pub fn autorelease(obj: *mut Object) -> Result<*mut Object> {
         #[cfg(not(feature = "test-mock"))]
@@ -8,4 +8,5 @@ pub mod objc_property;
 
 pub use core_fixes::*;
 pub use macros::*;
-pub use objc_property::ObjcProperty;
+pub use objc_property::{ObjcProperty, ObjcPropertyValue, PropertyAccess, PropertyCache};
+pub use crate::core::utils::{is_main_thread, with_autorelease_pool, MainThreadMarker};
@@ -1,8 +1,13 @@
 //! ObjcProperty - Safe wrapper for Objective-C object lifetime management
+//!
+//! Also home to [`objc_property!`](crate::objc_property), which declares a
+//! typed, cached accessor for one property of a component's backing object.
 
 use std::cell::RefCell;
 use std::rc::Rc;
 use objc::runtime::Object;
+use crate::core::error::Result;
+use crate::core::objc_access::ObjcAccess;
 
 /// A safe wrapper for Objective-C objects with automatic lifetime management.
 #[derive(Clone, Debug)]
@@ -10,7 +15,14 @@ pub struct ObjcProperty(Rc<RefCell<*mut Object>>);
 
 impl ObjcProperty {
     /// Creates a new ObjcProperty from a raw Objective-C object pointer.
+    ///
+    /// Mock builds have no objects, so a null `obj` is replaced by a
+    /// placeholder address unique to this property and its clones. State the
+    /// mock records per object then stays apart; the placeholder is never
+    /// messaged.
     pub fn retain(obj: *mut Object) -> Self {
+        #[cfg(feature = "test-mock")]
+        let obj = if obj.is_null() { mock_placeholder() } else { obj };
         ObjcProperty(Rc::new(RefCell::new(obj)))
     }
 
@@ -32,12 +44,188 @@ impl ObjcProperty {
     }
 }
 
+/// A distinct non-null address standing in for a mock object
+#[cfg(feature = "test-mock")]
+fn mock_placeholder() -> *mut Object {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static NEXT: AtomicUsize = AtomicUsize::new(1);
+    let index = NEXT.fetch_add(1, Ordering::Relaxed);
+    std::ptr::without_provenance_mut(index * std::mem::align_of::<usize>())
+}
+
 impl Drop for ObjcProperty {
     fn drop(&mut self) {
         // Cleanup handled by Rc
     }
 }
 
+/// A Rust type that can be bridged to and from an Objective-C property
+pub trait ObjcPropertyValue: Clone + Default {
+    /// Send `setter` with `value` to the backing object of `access`
+    fn send<A: ObjcAccess + ?Sized>(access: &A, setter: &str, value: &Self) -> Result<()>;
+
+    /// Read the property through `getter`, `None` if it can't be read
+    fn fetch<A: ObjcAccess + ?Sized>(access: &A, getter: &str) -> Option<Self>;
+}
+
+impl ObjcPropertyValue for String {
+    fn send<A: ObjcAccess + ?Sized>(access: &A, setter: &str, value: &Self) -> Result<()> {
+        access.set_string_property(setter, value)
+    }
+
+    fn fetch<A: ObjcAccess + ?Sized>(access: &A, getter: &str) -> Option<Self> {
+        access.string_property(getter)
+    }
+}
+
+impl ObjcPropertyValue for bool {
+    fn send<A: ObjcAccess + ?Sized>(access: &A, setter: &str, value: &Self) -> Result<()> {
+        access.set_bool_property(setter, *value)
+    }
+
+    fn fetch<A: ObjcAccess + ?Sized>(access: &A, getter: &str) -> Option<Self> {
+        access.bool_property(getter)
+    }
+}
+
+impl ObjcPropertyValue for f64 {
+    fn send<A: ObjcAccess + ?Sized>(access: &A, setter: &str, value: &Self) -> Result<()> {
+        access.set_f64_property(setter, *value)
+    }
+
+    fn fetch<A: ObjcAccess + ?Sized>(access: &A, getter: &str) -> Option<Self> {
+        access.f64_property(getter)
+    }
+}
+
+/// The Rust-side copy of a property declared with
+/// [`objc_property!`](crate::objc_property)
+///
+/// Empty until the property is first set or read.
+#[derive(Debug, Clone, Default)]
+pub struct PropertyCache<T>(RefCell<Option<T>>);
+
+impl<T: Clone> PropertyCache<T> {
+    /// Create an empty cache
+    pub fn new() -> Self {
+        PropertyCache(RefCell::new(None))
+    }
+
+    /// Get the cached value, if any
+    pub fn cached(&self) -> Option<T> {
+        self.0.borrow().clone()
+    }
+
+    fn store(&self, value: T) {
+        *self.0.borrow_mut() = Some(value);
+    }
+}
+
+/// Typed access to one property of a component's backing object, returned
+/// by accessors that [`objc_property!`](crate::objc_property) declares
+pub struct PropertyAccess<'a, A: ?Sized, T> {
+    owner: &'a A,
+    cache: &'a PropertyCache<T>,
+    setter: &'static str,
+    getter: &'static str,
+}
+
+impl<'a, A: ObjcAccess + ?Sized, T: ObjcPropertyValue> PropertyAccess<'a, A, T> {
+    /// Access the property of `owner` sent through `setter` and read
+    /// through `getter`, cached in `cache`
+    pub fn new(owner: &'a A, cache: &'a PropertyCache<T>, setter: &'static str, getter: &'static str) -> Self {
+        PropertyAccess { owner, cache, setter, getter }
+    }
+
+    /// Get the value, from the cache when it holds one
+    ///
+    /// The first read goes to the backing object; a property that can't be
+    /// read is the type's default.
+    pub fn get(&self) -> T {
+        if let Some(value) = self.cache.cached() {
+            return value;
+        }
+        self.reload()
+    }
+
+    /// Set the value on the backing object, caching it once sent
+    pub fn set(&self, value: impl Into<T>) -> Result<()> {
+        let value = value.into();
+        T::send(self.owner, self.setter, &value)?;
+        self.cache.store(value);
+        Ok(())
+    }
+
+    /// Read the value from the backing object again, for properties the
+    /// user can change, such as a text field's contents
+    ///
+    /// Keeps the cached value if the property can't be read.
+    pub fn reload(&self) -> T {
+        let value = T::fetch(self.owner, self.getter)
+            .or_else(|| self.cache.cached())
+            .unwrap_or_default();
+        self.cache.store(value.clone());
+        value
+    }
+}
+
+/// Declare an accessor for a property of the backing object
+///
+/// `objc_property!(title, setTitle:, title, String)` inside an `impl` block
+/// of an [`ObjcAccess`] type declares `fn title(&self)`, returning a
+/// [`PropertyAccess`] that sends `setTitle:` and reads `title`. The type
+/// must have a field of the same name holding a [`PropertyCache`] of the
+/// value type, which may be `String`, `bool` or `f64`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cocoanut::core::ObjcAccess;
+/// use cocoanut::utils::{ObjcProperty, PropertyCache};
+/// use objc::runtime::Object;
+///
+/// struct Badge {
+///     objc: ObjcProperty,
+///     title: PropertyCache<String>,
+/// }
+///
+/// impl Badge {
+///     cocoanut::objc_property!(title, setTitle:, title, String);
+/// }
+///
+/// impl ObjcAccess for Badge {
+///     fn with_backing_obj_mut<F: Fn(*mut Object)>(&self, handler: F) {
+///         self.objc.with_mut(handler);
+///     }
+///
+///     fn get_from_backing_obj<F: Fn(&Object) -> R, R>(&self, handler: F) -> R {
+///         self.objc.get(handler)
+///     }
+/// }
+///
+/// # fn main() -> cocoanut::Result<()> {
+/// # let badge = Badge { objc: ObjcProperty::retain(std::ptr::null_mut()), title: PropertyCache::new() };
+/// badge.title().set("New")?;
+/// assert_eq!(badge.title().get(), "New");
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! objc_property {
+    ($name:ident, $setter:ident :, $getter:ident, $ty:ty) => {
+        #[doc = concat!("Access the `", stringify!($getter), "` property")]
+        pub fn $name(&self) -> $crate::utils::objc_property::PropertyAccess<'_, Self, $ty> {
+            $crate::utils::objc_property::PropertyAccess::new(
+                self,
+                &self.$name,
+                concat!(stringify!($setter), ":"),
+                stringify!($getter),
+            )
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -60,4 +248,55 @@ mod tests {
         });
         assert!(*called.borrow());
     }
+
+    #[cfg(feature = "test-mock")]
+    struct MockControl {
+        objc: ObjcProperty,
+        title: PropertyCache<String>,
+        enabled: PropertyCache<bool>,
+        opacity: PropertyCache<f64>,
+    }
+
+    #[cfg(feature = "test-mock")]
+    impl MockControl {
+        crate::objc_property!(title, setTitle:, title, String);
+        crate::objc_property!(enabled, setEnabled:, isEnabled, bool);
+        crate::objc_property!(opacity, setAlphaValue:, alphaValue, f64);
+    }
+
+    #[cfg(feature = "test-mock")]
+    impl ObjcAccess for MockControl {
+        fn with_backing_obj_mut<F: Fn(*mut Object)>(&self, handler: F) {
+            self.objc.with_mut(handler);
+        }
+
+        fn get_from_backing_obj<F: Fn(&Object) -> R, R>(&self, handler: F) -> R {
+            self.objc.get(handler)
+        }
+    }
+
+    #[cfg(feature = "test-mock")]
+    #[test]
+    fn test_objc_property_accessors() {
+        let control = MockControl {
+            objc: ObjcProperty::retain(std::ptr::null_mut()),
+            title: PropertyCache::new(),
+            enabled: PropertyCache::new(),
+            opacity: PropertyCache::new(),
+        };
+        assert_eq!(control.title().get(), "");
+        assert!(!control.enabled().get());
+
+        control.title().set("Save").unwrap();
+        control.enabled().set(true).unwrap();
+        control.opacity().set(0.5).unwrap();
+        assert_eq!(control.title().get(), "Save");
+        assert_eq!(control.title.cached().as_deref(), Some("Save"));
+        assert!(control.enabled().get());
+        assert_eq!(control.opacity().get(), 0.5);
+
+        // The mock object reports what was sent; unreadable values keep the cache
+        assert_eq!(control.title().reload(), "Save");
+        assert!(control.enabled().reload());
+    }
 }
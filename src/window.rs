@@ -2,9 +2,112 @@
 
 use crate::core::error::{CocoanutError, Result};
 use crate::builder::WindowBuilder;
+use crate::core::traits::Drawable;
+use crate::features::drawing::{Color, Point};
+#[cfg(not(feature = "test-mock"))]
+use crate::features::drawing::Rect;
+#[cfg(not(feature = "test-mock"))]
+use crate::features::zero_cost::{ZeroCostRect, ZeroCostSize};
+use crate::features::macos::{Appearance, Screen};
 use objc::runtime::Object;
 use objc::{msg_send, sel, sel_impl};
 use std::ffi::CString;
+use std::sync::Arc;
+
+#[cfg(not(feature = "test-mock"))]
+use objc::declare::ClassDecl;
+#[cfg(not(feature = "test-mock"))]
+use objc::runtime::{Class, Sel};
+#[cfg(not(feature = "test-mock"))]
+use objc::class;
+#[cfg(not(feature = "test-mock"))]
+use std::ffi::c_void;
+#[cfg(not(feature = "test-mock"))]
+use std::sync::Once;
+
+type CloseHandler = Arc<dyn Fn() + Send + Sync>;
+
+/// Frames saved with `Window::save_frame`, standing in for the user defaults
+#[cfg(feature = "test-mock")]
+static SAVED_FRAMES: std::sync::Mutex<Vec<(String, (f64, f64))>> = std::sync::Mutex::new(Vec::new());
+
+/// Stacking level of a window, mapping to the `NSWindowLevel` constants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WindowLevel {
+    /// Ordinary document windows
+    #[default]
+    Normal,
+    /// Tool palettes that stay above normal windows
+    Floating,
+    /// Modal panels
+    ModalPanel,
+    /// Pop-up menus
+    PopUpMenu,
+    /// Above everything, like a screen saver
+    ScreenSaver,
+}
+
+impl WindowLevel {
+    /// The `NSWindowLevel` value
+    pub fn to_ns_level(self) -> i64 {
+        match self {
+            WindowLevel::Normal => 0,
+            WindowLevel::Floating => 3,
+            WindowLevel::ModalPanel => 8,
+            WindowLevel::PopUpMenu => 101,
+            WindowLevel::ScreenSaver => 1000,
+        }
+    }
+
+    /// Check if this is a panel level, whose windows must stay visible
+    /// while the application is inactive to be of any use
+    pub fn is_panel_level(self) -> bool {
+        matches!(self, WindowLevel::Floating | WindowLevel::ModalPanel)
+    }
+}
+
+/// How a window joins tab groups, mapping to `NSWindowTabbingMode`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TabbingMode {
+    /// Follow the user's "Prefer tabs" setting
+    #[default]
+    Automatic,
+    /// Always open as a tab of a similar window
+    Preferred,
+    /// Never show as a tab
+    Disallowed,
+}
+
+impl TabbingMode {
+    /// The `NSWindowTabbingMode` value
+    pub fn to_ns_tabbing_mode(self) -> i64 {
+        match self {
+            TabbingMode::Automatic => 0,
+            TabbingMode::Preferred => 1,
+            TabbingMode::Disallowed => 2,
+        }
+    }
+}
+
+/// Where a window goes relative to another, mapping to
+/// `NSWindowOrderingMode`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowOrder {
+    /// In front of the other window, or as the tab after it
+    Above,
+    /// Behind the other window, or as the tab before it
+    Below,
+}
+
+impl WindowOrder {
+    /// The `NSWindowOrderingMode` value
+    pub fn to_ns_ordering_mode(self) -> i64 {
+        match self {
+            WindowOrder::Above => 1,
+            WindowOrder::Below => -1,
+        }
+    }
+}
 
 /// A macOS window wrapper
 pub struct Window {
@@ -12,6 +115,23 @@ pub struct Window {
     title: String,
     width: f64,
     height: f64,
+    background_color: Option<Color>,
+    appearance: Option<Appearance>,
+    title_visible: bool,
+    titlebar_transparent: bool,
+    level: WindowLevel,
+    tabbing_mode: TabbingMode,
+    /// Mock builds have no frame to read the position from
+    #[cfg(feature = "test-mock")]
+    top_left: Point,
+    frame_autosave_name: Option<String>,
+    close_observer: Option<CloseObserver>,
+    content: Option<Box<dyn Drawable>>,
+    /// Mock builds have no responder chain to ask
+    #[cfg(feature = "test-mock")]
+    first_responder: *mut Object,
+    /// Views of the key view loop, retained so the pointers stay valid
+    key_view_order: Vec<*mut Object>,
 }
 
 impl Window {
@@ -47,6 +167,20 @@ impl Window {
             title: String::new(),
             width: 800.0,
             height: 600.0,
+            background_color: None,
+            appearance: None,
+            title_visible: true,
+            titlebar_transparent: false,
+            level: WindowLevel::Normal,
+            tabbing_mode: TabbingMode::Automatic,
+            #[cfg(feature = "test-mock")]
+            top_left: Point::new(100.0, 100.0 + 600.0),
+            frame_autosave_name: None,
+            close_observer: None,
+            content: None,
+            #[cfg(feature = "test-mock")]
+            first_responder: std::ptr::null_mut(),
+            key_view_order: Vec::new(),
         }
     }
 
@@ -62,6 +196,10 @@ impl Window {
     /// 
     /// Returns a `Result<Window>` containing the new window instance
     /// 
+    /// # Panics
+    /// 
+    /// In debug builds, panics when called off the main thread.
+    /// 
     /// # Example
     /// 
     /// ```rust,no_run
@@ -80,11 +218,27 @@ impl Window {
                 title: title.to_string(),
                 width,
                 height,
+                background_color: None,
+                appearance: None,
+                title_visible: true,
+                titlebar_transparent: false,
+                level: WindowLevel::Normal,
+                tabbing_mode: TabbingMode::Automatic,
+                #[cfg(feature = "test-mock")]
+                top_left: Point::new(100.0, 100.0 + height),
+                frame_autosave_name: None,
+                close_observer: None,
+                content: None,
+                #[cfg(feature = "test-mock")]
+                first_responder: std::ptr::null_mut(),
+                key_view_order: Vec::new(),
             });
         }
         
         #[cfg(not(feature = "test-mock"))]
         unsafe {
+            crate::core::utils::debug_assert_main_thread("Window");
+
             use cocoa::foundation::{NSRect, NSPoint, NSSize};
             
             let window_class = objc::class!(NSWindow);
@@ -127,6 +281,20 @@ impl Window {
                 title: title.to_string(),
                 width,
                 height,
+                background_color: None,
+                appearance: None,
+                title_visible: true,
+                titlebar_transparent: false,
+                level: WindowLevel::Normal,
+                tabbing_mode: TabbingMode::Automatic,
+                #[cfg(feature = "test-mock")]
+                top_left: Point::new(100.0, 100.0 + height),
+                frame_autosave_name: None,
+                close_observer: None,
+                content: None,
+                #[cfg(feature = "test-mock")]
+                first_responder: std::ptr::null_mut(),
+                key_view_order: Vec::new(),
             })
         }
     }
@@ -216,6 +384,10 @@ impl Window {
     pub fn close(&self) -> Result<()> {
         #[cfg(feature = "test-mock")]
         {
+            // No window posts the notification, so simulate it
+            if let Some(observer) = &self.close_observer {
+                observer.notify();
+            }
             return Ok(());
         }
         
@@ -226,6 +398,18 @@ impl Window {
         }
     }
     
+    /// Run `handler` when the window is about to close
+    ///
+    /// Replaces any previously set handler.
+    pub fn on_close<F>(&mut self, handler: F) -> Result<()>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.close_observer = None;
+        self.close_observer = Some(CloseObserver::new(self.ns_window, Arc::new(handler))?);
+        Ok(())
+    }
+
     /// Check if window is visible
     pub fn is_visible(&self) -> bool {
         #[cfg(feature = "test-mock")]
@@ -254,6 +438,42 @@ impl Window {
         }
     }
     
+    /// Get the screen position of the window's top-left corner
+    pub fn top_left(&self) -> Point {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let frame: cocoa::foundation::NSRect = msg_send![self.ns_window, frame];
+            Point::new(frame.origin.x, frame.origin.y + frame.size.height)
+        }
+        #[cfg(feature = "test-mock")]
+        self.top_left
+    }
+
+    /// Place the window down and to the right of `previous`, the way new
+    /// document windows cascade
+    ///
+    /// Without this, windows opened one after another stack exactly on top
+    /// of each other.
+    pub fn cascade_from(&mut self, previous: &Window) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            use cocoa::foundation::NSPoint;
+
+            // A zero point leaves `previous` in place and returns the next
+            // cascade position
+            let next: NSPoint = msg_send![previous.ns_window, cascadeTopLeftFromPoint: NSPoint::new(0.0, 0.0)];
+            let _: NSPoint = msg_send![self.ns_window, cascadeTopLeftFromPoint: next];
+        }
+        #[cfg(feature = "test-mock")]
+        {
+            // The offset AppKit steps cascaded windows by
+            const CASCADE_OFFSET: f64 = 20.0;
+            let from = previous.top_left();
+            self.top_left = Point::new(from.x + CASCADE_OFFSET, from.y - CASCADE_OFFSET);
+        }
+        Ok(())
+    }
+
     /// Get the underlying NSWindow pointer
     pub(crate) fn ns_window(&self) -> *mut Object {
         self.ns_window
@@ -295,12 +515,477 @@ impl Window {
             Ok(())
         }
     }
+    
+    /// Replace the window's content view with `view`, which then fills
+    /// the window and resizes with it
+    ///
+    /// The window keeps `view` alive until it is replaced or the window is
+    /// dropped. Subviews added to the previous content view go with it.
+    pub fn set_content_view(&mut self, view: Box<dyn Drawable>) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_view = view.as_view();
+            if ns_view.is_null() {
+                return Err(CocoanutError::ObjcNull("content view"));
+            }
+            let _: () = msg_send![self.ns_window, setContentView: ns_view];
+        }
+        self.content = Some(view);
+        Ok(())
+    }
+
+    /// Get the window's content view
+    ///
+    /// This is the view installed with [`Window::set_content_view`], or the
+    /// window's own content view if none was installed.
+    pub fn content_view(&self) -> *mut Object {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            msg_send![self.ns_window, contentView]
+        }
+        #[cfg(feature = "test-mock")]
+        self.content.as_ref().map_or(std::ptr::null_mut(), |view| view.as_view())
+    }
+
+    /// Get the view installed with [`Window::set_content_view`], if any
+    pub fn content(&self) -> Option<&dyn Drawable> {
+        self.content.as_deref()
+    }
+
+    /// Give keyboard focus to `view`
+    ///
+    /// Fails if the view is nil or refuses to become first responder.
+    pub fn make_first_responder(&mut self, view: &dyn Drawable) -> Result<()> {
+        let ns_view = view.as_view();
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            if ns_view.is_null() {
+                return Err(CocoanutError::ObjcNull("first responder"));
+            }
+            let accepted: bool = msg_send![self.ns_window, makeFirstResponder: ns_view];
+            if !accepted {
+                return Err(CocoanutError::SystemError(
+                    "View refused to become first responder".to_string()
+                ));
+            }
+        }
+        #[cfg(feature = "test-mock")]
+        {
+            self.first_responder = ns_view;
+        }
+        Ok(())
+    }
+
+    /// Get the view that has keyboard focus, if any
+    ///
+    /// While no view has focus this is `None`; AppKit may report the window
+    /// itself as first responder in that case.
+    pub fn first_responder(&self) -> Option<*mut Object> {
+        #[cfg(not(feature = "test-mock"))]
+        let responder: *mut Object = unsafe { msg_send![self.ns_window, firstResponder] };
+        #[cfg(feature = "test-mock")]
+        let responder = self.first_responder;
+        (!responder.is_null() && responder != self.ns_window).then_some(responder)
+    }
+
+    /// Set the order Tab moves focus through `views`
+    ///
+    /// Each view's next key view is the one after it, and the last wraps
+    /// around to the first. The first view also gets focus when the window
+    /// opens.
+    pub fn set_key_view_order(&mut self, views: &[&dyn Drawable]) -> Result<()> {
+        let order: Vec<*mut Object> = views.iter().map(|view| view.as_view()).collect();
+        if order.is_empty() {
+            return Err(CocoanutError::InvalidParameter("Key view order is empty".to_string()));
+        }
+        for (index, ns_view) in order.iter().enumerate() {
+            if order[..index].contains(ns_view) {
+                return Err(CocoanutError::InvalidParameter(
+                    format!("View at position {} appears twice in the key view order", index)
+                ));
+            }
+        }
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            if order.iter().any(|ns_view| ns_view.is_null()) {
+                return Err(CocoanutError::ObjcNull("key view"));
+            }
+            let _: () = msg_send![self.ns_window, setAutorecalculatesKeyViewLoop: false];
+            for (index, &ns_view) in order.iter().enumerate() {
+                let next = order[(index + 1) % order.len()];
+                let _: () = msg_send![ns_view, setNextKeyView: next];
+            }
+            let _: () = msg_send![self.ns_window, setInitialFirstResponder: order[0]];
+            for &ns_view in &order {
+                let _: *mut Object = msg_send![ns_view, retain];
+            }
+            for &ns_view in &self.key_view_order {
+                let _: () = msg_send![ns_view, release];
+            }
+        }
+        self.key_view_order = order;
+        Ok(())
+    }
+
+    /// Get the views set with [`Window::set_key_view_order`], in Tab order
+    ///
+    /// The window keeps them alive until the order is replaced or the window
+    /// is dropped.
+    pub fn key_view_order(&self) -> &[*mut Object] {
+        &self.key_view_order
+    }
+
+    /// Get the background color set with `set_background_color`
+    pub fn background_color(&self) -> Option<Color> {
+        self.background_color
+    }
+
+    /// Set the window's background color
+    pub fn set_background_color(&mut self, color: Color) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_window, setBackgroundColor: color.to_ns_color()];
+        }
+        self.background_color = Some(color);
+        Ok(())
+    }
+
+    /// Get the appearance forced with `set_appearance`, if any
+    pub fn appearance(&self) -> Option<Appearance> {
+        self.appearance
+    }
+
+    /// Force this window to use a light or dark appearance
+    ///
+    /// Other windows keep following the application's appearance.
+    pub fn set_appearance(&mut self, appearance: Appearance) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let name = crate::core::utils::string_to_ns_string(appearance.ns_appearance_name())?;
+            let ns_appearance: *mut Object = msg_send![objc::class!(NSAppearance), appearanceNamed: name];
+            if ns_appearance.is_null() {
+                return Err(CocoanutError::SystemError(
+                    format!("Unknown appearance {}", appearance.ns_appearance_name())
+                ));
+            }
+            let _: () = msg_send![self.ns_window, setAppearance: ns_appearance];
+        }
+        self.appearance = Some(appearance);
+        Ok(())
+    }
+
+    /// Check if the title is drawn in the title bar
+    pub fn is_title_visible(&self) -> bool {
+        self.title_visible
+    }
+
+    /// Show or hide the title text in the title bar
+    pub fn set_title_visibility(&mut self, visible: bool) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            // NSWindowTitleVisible / NSWindowTitleHidden
+            let visibility: i64 = if visible { 0 } else { 1 };
+            let _: () = msg_send![self.ns_window, setTitleVisibility: visibility];
+        }
+        self.title_visible = visible;
+        Ok(())
+    }
+
+    /// Check if the title bar is transparent
+    pub fn is_titlebar_transparent(&self) -> bool {
+        self.titlebar_transparent
+    }
+
+    /// Let the window's content show through the title bar
+    ///
+    /// Combined with a hidden title and a background color this gives a
+    /// custom-styled window that keeps the standard window buttons.
+    pub fn set_titlebar_transparent(&mut self, transparent: bool) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_window, setTitlebarAppearsTransparent: transparent];
+        }
+        self.titlebar_transparent = transparent;
+        Ok(())
+    }
+
+    /// Get the window's stacking level
+    pub fn level(&self) -> WindowLevel {
+        self.level
+    }
+
+    /// Set the window's stacking level
+    ///
+    /// Windows at a [panel level](WindowLevel::is_panel_level) stay visible
+    /// when the application is inactive, so palettes remain above other
+    /// apps' windows; other levels leave `hidesOnDeactivate` as it was.
+    pub fn set_level(&mut self, level: WindowLevel) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_window, setLevel: level.to_ns_level()];
+            if level.is_panel_level() {
+                let _: () = msg_send![self.ns_window, setHidesOnDeactivate: false];
+            }
+        }
+        self.level = level;
+        Ok(())
+    }
+
+    /// Keep the window above normal windows, or return it to the normal level
+    pub fn set_always_on_top(&mut self, on_top: bool) -> Result<()> {
+        self.set_level(if on_top { WindowLevel::Floating } else { WindowLevel::Normal })
+    }
+
+    /// Get the tabbing mode
+    pub fn tabbing_mode(&self) -> TabbingMode {
+        self.tabbing_mode
+    }
+
+    /// Set whether the window opens as a tab of similar windows
+    pub fn set_tabbing_mode(&mut self, mode: TabbingMode) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_window, setTabbingMode: mode.to_ns_tabbing_mode()];
+        }
+        self.tabbing_mode = mode;
+        Ok(())
+    }
+
+    /// Move `window` into this window's tab group, as the tab after
+    /// ([`WindowOrder::Above`]) or before this window's tab
+    ///
+    /// Fails if `window` is this window or tabbing is disallowed for either.
+    pub fn add_tabbed_window(&self, window: &Window, ordered: WindowOrder) -> Result<()> {
+        if std::ptr::eq(self, window) || (!self.ns_window.is_null() && self.ns_window == window.ns_window) {
+            return Err(CocoanutError::InvalidParameter("Cannot add a window as its own tab".to_string()));
+        }
+        if self.tabbing_mode == TabbingMode::Disallowed || window.tabbing_mode == TabbingMode::Disallowed {
+            return Err(CocoanutError::InvalidParameter(
+                "Cannot add a tab to a window that disallows tabbing".to_string()
+            ));
+        }
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_window, addTabbedWindow: window.ns_window
+                                                         ordered: ordered.to_ns_ordering_mode()];
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = ordered;
+        Ok(())
+    }
+
+    /// Move the window onto another display
+    ///
+    /// The window keeps its offset from the top-left of its current screen
+    /// and is kept inside `screen`'s visible frame.
+    pub fn move_to_screen(&self, screen: &Screen) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            use cocoa::foundation::NSRect;
+
+            let frame: NSRect = msg_send![self.ns_window, frame];
+            let current: *mut Object = msg_send![self.ns_window, screen];
+            let from = if current.is_null() {
+                None
+            } else {
+                let visible: NSRect = msg_send![current, visibleFrame];
+                Some(ZeroCostRect::from(visible).into())
+            };
+            let window = ZeroCostRect::from(frame).into();
+            self.set_frame(screen.place(window, from));
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = screen;
+        Ok(())
+    }
+
+    /// Center the window in `screen`'s visible frame
+    pub fn center_on(&self, screen: &Screen) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            use cocoa::foundation::NSRect;
+
+            let frame: NSRect = msg_send![self.ns_window, frame];
+            self.set_frame(screen.centered(ZeroCostSize::from(frame.size).into()));
+        }
+        #[cfg(feature = "test-mock")]
+        let _ = screen;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "test-mock"))]
+    unsafe fn set_frame(&self, frame: Rect) {
+        let frame: cocoa::foundation::NSRect = ZeroCostRect::from(frame).into();
+        unsafe {
+            let _: () = msg_send![self.ns_window, setFrame: frame display: true];
+        }
+    }
+
+    /// Get the name the window's frame is autosaved under, if any
+    pub fn frame_autosave_name(&self) -> Option<&str> {
+        self.frame_autosave_name.as_deref()
+    }
+
+    /// Have macOS save the window's position and size under `name` whenever
+    /// they change, and restore them now if they were saved before
+    ///
+    /// Frames are kept in the user defaults, so they survive relaunches. An
+    /// empty name turns autosaving off. Returns
+    /// `CocoanutError::InvalidParameter` if another window already uses
+    /// `name`.
+    pub fn set_frame_autosave_name(&mut self, name: &str) -> Result<()> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_name = crate::core::utils::string_to_ns_string(name)?;
+            let accepted: bool = msg_send![self.ns_window, setFrameAutosaveName: ns_name];
+            if !accepted {
+                return Err(CocoanutError::InvalidParameter(
+                    format!("Frame autosave name {:?} is used by another window", name)
+                ));
+            }
+            self.sync_content_size();
+        }
+        self.frame_autosave_name = if name.is_empty() { None } else { Some(name.to_string()) };
+        Ok(())
+    }
+
+    /// Save the window's current position and size in the user defaults
+    ///
+    /// The frame is saved under the autosave name, or the title when there
+    /// is none.
+    pub fn save_frame(&self) -> Result<()> {
+        let name = self.frame_name()?;
+
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let ns_name = crate::core::utils::string_to_ns_string(name)?;
+            let _: () = msg_send![self.ns_window, saveFrameUsingName: ns_name];
+        }
+        #[cfg(feature = "test-mock")]
+        {
+            let mut saved = SAVED_FRAMES.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            saved.retain(|(saved_name, _)| saved_name != name);
+            saved.push((name.to_string(), (self.width, self.height)));
+        }
+        Ok(())
+    }
+
+    /// Move and resize the window to the frame last saved with `save_frame`
+    ///
+    /// Returns `false`, leaving the window alone, if no frame was saved.
+    pub fn restore_frame(&mut self) -> Result<bool> {
+        let name = self.frame_name()?.to_string();
+
+        #[cfg(not(feature = "test-mock"))]
+        let restored = unsafe {
+            let ns_name = crate::core::utils::string_to_ns_string(&name)?;
+            let restored: bool = msg_send![self.ns_window, setFrameUsingName: ns_name];
+            self.sync_content_size();
+            restored
+        };
+        #[cfg(feature = "test-mock")]
+        let restored = {
+            let saved = SAVED_FRAMES.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            match saved.iter().find(|(saved_name, _)| *saved_name == name) {
+                Some((_, (width, height))) => {
+                    self.width = *width;
+                    self.height = *height;
+                    true
+                }
+                None => false,
+            }
+        };
+        Ok(restored)
+    }
+
+    /// The user defaults name for `save_frame` and `restore_frame`
+    fn frame_name(&self) -> Result<&str> {
+        match self.frame_autosave_name.as_deref() {
+            Some(name) => Ok(name),
+            None if !self.title.is_empty() => Ok(&self.title),
+            None => Err(CocoanutError::InvalidParameter(
+                "Saving a frame needs an autosave name or a title".to_string()
+            )),
+        }
+    }
+
+    /// Pick up a content size changed by AppKit
+    #[cfg(not(feature = "test-mock"))]
+    unsafe fn sync_content_size(&mut self) {
+        use cocoa::foundation::NSRect;
+
+        unsafe {
+            let content_view: *mut Object = msg_send![self.ns_window, contentView];
+            if !content_view.is_null() {
+                let frame: NSRect = msg_send![content_view, frame];
+                self.width = frame.size.width;
+                self.height = frame.size.height;
+            }
+        }
+    }
+
+    /// Present another window as a document-modal sheet over this window
+    /// 
+    /// # Arguments
+    /// 
+    /// * `sheet` - The window to present as a sheet
+    /// * `completion` - Called with the response code passed to `end_sheet`
+    /// 
+    /// # Returns
+    /// 
+    /// Returns a `Result<()>` indicating success or failure
+    pub fn begin_sheet<F>(&self, sheet: &Window, completion: F) -> Result<()>
+    where
+        F: Fn(isize) + 'static,
+    {
+        if std::ptr::eq(self, sheet) {
+            return Err(CocoanutError::InvalidParameter(
+                "A window cannot be presented as a sheet on itself".to_string()
+            ));
+        }
+        
+        #[cfg(feature = "test-mock")]
+        {
+            let _ = completion;
+            return Ok(());
+        }
+        
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let handler = block::ConcreteBlock::new(completion).copy();
+            let _: () = msg_send![self.ns_window, beginSheet: sheet.ns_window completionHandler: &*handler];
+            Ok(())
+        }
+    }
+    
+    /// Dismiss a sheet presented with `begin_sheet`
+    /// 
+    /// # Arguments
+    /// 
+    /// * `sheet` - The sheet window to dismiss
+    /// * `code` - The response code passed to the sheet's completion closure
+    pub fn end_sheet(&self, sheet: &Window, code: isize) -> Result<()> {
+        #[cfg(feature = "test-mock")]
+        {
+            let _ = (sheet, code);
+            return Ok(());
+        }
+        
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            let _: () = msg_send![self.ns_window, endSheet: sheet.ns_window returnCode: code];
+            Ok(())
+        }
+    }
 }
 
 impl Drop for Window {
     fn drop(&mut self) {
         #[cfg(not(feature = "test-mock"))]
         unsafe {
+            for &ns_view in &self.key_view_order {
+                let _: () = msg_send![ns_view, release];
+            }
             let _: () = msg_send![self.ns_window, release];
         }
     }
@@ -308,3 +993,92 @@ impl Drop for Window {
 
 unsafe impl Send for Window {}
 unsafe impl Sync for Window {}
+
+/// Runs a handler when a window posts `NSWindowWillCloseNotification`
+struct CloseObserver {
+    observer: *mut Object,
+    #[cfg(feature = "test-mock")]
+    handler: CloseHandler,
+}
+
+impl CloseObserver {
+    fn new(ns_window: *mut Object, handler: CloseHandler) -> Result<Self> {
+        #[cfg(not(feature = "test-mock"))]
+        unsafe {
+            if ns_window.is_null() {
+                return Ok(CloseObserver { observer: std::ptr::null_mut() });
+            }
+            let name = crate::core::utils::string_to_ns_string("NSWindowWillCloseNotification")?;
+            let slot = Box::into_raw(Box::new(handler)) as *mut c_void;
+            let observer: *mut Object = msg_send![close_observer_class(), new];
+            (*observer).set_ivar::<*mut c_void>("rustHandler", slot);
+            let center: *mut Object = msg_send![class!(NSNotificationCenter), defaultCenter];
+            let _: () = msg_send![center, addObserver: observer
+                                          selector: sel!(windowWillClose:)
+                                              name: name
+                                            object: ns_window];
+            Ok(CloseObserver { observer })
+        }
+
+        #[cfg(feature = "test-mock")]
+        {
+            let _ = ns_window;
+            Ok(CloseObserver { observer: std::ptr::null_mut(), handler })
+        }
+    }
+
+    /// Run the handler as if the window were closing
+    #[cfg(feature = "test-mock")]
+    fn notify(&self) {
+        (self.handler)();
+    }
+}
+
+impl Drop for CloseObserver {
+    fn drop(&mut self) {
+        if !self.observer.is_null() {
+            #[cfg(not(feature = "test-mock"))]
+            unsafe {
+                let center: *mut Object = msg_send![class!(NSNotificationCenter), defaultCenter];
+                let _: () = msg_send![center, removeObserver: self.observer];
+                let slot = *(*self.observer).get_ivar::<*mut c_void>("rustHandler");
+                (*self.observer).set_ivar::<*mut c_void>("rustHandler", std::ptr::null_mut());
+                if !slot.is_null() {
+                    drop(Box::from_raw(slot as *mut CloseHandler));
+                }
+                let _: () = msg_send![self.observer, release];
+            }
+        }
+    }
+}
+
+/// Lazily register the ObjC class observing window close notifications
+#[cfg(not(feature = "test-mock"))]
+fn close_observer_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("CocoanutWindowCloseObserver", class!(NSObject))
+            .expect("CocoanutWindowCloseObserver already registered");
+        decl.add_ivar::<*mut c_void>("rustHandler");
+
+        extern "C" fn window_will_close(this: &Object, _cmd: Sel, _notification: *mut Object) {
+            unsafe {
+                let slot = *this.get_ivar::<*mut c_void>("rustHandler") as *const CloseHandler;
+                if let Some(handler) = slot.as_ref() {
+                    handler();
+                }
+            }
+        }
+
+        unsafe {
+            decl.add_method(
+                sel!(windowWillClose:),
+                window_will_close as extern "C" fn(&Object, Sel, *mut Object),
+            );
+        }
+        decl.register();
+    });
+
+    Class::get("CocoanutWindowCloseObserver").expect("CocoanutWindowCloseObserver not registered")
+}
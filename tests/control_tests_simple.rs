@@ -2,6 +2,7 @@
 
 use cocoanut::*;
 use cocoanut::controls::{Button, Label, TextField};
+use cocoanut::macos_integration::AccessibilityRole;
 
 #[test]
 fn test_button_creation() {
@@ -123,3 +124,77 @@ fn test_control_memory_management() {
     assert_eq!(text_fields.len(), 10);
     // Controls should be dropped here without panicking
 }
+
+#[test]
+fn test_control_default_accessibility_roles() {
+    let button = Button::new("OK").unwrap();
+    assert_eq!(button.accessibility_role(), AccessibilityRole::Button);
+
+    let label = Label::new("Name").unwrap();
+    assert_eq!(label.accessibility_role(), AccessibilityRole::StaticText);
+
+    let text_field = TextField::new("").unwrap();
+    assert_eq!(text_field.accessibility_role(), AccessibilityRole::TextField);
+}
+
+#[test]
+fn test_control_accessibility_role_change() {
+    let mut button = Button::new("Toggle").unwrap();
+    assert!(button.set_accessibility_role(AccessibilityRole::Checkbox).is_ok());
+    assert_eq!(button.accessibility_role(), AccessibilityRole::Checkbox);
+    assert!(button.set_accessibility_value("1").is_ok());
+}
+
+#[test]
+fn test_controls_release_on_drop() {
+    use cocoanut::utils::MemoryManager;
+
+    let before = MemoryManager::mock_balance();
+    {
+        let _button = Button::new("Leak Check").unwrap();
+        let _label = Label::new("Leak Check").unwrap();
+        let _field = TextField::new("Leak Check").unwrap();
+        assert_eq!(MemoryManager::mock_balance(), before + 3);
+    }
+    assert_eq!(MemoryManager::mock_balance(), before);
+}
+
+#[test]
+fn test_clone_controls() {
+    use cocoanut::utils::MemoryManager;
+
+    let before = MemoryManager::mock_balance();
+    let button = Button::new("Shared").unwrap();
+    let copy = button.clone();
+    assert_eq!(copy.title(), "Shared");
+    assert_eq!(copy.as_view(), button.as_view());
+    assert_eq!(MemoryManager::mock_balance(), before + 2);
+
+    let label = Label::new("Shared label").unwrap();
+    assert_eq!(label.clone().text(), "Shared label");
+    let field = TextField::new("Shared field").unwrap();
+    assert_eq!(field.clone().text(), "Shared field");
+
+    drop(button);
+    drop(copy);
+    drop(label);
+    drop(field);
+    assert_eq!(MemoryManager::mock_balance(), before);
+}
+
+#[test]
+fn test_button_click_handler() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let clicks = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&clicks);
+    let mut button = Button::new("Count").unwrap();
+    button.perform_click();
+    button.on_click(move || {
+        counter.fetch_add(1, Ordering::SeqCst);
+    });
+    button.perform_click();
+    button.clone().perform_click();
+    assert_eq!(clicks.load(Ordering::SeqCst), 2);
+}
@@ -177,3 +177,61 @@ fn test_drawing_memory_management() {
     assert_eq!(rects.len(), 100);
     // Should be dropped without panicking
 }
+
+#[test]
+fn test_rect_zero_cost_round_trip() {
+    use cocoanut::zero_cost::ZeroCostRect;
+
+    let rect = Rect::from_xywh(10.0, 20.0, 100.0, 200.0);
+    let zero_cost: ZeroCostRect = rect.into();
+    assert_eq!(zero_cost.x(), 10.0);
+    assert_eq!(zero_cost.y(), 20.0);
+    assert_eq!(zero_cost.width(), 100.0);
+    assert_eq!(zero_cost.height(), 200.0);
+    assert_eq!(Rect::from(zero_cost), rect);
+}
+
+#[test]
+fn test_two_stop_linear_gradient() {
+    use cocoanut::drawing::{Gradient, GradientKind};
+
+    let start = Color::rgb(0.1, 0.3, 0.8).unwrap();
+    let end = Color::rgb(0.4, 0.1, 0.7).unwrap();
+    let gradient = Gradient::linear(start, end);
+    assert_eq!(gradient.kind(), GradientKind::Linear);
+    assert_eq!(gradient.stops().len(), 2);
+    assert_eq!(gradient.stops()[0].color, start);
+    assert_eq!(gradient.stops()[1].location, 1.0);
+
+    unsafe {
+        assert!(gradient.apply_as_background(std::ptr::null_mut(), 90.0).is_ok());
+        assert!(Gradient::radial(start, end).apply_as_background(std::ptr::null_mut(), 0.0).is_ok());
+    }
+}
+
+#[test]
+fn test_gradient_stops_are_clamped_and_sorted() {
+    use cocoanut::drawing::{Gradient, GradientKind, GradientStop};
+
+    let gradient = Gradient::linear(Color::black(), Color::white())
+        .with_stop(Color::red(), 1.5)
+        .with_stop(Color::blue(), -0.5)
+        .with_stop(Color::green(), 0.25);
+    let locations: Vec<f64> = gradient.stops().iter().map(|stop| stop.location).collect();
+    assert_eq!(locations, [0.0, 0.0, 0.25, 1.0, 1.0]);
+
+    let single = vec![GradientStop { color: Color::red(), location: 0.5 }];
+    assert!(Gradient::from_stops(GradientKind::Radial, single).is_err());
+}
+
+#[test]
+fn test_gradient_angle_unit_points() {
+    use cocoanut::drawing::Gradient;
+
+    let (start, end) = Gradient::unit_points(0.0);
+    assert_eq!((start, end), (Point::new(0.0, 0.5), Point::new(1.0, 0.5)));
+
+    let (start, end) = Gradient::unit_points(90.0);
+    assert!((start.x - 0.5).abs() < 1e-9 && start.y.abs() < 1e-9);
+    assert!((end.x - 0.5).abs() < 1e-9 && (end.y - 1.0).abs() < 1e-9);
+}
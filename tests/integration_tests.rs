@@ -63,3 +63,68 @@ fn test_error_types() {
     let err2 = CocoanutError::WindowCreationFailed("window".to_string());
     assert!(format!("{}", err2).contains("window"));
 }
+
+#[test]
+fn test_error_categories() {
+    let err = Color::new(2.0, 0.0, 0.0, 1.0).unwrap_err();
+    assert!(matches!(err, CocoanutError::InvalidColor(_)));
+
+    let err = CocoanutError::ObjcNull("NSWindow");
+    assert!(format!("{}", err).contains("NSWindow"));
+    assert!(format!("{}", CocoanutError::ThreadViolation).contains("main thread"));
+
+    let path = std::path::PathBuf::from("/missing/icon.png");
+    let err = CocoanutError::FileNotFound(path.clone());
+    assert!(format!("{}", err).contains("/missing/icon.png"));
+}
+
+#[test]
+fn test_error_from_io_and_utf8() {
+    let io = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied");
+    let err: CocoanutError = io.into();
+    assert!(matches!(err, CocoanutError::Io(_)));
+
+    let bytes = vec![0xff, 0xfe];
+    let err: CocoanutError = std::str::from_utf8(&bytes).unwrap_err().into();
+    assert!(matches!(err, CocoanutError::InvalidUtf8(_)));
+}
+
+#[test]
+fn test_result_context() {
+    let result: Result<()> = Err(CocoanutError::InvalidParameter("width".to_string()));
+    let err = result.context("resizing window").unwrap_err();
+    assert!(matches!(err, CocoanutError::SystemError(_)));
+    assert_eq!(err.to_string(), "System error: resizing window: Invalid parameter: width");
+
+    let io: std::result::Result<(), std::io::Error> =
+        Err(std::io::Error::new(std::io::ErrorKind::NotFound, "missing"));
+    let err = io.with_context(|| format!("loading {}", "icon.png")).unwrap_err();
+    assert_eq!(err.to_string(), "System error: loading icon.png: missing");
+
+    let ok: Result<u32> = Ok(7);
+    let value = ok.with_context(|| panic!("context must not be built on success")).unwrap();
+    assert_eq!(value, 7);
+}
+
+#[test]
+fn test_main_thread_marker_off_main_thread() {
+    let (on_main, marker) = std::thread::spawn(|| {
+        (cocoanut::utils::is_main_thread(), cocoanut::utils::MainThreadMarker::new().is_some())
+    })
+    .join()
+    .unwrap();
+    assert!(!on_main);
+    assert!(!marker);
+    assert_eq!(std::mem::size_of::<cocoanut::utils::MainThreadMarker>(), 0);
+}
+
+#[test]
+fn test_with_autorelease_pool_returns_value() {
+    let mut created = 0;
+    let labels = cocoanut::utils::with_autorelease_pool(|| {
+        created += 1;
+        (0..3).map(|row| format!("Row {}", row)).collect::<Vec<_>>()
+    });
+    assert_eq!(created, 1);
+    assert_eq!(labels, ["Row 0", "Row 1", "Row 2"]);
+}
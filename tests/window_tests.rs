@@ -2,7 +2,6 @@
 
 use cocoanut::*;
 use cocoanut::window::Window;
-use cocoanut::drawing::{Point, Size, Rect};
 
 #[test]
 fn test_window_creation() {
@@ -25,7 +24,7 @@ fn test_window_dimensions() {
 
 #[test]
 fn test_window_position() {
-    let window = Window::new("Position Test", 400.0, 300.0).unwrap();
+    let _window = Window::new("Position Test", 400.0, 300.0).unwrap();
     
     // Test initial position (Window doesn't have position method yet)
     // This test would need to be implemented when position() method is added
@@ -61,7 +60,7 @@ fn test_window_title_change() {
     
     assert_eq!(window.title(), "Original Title");
     
-    window.set_title("New Title");
+    window.set_title("New Title").unwrap();
     assert_eq!(window.title(), "New Title");
 }
 
@@ -74,7 +73,7 @@ fn test_window_resize() {
     assert_eq!(original_height, 300.0);
     
     // Resize window
-    window.set_size(800.0, 600.0);
+    window.set_size(800.0, 600.0).unwrap();
     let (new_width, new_height) = window.size();
     assert_eq!(new_width, 800.0);
     assert_eq!(new_height, 600.0);
@@ -315,3 +314,222 @@ fn test_window_creation_with_negative_dimensions() {
         }
     }
 }
+
+#[test]
+fn test_window_sheet_presentation() {
+    let parent = Window::new("Document", 800.0, 600.0).unwrap();
+    let sheet = Window::new("Preferences", 400.0, 300.0).unwrap();
+
+    assert!(parent.begin_sheet(&sheet, |_code| {}).is_ok());
+    assert!(parent.end_sheet(&sheet, 1).is_ok());
+}
+
+#[test]
+fn test_window_sheet_on_itself_fails() {
+    let window = Window::new("Document", 800.0, 600.0).unwrap();
+    assert!(window.begin_sheet(&window, |_code| {}).is_err());
+}
+
+#[test]
+fn test_window_background_color() {
+    let mut window = Window::new("Styled", 400.0, 300.0).unwrap();
+    assert_eq!(window.background_color(), None);
+
+    let color = cocoanut::drawing::Color::rgb(0.1, 0.2, 0.3).unwrap();
+    assert!(window.set_background_color(color).is_ok());
+    assert_eq!(window.background_color(), Some(color));
+}
+
+#[test]
+fn test_window_appearance_and_titlebar() {
+    use cocoanut::macos_features::Appearance;
+
+    assert_eq!(Appearance::Light.ns_appearance_name(), "NSAppearanceNameAqua");
+    assert_eq!(Appearance::Dark.ns_appearance_name(), "NSAppearanceNameDarkAqua");
+
+    let mut window = Window::new("Styled", 400.0, 300.0).unwrap();
+    assert_eq!(window.appearance(), None);
+    assert!(window.set_appearance(Appearance::Dark).is_ok());
+    assert_eq!(window.appearance(), Some(Appearance::Dark));
+
+    assert!(window.is_title_visible());
+    assert!(!window.is_titlebar_transparent());
+    assert!(window.set_title_visibility(false).is_ok());
+    assert!(window.set_titlebar_transparent(true).is_ok());
+    assert!(!window.is_title_visible());
+    assert!(window.is_titlebar_transparent());
+}
+
+#[test]
+fn test_window_level_constants() {
+    use cocoanut::window::WindowLevel;
+
+    assert_eq!(WindowLevel::Normal.to_ns_level(), 0);
+    assert_eq!(WindowLevel::Floating.to_ns_level(), 3);
+    assert_eq!(WindowLevel::ModalPanel.to_ns_level(), 8);
+    assert_eq!(WindowLevel::PopUpMenu.to_ns_level(), 101);
+    assert_eq!(WindowLevel::ScreenSaver.to_ns_level(), 1000);
+
+    assert!(WindowLevel::Floating.is_panel_level());
+    assert!(WindowLevel::ModalPanel.is_panel_level());
+    assert!(!WindowLevel::Normal.is_panel_level());
+    assert!(!WindowLevel::PopUpMenu.is_panel_level());
+}
+
+#[test]
+fn test_window_always_on_top() {
+    use cocoanut::window::WindowLevel;
+
+    let mut window = Window::new("Palette", 200.0, 300.0).unwrap();
+    assert_eq!(window.level(), WindowLevel::Normal);
+
+    assert!(window.set_always_on_top(true).is_ok());
+    assert_eq!(window.level(), WindowLevel::Floating);
+    assert!(window.set_level(WindowLevel::ModalPanel).is_ok());
+    assert_eq!(window.level(), WindowLevel::ModalPanel);
+    assert!(window.set_always_on_top(false).is_ok());
+    assert_eq!(window.level(), WindowLevel::Normal);
+}
+
+#[test]
+fn test_window_tabbing_constants() {
+    use cocoanut::window::{TabbingMode, WindowOrder};
+
+    assert_eq!(TabbingMode::Automatic.to_ns_tabbing_mode(), 0);
+    assert_eq!(TabbingMode::Preferred.to_ns_tabbing_mode(), 1);
+    assert_eq!(TabbingMode::Disallowed.to_ns_tabbing_mode(), 2);
+    assert_eq!(WindowOrder::Above.to_ns_ordering_mode(), 1);
+    assert_eq!(WindowOrder::Below.to_ns_ordering_mode(), -1);
+}
+
+#[test]
+fn test_window_add_tabbed_window() {
+    use cocoanut::window::{TabbingMode, WindowOrder};
+
+    let mut document = Window::new("Untitled", 600.0, 400.0).unwrap();
+    let mut second = Window::new("Untitled 2", 600.0, 400.0).unwrap();
+    assert_eq!(document.tabbing_mode(), TabbingMode::Automatic);
+
+    assert!(document.set_tabbing_mode(TabbingMode::Preferred).is_ok());
+    assert_eq!(document.tabbing_mode(), TabbingMode::Preferred);
+    assert!(document.add_tabbed_window(&second, WindowOrder::Above).is_ok());
+    assert!(document.add_tabbed_window(&document, WindowOrder::Above).is_err());
+
+    second.set_tabbing_mode(TabbingMode::Disallowed).unwrap();
+    assert!(document.add_tabbed_window(&second, WindowOrder::Below).is_err());
+}
+
+#[test]
+fn test_window_cascade_from() {
+    let first = Window::new("Document 1", 600.0, 400.0).unwrap();
+    let mut second = Window::new("Document 2", 600.0, 400.0).unwrap();
+    let mut third = Window::new("Document 3", 600.0, 400.0).unwrap();
+
+    second.cascade_from(&first).unwrap();
+    third.cascade_from(&second).unwrap();
+    assert_ne!(first.top_left(), second.top_left());
+    assert_ne!(second.top_left(), third.top_left());
+
+    // Each window steps right and down from the one before
+    assert!(second.top_left().x > first.top_left().x);
+    assert!(second.top_left().y < first.top_left().y);
+}
+
+#[test]
+fn test_window_screen_placement() {
+    use cocoanut::macos_features::Screen;
+
+    let window = Window::new("Placement", 400.0, 300.0).unwrap();
+    let screen = Screen::main().expect("a display is attached");
+    assert!(window.move_to_screen(&screen).is_ok());
+    assert!(window.center_on(&screen).is_ok());
+}
+
+#[test]
+fn test_window_frame_autosave_name() {
+    let mut window = Window::new("Autosave", 400.0, 300.0).unwrap();
+    assert_eq!(window.frame_autosave_name(), None);
+
+    assert!(window.set_frame_autosave_name("InspectorWindow").is_ok());
+    assert_eq!(window.frame_autosave_name(), Some("InspectorWindow"));
+    assert!(window.set_frame_autosave_name("").is_ok());
+    assert_eq!(window.frame_autosave_name(), None);
+
+    let window = Window::builder()
+        .title("Built")
+        .frame_autosave_name("BuiltWindow")
+        .build()
+        .unwrap();
+    assert_eq!(window.frame_autosave_name(), Some("BuiltWindow"));
+}
+
+#[test]
+fn test_window_save_and_restore_frame() {
+    let mut window = Window::new("Saved Frame Test", 500.0, 400.0).unwrap();
+    assert!(window.restore_frame().is_ok_and(|restored| !restored));
+    window.save_frame().unwrap();
+
+    window.set_size(640.0, 480.0).unwrap();
+    assert!(window.restore_frame().unwrap());
+    assert_eq!(window.size(), (500.0, 400.0));
+
+    let mut untitled = Window::new("", 300.0, 200.0).unwrap();
+    assert!(untitled.save_frame().is_err());
+    untitled.set_frame_autosave_name("Untitled").unwrap();
+    assert!(untitled.save_frame().is_ok());
+}
+
+/// A root view standing in for a laid-out stack
+struct RootView(*mut objc::runtime::Object);
+
+impl cocoanut::core::traits::Drawable for RootView {
+    fn as_view(&self) -> *mut objc::runtime::Object {
+        self.0
+    }
+
+    fn set_visible(&self, _visible: bool) -> Result<()> {
+        Ok(())
+    }
+
+    fn is_visible(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn test_window_set_content_view() {
+    let mut window = Window::new("Content View Test", 400.0, 300.0).unwrap();
+    assert!(window.content().is_none());
+    assert!(window.content_view().is_null());
+
+    // Mock builds never dereference the view pointer
+    let root = 0x1000 as *mut objc::runtime::Object;
+    window.set_content_view(Box::new(RootView(root))).unwrap();
+    assert_eq!(window.content_view(), root);
+    assert_eq!(window.content().map(|view| view.as_view()), Some(root));
+
+    let replacement = 0x2000 as *mut objc::runtime::Object;
+    window.set_content_view(Box::new(RootView(replacement))).unwrap();
+    assert_eq!(window.content_view(), replacement);
+}
+
+#[test]
+fn test_window_focus_and_key_view_order() {
+    let mut window = Window::new("Focus Test", 400.0, 300.0).unwrap();
+    assert_eq!(window.first_responder(), None);
+
+    // Mock builds never dereference the view pointers
+    let name = RootView(0x1000 as *mut objc::runtime::Object);
+    let email = RootView(0x2000 as *mut objc::runtime::Object);
+    let notes = RootView(0x3000 as *mut objc::runtime::Object);
+
+    window.set_key_view_order(&[&name, &email, &notes]).unwrap();
+    assert_eq!(window.key_view_order(), [name.0, email.0, notes.0]);
+
+    assert!(window.make_first_responder(&email).is_ok());
+    assert_eq!(window.first_responder(), Some(email.0));
+
+    assert!(window.set_key_view_order(&[]).is_err());
+    assert!(window.set_key_view_order(&[&name, &email, &name]).is_err());
+    assert_eq!(window.key_view_order().len(), 3);
+}